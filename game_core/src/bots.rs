@@ -0,0 +1,101 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    game_data::{
+        custom_types::{NodeID, PlayerID},
+        enums::{bot_difficulty::BotDifficulty, player_input_type::PlayerInputType},
+        structs::{gamestate::GameState, player_input::PlayerInput},
+    },
+    pathfinding::shortest_path_cost,
+    rule_checker::RuleChecker,
+};
+
+/// A trait for the bot players that fill the empty seats of a game. A bot plays through the same inputs as the clients of the players, and only chooses inputs the rule checker accepts.
+pub trait BotPlayer {
+    /// How well the bot plays.
+    fn difficulty(&self) -> BotDifficulty;
+
+    /// Returns the next input the bot with the given id makes in its turn, for the game with the staged actions applied.
+    /// The bot ends its turn when it has nothing better to do, so the game controller should keep asking until the turn has passed to another player.
+    fn next_input(&self, game: &GameState, bot_id: PlayerID, rule_checker: &dyn RuleChecker) -> PlayerInput;
+}
+
+/// Returns the bot that plays with the given difficulty.
+pub fn bot_for(difficulty: BotDifficulty) -> Box<dyn BotPlayer> {
+    Box::new(RouteBot { difficulty })
+}
+
+/// The RouteBot struct is a bot that moves towards where its objective card tells it to go, one legal move at a time.
+///
+/// How often it makes the move that brings it closest to its goal, instead of a random legal move, depends on its difficulty.
+pub struct RouteBot {
+    pub difficulty: BotDifficulty,
+}
+
+impl RouteBot {
+    /// Returns the node the player should move towards: the pick up node until the package is picked up, and then the drop off node. Returns `None` if the player has nowhere to go.
+    fn goal_node_id(game: &GameState, bot_id: PlayerID) -> Option<NodeID> {
+        let objective_card = game.get_player_with_unique_id(bot_id).ok()?.objective_card?;
+        match (objective_card.picked_package_up, objective_card.dropped_package_off) {
+            (false, _) => Some(objective_card.pick_up_node_id),
+            (true, false) => Some(objective_card.drop_off_node_id),
+            (true, true) => None,
+        }
+    }
+
+    /// Returns the moves to the neighbours of the node the player is at that the rule checker accepts.
+    fn valid_moves(game: &GameState, bot_id: PlayerID, rule_checker: &dyn RuleChecker) -> Vec<PlayerInput> {
+        let Some(position) = game.get_player_with_unique_id(bot_id).ok().and_then(|player| player.position_node_id) else {
+            return Vec::new();
+        };
+        game.map
+            .get_neighbour_relationships_of_node_with_id(position)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|neighbour| PlayerInput {
+                related_node_id: Some(neighbour.to),
+                edge_id: Some(neighbour.edge_id),
+                ..PlayerInput::new(bot_id, game.id, PlayerInputType::Movement)
+            })
+            .filter(|input| rule_checker.is_input_valid(game, input).is_none())
+            .collect()
+    }
+
+    /// Returns the valid move that brings the player closest to the goal, if any of them brings it closer than it is.
+    fn best_move<'a>(game: &GameState, bot_id: PlayerID, moves: &'a [PlayerInput]) -> Option<&'a PlayerInput> {
+        let goal_node_id = Self::goal_node_id(game, bot_id)?;
+        let position = game.get_player_with_unique_id(bot_id).ok()?.position_node_id?;
+        let distance_now = shortest_path_cost(game.map.base(), position, goal_node_id)?;
+        moves
+            .iter()
+            .filter_map(|input| Some((input, shortest_path_cost(game.map.base(), input.related_node_id?, goal_node_id)?)))
+            .filter(|(_, distance)| *distance < distance_now)
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(input, _)| input)
+    }
+}
+
+impl BotPlayer for RouteBot {
+    fn difficulty(&self) -> BotDifficulty {
+        self.difficulty
+    }
+
+    fn next_input(&self, game: &GameState, bot_id: PlayerID, rule_checker: &dyn RuleChecker) -> PlayerInput {
+        let mut rng = rand::thread_rng();
+        let moves = Self::valid_moves(game, bot_id, rule_checker);
+        if let Some(best_move) = Self::best_move(game, bot_id, &moves) {
+            if rng.gen_range(0..100) < self.difficulty.best_move_percent() {
+                return best_move.clone();
+            }
+            if let Some(random_move) = moves.choose(&mut rng) {
+                return random_move.clone();
+            }
+        }
+        let turn_inputs = [PlayerInputType::NextTurn, PlayerInputType::Pass].map(|input_type| PlayerInput::new(bot_id, game.id, input_type));
+        if let Some(turn_input) = turn_inputs.iter().find(|input| rule_checker.is_input_valid(game, input).is_none()) {
+            return turn_input.clone();
+        }
+        // The player may have to move more before the turn can end, even if it does not bring it closer to the goal.
+        moves.choose(&mut rng).map_or_else(|| PlayerInput::new(bot_id, game.id, PlayerInputType::NextTurn), Clone::clone)
+    }
+}
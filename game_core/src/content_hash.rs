@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+use crate::game_data::custom_types::StateHash;
+
+/// The offset basis and prime of the 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: StateHash = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: StateHash = 0x0000_0100_0000_01b3;
+
+/// Returns the 64-bit FNV-1a hash of the bytes, which is the same on every platform.
+pub fn fnv1a_hash(bytes: &[u8]) -> StateHash {
+    bytes
+        .iter()
+        .fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ StateHash::from(*byte)).wrapping_mul(FNV_PRIME))
+}
+
+/// Returns a hash of the content of the value, which is the same for every value with the same content.
+///
+/// The hash is made from the compact JSON of the value with the keys of every object sorted, so values like maps kept in hash maps always get the same hash. Used to tell exactly which content, like which version of a map, a log entry or a report is about. Will return an error if the value could not be serialized.
+pub fn content_hash<T: Serialize>(value: &T) -> Result<StateHash, String> {
+    let sorted_value = serde_json::to_value(value).map_err(|e| format!("Failed to serialize the content to hash it because: {e}"))?;
+    let bytes = serde_json::to_vec(&sorted_value).map_err(|e| format!("Failed to serialize the content to hash it because: {e}"))?;
+    Ok(fnv1a_hash(&bytes))
+}
@@ -0,0 +1,140 @@
+//! The content_pack_archive module contains the functions for reading and validating the content packs that can be uploaded to the server, and describes their format.
+//!
+//! A content pack is an uncompressed tar archive (for example made with `tar -cf pack.tar -C pack_folder .`) with this layout:
+//! - `manifest.json`: the [`ContentPackManifest`] with the name and version of the pack.
+//! - `maps/<name>.json` and `maps/<name>.geojson`: maps in the same formats as the map folder of the server, see the [`map_loader`] module.
+//! - `situation_cards/<any name>.json`: one [`SituationCard`] per file, including its objective cards.
+//! - `scenarios/<any name>.json`: one [`ScenarioTemplate`] per file.
+//! - `rule_scripts/<name>.rhai` and `rule_scripts/<situation card id>/<name>.rhai`: rule scripts, in the same layout as the `rule_scripts` folder of the server.
+//! - `localization/<language>.json`: an object with the texts of the pack in the language, by their key.
+//!
+//! Files in other places are ignored.
+//!
+//! [`map_loader`]: ../map_loader/index.html
+//! [`ContentPackManifest`]: ../game_data/structs/content_pack/struct.ContentPackManifest.html
+//! [`SituationCard`]: ../game_data/structs/situation_card/struct.SituationCard.html
+//! [`ScenarioTemplate`]: ../game_data/structs/scenario_template/struct.ScenarioTemplate.html
+
+use std::{collections::HashMap, io::Read};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    game_data::{constants::MAX_OBJECTIVE_DECK_SIZE, structs::{content_pack::{ContentPack, ContentPackManifest}, map_library::DEFAULT_MAP_NAME}},
+    map_loader::{parse_map, MAP_FILE_EXTENSIONS},
+    map_validation::validate_map,
+    situation_card_list::built_in_situation_card_list,
+};
+
+/// The name of the file in a content pack that describes the pack.
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Reads a content pack from a tar archive. Returns an error if the archive could not be read, or a file in it is not in the right format.
+pub fn read_content_pack(archive: &[u8]) -> Result<ContentPack, String> {
+    let mut manifest = None;
+    let mut maps = Vec::new();
+    let mut situation_cards = Vec::new();
+    let mut scenarios = Vec::new();
+    let mut rule_scripts = Vec::new();
+    let mut localizations = Vec::new();
+
+    let mut tar_archive = tar::Archive::new(archive);
+    let entries = tar_archive.entries().map_err(|e| format!("The content pack is not a tar archive because: {e}"))?;
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("The content pack could not be read because: {e}"))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry
+            .path()
+            .map_err(|e| format!("The content pack has a file with a path that is not valid: {e}"))?
+            .to_string_lossy()
+            .trim_start_matches("./")
+            .to_string();
+        let mut content = String::new();
+        entry.read_to_string(&mut content).map_err(|e| format!("The file {path} in the content pack could not be read because: {e}"))?;
+
+        let (folder, file_name) = path.split_once('/').unwrap_or(("", path.as_str()));
+        match folder {
+            "" if file_name == MANIFEST_FILE_NAME => manifest = Some(parse_json::<ContentPackManifest>(&path, &content)?),
+            "maps" => {
+                let map_name = MAP_FILE_EXTENSIONS.iter().find_map(|extension| file_name.strip_suffix(extension)?.strip_suffix('.'));
+                if let Some(name) = map_name {
+                    maps.push((name.to_string(), parse_map(&content).map_err(|e| format!("The file {path} in the content pack is not valid because: {e}"))?));
+                }
+            }
+            "situation_cards" if file_name.ends_with(".json") => situation_cards.push(parse_json(&path, &content)?),
+            "scenarios" if file_name.ends_with(".json") => scenarios.push(parse_json(&path, &content)?),
+            "rule_scripts" if file_name.ends_with(".rhai") => rule_scripts.push((file_name.to_string(), content)),
+            "localization" => {
+                if let Some(language) = file_name.strip_suffix(".json") {
+                    localizations.push((language.to_string(), parse_json::<HashMap<String, String>>(&path, &content)?));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let Some(manifest) = manifest else {
+        return Err(format!("The content pack has no {MANIFEST_FILE_NAME}!"));
+    };
+    Ok(ContentPack {
+        manifest,
+        maps,
+        situation_cards,
+        scenarios,
+        rule_scripts,
+        localizations,
+    })
+}
+
+/// Returns the issues of the content pack, not including the rule scripts which are checked by the rule checker.
+///
+/// The maps are validated like the maps in the map folder, the situation cards can not have the id of a built-in card or another card in the pack or more objective cards than an objective deck,
+/// and the scenarios have to use situation cards and maps that exist in the pack, are built in or are one of the given map names.
+pub fn validate_content_pack(pack: &ContentPack, other_map_names: &[String]) -> Vec<String> {
+    let mut issues = Vec::new();
+    if pack.manifest.name.trim().is_empty() {
+        issues.push("The content pack has no name!".to_string());
+    }
+
+    for (name, map) in pack.maps.iter() {
+        if name == DEFAULT_MAP_NAME {
+            issues.push(format!("The map name {DEFAULT_MAP_NAME} is used by the map built into the server!"));
+        }
+        issues.extend(validate_map(name, map).issues.into_iter().map(|issue| format!("The map {name} is not valid: {issue}")));
+    }
+
+    let built_in_card_ids: Vec<_> = built_in_situation_card_list().iter().map(|card| card.card_id).collect();
+    let mut card_ids = Vec::new();
+    for card in pack.situation_cards.iter() {
+        if built_in_card_ids.contains(&card.card_id) {
+            issues.push(format!("The situation card {} has the id {} which is used by a built-in situation card!", card.title, card.card_id));
+        } else if card_ids.contains(&card.card_id) {
+            issues.push(format!("There are several situation cards with the id {}!", card.card_id));
+        }
+        card_ids.push(card.card_id);
+        if card.objective_cards.len() > MAX_OBJECTIVE_DECK_SIZE {
+            issues.push(format!("The situation card {} has {} objective cards, but can not have more than {MAX_OBJECTIVE_DECK_SIZE}!", card.title, card.objective_cards.len()));
+        }
+    }
+
+    for scenario in pack.scenarios.iter() {
+        if let Some(card_id) = scenario.situation_card_id {
+            if !card_ids.contains(&card_id) && !built_in_card_ids.contains(&card_id) {
+                issues.push(format!("The scenario {} uses the situation card {card_id} which does not exist!", scenario.name));
+            }
+        }
+        if let Some(map_name) = &scenario.map_name {
+            let is_known_map = map_name == DEFAULT_MAP_NAME || pack.maps.iter().any(|(name, _)| name == map_name) || other_map_names.contains(map_name);
+            if !is_known_map {
+                issues.push(format!("The scenario {} uses the map {map_name} which does not exist!", scenario.name));
+            }
+        }
+    }
+    issues
+}
+
+fn parse_json<T: DeserializeOwned>(path: &str, content: &str) -> Result<T, String> {
+    serde_json::from_str(content).map_err(|e| format!("The file {path} in the content pack is not valid because: {e}"))
+}
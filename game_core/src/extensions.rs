@@ -0,0 +1,38 @@
+use crate::game_extension::GameExtension;
+
+use self::{achievement_extension::AchievementExtension, emissions_extension::EmissionsExtension, group_metrics_extension::GroupMetricsExtension, objective_reveal_extension::ObjectiveRevealExtension, scoreboard_extension::ScoreboardExtension, scoring_extension::ScoringExtension, statistics_extension::StatisticsExtension, win_condition_extension::WinConditionExtension};
+
+/// The achievement_extension module contains the AchievementExtension struct which hands out the achievements to the players.
+pub mod achievement_extension;
+/// The emissions_extension module contains the EmissionsExtension struct which adds the emissions of the car moves to the players.
+pub mod emissions_extension;
+/// The group_metrics_extension module contains the GroupMetricsExtension struct which measures how the whole group is doing against the group targets of a cooperative game.
+pub mod group_metrics_extension;
+/// The objective_reveal_extension module contains the ObjectiveRevealExtension struct which reveals the objective cards of all the players when the game ends.
+pub mod objective_reveal_extension;
+/// The scoreboard_extension module contains the ScoreboardExtension struct which tallies the scoreboard of the policy of the orchestrator.
+pub mod scoreboard_extension;
+/// The scoring_extension module contains the ScoringExtension struct which updates the scores of the players.
+pub mod scoring_extension;
+/// The statistics_extension module contains the StatisticsExtension struct which keeps the statistics of a game.
+pub mod statistics_extension;
+/// The win_condition_extension module contains the WinConditionExtension struct which finishes the game at the end of a turn when the win condition of the game is broken or the turns have run out.
+pub mod win_condition_extension;
+
+/// Returns the extensions the game controller uses by default.
+///
+/// The scoring and scoreboard extensions come after the emissions extension, since the scores depend on the emissions, and the objective reveal extension comes after the statistics extension, since the reveals include the statistics of the players.
+/// The win condition extension comes after the emissions extension too, since the win condition can depend on the emissions, and the group metrics extension comes after the statistics extension, since the commute times are counted from when the statistics were started.
+#[must_use]
+pub fn default_extensions() -> Vec<Box<dyn GameExtension + Send + Sync>> {
+    vec![
+        Box::new(EmissionsExtension),
+        Box::new(ScoringExtension),
+        Box::new(ScoreboardExtension),
+        Box::new(WinConditionExtension),
+        Box::new(AchievementExtension),
+        Box::new(StatisticsExtension),
+        Box::new(GroupMetricsExtension),
+        Box::new(ObjectiveRevealExtension),
+    ]
+}
@@ -0,0 +1,79 @@
+use crate::{game_data::{custom_types::PlayerID, enums::{achievement::Achievement, in_game_id::InGameID}, structs::{achievement_progress::AchievementProgress, game_event::GameEventKind, gamestate::GameState, movement_outcome::MovementOutcome}}, game_extension::GameExtension};
+
+/// The AchievementExtension hands out the [`Achievement`]s to the players as they earn them, and records an `AchievementEarned` event for each of them.
+///
+/// A player can only earn each achievement once in a game. The achievements of every round are added up on the leaderboard.
+///
+/// [`Achievement`]: ../../game_data/enums/achievement/enum.Achievement.html
+pub struct AchievementExtension;
+
+impl AchievementExtension {
+    fn award(game: &mut GameState, player_id: PlayerID, achievement: Achievement) -> Result<(), String> {
+        let Some(player) = game.players.iter_mut().find(|player| player.unique_id == player_id) else {
+            return Err(format!("There is no player with the id {} in the game!", player_id));
+        };
+        if player.achievements.contains(&achievement) {
+            return Ok(());
+        }
+        player.achievements.push(achievement);
+        game.record_event(GameEventKind::AchievementEarned(player_id, achievement));
+        Ok(())
+    }
+
+    fn award_zero_emission_turn(game: &mut GameState) -> Result<(), String> {
+        let progress = &game.achievement_progress;
+        if progress.moves_this_turn == 0 || progress.car_moves_this_turn > 0 {
+            return Ok(());
+        }
+        let current_role = game.current_players_turn;
+        let Some(player_id) = game.players.iter().find(|player| player.in_game_id == current_role).map(|player| player.unique_id) else {
+            return Ok(());
+        };
+        Self::award(game, player_id, Achievement::ZeroEmissionRound)
+    }
+}
+
+impl GameExtension for AchievementExtension {
+    fn name(&self) -> &str {
+        "AchievementExtension"
+    }
+
+    fn on_game_start(&self, game: &mut GameState) -> Result<(), String> {
+        game.achievement_progress = AchievementProgress::default();
+        Ok(())
+    }
+
+    fn on_movement(&self, game: &mut GameState, outcome: &MovementOutcome) -> Result<(), String> {
+        let driver = game.get_player_with_unique_id(outcome.player_id)?;
+        let by_bus = driver.is_bus && !outcome.by_rail;
+        let by_car = !driver.is_bus && !outcome.by_rail;
+        game.achievement_progress.moves_this_turn += 1;
+        if by_car {
+            game.achievement_progress.car_moves_this_turn += 1;
+        }
+        if by_bus {
+            game.achievement_progress.add_bus_move(outcome.player_id);
+        }
+        let first_to_objective_taken = game.players.iter().any(|player| player.achievements.contains(&Achievement::FirstToObjective));
+        if outcome.delivered_objective && !first_to_objective_taken {
+            Self::award(game, outcome.player_id, Achievement::FirstToObjective)?;
+        }
+        Ok(())
+    }
+
+    fn on_turn_end(&self, game: &mut GameState) -> Result<(), String> {
+        if game.current_players_turn != InGameID::Orchestrator {
+            Self::award_zero_emission_turn(game)?;
+        }
+        game.achievement_progress.end_turn();
+        Ok(())
+    }
+
+    fn on_game_end(&self, game: &mut GameState) -> Result<(), String> {
+        Self::award_zero_emission_turn(game)?;
+        for player_id in game.achievement_progress.most_bus_moves() {
+            Self::award(game, player_id, Achievement::MostBusTrips)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{game_data::{constants::CAR_EMISSIONS_PER_MOVE, custom_types::{Emissions, PlayerID}, structs::{gamestate::GameState, movement_outcome::MovementOutcome}}, game_extension::GameExtension};
+
+/// The EmissionsExtension adds the emissions of every car move to the players in the car.
+///
+/// The emissions of a move are shared equally between the driver and the passengers. Moves by bus or rail have no emissions.
+pub struct EmissionsExtension;
+
+impl GameExtension for EmissionsExtension {
+    fn name(&self) -> &str {
+        "EmissionsExtension"
+    }
+
+    fn on_movement(&self, game: &mut GameState, outcome: &MovementOutcome) -> Result<(), String> {
+        let driver = game.get_player_with_unique_id(outcome.player_id)?;
+        if outcome.by_rail || driver.is_bus {
+            return Ok(());
+        }
+
+        let is_in_car = |player_id: PlayerID, riding_with: Option<PlayerID>| player_id == outcome.player_id || riding_with == Some(outcome.player_id);
+        let occupants = game.players.iter().filter(|player| is_in_car(player.unique_id, player.riding_with)).count();
+        let emissions_share = CAR_EMISSIONS_PER_MOVE / Emissions::try_from(occupants).map_err(|e| e.to_string())?;
+        game.players
+            .iter_mut()
+            .filter(|player| is_in_car(player.unique_id, player.riding_with))
+            .for_each(|player| player.emissions += emissions_share);
+        Ok(())
+    }
+}
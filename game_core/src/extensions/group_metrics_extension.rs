@@ -0,0 +1,43 @@
+use crate::{game_data::structs::{gamestate::GameState, group_metrics::GroupMetrics}, game_extension::GameExtension};
+
+/// The GroupMetricsExtension measures the [`GroupMetrics`] of cooperative games when a round starts, at the end of every turn and when the game ends.
+///
+/// The games with other win conditions are not measured.
+///
+/// [`GroupMetrics`]: ../../game_data/structs/group_metrics/struct.GroupMetrics.html
+pub struct GroupMetricsExtension;
+
+impl GroupMetricsExtension {
+    /// Replaces the metrics of the current round with how the group is doing now.
+    fn measure(game: &mut GameState) {
+        if !game.lobby_settings.win_condition.is_cooperative() {
+            return;
+        }
+        let metrics = GroupMetrics::measure(game);
+        match game.group_metrics.last_mut() {
+            Some(last_metrics) if last_metrics.round_number == metrics.round_number => *last_metrics = metrics,
+            _ => game.group_metrics.push(metrics),
+        }
+    }
+}
+
+impl GameExtension for GroupMetricsExtension {
+    fn name(&self) -> &str {
+        "GroupMetricsExtension"
+    }
+
+    fn on_game_start(&self, game: &mut GameState) -> Result<(), String> {
+        Self::measure(game);
+        Ok(())
+    }
+
+    fn on_turn_end(&self, game: &mut GameState) -> Result<(), String> {
+        Self::measure(game);
+        Ok(())
+    }
+
+    fn on_game_end(&self, game: &mut GameState) -> Result<(), String> {
+        Self::measure(game);
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{game_data::structs::{gamestate::GameState, objective_reveal::ObjectiveReveal}, game_extension::GameExtension};
+
+/// The ObjectiveRevealExtension reveals the objective cards of all the players when the game ends, for the debrief after the game.
+///
+/// The reveals are cleared when the game is started again, so that they are only shown once the game is over.
+pub struct ObjectiveRevealExtension;
+
+impl GameExtension for ObjectiveRevealExtension {
+    fn name(&self) -> &str {
+        "ObjectiveRevealExtension"
+    }
+
+    fn on_game_start(&self, game: &mut GameState) -> Result<(), String> {
+        game.objective_reveals.clear();
+        Ok(())
+    }
+
+    fn on_game_end(&self, game: &mut GameState) -> Result<(), String> {
+        game.objective_reveals = ObjectiveReveal::reveal_all(game);
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{game_data::structs::{gamestate::GameState, scoreboard::Scoreboard}, game_extension::GameExtension};
+
+/// The ScoreboardExtension tallies the [`Scoreboard`] of the policy of the orchestrator at the end of every turn.
+///
+/// [`Scoreboard`]: ../../game_data/structs/scoreboard/struct.Scoreboard.html
+pub struct ScoreboardExtension;
+
+impl GameExtension for ScoreboardExtension {
+    fn name(&self) -> &str {
+        "ScoreboardExtension"
+    }
+
+    fn on_game_start(&self, game: &mut GameState) -> Result<(), String> {
+        game.scoreboard = Scoreboard::default();
+        Ok(())
+    }
+
+    fn on_turn_end(&self, game: &mut GameState) -> Result<(), String> {
+        let mut scoreboard = std::mem::take(&mut game.scoreboard);
+        scoreboard.record_turn(game);
+        game.scoreboard = scoreboard;
+        Ok(())
+    }
+}
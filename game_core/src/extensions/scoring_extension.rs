@@ -0,0 +1,37 @@
+use crate::{game_data::{constants::EMISSIONS_PER_LOST_POINT, custom_types::Score, enums::in_game_id::InGameID, structs::{gamestate::GameState, player::Player, player_objective_card::PlayerObjectiveCard}}, game_extension::GameExtension};
+
+/// The ScoringExtension updates the scores of the players at the end of every turn and when the game ends.
+///
+/// A player gets the points of the objective card for picking up and dropping off what the card asks for, and loses points for the emissions the player is responsible for.
+pub struct ScoringExtension;
+
+impl ScoringExtension {
+    fn score_of_player(player: &Player) -> Score {
+        let objective_score = player.objective_card.as_ref().map_or(0, PlayerObjectiveCard::earned_score);
+        let emissions_penalty = Score::try_from(player.emissions / EMISSIONS_PER_LOST_POINT).unwrap_or(Score::MAX);
+        objective_score.saturating_sub(emissions_penalty)
+    }
+
+    fn update_scores(game: &mut GameState) {
+        game.players
+            .iter_mut()
+            .filter(|player| player.in_game_id != InGameID::Orchestrator)
+            .for_each(|player| player.score = Self::score_of_player(player));
+    }
+}
+
+impl GameExtension for ScoringExtension {
+    fn name(&self) -> &str {
+        "ScoringExtension"
+    }
+
+    fn on_turn_end(&self, game: &mut GameState) -> Result<(), String> {
+        Self::update_scores(game);
+        Ok(())
+    }
+
+    fn on_game_end(&self, game: &mut GameState) -> Result<(), String> {
+        Self::update_scores(game);
+        Ok(())
+    }
+}
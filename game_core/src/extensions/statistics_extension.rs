@@ -0,0 +1,54 @@
+use crate::{game_data::{enums::player_input_type::PlayerInputType, structs::{game_statistics::GameStatistics, gamestate::GameState, player_input::PlayerInput}}, game_extension::GameExtension};
+
+/// The StatisticsExtension keeps the [`GameStatistics`] of a game up to date.
+///
+/// [`GameStatistics`]: ../../game_data/structs/game_statistics/struct.GameStatistics.html
+pub struct StatisticsExtension;
+
+impl GameExtension for StatisticsExtension {
+    fn name(&self) -> &str {
+        "StatisticsExtension"
+    }
+
+    fn on_game_start(&self, game: &mut GameState) -> Result<(), String> {
+        game.statistics = GameStatistics {
+            started_in_turn: game.turn_number,
+            ..GameStatistics::default()
+        };
+        Ok(())
+    }
+
+    fn on_input_applied(&self, game_before: &GameState, game: &mut GameState, input: &PlayerInput) -> Result<(), String> {
+        if input.input_type == PlayerInputType::Movement {
+            game.statistics.moves_made += 1;
+            let role = game.get_player_with_unique_id(input.player_id)?.in_game_id;
+            match game.statistics.moves_per_role.iter_mut().find(|(r, _)| *r == role) {
+                Some((_, moves)) => *moves += 1,
+                None => game.statistics.moves_per_role.push((role, 1)),
+            }
+        }
+        let turn_number = game.turn_number;
+        for player in game.players.iter() {
+            let had_delivered = game_before
+                .get_player_with_unique_id(player.unique_id)
+                .is_ok_and(|player_before| player_before.objective_card.as_ref().is_some_and(|objective_card| objective_card.dropped_package_off));
+            let has_delivered = player.objective_card.as_ref().is_some_and(|objective_card| objective_card.dropped_package_off);
+            if has_delivered && !had_delivered && game.statistics.objective_completion_turn_of_role(player.in_game_id).is_none() {
+                game.statistics.objective_completion_turns.push((player.in_game_id, turn_number));
+            }
+        }
+        let new_measures = game.measure_ledger.len().saturating_sub(game_before.measure_ledger.len());
+        game.statistics.measures_enacted += u32::try_from(new_measures).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    fn on_turn_end(&self, game: &mut GameState) -> Result<(), String> {
+        game.statistics.turns_played += 1;
+        Ok(())
+    }
+
+    fn on_game_end(&self, game: &mut GameState) -> Result<(), String> {
+        game.statistics.ended_in_turn = Some(game.turn_number);
+        Ok(())
+    }
+}
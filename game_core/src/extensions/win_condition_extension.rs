@@ -0,0 +1,18 @@
+use crate::{game_data::structs::gamestate::GameState, game_extension::GameExtension, win_conditions::check_game_status_at_turn_end};
+
+/// The WinConditionExtension checks the win condition of the game at the end of every turn, and finishes the game if it's broken or the turns have run out.
+/// See [`check_game_status_at_turn_end`].
+///
+/// [`check_game_status_at_turn_end`]: ../../win_conditions/fn.check_game_status_at_turn_end.html
+pub struct WinConditionExtension;
+
+impl GameExtension for WinConditionExtension {
+    fn name(&self) -> &str {
+        "WinConditionExtension"
+    }
+
+    fn on_turn_end(&self, game: &mut GameState) -> Result<(), String> {
+        game.status = check_game_status_at_turn_end(game);
+        Ok(())
+    }
+}
@@ -1,27 +1,73 @@
 use std::{
     any::type_name,
-    sync::{Arc, RwLock},
-    time::{Instant},
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
-use logging::logger::{LogData, LogLevel, Logger};
+use logging::{log_sender::LogSender, logger::{LogData, LogLevel}};
 
 use crate::{
-    rule_checker::RuleChecker, game_data::{structs::{gamestate::GameState, new_game_info::NewGameInfo, player_input::PlayerInput, player::Player, situation_card_list::SituationCardList}, custom_types::{GameID, PlayerID, NodeID}, enums::{player_input_type::PlayerInputType}, constants::PLAYER_TIMEOUT},
+    bots::bot_for, rule_checker::RuleChecker, win_conditions, replay::{ReplayLog, ReplayedGame}, replication::{StateReplicator, ReplicationEntry}, update_publisher::UpdatePublisher, game_extension::GameExtension, extensions::default_extensions, content_pack_archive::{read_content_pack, validate_content_pack}, objective_deck_validation::validate_objective_deck, situation_card_list::set_content_pack_situation_cards, game_data::{structs::{access_tokens::AccessTokens, content_pack::{ContentPack, ContentPackReport}, objective_deck::{ObjectiveDeck, ObjectiveDeckReport}, scenario_template::ScenarioTemplate, campaign::Campaign, text_filter::TextFilter, gamestate::GameState, game_summary::GameSummary, leaderboard::Leaderboard, render_model::RenderModel, game_overview::GameOverview, game_comparison::{GameComparison, GameReference}, game_snapshot::GameSnapshot, game_history::GameHistory, new_game_info::NewGameInfo, player_input::PlayerInput, quarantined_input::QuarantinedInput, local_players::{LocalPlayers, LocalPlayersView, PlayerView}, player_view_cache::PlayerViewCache, demo_mode::DemoMode, game_event::GameEventKind, rule_override::RuleOverride, rule_violation::RuleViolation, player::Player, situation_card_list::SituationCardList, map_library::{MapLibrary, DEFAULT_MAP_NAME}, game_map::GameMap, map_overlay::MapOverlay, node_map::NodeMap, session_clock::current_timestamp, turn_audit::{ActionAudit, TurnAudit}, input_record::{InputOutcome, InputRecord}, movement_outcome::MovementOutcome, move_plan::{MovePlanExecution, PlannedMove}, input_batch::InputBatchExecution, objective_progress::ObjectiveProgress, player_connection::PlayerConnection, player_session::{DisconnectedPlayer, PlayerSession}, chat_message::ChatMessage, bot_seat::BotSeat}, custom_types::{ChatMessageID, GameID, PlayerID, NodeID, StateVersion, ControlToken, SessionToken, TurnNumber, Timestamp, Milliseconds}, enums::{access_level::AccessLevel, connection_quality::ConnectionQuality, game_status::GameStatus, in_game_id::InGameID, export_format::ExportFormat, player_input_type::PlayerInputType, state_section::StateSection, text_kind::TextKind}, constants::{PLAYER_TIMEOUT, INACTIVITY_WARNING_PERCENT, MAX_PLANNED_MOVES, MAX_INPUT_BATCH_SIZE, MAX_BOT_INPUTS_IN_A_ROW, MAX_STORED_TURN_SNAPSHOTS, QUARANTINED_INPUTS_FOLDER}},
 };
 
 /// The GameController struct is the game manager and is what should be used to control all of the games on the server. It has all the neccessary functions to create and handle games.
 pub struct GameController {
-    pub games: Vec<GameState>,
+    /// The games on the server by their ids. A game is shared with the snapshots of it that have been handed out, and is only copied if it's changed while one of them is still in use.
+    pub games: HashMap<GameID, Arc<GameState>>,
+    /// The copies of the games that are kept to go back in them, by the ids of the games. They are kept apart from the games so that they are not copied with them.
+    histories: HashMap<GameID, GameHistory>,
     pub unique_ids: Vec<(PlayerID, Instant)>,
-    pub logger: Arc<RwLock<dyn Logger + Send + Sync>>,
+    /// Sends the log entries to the logging thread, so that logging never blocks the games.
+    pub logger: LogSender,
     pub rule_checker: Box<dyn RuleChecker + Send + Sync>,
+    pub replicators: Vec<Box<dyn StateReplicator + Send + Sync>>,
+    /// The publishers that get the new state of a game every time an input to it succeeds, so that it can be pushed to the clients of its players.
+    pub update_publishers: Vec<Box<dyn UpdatePublisher + Send + Sync>>,
+    /// The extensions whose hooks are called while the games are played, in order. Scoring, emissions and statistics are registered by default.
+    pub extensions: Vec<Box<dyn GameExtension + Send + Sync>>,
+    /// The players that are played from the same client (hot-seat play), grouped by their control token.
+    pub local_players: Vec<LocalPlayers>,
+    /// The ids of the games that were ended by a server reset, used to tell the clients why their game is gone.
+    pub ended_game_ids: Vec<GameID>,
+    /// The maps the server has loaded and validated. Only contains the default map unless other maps are loaded with [`GameController::set_map_library`].
+    pub map_library: MapLibrary,
+    /// The tokens that are allowed to use the server. Access control is disabled unless other tokens are set with [`GameController::set_access_tokens`].
+    pub access_tokens: AccessTokens,
+    /// The valid content packs that have been uploaded since the server started.
+    pub content_packs: Vec<ContentPack>,
+    /// The name of the content pack whose maps, situation cards, scenarios, rule scripts and texts are used, if any.
+    pub active_content_pack: Option<String>,
+    /// Cleans up and checks the names and other texts the players send before they reach the games.
+    pub text_filter: TextFilter,
+    /// The objective decks facilitators have uploaded, which lobbies can choose to deal the objective cards from.
+    pub objective_decks: Vec<ObjectiveDeck>,
+    /// The legal nodes computed for the players in the latest versions of the games, so that players polling an unchanged game do not make the server compute them again.
+    pub player_view_cache: PlayerViewCache,
+    /// The limits of the server if it runs the public demo, see [`GameController::set_demo_mode`]. The server has no such limits if it's `None`.
+    pub demo_mode: Option<DemoMode>,
+    /// The ids of the games that were removed because nobody had sent an input to them for too long, used to tell the clients why their game is gone.
+    pub expired_game_ids: Vec<GameID>,
+    /// How often and when each player last checked in, used to tell the facilitators about the players whose connection is flaky or stale.
+    pub player_connections: Vec<PlayerConnection>,
+    /// The sessions of the player ids, which let the players whose ids were removed for being inactive reconnect to their games.
+    pub player_sessions: Vec<PlayerSession>,
+    /// How long a player can go without checking in before the id of the player is removed. [`PLAYER_TIMEOUT`](../game_data/constants/constant.PLAYER_TIMEOUT.html) unless another timeout is set with [`GameController::set_player_timeout`].
+    pub player_timeout: Duration,
+    /// The id of the game each player is in, so that the game of a player is found without going through the players of every game.
+    player_games: HashMap<PlayerID, GameID>,
 }
 
 macro_rules! log {
     ($logger:expr, $level:expr, $message:expr) => {
-        if let Ok(mut logger) = $logger.write() {
-            logger.log(LogData::new($level, $message, type_name::<Self>()));
+        $logger.log(LogData::new($level, $message, type_name::<Self>()))
+    };
+    ($logger:expr, $level:expr, $message:expr, $game:expr) => {
+        {
+            let references = $game.log_references();
+            $logger.log(LogData::new($level, $message, type_name::<Self>()).with_references(&references));
         }
     };
 }
@@ -29,21 +75,398 @@ macro_rules! log {
 impl GameController {
     /// Creates a new game and assigns the host to the game.
     pub fn new(
-        logger: Arc<RwLock<dyn Logger + Send + Sync>>,
+        logger: LogSender,
         rule_checker: Box<dyn RuleChecker + Send + Sync>,
     ) -> Self {
         Self {
-            games: Vec::new(),
+            games: HashMap::new(),
+            histories: HashMap::new(),
             unique_ids: Vec::new(),
             logger,
             rule_checker,
+            replicators: Vec::new(),
+            update_publishers: Vec::new(),
+            extensions: default_extensions(),
+            local_players: Vec::new(),
+            ended_game_ids: Vec::new(),
+            map_library: MapLibrary::default(),
+            access_tokens: AccessTokens::default(),
+            content_packs: Vec::new(),
+            active_content_pack: None,
+            text_filter: TextFilter::default(),
+            objective_decks: Vec::new(),
+            player_view_cache: PlayerViewCache::default(),
+            demo_mode: None,
+            expired_game_ids: Vec::new(),
+            player_connections: Vec::new(),
+            player_sessions: Vec::new(),
+            player_timeout: PLAYER_TIMEOUT,
+            player_games: HashMap::new(),
+        }
+    }
+
+    /// Adds a replicator that every change to the games and player ids are sent to, for example so that a standby server or a storage backend can keep a copy of them.
+    pub fn add_replicator(&mut self, replicator: Box<dyn StateReplicator + Send + Sync>) {
+        self.replicators.push(replicator);
+    }
+
+    /// Adds a publisher that gets the new state of a game every time an input to it succeeds, for example to push it to the clients of the players over WebSockets.
+    pub fn add_update_publisher(&mut self, update_publisher: Box<dyn UpdatePublisher + Send + Sync>) {
+        self.update_publishers.push(update_publisher);
+    }
+
+    /// Adds an extension whose hooks are called while the games are played, after the extensions that are already added.
+    pub fn add_extension(&mut self, extension: Box<dyn GameExtension + Send + Sync>) {
+        self.extensions.push(extension);
+    }
+
+    /// Replaces the maps the server can use, for example with the maps loaded from the maps folder when the server starts.
+    pub fn set_map_library(&mut self, map_library: MapLibrary) {
+        self.map_library = map_library;
+    }
+
+    /// Sets the filter used on the names and other texts the players send, replacing the one that was set before.
+    pub fn set_text_filter(&mut self, text_filter: TextFilter) {
+        self.text_filter = text_filter;
+    }
+
+    /// Limits the server so that it can be left open on the internet for the public demo: only a few games can exist at the same time, and the games nobody has sent an input to for a while are removed.
+    pub fn set_demo_mode(&mut self, demo_mode: DemoMode) {
+        log!(self.logger, LogLevel::Info, format!("Running in demo mode with at most {} games that are removed after {} minutes without inputs", demo_mode.max_games, demo_mode.game_expiry / 60_000).as_str());
+        self.demo_mode = Some(demo_mode);
+    }
+
+    /// Sets how long a player can go without checking in before the id of the player is removed. The player is asked whether they are still there when [`INACTIVITY_WARNING_PERCENT`](../game_data/constants/constant.INACTIVITY_WARNING_PERCENT.html) of the timeout has passed.
+    pub fn set_player_timeout(&mut self, player_timeout: Duration) {
+        log!(self.logger, LogLevel::Info, format!("Removing the ids of the players that have not checked in for {} seconds", player_timeout.as_secs()).as_str());
+        self.player_timeout = player_timeout;
+    }
+
+    /// Sets the tokens that are allowed to use the server, replacing the ones that were set before.
+    pub fn set_access_tokens(&mut self, access_tokens: AccessTokens) {
+        self.access_tokens = access_tokens;
+    }
+
+    /// Reads and validates a content pack without keeping it. Returns an error if the archive could not be read, and the issues of the pack in the report otherwise.
+    pub fn validate_content_pack(&self, archive: &[u8]) -> Result<ContentPackReport, String> {
+        let pack = read_content_pack(archive)?;
+        Ok(self.content_pack_report(&pack))
+    }
+
+    /// Reads and validates a content pack, and keeps it so that it can be activated if it has no issues. A pack with the same name as a pack that is kept already replaces it, unless that pack is active.
+    /// Returns an error if the archive could not be read, and the issues of the pack in the report otherwise.
+    pub fn upload_content_pack(&mut self, archive: &[u8]) -> Result<ContentPackReport, String> {
+        let pack = read_content_pack(archive)?;
+        let mut report = self.content_pack_report(&pack);
+        if report.is_active {
+            report.issues.push(format!("The content pack {} is active and can therefore not be replaced!", pack.manifest.name));
+        }
+        if !report.is_valid() {
+            log!(self.logger, LogLevel::Warning, format!("The content pack {} was not kept because: {}", pack.manifest.name, report.issues.join(" ")).as_str());
+            return Ok(report);
+        }
+        log!(self.logger, LogLevel::Info, format!("Keeping the content pack {} version {}", pack.manifest.name, pack.manifest.version).as_str());
+        self.content_packs.retain(|existing_pack| existing_pack.manifest.name != pack.manifest.name);
+        self.content_packs.push(pack);
+        Ok(report)
+    }
+
+    /// Returns the reports of all the content packs that are kept.
+    pub fn content_pack_reports(&self) -> Vec<ContentPackReport> {
+        self.content_packs.iter().map(|pack| self.content_pack_report(pack)).collect()
+    }
+
+    /// Starts using the maps, situation cards, scenarios, rule scripts and texts of the content pack with the given name, instead of the ones from the pack that was active before.
+    /// The games that are already created keep their situation card and map, but should not use a situation card from the pack that was active before since it can no longer be found.
+    pub fn activate_content_pack(&mut self, name: &str) -> Result<ContentPackReport, String> {
+        let Some(pack) = self.content_packs.iter().find(|pack| pack.manifest.name == name).cloned() else {
+            log!(self.logger, LogLevel::Error, format!("There is no content pack called {name} and it can therefore not be activated!").as_str());
+            return Err(format!("There is no content pack called {name}!"));
+        };
+        let mut report = self.content_pack_report(&pack);
+        if !report.is_valid() {
+            return Err(format!("The content pack {name} can not be activated because: {}", report.issues.join(" ")));
+        }
+        self.rule_checker.set_content_pack_rules(&pack.rule_scripts)?;
+        self.map_library.set_content_pack_maps(pack.maps.clone());
+        set_content_pack_situation_cards(pack.situation_cards.clone());
+        self.active_content_pack = Some(pack.manifest.name.clone());
+        self.player_view_cache.clear();
+        log!(self.logger, LogLevel::Info, format!("Activated the content pack {} version {}", pack.manifest.name, pack.manifest.version).as_str());
+        report.is_active = true;
+        Ok(report)
+    }
+
+    /// Returns the scenarios of the active content pack.
+    pub fn get_scenarios(&self) -> Vec<ScenarioTemplate> {
+        self.get_active_content_pack().map(|pack| pack.scenarios.clone()).unwrap_or_default()
+    }
+
+    /// Returns the texts of the active content pack in the given language. Returns an error if there is no active pack or it has no texts in the language.
+    pub fn get_localization(&self, language: &str) -> Result<HashMap<String, String>, String> {
+        let Some(pack) = self.get_active_content_pack() else {
+            return Err("There is no active content pack!".to_string());
+        };
+        pack.localizations
+            .iter()
+            .find(|(pack_language, _)| pack_language == language)
+            .map(|(_, texts)| texts.clone())
+            .ok_or_else(|| format!("The content pack {} has no texts in the language {language}!", pack.manifest.name))
+    }
+
+    fn get_active_content_pack(&self) -> Option<&ContentPack> {
+        let name = self.active_content_pack.as_ref()?;
+        self.content_packs.iter().find(|pack| pack.manifest.name == *name)
+    }
+
+    fn content_pack_report(&self, pack: &ContentPack) -> ContentPackReport {
+        let is_active = self.active_content_pack.as_ref().is_some_and(|name| *name == pack.manifest.name);
+        let mut report = ContentPackReport::new(pack, is_active);
+        // The maps of the active pack are in the map library, so they would clash with themselves.
+        let other_map_names: Vec<String> = self
+            .map_library
+            .map_names()
+            .into_iter()
+            .filter(|name| !self.get_active_content_pack().is_some_and(|active_pack| active_pack.maps.iter().any(|(map_name, _)| map_name == name)))
+            .collect();
+        report.issues = validate_content_pack(pack, &other_map_names);
+        let clashing_maps = pack
+            .maps
+            .iter()
+            .filter(|(name, _)| name != DEFAULT_MAP_NAME && other_map_names.contains(name))
+            .map(|(name, _)| format!("There is already a map called {name}!"));
+        report.issues.extend(clashing_maps);
+        if let Some(e) = self.rule_checker.check_content_pack_rules(&pack.rule_scripts) {
+            report.issues.push(e);
+        }
+        report
+    }
+
+    /// Validates the objective deck against the default map without keeping it. Returns an error if the default map could not be found.
+    pub fn validate_objective_deck(&self, deck: &ObjectiveDeck) -> Result<ObjectiveDeckReport, String> {
+        let map = self.map_library.get_map(DEFAULT_MAP_NAME)?;
+        Ok(validate_objective_deck(deck, &map))
+    }
+
+    /// Validates the objective deck and keeps it so that lobbies can choose it if it has no issues. A deck with the same name as a deck that is kept already replaces it.
+    /// The games that already use the replaced deck keep their copy of it.
+    pub fn upload_objective_deck(&mut self, mut deck: ObjectiveDeck) -> Result<ObjectiveDeckReport, String> {
+        deck.name = deck.name.trim().to_string();
+        let report = self.validate_objective_deck(&deck)?;
+        if !report.is_valid() {
+            log!(self.logger, LogLevel::Warning, format!("The objective deck {} was not kept because: {}", deck.name, report.issues.join(" ")).as_str());
+            return Ok(report);
+        }
+        log!(self.logger, LogLevel::Info, format!("Keeping the objective deck {} with {} cards", deck.name, deck.cards.len()).as_str());
+        self.objective_decks.retain(|existing_deck| existing_deck.name != deck.name);
+        self.objective_decks.push(deck);
+        Ok(report)
+    }
+
+    /// Removes the objective deck with the given name, so that new lobbies can no longer choose it. Returns an error if there is no such deck.
+    pub fn delete_objective_deck(&mut self, name: &str) -> Result<(), String> {
+        if !self.objective_decks.iter().any(|deck| deck.name == name) {
+            return Err(format!("There is no objective deck called {name}!"));
+        }
+        log!(self.logger, LogLevel::Info, format!("Removing the objective deck {name}").as_str());
+        self.objective_decks.retain(|deck| deck.name != name);
+        Ok(())
+    }
+
+    /// Creates a new access token with the given access level. Returns an error if access control is disabled.
+    pub fn issue_access_token(&mut self, level: AccessLevel) -> Result<String, String> {
+        log!(self.logger, LogLevel::Info, format!("Issuing a new access token with the {:?} access level", level).as_str());
+        self.access_tokens.issue_token(level)
+    }
+
+    /// Restores a game that was saved earlier together with its history, including the ids of its players. The players are checked in now so that they have time to reconnect.
+    /// Will return an error, and leave the game out, if the map the game is played on is not in the map library, like a map of a content pack that has not been uploaded since the server was restarted.
+    pub fn restore_game(&mut self, snapshot: GameSnapshot, history: GameHistory) -> Result<(), String> {
+        log!(self.logger, LogLevel::Info, format!("Restoring game with id: {}", snapshot.game.id).as_str());
+        let base_map = self.base_map_of(&snapshot.game)?;
+        for player in snapshot.game.players.iter() {
+            match self.unique_ids.iter_mut().find(|(id, _)| id == &player.unique_id) {
+                Some(id) => id.1 = Instant::now(),
+                None => self.unique_ids.push((player.unique_id, Instant::now())),
+            }
+            self.reset_connection(player.unique_id, current_timestamp());
+        }
+        self.histories.insert(snapshot.game.id, history);
+        self.insert_game(snapshot.into_game_state_on(base_map));
+        Ok(())
+    }
+
+    /// Restores all the games that were saved earlier, like [`GameController::restore_game`] does for one game.
+    /// Returns the ids of the games that could not be restored and why, so that their saves can be quarantined.
+    pub fn load_all(&mut self, saved_games: Vec<(GameSnapshot, GameHistory)>) -> Vec<(GameID, String)> {
+        log!(self.logger, LogLevel::Info, format!("Restoring {} saved games", saved_games.len()).as_str());
+        saved_games
+            .into_iter()
+            .filter_map(|(snapshot, history)| {
+                let game_id = snapshot.game.id;
+                self.restore_game(snapshot, history).err().map(|reason| (game_id, reason))
+            })
+            .collect()
+    }
+
+    /// Flushes every game to the replicators (see [`StateReplicator::flush`]), so that a storage backend or a standby server gets a full copy of the games.
+    /// The replicators already get every change made through the inputs, but this also covers what changes without an input, like the session clocks and the connections of the players.
+    pub fn save_all(&self) {
+        log!(self.logger, LogLevel::Debug, format!("Saving all the {} games", self.games.len()).as_str());
+        for game in self.games.values() {
+            let entry = ReplicationEntry::GameUpdated(Box::new(GameSnapshot::new(game)));
+            self.replicators.iter().for_each(|replicator| replicator.flush(entry.clone()));
+        }
+    }
+
+    /// Applies a change that was replicated from another (primary) server.
+    pub fn apply_replication_entry(&mut self, entry: ReplicationEntry) {
+        log!(self.logger, LogLevel::Debug, format!("Applying replication entry: {:?}", entry).as_str());
+        match entry {
+            ReplicationEntry::PlayerIdAdded(id) => {
+                if self.unique_ids.iter().all(|(l_id, _)| l_id != &id) {
+                    self.unique_ids.push((id, Instant::now()));
+                }
+            }
+            ReplicationEntry::PlayerIdRemoved(id) => {
+                self.unique_ids.retain(|(l_id, _)| l_id != &id);
+                if let Some(game) = self.player_games.remove(&id).and_then(|game_id| self.games.get_mut(&game_id)) {
+                    Arc::make_mut(game).players.retain(|player| player.unique_id != id);
+                }
+                self.local_players.iter_mut().for_each(|local_players| {
+                    local_players.player_ids.retain(|player_id| player_id != &id);
+                });
+            }
+            ReplicationEntry::GameUpdated(snapshot) => match self.base_map_of(&snapshot.game) {
+                Ok(base_map) => self.insert_game(snapshot.into_game_state_on(base_map)),
+                Err(e) => log!(self.logger, LogLevel::Error, format!("The replicated game with id {} is not kept because: {}", snapshot.game.id, e).as_str()),
+            },
+            ReplicationEntry::GameRemoved(id) => self.remove_game(id),
+            ReplicationEntry::GameHistoryUpdated(id, history) => {
+                self.histories.insert(id, *history);
+            }
+            ReplicationEntry::ReplayEntryAppended(id, replay_entry) => match self.histories.get_mut(&id) {
+                Some(history) if history.replay_log.has_started() => history.replay_log.entries.push(*replay_entry),
+                _ => log!(self.logger, LogLevel::Warning, format!("The replay entry {} of the game with id {} is left out, since the replay log of the game has not been replicated", replay_entry.sequence_number, id).as_str()),
+            },
+            ReplicationEntry::LocalPlayersUpdated(local_players) => {
+                match self.local_players.iter_mut().find(|l| l.control_token == local_players.control_token) {
+                    Some(existing_local_players) => *existing_local_players = local_players,
+                    None => self.local_players.push(local_players),
+                }
+            }
+            ReplicationEntry::ServerReset => {
+                self.clear_games_and_ids();
+            }
         }
     }
 
-    /// Gets all the created games on the server.
-    pub fn get_created_games(&mut self) -> Vec<GameState> {
+    /// Ends all the games and forgets all the player ids and control tokens, so that the server can be used for a new session without being restarted.
+    /// Clients that ask for one of the ended games are told that the server was reset. Returns the amount of games that were ended.
+    pub fn reset_server(&mut self) -> usize {
+        let ended_games = self.clear_games_and_ids();
+        self.replicate(ReplicationEntry::ServerReset);
+        log!(self.logger, LogLevel::Info, format!("Reset the server and ended {} games!", ended_games).as_str());
+        ended_games
+    }
+
+    fn clear_games_and_ids(&mut self) -> usize {
+        let ended_games = self.games.len();
+        self.ended_game_ids.extend(self.games.keys().copied());
+        self.games.clear();
+        self.histories.clear();
+        self.player_games.clear();
+        self.player_view_cache.clear();
+        self.unique_ids.clear();
+        self.player_connections.clear();
+        self.player_sessions.clear();
+        self.local_players.clear();
+        ended_games
+    }
+
+    /// Marks all the player ids as checked in now. This should be used when a standby server takes over, so that the players have time to reconnect before they are removed for being inactive.
+    pub fn refresh_all_check_ins(&mut self) {
+        log!(self.logger, LogLevel::Info, "Refreshing the check in of all player ids!");
+        self.unique_ids
+            .iter_mut()
+            .for_each(|id| id.1 = Instant::now());
+        let now = current_timestamp();
+        self.player_connections
+            .iter_mut()
+            .for_each(|connection| *connection = PlayerConnection::new(connection.player_id, now));
+    }
+
+    fn replicate(&self, entry: ReplicationEntry) {
+        self.replicators
+            .iter()
+            .for_each(|replicator| replicator.replicate(entry.clone()));
+    }
+
+    fn replicate_game_with_id(&self, game_id: GameID) {
+        if let Some(game) = self.game(game_id) {
+            Self::replicate_game(&self.replicators, game);
+        }
+    }
+
+    /// Returns the game with the given id, if there is one.
+    fn game(&self, game_id: GameID) -> Option<&GameState> {
+        self.games.get(&game_id).map(Arc::as_ref)
+    }
+
+    /// Adds the game, or replaces the game with the same id, and indexes the players in it.
+    fn insert_game(&mut self, game: GameState) {
+        let game_id = game.id;
+        self.games.insert(game_id, Arc::new(game));
+        self.index_players_of_game(game_id);
+    }
+
+    /// Removes the game with the given id and its history, and the players in it from the index.
+    fn remove_game(&mut self, game_id: GameID) {
+        self.games.remove(&game_id);
+        self.histories.remove(&game_id);
+        self.index_players_of_game(game_id);
+    }
+
+    /// Indexes the players of the game with the given id again, after players have joined or left it.
+    fn index_players_of_game(&mut self, game_id: GameID) {
+        self.player_games.retain(|_, indexed_game_id| *indexed_game_id != game_id);
+        if let Some(game) = self.games.get(&game_id) {
+            self.player_games.extend(game.players.iter().map(|player| (player.unique_id, game_id)));
+        }
+    }
+
+    /// Returns the games ordered by their ids, as snapshots that share the games with the controller instead of copying them.
+    fn game_snapshots(&self, filter: impl Fn(&GameState) -> bool) -> Vec<Arc<GameState>> {
+        let mut games: Vec<Arc<GameState>> = self.games.values().filter(|game| filter(game)).cloned().collect();
+        games.sort_by_key(|game| game.id);
+        games
+    }
+
+    /// Sends the history of the game with the given id to the replicators. It's only sent when more than the replay log has changed, since it has many copies of the game.
+    fn replicate_history(replicators: &[Box<dyn StateReplicator + Send + Sync>], game_id: GameID, history: &GameHistory) {
+        if replicators.is_empty() {
+            return;
+        }
+        let entry = ReplicationEntry::GameHistoryUpdated(game_id, Box::new(history.clone()));
+        replicators
+            .iter()
+            .for_each(|replicator| replicator.replicate(entry.clone()));
+    }
+
+    fn replicate_game(replicators: &[Box<dyn StateReplicator + Send + Sync>], game: &GameState) {
+        if replicators.is_empty() {
+            return;
+        }
+        let entry = ReplicationEntry::GameUpdated(Box::new(GameSnapshot::new(game)));
+        replicators
+            .iter()
+            .for_each(|replicator| replicator.replicate(entry.clone()));
+    }
+
+    /// Gets all the created games on the server, ordered by their ids.
+    pub fn get_created_games(&mut self) -> Vec<Arc<GameState>> {
         self.remove_empty_games();
-        self.games.clone()
+        self.game_snapshots(|_| true)
     }
 
     /// Generates a new unique id that a player can use and returns it, but also puts it in the list of unique ids that the controller has.
@@ -58,14 +481,41 @@ impl GameController {
         };
 
         self.unique_ids.push((new_id, Instant::now()));
+        self.reset_connection(new_id, current_timestamp());
+        self.replicate(ReplicationEntry::PlayerIdAdded(new_id));
 
         log!(self.logger, LogLevel::Debug, format!("Made unique ID: {}", new_id).as_str());
         
         Ok(new_id)
     }
 
+    /// Makes the session token of the player id, which lets the player reconnect with [`GameController::reconnect_player`] if the id is removed for being inactive.
+    /// The token is only handed out once, before the id has joined a game, since the ids of the players in a game can be seen by everyone and anyone could otherwise get the token of another player.
+    pub fn create_session_token(&mut self, player_id: PlayerID) -> Result<SessionToken, String> {
+        log!(self.logger, LogLevel::Debug, format!("Making the session token of player with id: {}", player_id).as_str());
+        if self.unique_ids.iter().all(|(id, _)| *id != player_id) {
+            log!(self.logger, LogLevel::Error, format!("Player with id {} does not exist and can therefore not get a session token!", player_id).as_str());
+            return Err(format!("Player with id {} does not exist!", player_id));
+        }
+        if self.player_sessions.iter().any(|session| session.player_id == player_id) {
+            log!(self.logger, LogLevel::Warning, format!("The session token of player with id {} was asked for again and is not handed out", player_id).as_str());
+            return Err(format!("The session token of player with id {} has already been handed out!", player_id));
+        }
+        if self.player_games.contains_key(&player_id) {
+            log!(self.logger, LogLevel::Warning, format!("Player with id {} asked for a session token after joining a game and does not get one", player_id).as_str());
+            return Err(format!("Player with id {} is already in a game and can no longer get a session token!", player_id));
+        }
+        // The token is kept below 2^53, so that JavaScript clients can read it as a number.
+        let token = rand::random::<SessionToken>() >> 11;
+        self.player_sessions.push(PlayerSession::new(player_id, token));
+        Ok(token)
+    }
+
     /// Creates a new game based and assigns the host (the one who requested to create a game) to the game.
-    pub fn create_new_game(&mut self, new_lobby: NewGameInfo) -> Result<GameState, String> {
+    pub fn create_new_game(&mut self, mut new_lobby: NewGameInfo) -> Result<GameState, String> {
+        self.sanitize_new_game_info(&mut new_lobby)?;
+        self.expire_inactive_games();
+        self.check_room_for_new_game()?;
         let new_game = match self.create_new_game_and_assign_host(new_lobby) {
             Ok(game) => game,
             Err(e) => {
@@ -73,16 +523,339 @@ impl GameController {
                 return Err(e)
             },
         };
-        log!(self.logger, LogLevel::Info, format!("Created new game with id: {}", new_game.id).as_str());
-        self.games.push(new_game.clone());
+        log!(self.logger, LogLevel::Info, format!("Created new game with id: {}", new_game.id).as_str(), new_game);
+        self.insert_game(new_game.clone());
+        self.replicate_game_with_id(new_game.id);
+        let mut new_game = new_game;
+        new_game.set_update_hints(None);
         Ok(new_game)
     }
 
+    /// Creates a new lobby hosted by the given host from the game with the given id, as it was at the start of the given turn or as it is now if no turn is given.
+    /// The players of the game are copied as open seats that the players of the new lobby claim before it's started, see [`GameState::fork`].
+    pub fn fork_game(&mut self, game_id: GameID, at_turn: Option<TurnNumber>, mut new_lobby: NewGameInfo) -> Result<GameState, String> {
+        self.sanitize_new_game_info(&mut new_lobby)?;
+        self.expire_inactive_games();
+        self.check_room_for_new_game()?;
+        log!(self.logger, LogLevel::Debug, format!("Trying to fork the game with id {} at turn {:?} into a new game with name {}", game_id, at_turn, new_lobby.name).as_str());
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not fork it!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        let earlier_copy = match at_turn {
+            Some(turn_number) if turn_number != game.turn_number => match self.histories.get(&game_id).and_then(|history| history.turn_snapshot(turn_number)) {
+                Some(snapshot) => Some(snapshot),
+                None => return Err(format!("There is no copy of the game from turn {turn_number}! Only the latest {MAX_STORED_TURN_SNAPSHOTS} turns can be forked from.")),
+            },
+            _ => None,
+        };
+        let forked_game = match game.fork(new_lobby.name.clone(), self.generate_unused_game_id(), earlier_copy) {
+            Ok(forked_game) => forked_game,
+            Err(e) => {
+                log!(self.logger, LogLevel::Error, format!("Failed to fork the game with id {} because: {}", game_id, e).as_str());
+                return Err(e);
+            },
+        };
+        let mut forked_game = self.assign_host_to_new_game(forked_game, new_lobby.host.clone())?;
+        forked_game.last_activity = current_timestamp();
+        forked_game.expiry_warning_sent = false;
+        log!(self.logger, LogLevel::Info, format!("Forked the game with id {} into the new game with id {} hosted by the player with id {}", game_id, forked_game.id, new_lobby.host.unique_id).as_str(), forked_game);
+        self.insert_game(forked_game.clone());
+        self.replicate_game_with_id(forked_game.id);
+        forked_game.set_update_hints(None);
+        Ok(forked_game)
+    }
+
     /// Handles the player input and returns the new game state if the player input was valid.
     pub fn handle_player_input(&mut self, player_input: PlayerInput) -> Result<GameState, String> {
+        self.handle_input_of_player(player_input, true)
+    }
+
+    /// Ends the current turn of the game with the given id as if the player whose turn it is had ended it, without checking the rules. Meant for operators when a player can not end the turn, like when the client of the player has crashed.
+    /// Will return an error if there is no game with the given id, it's in the lobby or no player has the current turn.
+    pub fn force_next_turn(&mut self, game_id: GameID) -> Result<GameState, String> {
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not force the turn to end!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        if game.is_lobby {
+            return Err(format!("The game with id {} is in the lobby, so there is no turn to end!", game_id));
+        }
+        let Some(player) = game.players.iter().find(|player| player.in_game_id == game.current_players_turn) else {
+            return Err(format!("No player in the game with id {} has the turn {:?}!", game_id, game.current_players_turn));
+        };
+        log!(self.logger, LogLevel::Warning, format!("Forcing the turn of the player with id {} in the game with id {} to end", player.unique_id, game_id).as_str(), game);
+        self.handle_input_of_player(PlayerInput::new(player.unique_id, game_id, PlayerInputType::NextTurn), false)
+    }
+
+    /// Ticks the timed props that are due in all the games, through the same pipeline as the inputs of the players (without checking the rules), on behalf of the orchestrator of each game.
+    /// Only the ongoing games with timed props in their lobby settings are ticked, and the props of a game without an orchestrator don't tick. Returns the amount of ticks.
+    pub fn tick_due_timed_props(&mut self) -> usize {
+        let now = current_timestamp();
+        let due_ticks: Vec<(PlayerID, GameID, String)> = self
+            .games
+            .values()
+            .filter(|game| !game.lobby_settings.timed_props.is_empty() && !game.status.is_finished())
+            .flat_map(|game| {
+                let orchestrator_id = game.players.iter().find(|player| player.in_game_id == InGameID::Orchestrator).map(|player| player.unique_id);
+                game.due_timed_props(now)
+                    .into_iter()
+                    .filter_map(move |name| orchestrator_id.map(|orchestrator_id| (orchestrator_id, game.id, name)))
+            })
+            .collect();
+        for (orchestrator_id, game_id, name) in due_ticks.iter() {
+            log!(self.logger, LogLevel::Debug, format!("Ticking the timed prop {} of the game with id {}", name, game_id).as_str());
+            let activity_before = self.game(*game_id).map(|game| (game.last_activity, game.expiry_warning_sent));
+            let mut input = PlayerInput::new(*orchestrator_id, *game_id, PlayerInputType::TickTimedProp);
+            input.related_string = Some(name.clone());
+            if let Err(e) = self.handle_input_of_player(input, false) {
+                log!(self.logger, LogLevel::Warning, format!("Failed to tick the timed prop {} of the game with id {} because: {}", name, game_id, e).as_str());
+            }
+            // The ticks are not activity of the players, so they should not keep an abandoned game from being removed.
+            if let (Some(game), Some((last_activity, expiry_warning_sent))) = (self.games.get_mut(game_id).map(Arc::make_mut), activity_before) {
+                game.last_activity = last_activity;
+                game.expiry_warning_sent = expiry_warning_sent;
+            }
+        }
+        due_ticks.len()
+    }
+
+    /// Returns the player with the given id if the player is in a game. Will return an error if no game has the player.
+    pub fn get_player(&self, player_id: PlayerID) -> Result<Player, String> {
+        self.player_games
+            .get(&player_id)
+            .and_then(|game_id| self.game(*game_id))
+            .and_then(|game| game.players.iter().find(|player| player.unique_id == player_id))
+            .cloned()
+            .ok_or_else(|| format!("The player with id {} is not in any game!", player_id))
+    }
+
+    /// Handles the player input like [`GameController::handle_player_input`], but only checks the rules if `check_rules` is true.
+    ///
+    /// The bot players of the game are then updated to match its lobby settings, and play if it has become the turn of one of them.
+    ///
+    /// If handling the input panics, the panic is caught so that it does not take the other games down with it: the game is restored to how it was before the input.
+    /// The input is saved together with the game as it was before the input to the [`QUARANTINED_INPUTS_FOLDER`](../game_data/constants/constant.QUARANTINED_INPUTS_FOLDER.html), and the log entry of the panic refers to that file.
+    fn handle_input_of_player(&mut self, player_input: PlayerInput, check_rules: bool) -> Result<GameState, String> {
+        let game_id = player_input.game_id;
+        let game_before = self.games.get(&game_id).cloned();
+        let input = player_input.clone();
+        match panic::catch_unwind(AssertUnwindSafe(|| self.process_player_input_with_follow_up(player_input, check_rules).map(|game| self.update_bot_players(game)))) {
+            Ok(result) => {
+                self.index_players_of_game(game_id);
+                if let Ok(game) = &result {
+                    self.update_publishers.iter().for_each(|update_publisher| update_publisher.publish(game));
+                }
+                result
+            }
+            Err(panic) => {
+                let panic_message = panic
+                    .downcast_ref::<&str>()
+                    .map(|message| (*message).to_string())
+                    .or_else(|| panic.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
+                self.player_view_cache.invalidate_game(game_id);
+                let Some(game_before) = game_before else {
+                    log!(self.logger, LogLevel::Error, format!("Handling the input {:?} for the game with id {} panicked because: {}", input, game_id, panic_message).as_str());
+                    return Err("An internal error happened while handling the input!".to_string());
+                };
+                let version = game_before.version;
+                let quarantined_input = QuarantinedInput { input, panic_message, quarantined_at: current_timestamp(), game: GameState::clone(&game_before) };
+                let saved_to = match quarantined_input.save_to_folder(Path::new(QUARANTINED_INPUTS_FOLDER)) {
+                    Ok(path) => format!("The input and the game were saved to {path}"),
+                    Err(e) => format!("The input and the game could not be saved because: {e}"),
+                };
+                log!(self.logger, LogLevel::Error, format!("Handling the input {:?} for the game with id {} at version {} panicked because: {}. The game was restored to how it was before the input. {}", quarantined_input.input, game_id, version, quarantined_input.panic_message, saved_to).as_str(), game_before);
+                self.games.insert(game_id, game_before);
+                self.index_players_of_game(game_id);
+                self.replicate_game_with_id(game_id);
+                Err("An internal error happened while handling the input! The game is as it was before the input.".to_string())
+            }
+        }
+    }
+
+    /// Makes the bot players of the game match the bot seats in its lobby settings, and lets them play for as long as it's the turn of one of them.
+    /// Returns the given state of the game if nothing changed, and the latest state of the game otherwise.
+    fn update_bot_players(&mut self, game: GameState) -> GameState {
+        let game_id = game.id;
+        let bots_changed = self.sync_bot_players(game_id);
+        let bots_played = self.play_bot_turns(game_id);
+        if !bots_changed && !bots_played {
+            return game;
+        }
+        self.get_game_by_id(game_id, None).unwrap_or(game)
+    }
+
+    /// Adds a bot player for every bot seat in the lobby settings of the game that does not have one, and removes the bot players whose seats are gone. Returns `true` if any bot players were changed.
+    fn sync_bot_players(&mut self, game_id: GameID) -> bool {
+        let Some(game) = self.game(game_id) else {
+            return false;
+        };
+        let bot_seats = game.lobby_settings.bots.clone();
+        let removed_bot_ids: Vec<PlayerID> = game
+            .players
+            .iter()
+            .filter(|player| player.is_bot() && bot_seats.iter().all(|seat| seat.role != player.in_game_id))
+            .map(|player| player.unique_id)
+            .collect();
+        let new_bot_seats: Vec<BotSeat> = bot_seats.iter().filter(|seat| game.players.iter().all(|player| player.in_game_id != seat.role)).copied().collect();
+        let changed_difficulty = game
+            .players
+            .iter()
+            .any(|player| player.is_bot() && bot_seats.iter().any(|seat| seat.role == player.in_game_id && player.bot != Some(seat.difficulty)));
+        if removed_bot_ids.is_empty() && new_bot_seats.is_empty() && !changed_difficulty {
+            return false;
+        }
+
+        let mut new_bots = Vec::new();
+        for seat in new_bot_seats {
+            match self.generate_player_id().map_err(str::to_string) {
+                Ok(bot_id) => new_bots.push((bot_id, seat)),
+                Err(e) => log!(self.logger, LogLevel::Error, format!("Could not add a bot with the role {:?} to the game with id {} because: {}", seat.role, game_id, e).as_str()),
+            }
+        }
+        let Some(game) = self.games.get_mut(&game_id).map(Arc::make_mut) else {
+            return false;
+        };
+        for bot_id in removed_bot_ids.iter() {
+            game.remove_player_with_id(*bot_id);
+            game.record_event(GameEventKind::PlayerLeft(*bot_id));
+            log!(self.logger, LogLevel::Info, format!("The bot with id {} left the game with id {}", bot_id, game_id).as_str());
+        }
+        for (bot_id, seat) in new_bots.iter() {
+            let mut bot = Player::new(*bot_id, "Bot".to_string());
+            bot.bot = Some(seat.difficulty);
+            if let Err(e) = game.assign_player_to_game(bot).and_then(|_| game.assign_player_role((*bot_id, seat.role)).map_err(str::to_string)) {
+                log!(self.logger, LogLevel::Error, format!("Could not add a bot with the role {:?} to the game with id {} because: {}", seat.role, game_id, e).as_str());
+                game.players.retain(|player| player.unique_id != *bot_id);
+                continue;
+            }
+            game.record_event(GameEventKind::PlayerJoined(*bot_id));
+            log!(self.logger, LogLevel::Info, format!("A bot with the role {:?} and the id {} joined the game with id {}", seat.role, bot_id, game_id).as_str());
+        }
+        for player in game.players.iter_mut().filter(|player| player.is_bot()) {
+            if let Some(seat) = bot_seats.iter().find(|seat| seat.role == player.in_game_id) {
+                player.bot = Some(seat.difficulty);
+            }
+        }
+        game.mark_sections_changed(&[StateSection::Players]);
+        Self::replicate_game(&self.replicators, game);
+        self.player_view_cache.invalidate_game(game_id);
+
+        // The ids of the bots that failed to join or have left are not used by anyone, so they are removed right away instead of when they time out.
+        let unused_bot_ids: Vec<PlayerID> = removed_bot_ids
+            .into_iter()
+            .chain(new_bots.into_iter().map(|(bot_id, _)| bot_id))
+            .filter(|bot_id| self.game(game_id).is_none_or(|game| !game.contains_player_with_unique_id(*bot_id)))
+            .collect();
+        self.unique_ids.retain(|(id, _)| !unused_bot_ids.contains(id));
+        self.player_connections.retain(|connection| !unused_bot_ids.contains(&connection.player_id));
+        unused_bot_ids
+            .into_iter()
+            .for_each(|bot_id| self.replicate(ReplicationEntry::PlayerIdRemoved(bot_id)));
+        self.index_players_of_game(game_id);
+        true
+    }
+
+    /// Lets the bot players of the game make their inputs, through the same pipeline as the inputs of the players, for as long as it's the turn of one of them.
+    /// An input of a bot that is rejected ends the turn of the bot, so that the game never waits for a bot. Returns `true` if any bot made an input.
+    fn play_bot_turns(&mut self, game_id: GameID) -> bool {
+        let mut bots_played = false;
+        for _ in 0..MAX_BOT_INPUTS_IN_A_ROW {
+            let Some(game) = self.game(game_id) else {
+                break;
+            };
+            if game.is_lobby || game.status.is_finished() {
+                break;
+            }
+            let Some((bot_id, difficulty)) = game
+                .players
+                .iter()
+                .find(|player| player.in_game_id == game.current_players_turn)
+                .and_then(|player| player.bot.map(|difficulty| (player.unique_id, difficulty)))
+            else {
+                break;
+            };
+            let applied_game = match Self::applied_game(game, &self.extensions) {
+                Ok(applied_game) => applied_game,
+                Err(e) => {
+                    log!(self.logger, LogLevel::Error, format!("The bot with id {} could not play in the game with id {} because: {}", bot_id, game_id, e).as_str());
+                    break;
+                }
+            };
+            let input = bot_for(difficulty).next_input(&applied_game, bot_id, self.rule_checker.as_ref());
+            log!(self.logger, LogLevel::Debug, format!("The bot with id {} makes the input {:?} in the game with id {}", bot_id, input.input_type, game_id).as_str());
+            bots_played = true;
+            if let Err(e) = self.process_player_input(input, true, None) {
+                log!(self.logger, LogLevel::Warning, format!("The input of the bot with id {} in the game with id {} was rejected, so its turn is ended because: {}", bot_id, game_id, e).as_str());
+                if let Err(e) = self.process_player_input(PlayerInput::new(bot_id, game_id, PlayerInputType::NextTurn), false, None) {
+                    log!(self.logger, LogLevel::Error, format!("Could not end the turn of the bot with id {} in the game with id {} because: {}", bot_id, game_id, e).as_str());
+                    break;
+                }
+            }
+        }
+        bots_played
+    }
+
+    /// Processes the input, and then the input it refers to if it was accepted: the input of the dead letter for a RetryDeadLetter input, the input of the rule warning for an OverrideRule input and the input in `override_input` for an OverrideAndApply input.
+    /// The input it refers to is processed as the player who first sent it and checked against the rules like any other input, except for the rules the orchestrator overrode. A retried input that fails again is kept as a new dead letter.
+    fn process_player_input_with_follow_up(&mut self, player_input: PlayerInput, check_rules: bool) -> Result<GameState, String> {
+        let justification = match (&player_input.input_type, &player_input.related_string) {
+            (&PlayerInputType::OverrideAndApply, Some(text)) => Some(self.sanitize_text(text, TextKind::Other)?),
+            _ => None,
+        };
+        let follow_up = self.game(player_input.game_id).and_then(|game| match player_input.input_type {
+            PlayerInputType::RetryDeadLetter => player_input
+                .dead_letter_id
+                .and_then(|dead_letter_id| game.get_dead_letter(dead_letter_id).ok())
+                .map(|dead_letter| (dead_letter.input.clone(), None)),
+            PlayerInputType::OverrideRule => player_input
+                .rule_warning_id
+                .and_then(|rule_warning_id| game.get_rule_warning(rule_warning_id).ok())
+                .map(|rule_warning| {
+                    let rule_override = RuleOverride {
+                        overridden_by: player_input.player_id,
+                        player_id: rule_warning.input.player_id,
+                        input_type: rule_warning.input.input_type.clone(),
+                        rules: rule_warning.rule_names(),
+                        justification: None,
+                    };
+                    (rule_warning.input.clone(), Some(rule_override))
+                }),
+            PlayerInputType::OverrideAndApply => player_input.override_input.as_deref().map(|override_input| {
+                let rule_override = RuleOverride {
+                    overridden_by: player_input.player_id,
+                    player_id: override_input.player_id,
+                    input_type: override_input.input_type.clone(),
+                    rules: player_input.overridden_rules.clone(),
+                    justification,
+                };
+                (override_input.clone(), Some(rule_override))
+            }),
+            _ => None,
+        });
+        let game = self.process_player_input(player_input, check_rules, None)?;
+        let Some((follow_up_input, rule_override)) = follow_up else {
+            return Ok(game);
+        };
+        log!(self.logger, LogLevel::Info, format!("Sending the input {:?} of the player with id {} in the game with id {} again", follow_up_input.input_type, follow_up_input.player_id, follow_up_input.game_id).as_str());
+        self.process_player_input(follow_up_input, true, rule_override.as_ref())
+    }
+
+    fn process_player_input(&mut self, mut player_input: PlayerInput, check_rules: bool, rule_override: Option<&RuleOverride>) -> Result<GameState, String> {
         log!(self.logger, LogLevel::Debug, format!("Handling player input: {:?}", player_input).as_str());
+        if let Some(text) = player_input.related_string.take() {
+            let kind = match player_input.input_type {
+                PlayerInputType::RenamePlayer => TextKind::PlayerName,
+                PlayerInputType::AnnotateMap => TextKind::Note,
+                PlayerInputType::ChatMessage => TextKind::ChatMessage,
+                _ => TextKind::Other,
+            };
+            player_input.related_string = Some(self.sanitize_text(&text, kind)?);
+        }
         self.remove_empty_games();
         self.remove_inactive_ids();
+        self.record_due_session_reminders();
 
         if !self
             .unique_ids
@@ -93,11 +866,15 @@ impl GameController {
             return Err("There does not exist a player with the unique id".to_string());
         }
 
-        let mut games_iter = self.games.iter_mut();
+        if self.access_tokens.is_enabled() && player_input.requires_elevation() && player_input.access_level.is_none_or(|level| level < AccessLevel::Facilitator) {
+            log!(self.logger, LogLevel::Warning, format!("The player with id {} tried to do the input {:?} without a facilitator token", player_input.player_id, player_input.input_type).as_str());
+            return Err(format!("The input {:?} can only be sent with a facilitator or admin token!", player_input.input_type));
+        }
 
         let connected_game_id = player_input.game_id;
+        self.player_view_cache.invalidate_game(connected_game_id);
 
-        let related_game = match games_iter.find(|game| game.id == connected_game_id) {
+        let related_game = match self.games.get_mut(&connected_game_id).map(Arc::make_mut) {
             Some(game) => game,
             None => {
                 log!(self.logger, LogLevel::Error, "Could not find the game the player has done an input for!");
@@ -106,121 +883,711 @@ impl GameController {
         };
         log!(self.logger, LogLevel::Debug, format!("Found game with id: {}", related_game.id).as_str());
 
-        let mut related_game_clone = related_game.clone();
-        match Self::apply_game_actions(&mut related_game_clone) {
-            Ok(_) => (),
+        let related_game_clone = match Self::applied_game(related_game, &self.extensions) {
+            Ok(game) => game,
             Err(e) => {
                 log!(self.logger, LogLevel::Error, format!("Failed to apply previous game actions to the clone of the game with id: {} because: {}", related_game.id, e).as_str());
                 return Err(e);
             },
-        }
+        };
         log!(self.logger, LogLevel::Debug, format!("Applied previous game actions to the clone of the game with id: {}", related_game.id).as_str());
 
-        if let Some(error) = self
-            .rule_checker
-            .is_input_valid(&related_game_clone, &player_input)
-        {
-            log!(self.logger, LogLevel::Error, format!("The input was not valid for the game with id: {} because: {}", related_game.id, error).as_str());
-            return Err(format!("The input was not valid! Because: {error}"));
+        let mut force_full_resync = false;
+        if let Some(known_state_hash) = player_input.known_state_hash {
+            match related_game_clone.state_hash() {
+                Ok(state_hash) if state_hash != known_state_hash => {
+                    force_full_resync = true;
+                    log!(self.logger, LogLevel::Warning, format!("The client of the player with id {} has diverged from the game with id {}! The client has the state hash {} (version {:?}), but the server has the state hash {} (version {}) in turn {}. The whole game state is sent to the client.", player_input.player_id, related_game.id, known_state_hash, player_input.known_version, state_hash, related_game_clone.version, related_game_clone.turn_number).as_str());
+                }
+                Ok(_) => (),
+                Err(e) => log!(self.logger, LogLevel::Error, format!("Could not check the state hash of the client because: {}", e).as_str()),
+            }
+        }
+
+        let violations: Vec<RuleViolation> = match check_rules {
+            true => self
+                .rule_checker
+                .find_rule_violations(&related_game_clone, &player_input)
+                .into_iter()
+                .filter(|violation| rule_override.is_none_or(|rule_override| !rule_override.rules.contains(&violation.rule_name)))
+                .collect(),
+            false => Vec::new(),
+        };
+        if let Some(violation) = violations.first().cloned() {
+            related_game.record_rule_violation(player_input.player_id, &violation.rule_name);
+            if violations.iter().all(|violation| related_game.lobby_settings.is_rule_lenient(&violation.rule_name)) {
+                let rule_names: Vec<&str> = violations.iter().map(|violation| violation.rule_name.as_str()).collect();
+                let rule_warning_id = related_game.add_rule_warning(player_input.clone(), violations.clone());
+                related_game.record_input(related_game.turn_number, player_input, InputOutcome::AwaitingOverride(violation.clone()));
+                related_game.mark_sections_changed(&[StateSection::Turn]);
+                Self::replicate_game(&self.replicators, related_game);
+                log!(self.logger, LogLevel::Warning, format!("The input broke the lenient rules {} of the game with id: {}, and waits for the orchestrator to accept it with the rule warning {}: {}", rule_names.join(", "), related_game.id, rule_warning_id, violation.message).as_str(), related_game_clone);
+                return Err(format!("The input breaks a rule, but the orchestrator can accept it as a house rule! Because: {}", violation.message));
+            }
+            related_game.record_input(related_game.turn_number, player_input, InputOutcome::Rejected(violation.clone()));
+            Self::replicate_game(&self.replicators, related_game);
+            log!(self.logger, LogLevel::Error, format!("The input was not valid for the game with id: {} because of the rule {}: {}", related_game.id, violation.rule_name, violation.message).as_str(), related_game_clone);
+            return Err(format!("The input was not valid! Because: {}", violation.message));
+        }
+        log!(self.logger, LogLevel::Debug, format!("The input was valid for the game with id: {}", related_game.id).as_str());
+        if let Some(rule_override) = rule_override {
+            log!(self.logger, LogLevel::Warning, format!("The orchestrator with id {} overrode the rules {} for the input {:?} of the player with id {} in the game with id {}, because: {}", rule_override.overridden_by, rule_override.rules.join(", "), player_input.input_type, player_input.player_id, related_game.id, rule_override.justification.as_deref().unwrap_or("it was accepted as a house rule")).as_str(), related_game_clone);
+        }
+
+        let history = self.histories.entry(connected_game_id).or_default();
+        let input_turn_number = related_game.turn_number;
+        let was_lobby = related_game.is_lobby;
+        let replay_start = (was_lobby && !history.replay_log.has_started()).then(|| related_game.saved_copy());
+        let objective_cards_before = related_game.objective_cards();
+        let was_finished = related_game.status.is_finished();
+        match Self::handle_input(player_input.clone(), related_game, history, &self.extensions) {
+            Ok(_) => (),
+            Err(e) => {
+                log!(self.logger, LogLevel::Error, format!("Failed to handle player input because: {}", e).as_str(), related_game);
+                related_game.record_input(input_turn_number, player_input.clone(), InputOutcome::Failed(e.clone()));
+                related_game.add_dead_letter(player_input, e.clone());
+                related_game.mark_sections_changed(&[StateSection::Modifiers]);
+                Self::replicate_game(&self.replicators, related_game);
+                return Err(e);
+            }
+        };
+        match rule_override {
+            Some(rule_override) => {
+                related_game.record_input(input_turn_number, player_input.clone(), InputOutcome::Overridden(rule_override.clone()));
+                related_game.record_event(GameEventKind::RuleOverridden(rule_override.clone()));
+            }
+            None => related_game.record_input(input_turn_number, player_input.clone(), InputOutcome::Accepted),
+        }
+        related_game.pause_or_resume_session_clock(&player_input.input_type, current_timestamp());
+        if let (false, GameStatus::Finished { winners, reason, .. }) = (was_finished, &related_game.status) {
+            log!(self.logger, LogLevel::Info, format!("The game with id {} is finished ({:?}), and the winners are the players with the ids {:?}", related_game.id, reason, winners).as_str());
+        }
+        if let Some(replay_start) = replay_start.filter(|_| !related_game.is_lobby) {
+            history.replay_log = ReplayLog::new(replay_start);
+        }
+        log!(self.logger, LogLevel::Info, format!("Added/Handled the new input to the game with id: {}", related_game.id).as_str(), related_game);
+
+        #[cfg(debug_assertions)]
+        for discrepancy in Self::audit_game_actions(self.rule_checker.as_ref(), &self.extensions, related_game).discrepancies() {
+            log!(self.logger, LogLevel::Error, format!("The staged action {} of the game with id {} is inconsistent with the rules: {:?}", discrepancy.index, related_game.id, discrepancy).as_str());
+        }
+
+        let mut apply_result = Self::applied_game(related_game, &self.extensions);
+        let is_logged = history.replay_log.has_started() && ReplayLog::is_logged(&player_input.input_type);
+        if is_logged {
+            let state_hash = apply_result.as_ref().ok().and_then(|game_clone| game_clone.state_hash().ok());
+            let started_round = was_lobby && !related_game.is_lobby;
+            let dealt_players = (started_round || related_game.objective_cards() != objective_cards_before).then(|| related_game.players.clone());
+            history.replay_log.append(input_turn_number, player_input.clone(), dealt_players, state_hash);
+        }
+        let history_changed = (was_lobby && !related_game.is_lobby) || matches!(player_input.input_type, PlayerInputType::NextTurn | PlayerInputType::CreateCheckpoint | PlayerInputType::RestoreCheckpoint | PlayerInputType::NextScenario);
+        match history.replay_log.entries.last() {
+            _ if history_changed => Self::replicate_history(&self.replicators, connected_game_id, history),
+            Some(replay_entry) if is_logged => self.replicators.iter().for_each(|replicator| replicator.replicate(ReplicationEntry::ReplayEntryAppended(connected_game_id, Box::new(replay_entry.clone())))),
+            _ => (),
+        }
+        if let Ok(game_clone) = &mut apply_result {
+            let changed_sections = GameState::changed_sections(&related_game_clone, game_clone);
+            related_game.mark_sections_changed(&changed_sections);
+            game_clone.mark_sections_changed(&changed_sections);
+            related_game.record_events_between(&related_game_clone, game_clone);
+            game_clone.events = related_game.events.clone();
+            related_game.keep_applied_actions(game_clone.clone());
+            if related_game_clone.is_lobby && !game_clone.is_lobby && !game_clone.objective_deal_warnings.is_empty() {
+                log!(self.logger, LogLevel::Warning, format!("The objective cards of the game with id {} were dealt without meeting all the deal constraints: {}", related_game.id, game_clone.objective_deal_warnings.join(" ")).as_str(), game_clone);
+            }
+        }
+        Self::replicate_game(&self.replicators, related_game);
+        match apply_result {
+            Ok(mut game_clone) => {
+                if player_input.input_type == PlayerInputType::Movement {
+                    if let Some(outcome) = &game_clone.last_movement {
+                        log!(self.logger, LogLevel::Info, format!("The player with id {} moved from node {} to node {} in the game with id {}: {:?}", outcome.player_id, outcome.from_node_id, outcome.to_node_id, game_clone.id, outcome).as_str(), game_clone);
+                    }
+                }
+                self.get_legal_nodes(&mut game_clone, player_input.player_id);
+                game_clone.set_update_hints(if force_full_resync { None } else { player_input.known_version });
+                game_clone.session_clock.update(current_timestamp());
+                Ok(game_clone)
+            },
+            Err(e) => {
+                log!(self.logger, LogLevel::Error, format!("Failed to apply the game actions to the clone of the game with id: {} because: {}", connected_game_id, e).as_str(), related_game);
+                Err(e)
+            },
+        }
+    }
+
+    /// Creates a new control token that a client can register local players under, so that multiple players can play from the same client (hot-seat play).
+    pub fn create_control_token(&mut self) -> Result<ControlToken, String> {
+        log!(self.logger, LogLevel::Debug, "Creating new control token");
+        let mut token = rand::random::<ControlToken>();
+        let mut found_unused_token = false;
+        for _ in 0..100_000 {
+            if self.local_players.iter().all(|l| l.control_token != token) {
+                found_unused_token = true;
+                break;
+            }
+            token = rand::random::<ControlToken>();
+        }
+        if !found_unused_token {
+            log!(self.logger, LogLevel::Error, "Failed to make new control token!");
+            return Err("Failed to make new control token!".to_string());
+        }
+        let local_players = LocalPlayers::new(token);
+        self.local_players.push(local_players.clone());
+        self.replicate(ReplicationEntry::LocalPlayersUpdated(local_players));
+        log!(self.logger, LogLevel::Debug, format!("Made control token: {}", token).as_str());
+        Ok(token)
+    }
+
+    /// Generates a new player id, like [`generate_player_id`](#method.generate_player_id), and registers it as a local player under the given control token.
+    pub fn generate_local_player_id(&mut self, control_token: ControlToken) -> Result<PlayerID, String> {
+        if self.local_players.iter().all(|l| l.control_token != control_token) {
+            log!(self.logger, LogLevel::Error, format!("There is no control token {} and can therefore not make a local player", control_token).as_str());
+            return Err(format!("There is no control token {control_token}!"));
+        }
+        let player_id = match self.generate_player_id() {
+            Ok(id) => id,
+            Err(e) => return Err(e.to_string()),
+        };
+        let Some(local_players) = self.local_players.iter_mut().find(|l| l.control_token == control_token) else {
+            return Err(format!("There is no control token {control_token}!"));
+        };
+        local_players.player_ids.push(player_id);
+        let local_players = local_players.clone();
+        self.replicate(ReplicationEntry::LocalPlayersUpdated(local_players));
+        log!(self.logger, LogLevel::Info, format!("Registered local player with id {} under control token {}", player_id, control_token).as_str());
+        Ok(player_id)
+    }
+
+    /// Handles the input of one of the local players registered under the given control token. Will return an error if the player in the input is not one of the local players.
+    pub fn handle_local_player_input(&mut self, control_token: ControlToken, player_input: PlayerInput) -> Result<GameState, String> {
+        let local_player_ids = self.get_local_player_ids(control_token)?;
+        if !local_player_ids.contains(&player_input.player_id) {
+            log!(self.logger, LogLevel::Error, format!("The player with id {} is not a local player of control token {}", player_input.player_id, control_token).as_str());
+            return Err(format!("The player with id {} is not one of the local players of this client!", player_input.player_id));
+        }
+        self.handle_player_input(player_input)
+    }
+
+    /// Checks in all the local players registered under the given control token. See [`update_check_in_and_remove_inactive`](#method.update_check_in_and_remove_inactive).
+    pub fn update_local_check_in_and_remove_inactive(&mut self, control_token: ControlToken) -> Result<(), String> {
+        let local_player_ids = self.get_local_player_ids(control_token)?;
+        for id in self.unique_ids.iter_mut() {
+            if local_player_ids.contains(&id.0) {
+                id.1 = Instant::now();
+            }
+        }
+        let now = current_timestamp();
+        local_player_ids.iter().for_each(|player_id| self.record_check_in(*player_id, now));
+        self.remove_inactive_ids();
+        self.remove_empty_games();
+        Ok(())
+    }
+
+    /// Returns the game with the given id together with the view of each of the local players of the given control token that are in the game.
+    pub fn get_local_players_view(&mut self, control_token: ControlToken, game_id: GameID, known_version: Option<StateVersion>) -> Result<LocalPlayersView, String> {
+        let local_player_ids = self.get_local_player_ids(control_token)?;
+        let mut game = self.get_game_by_id(game_id, known_version)?;
+        let mut player_views = Vec::new();
+        for player_id in local_player_ids {
+            if !game.contains_player_with_unique_id(player_id) {
+                continue;
+            }
+            game.legal_nodes = Vec::new();
+            self.get_cached_legal_nodes(&mut game, player_id);
+            let move_plan = game.move_plan_of_player(player_id);
+            let can_execute_plan = !move_plan.is_empty() && !game.is_lobby && game.get_player_with_unique_id(player_id).is_ok_and(|player| player.in_game_id == game.current_players_turn);
+            player_views.push(PlayerView { player_id, legal_nodes: game.legal_nodes.clone(), move_plan, can_execute_plan });
+        }
+        game.legal_nodes = Vec::new();
+        Ok(LocalPlayersView { game, player_views })
+    }
+
+    fn get_local_player_ids(&self, control_token: ControlToken) -> Result<Vec<PlayerID>, String> {
+        let Some(local_players) = self.local_players.iter().find(|l| l.control_token == control_token) else {
+            log!(self.logger, LogLevel::Error, format!("There is no control token {}", control_token).as_str());
+            return Err(format!("There is no control token {control_token}!"));
+        };
+        Ok(local_players.player_ids.clone())
+    }
+
+    /// Returns the amount of unique player ids that have been created.
+    pub fn get_amount_of_created_player_ids(&self) -> i32 {
+        self.unique_ids.len() as i32
+    }
+
+    /// Returns all the games that have not started yet, ordered by their ids.
+    pub fn get_all_lobbies(&self) -> Vec<Arc<GameState>> {
+        log!(self.logger, LogLevel::Debug, "Getting all lobbies!");
+        self.game_snapshots(|game| game.is_lobby)
+    }
+
+    /// Adds the player to the game if there is room for the player and the player is not in another game. It will also return other errors if it cannot add the player to the game.
+    pub fn join_game(&mut self, game_id: GameID, mut player: Player) -> Result<GameState, String> {
+        log!(self.logger, LogLevel::Debug, format!("Player with id: {} is trying to join game with id: {}", player.unique_id, game_id).as_str());
+        player.name = self.sanitize_text(&player.name, TextKind::PlayerName)?;
+        if self.player_games.contains_key(&player.unique_id) {
+            log!(self.logger, LogLevel::Error, format!("The player with id: {} is already connected to another game.", player.unique_id).as_str());
+            return Err("The player is already connected to another game.".to_string());
+        }
+        let related_game = match self.games.get_mut(&game_id).map(Arc::make_mut) {
+            Some(game) => game,
+            None => {
+                log!(self.logger, LogLevel::Error, format!("Could not find the game the player with id: {} is trying to join!", player.unique_id).as_str());
+                return Err("Could not find the game the player is trying to join!".to_string())
+            }
+        };
+        match related_game.assign_player_to_game(player.clone()) {
+            Ok(_) => (),
+            Err(e) => {
+                log!(self.logger, LogLevel::Error, format!("Failed to assign player with id: {} to game with id: {} because: {}", player.unique_id, game_id, e).as_str());
+                return Err(e);
+            },
+        };
+        related_game.mark_sections_changed(&[StateSection::Players]);
+        related_game.record_event(GameEventKind::PlayerJoined(player.unique_id));
+        log!(self.logger, LogLevel::Info, format!("Player with id: {} joined game with id: {}", player.unique_id, game_id).as_str());
+        let mut game_clone = related_game.clone();
+        game_clone.set_update_hints(None);
+        self.index_players_of_game(game_id);
+        self.replicate_game_with_id(game_id);
+        Ok(game_clone)
+    }
+
+    /// Reconnects a player whose id was removed for being inactive, using the session token the player got from [`GameController::create_session_token`].
+    /// The id is taken into use again and the player is put back into the game it was in, with the role, position, remaining moves and everything else the player had.
+    ///
+    /// The player can not reconnect if the game has been removed, another player has taken the role or the id was removed more than [`RECONNECT_WINDOW`] ago.
+    ///
+    /// [`RECONNECT_WINDOW`]: ../game_data/constants/constant.RECONNECT_WINDOW.html
+    pub fn reconnect_player(&mut self, old_id: PlayerID, token: SessionToken) -> Result<GameState, String> {
+        log!(self.logger, LogLevel::Debug, format!("Player with id: {} is trying to reconnect", old_id).as_str());
+        self.remove_expired_sessions(current_timestamp());
+        let Some(session_position) = self.player_sessions.iter().position(|session| session.player_id == old_id && session.token == token) else {
+            log!(self.logger, LogLevel::Error, format!("There is no session for the player with id: {} and the given token", old_id).as_str());
+            return Err("There is no session with the given player id and session token, or the player was disconnected too long ago!".to_string());
+        };
+        let Some(disconnected) = self.player_sessions[session_position].disconnected_player.clone() else {
+            return Err("The player is still connected and does not need to reconnect!".to_string());
+        };
+        if self.unique_ids.iter().any(|(id, _)| *id == old_id) {
+            return Err("The player id is already in use!".to_string());
+        }
+        let game_id = disconnected.game_id;
+        let Some(game) = self.games.get_mut(&game_id).map(Arc::make_mut) else {
+            self.player_sessions.remove(session_position);
+            log!(self.logger, LogLevel::Error, format!("The game with id: {} that player with id: {} was in no longer exists", game_id, old_id).as_str());
+            return Err("The game the player was in no longer exists!".to_string());
+        };
+        if let Err(e) = game.rejoin_player(disconnected.player) {
+            log!(self.logger, LogLevel::Error, format!("Failed to put player with id: {} back into game with id: {} because: {}", old_id, game_id, e).as_str());
+            return Err(e);
+        }
+        game.mark_sections_changed(&[StateSection::Players]);
+        game.record_event(GameEventKind::PlayerJoined(old_id));
+        let mut game_clone = game.clone();
+        game_clone.set_update_hints(None);
+        self.update_publishers.iter().for_each(|update_publisher| update_publisher.publish(game));
+        self.player_sessions[session_position].disconnected_player = None;
+        self.index_players_of_game(game_id);
+        self.unique_ids.push((old_id, Instant::now()));
+        self.reset_connection(old_id, current_timestamp());
+        self.replicate(ReplicationEntry::PlayerIdAdded(old_id));
+        self.replicate_game_with_id(game_id);
+        log!(self.logger, LogLevel::Info, format!("Player with id: {} reconnected to game with id: {}", old_id, game_id).as_str());
+        Ok(game_clone)
+    }
+
+    /// Removes the sessions of the disconnected players that can no longer reconnect, because they were disconnected too long ago or their game is gone.
+    fn remove_expired_sessions(&mut self, now: Timestamp) {
+        let games = &self.games;
+        self.player_sessions.retain(|session| {
+            !session.has_expired_at(now)
+                && session
+                    .disconnected_player
+                    .as_ref()
+                    .is_none_or(|disconnected| games.contains_key(&disconnected.game_id))
+        });
+    }
+
+    /// Gets the game with the given id. If there was a problem with getting the game it will return a string with the error.
+    /// 
+    /// The update hints of the returned game are set to the sections that have changed since `known_version`, or all sections if it is `None`.
+    pub fn get_game_by_id(&mut self, game_id: GameID, known_version: Option<StateVersion>) -> Result<GameState, String> {
+        log!(self.logger, LogLevel::Debug, format!("Trying to get game with id: {}", game_id).as_str());
+        self.record_due_session_reminders();
+        self.record_stale_connections();
+        self.expire_inactive_games();
+        let Some(game) = self.games.get_mut(&game_id).map(Arc::make_mut) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not return the wanted game!", game_id).as_str());
+            if self.ended_game_ids.contains(&game_id) {
+                return Err(format!("The game with id {} was ended because the server was reset!", game_id));
+            }
+            if self.expired_game_ids.contains(&game_id) {
+                return Err(format!("The game with id {} was removed because nobody had played it for a while!", game_id));
+            }
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        match Self::applied_game(game, &self.extensions) {
+            Ok(mut game_clone) => {
+                game.keep_applied_actions(game_clone.clone());
+                if !game_clone.is_lobby {
+                    let current_players_turn = game_clone.current_players_turn;
+                    let players = game_clone.players.clone();
+                    let Some(player) = players.iter().find(|p| p.in_game_id == current_players_turn) else {
+                        log!(self.logger, LogLevel::Error, format!("Failed to apply the game actions to the clone of the game with id {} because there is no player that has the current in game turn {:?} and can therefore not return the wanted game!", game_id, current_players_turn).as_str());
+                        return Err(format!("There is no player that has the current in game turn {:?}!", current_players_turn));
+                    };
+                    self.get_cached_legal_nodes(&mut game_clone, player.unique_id);
+                }
+                game_clone.set_update_hints(known_version);
+                game_clone.session_clock.update(current_timestamp());
+                game_clone.player_connections = self.connections_of_players_in(&game_clone, current_timestamp());
+                log!(self.logger, LogLevel::Info, format!("Returning game with id: {}", game_id).as_str());
+                Ok(game_clone)},
+            Err(e) => {
+                log!(self.logger, LogLevel::Error, format!("Failed to apply the game actions to the clone of the game with id: {} because: {} and can therefore not return the wanted game", game_id, e).as_str());
+                Err(e)
+            },
+        }
+    }
+
+    /// Shows what the game would look like if the orchestrator's measure in the input was enacted, without changing the game. The input is checked against the rules like any other input, which for example rejects measures that would cut players off from the parking spots or their objectives.
+    /// Will return an error if the input is not a measure, the input is not valid or the measure could not be applied.
+    pub fn simulate_measure(&self, player_input: PlayerInput) -> Result<GameState, String> {
+        log!(self.logger, LogLevel::Debug, format!("Simulating measure: {:?}", player_input).as_str());
+        let Some(game) = self.game(player_input.game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not simulate the measure!", player_input.game_id).as_str());
+            return Err(format!("There is no game with id {}!", player_input.game_id));
+        };
+        let mut game_clone = Self::applied_game(game, &self.extensions)?;
+
+        if let Some(violation) = self.rule_checker.find_rule_violation(&game_clone, &player_input) {
+            log!(self.logger, LogLevel::Debug, format!("The simulated measure was not valid for the game with id: {} because of the rule {}: {}", game.id, violation.rule_name, violation.message).as_str());
+            return Err(format!("The measure is not valid! Because: {}", violation.message));
+        }
+
+        game_clone.apply_measure_input(player_input)?;
+        game_clone.set_update_hints(None);
+        Ok(game_clone)
+    }
+
+    /// Validates and applies every staged action of the game with the given id again, in order, and reports for each action which rule it breaks and why it fails to apply.
+    /// An action that is valid but fails to apply (or the other way around) points to a rule that does not match the game logic. Will return an error if there is no game with the given id.
+    pub fn audit_turn(&self, game_id: GameID) -> Result<TurnAudit, String> {
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not audit the turn!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        Ok(Self::audit_game_actions(self.rule_checker.as_ref(), &self.extensions, game))
+    }
+
+    fn audit_game_actions(rule_checker: &(dyn RuleChecker + Send + Sync), extensions: &[Box<dyn GameExtension + Send + Sync>], game: &GameState) -> TurnAudit {
+        let mut game_clone = game.clone();
+        // The rules should only see the actions that were staged before the audited action, like when the action was received.
+        game_clone.actions.clear();
+        let mut actions = Vec::new();
+        for (index, action) in game.actions.iter().enumerate() {
+            let rule_violation = rule_checker.find_rule_violation(&game_clone, action);
+            let mut applied_game = game_clone.clone();
+            let apply_error = match Self::apply_input(action.clone(), &mut applied_game, extensions) {
+                Ok(_) => {
+                    game_clone = applied_game;
+                    None
+                }
+                Err(e) => Some(e),
+            };
+            game_clone.actions.push(action.clone());
+            actions.push(ActionAudit {
+                index,
+                action: action.clone(),
+                rule_violation,
+                apply_error,
+            });
+        }
+        TurnAudit {
+            game_id: game.id,
+            turn_number: game.turn_number,
+            actions,
+        }
+    }
+
+    /// Returns the inputs the player with the given id has sent to the game with the given id, with when they were received and their outcomes. Only the inputs from the given turn and later are returned, or all of them if no turn is given.
+    /// Meant for clients that have reconnected and want to show the player what they have done in the game. Will return an error if there is no game with the given id.
+    pub fn get_player_inputs(&self, game_id: GameID, player_id: PlayerID, since_turn: Option<TurnNumber>) -> Result<Vec<InputRecord>, String> {
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not return the inputs of the player!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        Ok(game.player_inputs(player_id, since_turn.unwrap_or(0)))
+    }
+
+    /// Returns the messages in the chat of the game with the given id that were sent after the message with the given id, or all of them if no id is given.
+    /// Only the players in the game can read its chat. Will return an error if there is no such game or player.
+    pub fn get_chat_messages(&self, game_id: GameID, player_id: PlayerID, since_id: Option<ChatMessageID>) -> Result<Vec<ChatMessage>, String> {
+        let game = self.get_game_with_player(game_id, player_id)?;
+        Ok(game.chat_messages_since(since_id).to_vec())
+    }
+
+    /// Returns the moves the player with the given id plans to make in their next turn in the game with the given id. Will return an error if there is no such game or player.
+    pub fn get_move_plan(&self, game_id: GameID, player_id: PlayerID) -> Result<Vec<PlannedMove>, String> {
+        let game = self.get_game_with_player(game_id, player_id)?;
+        Ok(game.move_plan_of_player(player_id))
+    }
+
+    /// Estimates how far every player in the game has come with their objective card, see [`ObjectiveProgress`]. Only the orchestrator of the game can get the progress,
+    /// so that the objectives are not revealed to the other players. Will return an error if there is no such game or the player is not its orchestrator.
+    pub fn get_objective_progress(&self, game_id: GameID, player_id: PlayerID) -> Result<Vec<ObjectiveProgress>, String> {
+        let game = self.get_game_with_player(game_id, player_id)?;
+        if game.get_player_with_unique_id(player_id).map_or(true, |player| player.in_game_id != InGameID::Orchestrator) {
+            log!(self.logger, LogLevel::Warning, format!("The player with id {} tried to get the objective progress of the game with id {} without being the orchestrator", player_id, game_id).as_str());
+            return Err("Only the orchestrator can see the progress of the players!".to_string());
+        }
+        Ok(ObjectiveProgress::estimate_all(game))
+    }
+
+    /// Returns the replay log of the game with the given id: the game as it was right before it was started, and every input that has been accepted since. Will return an error if there is no game with the given id or it has not been started.
+    pub fn get_replay(&self, game_id: GameID) -> Result<ReplayLog, String> {
+        if self.game(game_id).is_none() {
+            return Err(format!("There is no game with id {}!", game_id));
         }
-        log!(self.logger, LogLevel::Debug, format!("The input was valid for the game with id: {}", related_game.id).as_str());
+        match self.histories.get(&game_id) {
+            Some(history) if history.replay_log.has_started() => Ok(history.replay_log.clone()),
+            _ => Err(format!("The game with id {} has not been started, so there is nothing to replay!", game_id)),
+        }
+    }
 
-        match Self::handle_input(player_input.clone(), related_game) {
-            Ok(_) => (),
-            Err(e) => {
-                log!(self.logger, LogLevel::Error, format!("Failed to handle player input because: {}", e).as_str());
-                return Err(e);
-            }
+    /// Reconstructs the game with the given id as it was at the start of the given turn, by replaying the inputs in its replay log on the copy of the game from right before it was started. The rules are not checked, since the inputs were accepted when they were sent.
+    /// Will return an error if there is no game with the given id, it has not been started, the turn has not been reached yet or one of the inputs could not be replayed.
+    pub fn replay_to_turn(&self, game_id: GameID, turn_number: TurnNumber) -> Result<ReplayedGame, String> {
+        let replay_log = self.get_replay(game_id)?;
+        let current_turn_number = self.game(game_id).map_or(0, |game| game.turn_number);
+        if turn_number > current_turn_number {
+            return Err(format!("The game with id {} has only reached turn {}, so it can not be replayed to turn {}!", game_id, current_turn_number, turn_number));
+        }
+        let Some(start) = replay_log.start else {
+            return Err(format!("The game with id {} has not been started, so there is nothing to replay!", game_id));
         };
-        log!(self.logger, LogLevel::Info, format!("Added/Handled the new input to the game with id: {}", related_game.id).as_str());
-
-        let mut game_clone = related_game.clone();
-        match Self::apply_game_actions(&mut game_clone) {
-            Ok(_) => {
-                self.get_legal_nodes(&mut game_clone, player_input.player_id);
-                Ok(game_clone.clone())
-            },
-            Err(e) => {
-                log!(self.logger, LogLevel::Error, format!("Failed to apply the game actions to the clone of the game with id: {} because: {}", related_game.id, e).as_str());
-                Err(e)
-            },
+        let base_map = self.base_map_of(&start.game)?;
+        let mut game = start.into_game_state_on(base_map);
+        let mut history = GameHistory::default();
+        let mut replayed_entries = 0;
+        let mut diverged_at = None;
+        for entry in replay_log.entries.iter() {
+            let has_started = !game.is_lobby || game.round_number > 0;
+            if has_started && game.turn_number >= turn_number {
+                break;
+            }
+            if let Err(e) = Self::handle_input(entry.input.clone(), &mut game, &mut history, &self.extensions) {
+                log!(self.logger, LogLevel::Error, format!("Could not replay the input {} of the game with id {} because: {}", entry.sequence_number, game_id, e).as_str());
+                return Err(format!("Could not replay the input {} ({:?} by the player with id {}) because: {}", entry.sequence_number, entry.input.input_type, entry.input.player_id, e));
+            }
+            entry.restore_dealt_players(&mut game);
+            replayed_entries += 1;
+            if diverged_at.is_none() && entry.state_hash.is_some() && Self::applied_game(&game, &self.extensions)?.state_hash().ok() != entry.state_hash {
+                log!(self.logger, LogLevel::Warning, format!("The replay of the game with id {} diverged from the game at the input {}", game_id, entry.sequence_number).as_str());
+                diverged_at = Some(entry.sequence_number);
+            }
         }
+        let game = Self::applied_game(&game, &self.extensions)?;
+        log!(self.logger, LogLevel::Info, format!("Replayed {} inputs of the game with id {} to turn {}", replayed_entries, game_id, turn_number).as_str());
+        Ok(ReplayedGame { turn_number, replayed_entries, diverged_at, game })
     }
 
-    /// Returns the amount of unique player ids that have been created.
-    pub fn get_amount_of_created_player_ids(&self) -> i32 {
-        self.unique_ids.len() as i32
+    /// Replaces the moves the player with the given id plans to make in their next turn, or removes the plan if there are no moves. The moves are not checked against the rules until the plan is executed.
+    /// Will return an error if there is no such game or player, or if there are more than [`MAX_PLANNED_MOVES`](../game_data/constants/constant.MAX_PLANNED_MOVES.html) moves.
+    pub fn set_move_plan(&mut self, game_id: GameID, player_id: PlayerID, moves: Vec<PlannedMove>) -> Result<Vec<PlannedMove>, String> {
+        if moves.len() > MAX_PLANNED_MOVES {
+            return Err(format!("A plan can not have more than {MAX_PLANNED_MOVES} moves!"));
+        }
+        self.get_game_with_player(game_id, player_id)?;
+        let Some(game) = self.games.get_mut(&game_id).map(Arc::make_mut) else {
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        game.set_move_plan(player_id, moves);
+        log!(self.logger, LogLevel::Debug, format!("Set the move plan of the player with id {} in the game with id {}", player_id, game_id).as_str());
+        Ok(game.move_plan_of_player(player_id))
     }
 
-    /// Returns all the games that have not started yet.
-    pub fn get_all_lobbies(&self) -> Vec<GameState> {
-        log!(self.logger, LogLevel::Debug, "Getting all lobbies!");
-        let mut lobbies = Vec::new();
-        self.games.clone().into_iter().for_each(|game| {
-            if game.is_lobby {
-                lobbies.push(game);
+    /// Executes the plan of the player with the given id by sending its moves as movement inputs one by one, so that every move is checked against the rules like any other input.
+    /// The execution stops at the first move that is not valid, and the moves that were not executed are kept as the plan. Will return an error if the player has no plan or the first move is not valid.
+    pub fn execute_move_plan(&mut self, game_id: GameID, player_id: PlayerID, access_level: Option<AccessLevel>) -> Result<MovePlanExecution, String> {
+        let moves = self.get_move_plan(game_id, player_id)?;
+        if moves.is_empty() {
+            return Err(format!("The player with id {} does not have a plan!", player_id));
+        }
+        let mut latest_game = None;
+        let mut executed_moves = 0;
+        let mut error = None;
+        for planned_move in moves.iter() {
+            let input = PlayerInput {
+                related_node_id: Some(planned_move.to_node_id),
+                edge_id: planned_move.edge_id,
+                access_level,
+                ..PlayerInput::new(player_id, game_id, PlayerInputType::Movement)
+            };
+            match self.handle_player_input(input) {
+                Ok(game) => {
+                    latest_game = Some(game);
+                    executed_moves += 1;
+                }
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
             }
-        });
-        lobbies
+        }
+        let remaining_moves = moves[executed_moves..].to_vec();
+        if let Some(game) = self.games.get_mut(&game_id).map(Arc::make_mut) {
+            game.set_move_plan(player_id, remaining_moves.clone());
+        }
+        let Some(game) = latest_game else {
+            return Err(format!("The first move of the plan could not be executed because: {}", error.unwrap_or_default()));
+        };
+        log!(self.logger, LogLevel::Info, format!("Executed {} of the {} planned moves of the player with id {} in the game with id {}", executed_moves, moves.len(), player_id, game_id).as_str());
+        Ok(MovePlanExecution { game, executed_moves, remaining_moves, error })
     }
 
-    /// Adds the player to the game if there is room for the player and the player is not in another game. It will also return other errors if it cannot add the player to the game.
-    pub fn join_game(&mut self, game_id: GameID, player: Player) -> Result<GameState, String> {
-        log!(self.logger, LogLevel::Debug, format!("Player with id: {} is trying to join game with id: {}", player.unique_id, game_id).as_str());
-        for game in self.games.iter() {
-            if game.contains_player_with_unique_id(player.unique_id) {
-                log!(self.logger, LogLevel::Error, format!("The player with id: {} is already connected to another game.", player.unique_id).as_str());
-                return Err("The player is already connected to another game.".to_string());
-            }
+    /// Handles a batch of inputs from one player in order, like [`GameController::handle_player_input`] does for each of them, so that a client on a slow network can send several inputs in one request.
+    /// The batch stops at the first input that fails, and the inputs after it are not handled. Will return an error if the batch is empty, has more than [`MAX_INPUT_BATCH_SIZE`](../game_data/constants/constant.MAX_INPUT_BATCH_SIZE.html) inputs,
+    /// mixes players or games, or if the first input fails.
+    pub fn handle_input_batch(&mut self, inputs: Vec<PlayerInput>, access_level: Option<AccessLevel>) -> Result<InputBatchExecution, String> {
+        let Some(first_input) = inputs.first() else {
+            return Err("The batch does not have any inputs!".to_string());
+        };
+        if inputs.len() > MAX_INPUT_BATCH_SIZE {
+            return Err(format!("A batch can not have more than {MAX_INPUT_BATCH_SIZE} inputs!"));
         }
-        let mut games_iter = self.games.iter_mut();
-        let related_game = match games_iter.find(|game| game.id == game_id) {
-            Some(game) => game,
-            None => {
-                log!(self.logger, LogLevel::Error, format!("Could not find the game the player with id: {} is trying to join!", player.unique_id).as_str());
-                return Err("Could not find the game the player is trying to join!".to_string())
+        let (player_id, game_id) = (first_input.player_id, first_input.game_id);
+        if inputs.iter().any(|input| input.player_id != player_id || input.game_id != game_id) {
+            return Err("All the inputs of a batch have to be from the same player to the same game!".to_string());
+        }
+        let input_count = inputs.len();
+        let mut latest_game = None;
+        let mut handled_inputs = 0;
+        let mut error = None;
+        for mut input in inputs {
+            input.access_level = access_level;
+            match self.handle_player_input(input) {
+                Ok(game) => {
+                    latest_game = Some(game);
+                    handled_inputs += 1;
+                }
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
             }
+        }
+        let Some(game) = latest_game else {
+            return Err(format!("The first input of the batch could not be handled because: {}", error.unwrap_or_default()));
         };
-        match related_game.assign_player_to_game(player.clone()) {
-            Ok(_) => (),
-            Err(e) => {
-                log!(self.logger, LogLevel::Error, format!("Failed to assign player with id: {} to game with id: {} because: {}", player.unique_id, game_id, e).as_str());
-                return Err(e);
-            },
+        log!(self.logger, LogLevel::Info, format!("Handled {} of the {} inputs in the batch of the player with id {} in the game with id {}", handled_inputs, input_count, player_id, game_id).as_str());
+        Ok(InputBatchExecution { game, handled_inputs, unhandled_inputs: input_count - handled_inputs, error })
+    }
+
+    fn get_game_with_player(&self, game_id: GameID, player_id: PlayerID) -> Result<&GameState, String> {
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not use the plan of the player!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
         };
-        log!(self.logger, LogLevel::Info, format!("Player with id: {} joined game with id: {}", player.unique_id, game_id).as_str());
-        Ok(related_game.clone())
+        if !game.contains_player_with_unique_id(player_id) {
+            return Err(format!("There is no player with id {} in the game!", player_id));
+        }
+        Ok(game)
     }
 
-    /// Gets the game with the given id. If there was a problem with getting the game it will return a string with the error.
-    pub fn get_game_by_id(&mut self, game_id: GameID) -> Result<GameState, String> {
-        log!(self.logger, LogLevel::Debug, format!("Trying to get game with id: {}", game_id).as_str());
-        let Some(game) = self.games.iter().find(|g| g.id == game_id) else {
-            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not return the wanted game!", game_id).as_str());
+    /// Creates a summary of the game with the given id, meant for exporting the outcome of the game. If there is no game with the given id it will return an error.
+    pub fn get_game_summary(&self, game_id: GameID) -> Result<GameSummary, String> {
+        log!(self.logger, LogLevel::Debug, format!("Trying to get the summary of the game with id: {}", game_id).as_str());
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not return the summary of the game!", game_id).as_str());
             return Err(format!("There is no game with id {}!", game_id));
         };
-        let mut game_clone = game.clone();
-        match Self::apply_game_actions(&mut game_clone) {
-            Ok(_) => {
-                if !game_clone.is_lobby {
-                    let current_players_turn = game_clone.current_players_turn;
-                    let players = game_clone.players.clone();
-                    let Some(player) = players.iter().find(|p| p.in_game_id == current_players_turn) else {
-                        log!(self.logger, LogLevel::Error, format!("Failed to apply the game actions to the clone of the game with id {} because there is no player that has the current in game turn {:?} and can therefore not return the wanted game!", game_id, current_players_turn).as_str());
-                        return Err(format!("There is no player that has the current in game turn {:?}!", current_players_turn));
-                    };
-                    self.get_legal_nodes(&mut game_clone, player.unique_id);
+        Ok(GameSummary::new(game))
+    }
+
+    /// Returns the leaderboard of the session the game with the given id is part of, which ranks the players by their cumulative score across the scenarios of the campaign.
+    pub fn get_leaderboard(&self, game_id: GameID) -> Result<Leaderboard, String> {
+        log!(self.logger, LogLevel::Debug, format!("Trying to get the leaderboard of the game with id: {}", game_id).as_str());
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not return the leaderboard of the game!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        Ok(Leaderboard::new(game))
+    }
+
+    /// Returns the render model of the game with the given id, which is what a renderer needs to draw an image of the board at the current turn.
+    pub fn get_render_model(&self, game_id: GameID) -> Result<RenderModel, String> {
+        log!(self.logger, LogLevel::Debug, format!("Trying to get the render model of the game with id: {}", game_id).as_str());
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not return the render model of the game!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        Ok(RenderModel::new(game))
+    }
+
+    /// Returns an overview of each of the games with the given ids, with the sections that have changed since the version of the game given with the id. Meant for facilitators overseeing several games at once.
+    /// The games that do not exist (anymore) are left out.
+    pub fn get_games_overview(&self, games: &[(GameID, Option<StateVersion>)]) -> Vec<GameOverview> {
+        log!(self.logger, LogLevel::Debug, format!("Getting the overview of {} games", games.len()).as_str());
+        games
+            .iter()
+            .filter_map(|(game_id, known_version)| {
+                let game = self.game(*game_id);
+                if game.is_none() {
+                    log!(self.logger, LogLevel::Debug, format!("There is no game with id {} to give an overview of", game_id).as_str());
                 }
-                log!(self.logger, LogLevel::Info, format!("Returning game with id: {}", game_id).as_str());
-                Ok(game_clone.clone())},
-            Err(e) => {
-                log!(self.logger, LogLevel::Error, format!("Failed to apply the game actions to the clone of the game with id: {} because: {} and can therefore not return the wanted game", game_id, e).as_str());
-                Err(e)
-            },
+                game.map(|game| GameOverview::new(game, *known_version))
+            })
+            .collect()
+    }
+
+    /// Returns at most `count` rows of the input history of the game with the given id in the given format, starting with the record at index `start`, or `None` if there are no more records.
+    /// Meant for streaming the export of long games a few rows at a time, so the whole history is never copied at once. If there is no game with the given id it will return an error.
+    pub fn export_input_rows(&self, game_id: GameID, start: usize, count: usize, format: ExportFormat) -> Result<Option<String>, String> {
+        let Some(game) = self.game(game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not export its inputs!", game_id).as_str());
+            return Err(format!("There is no game with id {}!", game_id));
+        };
+        let Some(records) = game.input_history.get(start..) else {
+            return Ok(None);
+        };
+        if records.is_empty() {
+            return Ok(None);
+        }
+        let mut rows = String::new();
+        for record in records.iter().take(count) {
+            rows.push_str(&record.to_export_row(format)?);
         }
+        Ok(Some(rows))
+    }
+
+    /// Compares the outcomes of the two referenced games, for example a forked game and the game it was forked from, or a game and one of its archived branches. If one of the games or branches does not exist it will return an error.
+    pub fn compare_games(&self, first: GameReference, second: GameReference) -> Result<GameComparison, String> {
+        log!(self.logger, LogLevel::Debug, format!("Trying to compare the game with id {} to the game with id {}", first.game_id, second.game_id).as_str());
+        let first_summary = self.get_referenced_summary(first)?;
+        let second_summary = self.get_referenced_summary(second)?;
+        Ok(GameComparison::new(first, &first_summary, second, &second_summary))
     }
 
-    /// Tells the game controller that a unique id is used by a player. This will also remove all inactive players. This means that if a player has not checked in after some amount of time, defined in [`constants`](../game_data/constants/index.html) as `PLAYER_TIMEOUT`, they will be removed.
+    fn get_referenced_summary(&self, reference: GameReference) -> Result<GameSummary, String> {
+        let Some(game) = self.game(reference.game_id) else {
+            log!(self.logger, LogLevel::Error, format!("There is no game with id {} and can therefore not compare it!", reference.game_id).as_str());
+            return Err(format!("There is no game with id {}!", reference.game_id));
+        };
+        let Some(branch) = reference.branch else {
+            return Ok(GameSummary::new(game));
+        };
+        let Some(archived_branch) = game.archived_branches.get(branch) else {
+            log!(self.logger, LogLevel::Error, format!("The game with id {} has no archived branch {} and can therefore not compare it!", reference.game_id, branch).as_str());
+            return Err(format!("The game with id {} has no archived branch {}! It has {} archived branches.", reference.game_id, branch, game.archived_branches.len()));
+        };
+        Ok(archived_branch.summary.clone())
+    }
+
+    /// Tells the game controller that a unique id is used by a player. This will also remove all inactive players. This means that if a player has not checked in after some amount of time, see [`GameController::player_timeout`], they will be removed.
     pub fn update_check_in_and_remove_inactive(
         &mut self,
         player_id: PlayerID,
@@ -230,31 +1597,224 @@ impl GameController {
             log!(self.logger, LogLevel::Error, format!("Player with id {} does not exist and can therefore not update the check in!", player_id).as_str());
             return Err(format!("Player with id {} does not exist!", player_id));
         }
-        for mut id in self.unique_ids.iter_mut() {
+        for id in self.unique_ids.iter_mut() {
             if id.0 == player_id {
                 id.1 = Instant::now();
             }
         }
+        self.record_check_in(player_id, current_timestamp());
         self.remove_inactive_ids();
         self.remove_empty_games();
+        self.record_due_session_reminders();
+        self.record_stale_connections();
         log!(self.logger, LogLevel::Debug, format!("Updated check in for player with id {} and removed unused ids and empty games!", player_id).as_str());
         Ok(())
     }
 
+    /// Records the session reminders that are due in the games, and replicates the games that got new reminders.
+    fn record_due_session_reminders(&mut self) {
+        let now = current_timestamp();
+        for game in self.games.values_mut() {
+            // Only the games with a session clock can have reminders, so the others are not copied if a snapshot of them is in use.
+            if game.session_clock.session_length.is_some() && Arc::make_mut(game).record_due_session_reminders(now) {
+                Self::replicate_game(&self.replicators, game);
+            }
+        }
+    }
+
+    fn record_check_in(&mut self, player_id: PlayerID, now: Timestamp) {
+        match self.player_connections.iter_mut().find(|connection| connection.player_id == player_id) {
+            Some(connection) => connection.check_in(now),
+            None => self.player_connections.push(PlayerConnection::new(player_id, now)),
+        }
+    }
+
+    fn reset_connection(&mut self, player_id: PlayerID, now: Timestamp) {
+        self.player_connections.retain(|connection| connection.player_id != player_id);
+        self.player_connections.push(PlayerConnection::new(player_id, now));
+    }
+
+    /// Judges the quality of the connection of every player again, and records a [`GameEventKind::PlayerConnectionStale`] event in the games of the players whose connection has become stale since it was last judged.
+    fn record_stale_connections(&mut self) {
+        let now = current_timestamp();
+        let mut stale_player_ids = Vec::new();
+        for connection in self.player_connections.iter_mut() {
+            let quality = connection.quality_at(now);
+            if quality == ConnectionQuality::Stale && connection.quality != ConnectionQuality::Stale {
+                stale_player_ids.push(connection.player_id);
+            }
+            connection.quality = quality;
+        }
+        for player_id in stale_player_ids {
+            let Some(game) = self.player_games.get(&player_id).and_then(|game_id| self.games.get_mut(game_id)).map(Arc::make_mut) else {
+                continue;
+            };
+            log!(self.logger, LogLevel::Info, format!("The connection of the player with id {} in the game with id {} has become stale", player_id, game.id).as_str());
+            game.record_event(GameEventKind::PlayerConnectionStale(player_id));
+            Self::replicate_game(&self.replicators, game);
+        }
+    }
+
+    /// Returns the connections of the players in the game, with their quality at the given time.
+    fn connections_of_players_in(&self, game: &GameState, now: Timestamp) -> Vec<PlayerConnection> {
+        game.players
+            .iter()
+            .filter_map(|player| self.player_connections.iter().find(|connection| connection.player_id == player.unique_id))
+            .map(|connection| PlayerConnection { quality: connection.quality_at(now), ..connection.clone() })
+            .collect()
+    }
+
+    /// Returns an error if the server is in demo mode and already has the most games it can have.
+    fn check_room_for_new_game(&self) -> Result<(), String> {
+        match self.demo_mode {
+            Some(demo_mode) if self.games.len() >= demo_mode.max_games => {
+                log!(self.logger, LogLevel::Warning, format!("Could not create a new game because the demo server already has {} games", self.games.len()).as_str());
+                Err("The demo server already has as many games as it can have! Please try again later.".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes the games nobody has sent an input to for too long if the server is in demo mode, and warns the players of the games that will soon be removed.
+    fn expire_inactive_games(&mut self) {
+        let Some(demo_mode) = self.demo_mode else {
+            return;
+        };
+        let now = current_timestamp();
+        let mut expired_game_ids = Vec::new();
+        for game in self.games.values_mut() {
+            let time_left = demo_mode.time_left(game.last_activity, now);
+            if time_left == 0 {
+                expired_game_ids.push(game.id);
+            } else if time_left <= demo_mode.expiry_warning && !game.expiry_warning_sent && Arc::make_mut(game).record_expiry_warning(time_left) {
+                Self::replicate_game(&self.replicators, game);
+            }
+        }
+        for id in expired_game_ids {
+            self.remove_game(id);
+            log!(self.logger, LogLevel::Info, format!("Removed the game with id {} because nobody had sent an input to it for {} minutes", id, demo_mode.game_expiry / 60_000).as_str());
+            self.player_view_cache.invalidate_game(id);
+            self.expired_game_ids.push(id);
+            self.replicate(ReplicationEntry::GameRemoved(id));
+        }
+    }
+
     fn remove_empty_games(&mut self) {
+        self.expire_inactive_games();
         log!(self.logger, LogLevel::Debug, "Removing empty games!");
-        self.games.retain(|game| !game.players.is_empty());
+        let empty_game_ids: Vec<GameID> = self
+            .games
+            .values()
+            .filter(|game| game.players.iter().all(Player::is_bot))
+            .map(|game| game.id)
+            .collect();
+        for id in empty_game_ids {
+            self.remove_game(id);
+            self.player_view_cache.invalidate_game(id);
+            self.replicate(ReplicationEntry::GameRemoved(id));
+        }
+    }
+
+    /// Asks the players that have not checked in for [`INACTIVITY_WARNING_PERCENT`](../game_data/constants/constant.INACTIVITY_WARNING_PERCENT.html) of the player timeout whether they are still there,
+    /// by setting when they will be removed in their connections and recording a [`GameEventKind::PlayerInactive`] event in their games, which is pushed to the clients of the games.
+    fn warn_inactive_players(&mut self) {
+        let now = current_timestamp();
+        let warn_after = self.player_timeout * INACTIVITY_WARNING_PERCENT / 100;
+        let inactive_players: Vec<(PlayerID, Duration)> = self
+            .unique_ids
+            .iter()
+            .filter(|(_, last_checkin)| last_checkin.elapsed() >= warn_after)
+            .map(|(id, last_checkin)| (*id, self.player_timeout.saturating_sub(last_checkin.elapsed())))
+            .filter(|(_, time_left)| !time_left.is_zero())
+            .collect();
+        for (player_id, time_left) in inactive_players {
+            let Some(connection) = self.player_connections.iter_mut().find(|connection| connection.player_id == player_id && connection.removal_at.is_none()) else {
+                continue;
+            };
+            connection.removal_at = Some(now + Milliseconds::try_from(time_left.as_millis()).unwrap_or(Milliseconds::MAX));
+            let Some(game) = self.player_games.get(&player_id).and_then(|game_id| self.games.get_mut(game_id)).map(Arc::make_mut) else {
+                continue;
+            };
+            let seconds_left = u32::try_from(time_left.as_millis().div_ceil(1000)).unwrap_or(u32::MAX);
+            log!(self.logger, LogLevel::Info, format!("The player with id {} in the game with id {} has not checked in for a while, and is asked whether they are still there before being removed in {} seconds", player_id, game.id, seconds_left).as_str());
+            game.record_event(GameEventKind::PlayerInactive(player_id, seconds_left));
+            Self::replicate_game(&self.replicators, game);
+            self.update_publishers.iter().for_each(|update_publisher| update_publisher.publish(game));
+        }
     }
 
     fn remove_inactive_ids(&mut self) {
+        self.warn_inactive_players();
         log!(self.logger, LogLevel::Debug, "Removing inactive ids!");
+        // The bots never check in, so they are kept for as long as they are in a game.
+        let bot_ids: Vec<PlayerID> = self.games.values().flat_map(|game| game.players.iter().filter(|player| player.is_bot()).map(|player| player.unique_id)).collect();
+        self.unique_ids
+            .iter_mut()
+            .filter(|(id, _)| bot_ids.contains(id))
+            .for_each(|id| id.1 = Instant::now());
+        let player_timeout = self.player_timeout;
+        let inactive_ids: Vec<PlayerID> = self
+            .unique_ids
+            .iter()
+            .filter(|(_, last_checkin)| last_checkin.elapsed() >= player_timeout)
+            .map(|(id, _)| *id)
+            .collect();
         self.unique_ids
-            .retain(|(_, last_checkin)| last_checkin.elapsed() < PLAYER_TIMEOUT);
+            .retain(|(_, last_checkin)| last_checkin.elapsed() < player_timeout);
+        for id in inactive_ids {
+            self.replicate(ReplicationEntry::PlayerIdRemoved(id));
+        }
         let remaining_ids = self.unique_ids.clone();
-        self.games.iter_mut().for_each(|game| {
+        self.player_connections
+            .retain(|connection| remaining_ids.iter().any(|(id, _)| connection.player_id == *id));
+        self.local_players.iter_mut().for_each(|local_players| {
+            local_players
+                .player_ids
+                .retain(|player_id| remaining_ids.iter().any(|(id, _)| player_id == id));
+        });
+        let now = current_timestamp();
+        let mut disconnected_players = Vec::new();
+        self.games.values_mut().for_each(|game| {
+            let removed_player_ids: Vec<PlayerID> = game
+                .players
+                .iter()
+                .filter(|player| remaining_ids.iter().all(|(id, _)| &player.unique_id != id))
+                .map(|player| player.unique_id)
+                .collect();
+            if removed_player_ids.is_empty() {
+                return;
+            }
+            let game = Arc::make_mut(game);
+            disconnected_players.extend(
+                game.players
+                    .iter()
+                    .filter(|player| removed_player_ids.contains(&player.unique_id))
+                    .map(|player| DisconnectedPlayer { game_id: game.id, player: player.clone(), disconnected_at: now }),
+            );
             game.players
                 .retain(|player| remaining_ids.iter().any(|(id, _)| &player.unique_id == id));
+            game.mark_sections_changed(&[StateSection::Players]);
+            removed_player_ids
+                .into_iter()
+                .for_each(|player_id| game.record_event(GameEventKind::PlayerTimedOut(player_id)));
+        });
+        disconnected_players.iter().for_each(|disconnected| {
+            self.player_games.remove(&disconnected.player.unique_id);
+        });
+        // The sessions of the removed players are kept so that they can reconnect to their games, while the sessions of removed ids that were not in a game are dropped.
+        self.player_sessions.retain_mut(|session| {
+            if session.disconnected_player.is_some() || remaining_ids.iter().any(|(id, _)| *id == session.player_id) {
+                return true;
+            }
+            match disconnected_players.iter().position(|disconnected| disconnected.player.unique_id == session.player_id) {
+                Some(position) => {
+                    session.disconnected_player = Some(disconnected_players.swap_remove(position));
+                    true
+                }
+                None => false,
+            }
         });
+        self.remove_expired_sessions(now);
         log!(self.logger, LogLevel::Debug, "Removed inactive ids!");
     }
 
@@ -265,18 +1825,18 @@ impl GameController {
         game.assign_player_role((input.player_id, related_role))
     }
 
-    fn generate_unused_unique_id(&mut self) -> Option<PlayerID> {
+    fn generate_unused_unique_id(&self) -> Option<PlayerID> {
         log!(self.logger, LogLevel::Debug, "Generating unused unique id!");
-        let mut id: PlayerID = rand::random::<PlayerID>();
+        let mut id = PlayerID(rand::random());
         let mut found_unique_id = false;
         for _ in 0..100_000 {
             {
-                if !self.unique_ids.iter().any(|(l_id, _)| l_id == &id) {
+                if !self.unique_ids.iter().any(|(l_id, _)| l_id == &id) && !self.player_sessions.iter().any(|session| session.player_id == id) {
                     found_unique_id = true;
                     break;
                 }
             }
-            id = rand::random::<PlayerID>();
+            id = PlayerID(rand::random());
         }
 
         if !found_unique_id {
@@ -287,57 +1847,115 @@ impl GameController {
         Some(id)
     }
 
+    fn sanitize_new_game_info(&self, new_lobby: &mut NewGameInfo) -> Result<(), String> {
+        new_lobby.name = self.sanitize_text(&new_lobby.name, TextKind::LobbyName)?;
+        new_lobby.host.name = self.sanitize_text(&new_lobby.host.name, TextKind::PlayerName)?;
+        Ok(())
+    }
+
+    fn sanitize_text(&self, text: &str, kind: TextKind) -> Result<String, String> {
+        self.text_filter.sanitize(text, kind).map_err(|e| {
+            log!(self.logger, LogLevel::Warning, format!("Rejected a {:?} text from a player because: {}", kind, e).as_str());
+            e
+        })
+    }
+
     fn create_new_game_and_assign_host(
-        &mut self,
+        &self,
         new_lobby: NewGameInfo,
     ) -> Result<GameState, String> {
         log!(self.logger, LogLevel::Debug, format!("Trying to create a new game with name {} and assigning host with id {}", new_lobby.name, new_lobby.host.unique_id).as_str());
+        let mut new_game = GameState::new(new_lobby.name.clone(), self.generate_unused_game_id());
+        if let Some(map_name) = &new_lobby.map {
+            let map = match self.map_library.get_map(map_name) {
+                Ok(map) => map,
+                Err(e) => {
+                    log!(self.logger, LogLevel::Error, format!("The game can not be created on the map {map_name} because: {e}").as_str());
+                    return Err(e);
+                }
+            };
+            new_game.map = GameMap::new(map, MapOverlay::default());
+            new_game.map_name = map_name.clone();
+        }
+        if let Some(deck_name) = &new_lobby.objective_deck {
+            let Some(objective_deck) = self.objective_decks.iter().find(|deck| deck.name == *deck_name) else {
+                log!(self.logger, LogLevel::Error, format!("There is no objective deck called {deck_name} and the game can therefore not be created!").as_str());
+                return Err(format!("There is no objective deck called {deck_name}!"));
+            };
+            new_game.objective_deck = Some(objective_deck.clone());
+        }
+        if !new_lobby.campaign.is_empty() {
+            let scenarios = self.get_scenarios();
+            let mut campaign_scenarios = Vec::new();
+            for scenario_name in new_lobby.campaign.iter() {
+                let Some(scenario) = scenarios.iter().find(|scenario| scenario.name == *scenario_name) else {
+                    log!(self.logger, LogLevel::Error, format!("There is no scenario called {scenario_name} and the campaign can therefore not be created!").as_str());
+                    return Err(format!("There is no scenario called {scenario_name}!"));
+                };
+                campaign_scenarios.push(scenario.clone());
+            }
+            let campaign = Campaign::new(campaign_scenarios)?;
+            if let Some(first_scenario) = campaign.current_scenario() {
+                new_game.apply_scenario(first_scenario)?;
+            }
+            new_game.campaign = Some(campaign);
+        }
+        if let Some(score_weights) = new_lobby.score_weights {
+            score_weights.validate()?;
+            new_game.score_weights = score_weights;
+        }
+        let new_game = self.assign_host_to_new_game(new_game, new_lobby.host.clone())?;
+        log!(self.logger, LogLevel::Info, format!("Created new game with name {} and assigned host with id {}", new_lobby.name, new_lobby.host.unique_id).as_str());
+        Ok(new_game)
+    }
+
+    fn assign_host_to_new_game(&self, mut new_game: GameState, host: Player) -> Result<GameState, String> {
         if self
             .unique_ids
             .iter()
-            .all(|(id, _)| id != &new_lobby.host.unique_id)
+            .all(|(id, _)| id != &host.unique_id)
         {
             log!(self.logger, LogLevel::Error, "A player that has a unique ID that was not made by the server cannot create a lobby and can therefore not create a new game");
             return Err("A player that has a unique ID that was not made by the server cannot create a lobby.".to_string());
         }
 
-        for game in self.games.iter() {
-            if game.contains_player_with_unique_id(new_lobby.host.unique_id) {
-                log!(self.logger, LogLevel::Error, "A player that is already connected to a game in progress cannot create a new game");
-                return Err("A player that is already connected to a game in progress cannot create a new game.".to_string());
-            }
+        if self.player_games.contains_key(&host.unique_id) {
+            log!(self.logger, LogLevel::Error, "A player that is already connected to a game in progress cannot create a new game");
+            return Err("A player that is already connected to a game in progress cannot create a new game.".to_string());
         }
 
-        let mut new_game = GameState::new(new_lobby.name.clone(), self.generate_unused_game_id());
-        match new_game.assign_player_to_game(new_lobby.host.clone()) {
+        match new_game.assign_player_to_game(host.clone()) {
             Ok(_) => (),
             Err(e) => {
-                log!(self.logger, LogLevel::Error, format!("Failed to assign host with id {} to the new game because: {}", new_lobby.host.unique_id, e).as_str());
+                log!(self.logger, LogLevel::Error, format!("Failed to assign host with id {} to the new game because: {}", host.unique_id, e).as_str());
                 return Err(format!("Failed to create new game because: {e}"));
             },
         };
-        log!(self.logger, LogLevel::Info, format!("Created new game with name {} and assigned host with id {}", new_lobby.name, new_lobby.host.unique_id).as_str());
         Ok(new_game)
     }
 
+    /// Returns the map in the map library the game is played on. Will return an error if the map is not in the library, like when the map file has been removed since the game was saved
+    /// or the map is from a content pack that has not been uploaded to this server. The game is never moved to another map, since its players and overlay refer to the nodes of its own map.
+    fn base_map_of(&self, game: &GameState) -> Result<Arc<NodeMap>, String> {
+        self.map_library.get_map(&game.map_name).map_err(|e| {
+            log!(self.logger, LogLevel::Error, format!("The game with id {} can not be put on its map {} because: {}", game.id, game.map_name, e).as_str());
+            format!("The map {} of the game with id {} is not available: {}", game.map_name, game.id, e)
+        })
+    }
+
     fn generate_unused_game_id(&self) -> GameID {
         log!(self.logger, LogLevel::Debug, "Trying to generate unused game id!");
-        let mut existing_game_ids = Vec::new();
-        for game in self.games.iter() {
-            existing_game_ids.push(game.id);
-        }
-
-        let mut id = rand::random::<GameID>();
-        while existing_game_ids.contains(&id) {
-            id = rand::random::<GameID>();
+        let mut id = GameID(rand::random());
+        while self.games.contains_key(&id) {
+            id = GameID(rand::random());
         }
         log!(self.logger, LogLevel::Debug, format!("Generated unused game id: {}", id).as_str());
         id
     }
 
-    fn apply_game_actions(game: &mut GameState) -> Result<(), String> {
+    fn apply_game_actions(game: &mut GameState, extensions: &[Box<dyn GameExtension + Send + Sync>]) -> Result<(), String> {
         for action in game.actions.clone().iter() {
-            match Self::apply_input(action.clone(), game) {
+            match Self::apply_input(action.clone(), game, extensions) {
                 Ok(_) => (),
                 Err(e) => return Err(e + " No actions are applied to the game."),
             };
@@ -345,60 +1963,169 @@ impl GameController {
         Ok(())
     }
 
-    fn game_next_turn(game: &mut GameState) -> Result<(), String> {
+    /// Returns a copy of the game with its staged actions applied. The applied state the game has kept is used if it's still up to date, so that the actions are not applied again.
+    fn applied_game(game: &GameState, extensions: &[Box<dyn GameExtension + Send + Sync>]) -> Result<GameState, String> {
+        if let Some(applied_game) = game.kept_applied_actions() {
+            return Ok(applied_game);
+        }
         let mut game_clone = game.clone();
-        match Self::apply_game_actions(&mut game_clone) {
+        Self::apply_game_actions(&mut game_clone, extensions)?;
+        Ok(game_clone)
+    }
+
+    fn game_next_turn(game: &mut GameState, extensions: &[Box<dyn GameExtension + Send + Sync>]) -> Result<(), String> {
+        let mut game_clone = Self::applied_game(game, extensions)?;
+        let was_game_over = game_clone.is_game_over();
+        match Self::call_extensions(extensions, &mut game_clone, |extension, game| extension.on_turn_end(game)) {
             Ok(_) => (),
             Err(e) => return Err(e),
         }
+        if !was_game_over && game_clone.is_game_over() {
+            Self::call_extensions(extensions, &mut game_clone, |extension, game| extension.on_game_end(game))?;
+        }
         std::mem::swap(game, &mut game_clone);
         game.actions.clear();
+        game.refill_moves_of_current_player();
         game.next_player_turn();
+        game.apply_modifier_persistence()?;
+        game.update_construction_works();
+        Self::apply_pending_measures(game, extensions);
         Ok(())
     }
 
-    fn add_action(input: PlayerInput, game: &mut GameState) -> Result<(), String> {
-        let mut game_clone = game.clone();
-        for action in game.actions.iter() {
-            match Self::apply_input(action.clone(), &mut game_clone) {
-                Ok(_) => (),
-                Err(e) => return Err(e),
+    /// Applies the measures the orchestrator sent during the turn that just ended. A measure that can no longer be applied is left out and recorded as failed in the input history.
+    fn apply_pending_measures(game: &mut GameState, extensions: &[Box<dyn GameExtension + Send + Sync>]) {
+        for measure in std::mem::take(&mut game.pending_measures) {
+            let mut game_with_measure = game.clone();
+            match Self::apply_input(measure.clone(), &mut game_with_measure, extensions) {
+                Ok(_) => *game = game_with_measure,
+                Err(e) => {
+                    let error = format!("The measure could not be applied when the turn ended because: {e}");
+                    game.record_input(game.turn_number, measure.clone(), InputOutcome::Failed(error.clone()));
+                    game.add_dead_letter(measure, error);
+                }
             }
         }
+    }
+
+    /// Stages the input as an action if it can be applied after the other staged actions. The input is applied to the applied state the game has kept, so that a long turn does not make the server apply every staged action again for each new action.
+    fn add_action(input: PlayerInput, game: &mut GameState, extensions: &[Box<dyn GameExtension + Send + Sync>]) -> Result<(), String> {
+        let max_staged_actions = game.lobby_settings.max_staged_actions();
+        if game.actions.len() >= max_staged_actions {
+            return Err(format!("No more than {} actions can be staged in one turn!", max_staged_actions));
+        }
+        let mut game_clone = Self::applied_game(game, extensions)?;
 
-        match Self::apply_input(input.clone(), &mut game_clone) {
+        match Self::apply_input(input.clone(), &mut game_clone, extensions) {
             Ok(_) => game.actions.push(input),
             Err(e) => return Err(e),
         }
+        game.keep_applied_actions(game_clone);
         Ok(())
     }
 
-    fn handle_input(input: PlayerInput, game: &mut GameState) -> Result<(), String> {
+    /// Handles the input and updates the status of the game, so that the game is finished as soon as an input meets the win conditions of the game.
+    /// The checkpoints and the copies of the game from the start of the turns are kept in the history of the game.
+    fn handle_input(input: PlayerInput, game: &mut GameState, history: &mut GameHistory, extensions: &[Box<dyn GameExtension + Send + Sync>]) -> Result<(), String> {
+        Self::stage_or_apply_input(input, game, history, extensions)?;
+        win_conditions::update_game_status(game);
+        Ok(())
+    }
+
+    fn stage_or_apply_input(input: PlayerInput, game: &mut GameState, history: &mut GameHistory, extensions: &[Box<dyn GameExtension + Send + Sync>]) -> Result<(), String> {
         if input.input_type == PlayerInputType::NextTurn {
-            return Self::game_next_turn(game);
+            let result = Self::game_next_turn(game, extensions);
+            game.applied_actions.clear();
+            if result.is_ok() {
+                history.record_turn_snapshot(game);
+            }
+            return result;
+        } else if input.input_type == PlayerInputType::CreateCheckpoint {
+            let Some(label) = input.related_string else {
+                return Err("There was no label to create the checkpoint with!".to_string());
+            };
+            game.applied_actions.clear();
+            return history.create_checkpoint(game, label);
+        } else if input.input_type == PlayerInputType::RestoreCheckpoint {
+            let Some(label) = input.related_string else {
+                return Err("There was no label of the checkpoint to restore!".to_string());
+            };
+            game.applied_actions.clear();
+            return history.restore_checkpoint(game, &label);
         } else if input.input_type == PlayerInputType::UndoAction {
+            game.applied_actions.clear();
             match game.actions.pop() {
-                Some(_) => return Ok(()),
+                Some(_) => {
+                    game.mark_last_staged_input_undone();
+                    return Ok(());
+                }
                 None => return Err("There is no action to undo!".to_string()),
             }
-        } else if input.input_type == PlayerInputType::ChangeRole
-            || input.input_type == PlayerInputType::StartGame
-            || input.input_type == PlayerInputType::AssignSituationCard
-            || input.input_type == PlayerInputType::LeaveGame
-        {
-            match Self::apply_input(input, game) {
-                Ok(_) => return Ok(()),
-                Err(e) => return Err(e),
+        } else if game.is_async_measure(&input) {
+            game.pending_measures.push(input);
+            return Ok(());
+        } else if !input.input_type.is_staged() {
+            game.applied_actions.clear();
+            let was_lobby = game.is_lobby;
+            let starts_next_scenario = input.input_type == PlayerInputType::NextScenario;
+            Self::apply_input(input, game, extensions)?;
+            // The next scenario is a new game, so the copies of the scenario before it are no longer of use.
+            if starts_next_scenario {
+                *history = GameHistory::default();
+            }
+            if was_lobby && !game.is_lobby {
+                history.record_turn_snapshot(game);
+            }
+            return Ok(());
+        }
+
+        Self::add_action(input, game, extensions)
+    }
+
+    fn apply_input(input: PlayerInput, game: &mut GameState, extensions: &[Box<dyn GameExtension + Send + Sync>]) -> Result<(), String> {
+        if extensions.is_empty() {
+            return Self::apply_input_to_game(input, game);
+        }
+        let game_before = game.clone();
+        match Self::apply_input_to_game(input.clone(), game) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        }
+        if game_before.is_lobby && !game.is_lobby {
+            Self::call_extensions(extensions, game, |extension, game| extension.on_game_start(game))?;
+        }
+        if input.input_type == PlayerInputType::Movement {
+            if let Some(outcome) = game.last_movement.clone() {
+                Self::call_extensions(extensions, game, |extension, game| extension.on_movement(game, &outcome))?;
             }
         }
+        Self::call_extensions(extensions, game, |extension, game| extension.on_input_applied(&game_before, game, &input))?;
+        if !game_before.is_game_over() && game.is_game_over() {
+            Self::call_extensions(extensions, game, |extension, game| extension.on_game_end(game))?;
+        }
+        Ok(())
+    }
 
-        Self::add_action(input, game)
+    fn call_extensions(
+        extensions: &[Box<dyn GameExtension + Send + Sync>],
+        game: &mut GameState,
+        hook: impl Fn(&(dyn GameExtension + Send + Sync), &mut GameState) -> Result<(), String>,
+    ) -> Result<(), String> {
+        for extension in extensions {
+            if let Err(e) = hook(extension.as_ref(), game) {
+                return Err(format!("The extension {} failed because: {}", extension.name(), e));
+            }
+        }
+        Ok(())
     }
 
-    fn apply_input(input: PlayerInput, game: &mut GameState) -> Result<(), String> {
+    fn apply_input_to_game(input: PlayerInput, game: &mut GameState) -> Result<(), String> {
         match input.input_type {
             PlayerInputType::Movement => match Self::handle_movement(input, game) {
-                Ok(_) => Ok(()),
+                Ok(outcome) => {
+                    game.last_movement = Some(outcome);
+                    Ok(())
+                },
                 Err(e) => Err(e),
             },
             PlayerInputType::ChangeRole => match Self::change_role_player(input, game) {
@@ -415,12 +2142,12 @@ impl GameController {
             PlayerInputType::UndoAction => {
                 Err("This cannot be done in GameController::apply_input!".to_string())
             }
-            PlayerInputType::ModifyDistrict => {
-                match Self::handle_district_restriction(input, game) {
-                    Ok(_) => Ok(()),
-                    Err(e) => Err(e),
-                }
-            }
+            PlayerInputType::ModifyDistrict
+            | PlayerInputType::ModifyEdgeRestrictions
+            | PlayerInputType::RevokeMeasure
+            | PlayerInputType::SetCongestionZone
+            | PlayerInputType::EnactMeasureTemplate
+            | PlayerInputType::ScheduleConstruction => game.apply_measure_input(input),
             PlayerInputType::StartGame => match game.start_game() {
                 Ok(_) => Ok(()),
                 Err(e) => Err(e),
@@ -445,15 +2172,6 @@ impl GameController {
                 game.remove_player_with_id(input.player_id);
                 Ok(())
             }
-            PlayerInputType::ModifyEdgeRestrictions => {
-                let Some(edge_mod) = input.edge_modifier else {
-                    return Err("There was no park and ride modifier when wanting to modify park and ride!".to_string());
-                };
-                if edge_mod.delete {
-                    return game.remove_restriction_from_edge(&edge_mod);
-                }
-                game.add_edge_restriction(&edge_mod, true)
-            }
             PlayerInputType::SetPlayerBusBool => {
                 let Some(boolean) = input.related_bool else {
                     return Err("There was no bool to set the bus bool with!".to_string());
@@ -461,10 +2179,91 @@ impl GameController {
                 game.set_player_bus_bool(input.player_id, boolean);
                 Ok(())
             },
+            PlayerInputType::ChangeLobbySettings => {
+                let Some(lobby_settings) = input.lobby_settings else {
+                    return Err("There were no lobby settings to change to!".to_string());
+                };
+                game.lobby_settings = lobby_settings;
+                Ok(())
+            },
+            PlayerInputType::RenamePlayer => {
+                let Some(new_name) = input.related_string else {
+                    return Err("There was no name to rename the player to!".to_string());
+                };
+                game.rename_player(input.player_id, new_name)
+            },
+            PlayerInputType::OfferRide => {
+                let Some(to_player_id) = input.related_player_id else {
+                    return Err("There was no player to offer a ride to!".to_string());
+                };
+                game.offer_ride(input.player_id, to_player_id)
+            },
+            PlayerInputType::AcceptRide => {
+                let Some(driver_id) = input.related_player_id else {
+                    return Err("There was no driver whose ride should be accepted!".to_string());
+                };
+                game.accept_ride(input.player_id, driver_id)
+            },
+            PlayerInputType::ExitVehicle => {
+                let Some(node_id) = input.related_node_id else {
+                    return Err("There was no node to exit the vehicle at!".to_string());
+                };
+                game.exit_vehicle(input.player_id, node_id)
+            },
+            PlayerInputType::Pass => game.pass(input.player_id),
+            PlayerInputType::ClaimSeat => {
+                let Some(seat) = input.related_role else {
+                    return Err("There was no role of the seat to claim!".to_string());
+                };
+                game.claim_seat(input.player_id, seat)
+            },
+            PlayerInputType::CreateCheckpoint | PlayerInputType::RestoreCheckpoint => Err("The checkpoints can only be created and restored by handling the input, since they are kept in the history of the game!".to_string()),
+            PlayerInputType::NextScenario => game.start_next_scenario(),
+            PlayerInputType::TickTimedProp => {
+                let Some(name) = input.related_string else {
+                    return Err("There was no name of the timed prop to tick!".to_string());
+                };
+                game.tick_timed_prop(input.player_id, &name)
+            },
+            PlayerInputType::RetryDeadLetter | PlayerInputType::DiscardDeadLetter => {
+                let Some(dead_letter_id) = input.dead_letter_id else {
+                    return Err("There was no dead letter id in the input, and it's therefore not known which dead letter to remove!".to_string());
+                };
+                game.remove_dead_letter(dead_letter_id).map(|_| ())
+            },
+            PlayerInputType::OverrideAndApply => Ok(()),
+            PlayerInputType::AnnotateMap => {
+                let Some(annotation_target) = input.annotation_target else {
+                    return Err("There was no district or edge in the input, and it's therefore not known where to attach the note!".to_string());
+                };
+                game.annotate_map(annotation_target, input.related_string)
+            },
+            PlayerInputType::ChatMessage => {
+                let Some(text) = input.related_string else {
+                    return Err("There was no message in the input, and it's therefore not known what to send to the chat!".to_string());
+                };
+                game.add_chat_message(input.player_id, text).map(|_| ())
+            },
+            PlayerInputType::OverrideRule => {
+                let Some(rule_warning_id) = input.rule_warning_id else {
+                    return Err("There was no rule warning id in the input, and it's therefore not known which input to accept!".to_string());
+                };
+                game.remove_rule_warning(rule_warning_id).map(|_| ())
+            },
+        }
+    }
+
+    /// Sets the legal nodes of the game to the ones of the player, from the player view cache if they have already been computed for this version of the game.
+    fn get_cached_legal_nodes(&mut self, game: &mut GameState, player_id: PlayerID) {
+        if let Some(legal_nodes) = self.player_view_cache.get(game.id, player_id, game.version) {
+            game.legal_nodes = legal_nodes;
+            return;
         }
+        self.get_legal_nodes(game, player_id);
+        self.player_view_cache.insert(game.id, player_id, game.version, game.legal_nodes.clone());
     }
 
-    fn get_legal_nodes(&mut self, game: &mut GameState, player_id: PlayerID) {
+    fn get_legal_nodes(&self, game: &mut GameState, player_id: PlayerID) {
         log!(self.logger, LogLevel::Debug, format!("Getting legal nodes for player with id {}!", player_id).as_str());
         let mut legal_nodes: Vec<NodeID> = Vec::new();
 
@@ -491,16 +2290,13 @@ impl GameController {
         };
 
         for relationship in neighbouring_node_relationships {
+            if legal_nodes.contains(&relationship.to) {
+                continue;
+            }
             let input = PlayerInput {
-                district_modifier: None, 
-                player_id: player.unique_id, 
-                game_id: connected_game_id, 
-                input_type: PlayerInputType::Movement, 
-                related_role: None, 
-                related_node_id: Some(relationship.to), 
-                situation_card_id: None, 
-                edge_modifier: None, 
-                related_bool: None
+                related_node_id: Some(relationship.to),
+                edge_id: Some(relationship.edge_id),
+                ..PlayerInput::new(player.unique_id, connected_game_id, PlayerInputType::Movement)
             };
             self.rule_checker.is_input_valid(game, &input).map_or_else(|| legal_nodes.push(relationship.to), |e| log!(self.logger, LogLevel::Debug, format!("Input was not valid because: {}", e).as_str()));
         }
@@ -508,29 +2304,25 @@ impl GameController {
         log!(self.logger, LogLevel::Debug, format!("Got legal nodes for player with id {}!", player_id).as_str());
     }
 
-    fn handle_movement(input: PlayerInput, game: &mut GameState) -> Result<(), String> {
+    fn handle_movement(input: PlayerInput, game: &mut GameState) -> Result<MovementOutcome, String> {
         let Some(related_node_id) = input.related_node_id else {
             return Err("There was no node related to the movement!".to_string());
         };
-        match game.move_player_with_id(input.player_id, related_node_id) {
-            Ok(_) => (),
+        let objective_before = game.get_player_with_unique_id(input.player_id)?.objective_card;
+        let mut outcome = match game.move_vehicle_with_id(input.player_id, related_node_id, input.edge_id) {
+            Ok(outcome) => outcome,
             Err(e) => return Err(format!("Failed to move player because: {e}")),
-        }
+        };
 
         match game.update_objective_status() {
             Ok(_) => (),
             Err(e) => return Err(e),
         }
-        Ok(())
-    }
-
-    fn handle_district_restriction(input: PlayerInput, game: &mut GameState) -> Result<(), String> {
-        let Some(district_modifier) = input.district_modifier else {
-            return Err("There was no district in the input modifier even though it was marked as a district input".to_string());
-        };
-        if district_modifier.delete {
-            return game.remove_district_modifier(district_modifier);
+        let objective_after = game.get_player_with_unique_id(input.player_id)?.objective_card;
+        if let (Some(before), Some(after)) = (objective_before, objective_after) {
+            outcome.picked_up_objective = !before.picked_package_up && after.picked_package_up;
+            outcome.delivered_objective = !before.dropped_package_off && after.dropped_package_off;
         }
-        game.add_district_modifier(district_modifier)
+        Ok(outcome)
     }
 }
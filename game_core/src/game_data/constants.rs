@@ -1,11 +1,89 @@
 use std::time::Duration;
 
-use super::custom_types::MovementValue;
+use super::custom_types::{Emissions, Milliseconds, Money, MovementCost, MovementValue, Score};
 
 pub const MAX_PLAYER_COUNT: usize = 7;
 pub const MAX_TOLL_MODIFIER_COUNT: usize = 1;
 pub const MAX_ACCESS_MODIFIER_COUNT: usize = 2;
 pub const MAX_PRIORITY_MODIFIER_COUNT: usize = 2;
 pub const START_MOVEMENT_AMOUNT: MovementValue = 8;
+/// The budget every player starts the game with. Congestion charges are paid from it.
+pub const START_PLAYER_BUDGET: Money = 20;
 pub const HEAVY_VEHICLE_INCLUSIVE_THRESHOLD: u32 = 5;
-pub const PLAYER_TIMEOUT: Duration = Duration::from_secs(90);
\ No newline at end of file
+pub const PLAYER_TIMEOUT: Duration = Duration::from_secs(90);
+/// How much of the player timeout, in percent, a player can go without checking in before the player and the orchestrator are warned that the player is about to be removed.
+pub const INACTIVITY_WARNING_PERCENT: u32 = 75;
+/// How long a player whose id was removed for being inactive can reconnect to the game with the session token, in milliseconds.
+pub const RECONNECT_WINDOW: Milliseconds = 30 * 60 * 1000;
+/// The most characters the name of a player can have.
+pub const MAX_PLAYER_NAME_LENGTH: usize = 32;
+/// The most characters the name of a lobby can have.
+pub const MAX_LOBBY_NAME_LENGTH: usize = 48;
+/// The most characters any other text from a player, like the label of a checkpoint, can have.
+pub const MAX_PLAYER_TEXT_LENGTH: usize = 200;
+/// The most characters a note the orchestrator attaches to the map can have, so it fits as a label on the map.
+pub const MAX_NOTE_LENGTH: usize = 60;
+/// The amount of characters in the access tokens the server issues.
+pub const ACCESS_TOKEN_LENGTH: usize = 32;
+/// The most characters a message in the chat of a game can have.
+pub const MAX_CHAT_MESSAGE_LENGTH: usize = 280;
+/// The amount of the latest events that are kept in each game.
+pub const MAX_STORED_EVENTS: usize = 50;
+/// The amount of the latest inputs that are kept in the input history of each game.
+pub const MAX_STORED_INPUT_RECORDS: usize = 2000;
+/// The amount of the latest chat messages that are kept in each game. The oldest ones are forgotten first.
+pub const MAX_STORED_CHAT_MESSAGES: usize = 200;
+/// The amount of the latest failed inputs that are kept in the dead letters of each game. The oldest ones are forgotten first.
+pub const MAX_DEAD_LETTERS: usize = 20;
+/// The rules that only give a warning in a game with the facilitated rule profile when the lobby settings don't choose the lenient rules themselves.
+pub const DEFAULT_LENIENT_RULES: [&str; 6] = ["enough_moves", "max_moves_per_turn", "min_moves_per_turn", "accessible_route", "freight_cargo_order", "afford_congestion_charge"];
+/// The most checkpoints each game can have, since every checkpoint keeps a copy of the game.
+pub const MAX_CHECKPOINTS: usize = 10;
+/// The amount of the latest turns a copy of the game is kept from, so that the game can be forked from those turns.
+pub const MAX_STORED_TURN_SNAPSHOTS: usize = 30;
+/// The largest replication entry (in bytes) a standby server accepts. The entries have whole games in them, and the history of a game many copies of it, so it's far larger than the limit of the other requests.
+pub const MAX_REPLICATION_ENTRY_SIZE: usize = 64 * 1024 * 1024;
+/// The extra movement cost of every move a freight player makes while loaded with cargo.
+pub const LOADED_CARGO_EXTRA_MOVEMENT_COST: MovementCost = 1;
+/// The emissions of one car move. It's divisible by every possible amount of people in a car, so that the emissions can be shared equally between the driver and the passengers.
+pub const CAR_EMISSIONS_PER_MOVE: Emissions = 60;
+/// The score a player gets for dropping off what the objective card asks for.
+pub const DELIVERED_OBJECTIVE_SCORE: Score = 100;
+/// The score a player gets for picking up what the objective card asks for, before it's dropped off.
+pub const PICKED_UP_OBJECTIVE_SCORE: Score = 40;
+/// A player loses one point of score for every this many emissions the player is responsible for.
+pub const EMISSIONS_PER_LOST_POINT: Emissions = 10;
+/// The points the policy of the orchestrator gets on the scoreboard for every traveller that reaches the destination.
+pub const SCOREBOARD_POINTS_PER_DELIVERY: Score = 50;
+/// The most cards an objective deck made by a facilitator, or a situation card in a content pack, can have. The cards are balanced by trying every combination, so the decks have to be small.
+pub const MAX_OBJECTIVE_DECK_SIZE: usize = 20;
+/// The most points a card in an objective deck made by a facilitator can give.
+pub const MAX_OBJECTIVE_CARD_POINTS: Score = 1000;
+/// The highest weight, in percent, the objective points, the emissions or the budget can have on the leaderboard.
+pub const MAX_SCORE_WEIGHT: u32 = 1000;
+/// The most timed props a game can have. The server ticks the props of every game on the same thread, so they are kept few.
+pub const MAX_TIMED_PROPS: usize = 10;
+/// How often the server checks if any of the timed props of the games are due to tick, in milliseconds.
+pub const TIMED_PROP_CHECK_INTERVAL: Milliseconds = 250;
+/// The most moves a player can plan for their next turn.
+pub const MAX_PLANNED_MOVES: usize = 30;
+/// The most inputs a player can send in one batch.
+pub const MAX_INPUT_BATCH_SIZE: usize = 30;
+/// The most views (the legal nodes of a player in a version of a game) the game controller keeps in its cache. The least recently used views are removed first.
+pub const MAX_CACHED_PLAYER_VIEWS: usize = 256;
+/// The most actions a player can stage in one turn. Lobbies can set a lower limit with `max_staged_actions` in the lobby settings.
+pub const MAX_STAGED_ACTIONS: usize = 200;
+/// The most inputs the bot players of a game can make in a row, so that a bot that can not end its turn does not stall the server.
+pub const MAX_BOT_INPUTS_IN_A_ROW: usize = 100;
+/// The most games that can exist at the same time on a server in demo mode.
+pub const DEMO_MAX_GAMES: usize = 10;
+/// How long a game on a server in demo mode can go without any inputs before it's removed.
+pub const DEMO_GAME_EXPIRY: Milliseconds = 30 * 60_000;
+/// How long before a game on a server in demo mode is removed for being inactive the players are warned.
+pub const DEMO_EXPIRY_WARNING: Milliseconds = 5 * 60_000;
+/// The folder (relative to where the server is started) the inputs that made the server panic are saved to, together with the game as it was before the input.
+pub const QUARANTINED_INPUTS_FOLDER: &str = "quarantined_inputs";
+/// How long a player can go without checking in before the connection of the player is considered flaky.
+pub const CONNECTION_FLAKY_AFTER: Milliseconds = 15_000;
+/// How long a player can go without checking in before the connection of the player is considered stale. It's shorter than [`PLAYER_TIMEOUT`], so that the facilitators are warned before the player is removed.
+pub const CONNECTION_STALE_AFTER: Milliseconds = 45_000;
@@ -1,13 +1,76 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
 use super::enums::restriction_type::RestrictionType;
 
-pub type NodeID = u8;
-pub type PlayerID = i32;
-pub type GameID = i32;
+/// Defines an id type that wraps an integer, so that the compiler catches when one kind of id is used where another is expected.
+/// The id is serialized as the integer itself, so the JSON of the API and the stored games is the same as with a bare integer.
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident($inner:ty)) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub $inner);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = <$inner as FromStr>::Err;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The id of a node on the map.
+    NodeID(u8)
+);
+id_type!(
+    /// The unique id of a player, given out by the server.
+    PlayerID(i32)
+);
+id_type!(
+    /// The id of a game, given out by the server.
+    GameID(i32)
+);
+
+pub type EdgeID = u16;
 pub type NeighbourRelationshipID = u8;
 pub type MovementCost = i16;
 pub type MovementValue = MovementCost;
 pub type MovesRemaining = MovementCost;
 pub type Money = i32;
 pub type SituationCardID = u8;
+pub type TurnNumber = u32;
+pub type RoundNumber = u32;
+pub type StateVersion = u64;
+/// A 64-bit FNV-1a hash of the canonical JSON of a game, see `GameState::state_hash`.
+pub type StateHash = u64;
+pub type MeasureID = u32;
+pub type ConstructionID = u32;
+pub type DeadLetterID = u32;
+pub type RuleWarningID = u32;
+pub type ChatMessageID = u32;
+pub type MeasureTemplateID = u8;
+pub type ControlToken = i32;
+/// The secret a player uses to reconnect with the same id after the id was removed for being inactive.
+pub type SessionToken = u64;
+pub type EventID = u64;
+pub type Emissions = u32;
+pub type Score = i32;
+/// Milliseconds since the unix epoch.
+pub type Timestamp = u64;
+/// An amount of time in milliseconds.
+pub type Milliseconds = u64;
+/// The id of an image the server serves, like the artwork of a map or a card. It's the name of the image file without the extension.
+pub type AssetID = String;
 pub type VehicleType = RestrictionType;
 pub type ErrorData = String;
\ No newline at end of file
@@ -1,16 +1,64 @@
 //! This module contains all the enums used in this library. 
 
+/// The access_level module contains the AccessLevel enum which describes what the holder of an access token is allowed to do.
+pub mod access_level;
+/// The achievement module contains the Achievement enum which contains the badges the players can earn in a game.
+pub mod achievement;
+/// The annotation_target module contains the AnnotationTarget enum which describes what on the map a note of the orchestrator is attached to.
+pub mod annotation_target;
+/// The bot_difficulty module contains the BotDifficulty enum which describes how well a bot player plays.
+pub mod bot_difficulty;
+/// The cargo_state module contains the CargoState enum which describes what a freight player is carrying.
+pub mod cargo_state;
+/// The connection_quality module contains the ConnectionQuality enum which describes how well the client of a player keeps in touch with the server.
+pub mod connection_quality;
+/// The construction_status module contains the ConstructionStatus enum which describes how far along scheduled construction works are.
+pub mod construction_status;
 /// The district_modifier_type module contains the DistrictModifierType enum which contains all the district modifier types.
 pub mod district_modifier_type;
 /// The district module contains the District enum which contains all the districts.
 pub mod district;
+/// The export_format module contains the ExportFormat enum which contains the formats the input history of a game can be exported in.
+pub mod export_format;
+/// The finish_reason module contains the FinishReason enum which describes why a game was finished.
+pub mod finish_reason;
+/// The game_length module contains the GameLength enum which describes how long a game should be.
+pub mod game_length;
+/// The game_phase module contains the GamePhase enum which describes the phase a game is in.
+pub mod game_phase;
+/// The game_status module contains the GameStatus enum which tells whether a game is still being played, and who won it if it's finished.
+pub mod game_status;
 /// The in_game_id module contains the InGameID enum which contains all the in game ids. An in game id is an id that is used in the game to identify which player's turn it is and who is the orchestrator.
 pub mod in_game_id;
+/// The modifier_persistence module contains the ModifierPersistence enum which decides how long the orchestrator's measures are kept.
+pub mod modifier_persistence;
+/// The move_refill module contains the MoveRefill enum which decides what happens with the players' unused moves when their turn is over.
+pub mod move_refill;
+/// The notification_class module contains the NotificationClass enum which describes how the clients should notify the players about an event.
+pub mod notification_class;
+/// The objective_difficulty module contains the ObjectiveDifficulty enum which describes how hard an objective card is compared to the length of the game.
+pub mod objective_difficulty;
 /// The player_input_type module contains the PlayerInputType enum which contains all the player input types.
 pub mod player_input_type;
 /// The restriction_type module contains the RestrictionType enum which contains all the restriction types.
 pub mod restriction_type;
+/// The rule_profile module contains the RuleProfile enum which decides how strictly the server enforces the rules of a game.
+pub mod rule_profile;
+/// The session_reminder module contains the SessionReminder enum which describes when the players are reminded of how much time is left of the session.
+pub mod session_reminder;
+/// The state_section module contains the StateSection enum which contains the sections of the game state that are tracked for changes.
+pub mod state_section;
+/// The text_kind module contains the TextKind enum which describes what a text sent by a player is used for.
+pub mod text_kind;
 /// The traffic module contains the Traffic enum which contains all the traffic types.
 pub mod traffic;
+/// The timed_prop_kind module contains the TimedPropKind enum which describes what a timed prop does to the game when it ticks.
+pub mod timed_prop_kind;
+/// The turn_end_policy module contains the TurnEndPolicy enum which decides when the players can end their turn.
+pub mod turn_end_policy;
 /// The type_entities_to_transport module contains the TypeEntitiesToTransport enum which contains all the types of entities that can be transported.
-pub mod type_entities_to_transport;
\ No newline at end of file
+pub mod type_entities_to_transport;
+/// The win_condition module contains the WinCondition enum which decides what success means in a scenario.
+pub mod win_condition;
+/// The winning_side module contains the WinningSide enum which describes which side won a finished game.
+pub mod winning_side;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// What the holder of an access token is allowed to do. Every level is allowed to do everything the levels below it are allowed to do.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AccessLevel {
+    /// Can play the games.
+    Participant,
+    /// Can also run the sessions, like becoming the orchestrator, restoring checkpoints and exporting, auditing, forking and comparing games.
+    Facilitator,
+    /// Can also use the admin endpoints, like resetting the server and issuing new tokens.
+    Admin,
+}
+
+impl AccessLevel {
+    /// Parses the name of an access level, ignoring the case.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "participant" => Ok(Self::Participant),
+            "facilitator" => Ok(Self::Facilitator),
+            "admin" => Ok(Self::Admin),
+            _ => Err(format!("{} is not an access level! It should be participant, facilitator or admin.", name.trim())),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// The Achievement enum contains the badges the players can earn in a game. They are handed out by the AchievementExtension.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum Achievement {
+    /// The first player to drop off what the objective card asks for.
+    FirstToObjective,
+    /// The player moved in a turn without making any emissions, by bus or by rail.
+    ZeroEmissionRound,
+    /// The player (or players) that made the most moves by bus in the game.
+    MostBusTrips,
+}
+
+impl Achievement {
+    /// Returns a short description of what the achievement is given for, for the clients to show.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::FirstToObjective => "First to complete the objective",
+            Self::ZeroEmissionRound => "Moved a whole turn without any emissions",
+            Self::MostBusTrips => "Made the most trips by bus",
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::EdgeID;
+
+use super::district::District;
+
+/// What on the map a note of the orchestrator is attached to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AnnotationTarget {
+    District(District),
+    Edge(EdgeID),
+}
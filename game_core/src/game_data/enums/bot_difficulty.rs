@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// How well a bot player plays, which decides how often it makes the move that brings it closest to its objective instead of a random legal move.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum BotDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl BotDifficulty {
+    /// Returns the chance, in percent, that the bot makes the best move it can find instead of a random legal move.
+    pub const fn best_move_percent(&self) -> u32 {
+        match self {
+            Self::Easy => 40,
+            Self::Normal => 75,
+            Self::Hard => 100,
+        }
+    }
+}
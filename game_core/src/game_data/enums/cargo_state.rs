@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes what a freight player is carrying.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum CargoState {
+    /// The cargo has not been picked up yet.
+    #[default]
+    Empty,
+    /// The cargo has been picked up and the player moves slower until it's delivered.
+    Loaded,
+    /// The cargo has been delivered at the delivery node.
+    Delivered,
+}
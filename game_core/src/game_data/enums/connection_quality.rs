@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// How well the client of a player keeps in touch with the server, judged by how often and how recently the player has checked in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ConnectionQuality {
+    /// The player checks in regularly.
+    Good,
+    /// The player checks in, but seldom or irregularly.
+    Flaky,
+    /// The player has not checked in for a while, and might have lost the connection or closed the client.
+    Stale,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The ConstructionStatus enum describes how far along scheduled construction works are.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum ConstructionStatus {
+    /// The works have been scheduled, but start more than one turn from now.
+    Scheduled,
+    /// The works start in the next turn, which the players are told about in advance.
+    Announced,
+    /// The edges are under construction.
+    InProgress,
+    /// The works are done and the edges are as they were before.
+    Finished,
+}
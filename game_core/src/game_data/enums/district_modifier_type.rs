@@ -1,8 +1,30 @@
-use serde::{Deserialize, Serialize};
+use crate::wire_codes;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+use crate::game_data::constants::{MAX_ACCESS_MODIFIER_COUNT, MAX_PRIORITY_MODIFIER_COUNT, MAX_TOLL_MODIFIER_COUNT};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum DistrictModifierType {
     Access,
     Priority,
     Toll,
-}
\ No newline at end of file
+}
+
+wire_codes!(DistrictModifierType {
+    Access = 0,
+    Priority = 1,
+    Toll = 2,
+});
+
+impl DistrictModifierType {
+    /// All the types of district modifiers.
+    pub const ALL: [Self; 3] = [Self::Access, Self::Priority, Self::Toll];
+
+    /// Returns how many modifiers of this type a district can have at the same time.
+    pub const fn max_count(&self) -> usize {
+        match self {
+            Self::Access => MAX_ACCESS_MODIFIER_COUNT,
+            Self::Priority => MAX_PRIORITY_MODIFIER_COUNT,
+            Self::Toll => MAX_TOLL_MODIFIER_COUNT,
+        }
+    }
+}
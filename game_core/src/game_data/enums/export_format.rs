@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The formats the input history of a game can be exported in. Both have one row per line, so the rows can be sent one by one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    /// Newline delimited JSON, where every line is an `InputRecord`.
+    Ndjson,
+    /// Comma separated values with a header line. The input itself is in the last column as JSON.
+    Csv,
+}
+
+impl ExportFormat {
+    /// Parses the name of an export format, ignoring the case.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "ndjson" => Ok(Self::Ndjson),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("{} is not an export format! It should be ndjson or csv.", name.trim())),
+        }
+    }
+
+    /// Returns the content type of a response in this format.
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Ndjson => "application/x-ndjson",
+            Self::Csv => "text/csv",
+        }
+    }
+
+    /// Returns the line that comes before the rows, if the format has one.
+    pub const fn header(self) -> Option<&'static str> {
+        match self {
+            Self::Ndjson => None,
+            Self::Csv => Some("turn_number,timestamp,player_id,input_type,outcome,rule_name,message,input\n"),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Why a game was finished.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum FinishReason {
+    /// Every player dropped off what their objective card asks for.
+    #[default]
+    AllObjectivesReached,
+    /// The targets of the orchestrator in the lobby settings were met.
+    OrchestratorTargetsMet,
+    /// The total emissions of the players went over the threshold of the win condition.
+    EmissionsExceeded,
+    /// A player spent more than their budget, which the win condition does not allow.
+    BudgetExceeded,
+    /// The most turns of the lobby settings have been played.
+    MaxTurnsReached,
+    /// The average commute time of the travellers went over the group target of a cooperative game, and can not come back under it.
+    CommuteTooLong,
+}
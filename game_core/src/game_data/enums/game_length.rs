@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::MovementCost;
+
+/// The wanted length of a game. It's used to deal objective cards that fit the length of the game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum GameLength {
+    Short,
+    #[default]
+    Medium,
+    Long,
+}
+
+impl GameLength {
+    /// Returns the movement cost of the path (from start to pick up and then to drop off) an objective should ideally have for this game length.
+    pub const fn target_objective_distance(&self) -> MovementCost {
+        match self {
+            Self::Short => 4,
+            Self::Medium => 6,
+            Self::Long => 8,
+        }
+    }
+}
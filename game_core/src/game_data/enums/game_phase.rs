@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// The phase a game is in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum GamePhase {
+    /// The players are joining and the orchestrator is choosing the settings.
+    Lobby,
+    Playing,
+    /// All the players have delivered what their objective card asks for.
+    GameOver,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::PlayerID;
+
+use super::{finish_reason::FinishReason, winning_side::WinningSide};
+
+/// Whether a game is still being played. See the [`win_conditions`](../../../win_conditions/index.html) module for when a game is finished.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum GameStatus {
+    #[default]
+    Ongoing,
+    /// The game is over, and the inputs that change the game are rejected. The winners are the players on the winning side.
+    Finished {
+        winners: Vec<PlayerID>,
+        #[serde(default)]
+        reason: FinishReason,
+        #[serde(default)]
+        winning_side: WinningSide,
+    },
+}
+
+impl GameStatus {
+    /// Returns `true` if the game is still being played.
+    pub fn is_ongoing(&self) -> bool {
+        *self == Self::Ongoing
+    }
+
+    /// Returns `true` if the game is over.
+    pub const fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished { .. })
+    }
+}
@@ -1,5 +1,5 @@
-use serde::{Deserialize, Serialize};
-#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+use crate::wire_codes;
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum InGameID {
     Undecided = 0,
     PlayerOne = 1,
@@ -11,6 +11,17 @@ pub enum InGameID {
     Orchestrator = 7,
 }
 
+wire_codes!(InGameID {
+    Undecided = 0,
+    PlayerOne = 1,
+    PlayerTwo = 2,
+    PlayerThree = 3,
+    PlayerFour = 4,
+    PlayerFive = 5,
+    PlayerSix = 6,
+    Orchestrator = 7,
+});
+
 impl InGameID {
     pub const fn next(&self) -> Self {
         match self {
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::TurnNumber;
+
+/// Decides what happens with the orchestrator's measures (district modifiers and edge restrictions) when the turns advance.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum ModifierPersistence {
+    /// The measures are kept until the orchestrator removes them.
+    Persistent,
+    /// All the measures are removed when the round is over (when it's the orchestrator's turn again).
+    #[default]
+    ResetEachRound,
+    /// The measures are kept for the given amount of turns after the turn they were enacted in.
+    DecayAfterNTurns(TurnNumber),
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::MovementValue;
+
+/// Decides what happens with the players' remaining moves when their turn is over.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum MoveRefill {
+    /// The moves are never refilled, so the players only have the moves they got when the game started.
+    #[default]
+    Never,
+    /// The remaining moves are set to the starting amount, so the moves a player did not use in their turn are lost.
+    UseItOrLoseIt,
+    /// The players get the starting amount of moves on top of the moves they did not use, where at most the given amount of unused moves are kept.
+    Banking(MovementValue),
+}
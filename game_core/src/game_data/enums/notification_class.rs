@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes what kind of notification (sound, vibration etc.) a client should use for an event, so that all clients map the events the same way.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum NotificationClass {
+    /// It's the turn of a (possibly) different player. The client should notify if it's the turn of one of its players.
+    YourTurn,
+    GameStarted,
+    MeasureEnacted,
+    GameEnded,
+    /// The facilitators and players are reminded of how much time is left of the session.
+    Reminder,
+    /// Events that should not have a special notification.
+    Info,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// How hard an objective card is compared to the length of the game, decided by the movement cost of its path (from start to pick up and then to drop off).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ObjectiveDifficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl ObjectiveDifficulty {
+    /// How far the distance of an objective can be from the target distance of the game length before it's easy or hard.
+    const MEDIUM_DISTANCE_MARGIN: i32 = 1;
+
+    /// Returns the difficulty of an objective with the given distance in a game with the given target distance.
+    pub const fn from_distance(distance: i32, target_distance: i32) -> Self {
+        if distance < target_distance - Self::MEDIUM_DISTANCE_MARGIN {
+            Self::Easy
+        } else if distance > target_distance + Self::MEDIUM_DISTANCE_MARGIN {
+            Self::Hard
+        } else {
+            Self::Medium
+        }
+    }
+}
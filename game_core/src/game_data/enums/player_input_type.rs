@@ -1,5 +1,5 @@
-use serde::{Deserialize, Serialize};
-#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Debug)]
+use crate::wire_codes;
+#[derive(Clone, PartialEq, Eq, PartialOrd, Debug)]
 pub enum PlayerInputType {
     Movement,
     ChangeRole,
@@ -12,4 +12,114 @@ pub enum PlayerInputType {
     LeaveGame,
     ModifyEdgeRestrictions,
     SetPlayerBusBool,
-}
\ No newline at end of file
+    ChangeLobbySettings,
+    RenamePlayer,
+    RevokeMeasure,
+    OfferRide,
+    AcceptRide,
+    ExitVehicle,
+    SetCongestionZone,
+    EnactMeasureTemplate,
+    /// Saves a copy of the game under the label in `related_string`.
+    CreateCheckpoint,
+    /// Restores the game to the checkpoint with the label in `related_string`.
+    RestoreCheckpoint,
+    /// Claims the open seat with the role in `related_role` in a forked game that has not been started.
+    ClaimSeat,
+    /// Ends the movement of the player for the rest of the turn. The remaining moves are lost if the moves are not kept between turns, see [`MoveRefill`](../move_refill/enum.MoveRefill.html).
+    Pass,
+    /// Starts the next scenario of the campaign the game is part of, once the current scenario is over. See [`Campaign`](../../structs/campaign/struct.Campaign.html).
+    NextScenario,
+    /// Ticks the timed prop with the name in `related_string`. Only sent by the server when the prop is due, see [`TimedProp`](../../structs/timed_prop/struct.TimedProp.html).
+    TickTimedProp,
+    /// Schedules the construction works in `construction_work`, which raise the movement cost of (or close) the edges while they last. See [`ConstructionWork`](../../structs/construction_work/struct.ConstructionWork.html).
+    ScheduleConstruction,
+    /// Sends the input of the dead letter with the id in `dead_letter_id` again, as the player who first sent it. See [`DeadLetter`](../../structs/dead_letter/struct.DeadLetter.html).
+    RetryDeadLetter,
+    /// Removes the dead letter with the id in `dead_letter_id` without sending its input again.
+    DiscardDeadLetter,
+    /// Accepts the input of the rule warning with the id in `rule_warning_id` as a house rule, so it's applied even though it broke the lenient rules. See [`RuleProfile`](../rule_profile/enum.RuleProfile.html).
+    OverrideRule,
+    /// Applies the input in `override_input` as the player who sent it, without checking the rules named in `overridden_rules`. The justification in `related_string` is required, and the override is recorded in the events and the input history.
+    OverrideAndApply,
+    /// Attaches the note in `related_string` to the district or edge in `annotation_target`, replacing the note that was there. The note is removed if `related_string` is empty or not set.
+    AnnotateMap,
+    /// Sends the message in `related_string` to the chat of the game. See [`ChatMessage`](../../structs/chat_message/struct.ChatMessage.html).
+    ChatMessage,
+}
+
+wire_codes!(PlayerInputType {
+    Movement = 0,
+    ChangeRole = 1,
+    All = 2,
+    NextTurn = 3,
+    UndoAction = 4,
+    ModifyDistrict = 5,
+    StartGame = 6,
+    AssignSituationCard = 7,
+    LeaveGame = 8,
+    ModifyEdgeRestrictions = 9,
+    SetPlayerBusBool = 10,
+    ChangeLobbySettings = 11,
+    RenamePlayer = 12,
+    RevokeMeasure = 13,
+    OfferRide = 14,
+    AcceptRide = 15,
+    ExitVehicle = 16,
+    SetCongestionZone = 17,
+    EnactMeasureTemplate = 18,
+    CreateCheckpoint = 19,
+    RestoreCheckpoint = 20,
+    ClaimSeat = 21,
+    Pass = 22,
+    NextScenario = 23,
+    TickTimedProp = 24,
+    ScheduleConstruction = 25,
+    RetryDeadLetter = 26,
+    DiscardDeadLetter = 27,
+    OverrideRule = 28,
+    OverrideAndApply = 29,
+    AnnotateMap = 30,
+    ChatMessage = 31,
+});
+
+impl PlayerInputType {
+    /// Returns `true` if inputs of this type are added to the staged actions of the turn, which are applied when the turn ends and can be undone until then.
+    pub const fn is_staged(&self) -> bool {
+        !matches!(
+            self,
+            Self::NextTurn
+                | Self::UndoAction
+                | Self::ChangeRole
+                | Self::StartGame
+                | Self::AssignSituationCard
+                | Self::LeaveGame
+                | Self::ChangeLobbySettings
+                | Self::RenamePlayer
+                | Self::CreateCheckpoint
+                | Self::RestoreCheckpoint
+                | Self::ClaimSeat
+                | Self::NextScenario
+                | Self::TickTimedProp
+                | Self::RetryDeadLetter
+                | Self::DiscardDeadLetter
+                | Self::OverrideRule
+                | Self::OverrideAndApply
+                | Self::AnnotateMap
+                | Self::ChatMessage
+        )
+    }
+
+    /// Returns `true` if an input of this type is kept in the dead letters of the game when it passes the rules but can't be applied, so that the orchestrator can retry it.
+    pub const fn can_be_retried(&self) -> bool {
+        !matches!(
+            self,
+            Self::UndoAction | Self::TickTimedProp | Self::RetryDeadLetter | Self::DiscardDeadLetter | Self::OverrideRule | Self::OverrideAndApply | Self::ChatMessage
+        )
+    }
+
+    /// Returns `true` if inputs of this type let the orchestrator correct a game that is being played, like revoking a measure, restoring a checkpoint, retrying a dead letter or overriding a rule.
+    pub const fn is_corrective(&self) -> bool {
+        matches!(self, Self::RevokeMeasure | Self::RestoreCheckpoint | Self::RetryDeadLetter | Self::DiscardDeadLetter | Self::OverrideRule | Self::OverrideAndApply)
+    }
+}
@@ -1,6 +1,6 @@
-use serde::{Deserialize, Serialize};
+use crate::wire_codes;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum RestrictionType {
     ParkAndRide,
     Electric,
@@ -9,8 +9,21 @@ pub enum RestrictionType {
     Destination,
     Heavy,
     OneWay, // This should never be chosen as a district restriction
+    /// Only buses and cars with passengers can use the edge. This should never be chosen as a district restriction.
+    BusLane,
 }
 
+wire_codes!(RestrictionType {
+    ParkAndRide = 0,
+    Electric = 1,
+    Emergency = 2,
+    Hazard = 3,
+    Destination = 4,
+    Heavy = 5,
+    OneWay = 6,
+    BusLane = 7,
+});
+
 impl RestrictionType {
     pub const fn times_to_increase_traffic_when_access(&self) -> usize {
         match self {
@@ -21,6 +34,7 @@ impl RestrictionType {
             Self::Destination => 1,
             Self::Heavy => 1,
             Self::OneWay => 0, // This should never be chosen as a district restriction
+            Self::BusLane => 0,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Decides how strictly the server enforces the rules of a game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum RuleProfile {
+    /// The server rejects every input that breaks a rule.
+    #[default]
+    Strict,
+    /// The server only warns about the inputs that break the lenient rules of the lobby settings, and the orchestrator can accept them as house rules with an `OverrideRule` input. The other rules are enforced as in the strict profile.
+    Facilitated,
+}
+
+impl RuleProfile {
+    /// Returns `true` if this is the strict profile.
+    pub fn is_strict(&self) -> bool {
+        *self == Self::Strict
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::Milliseconds;
+
+/// A point in the session of a game where the players are reminded of how much time is left.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SessionReminder {
+    /// When half of the session has passed.
+    Halfway,
+    /// When the given amount of minutes are left of the session.
+    MinutesLeft(u32),
+}
+
+impl SessionReminder {
+    /// The reminders used if the orchestrator has not chosen any.
+    pub const DEFAULT: [Self; 2] = [Self::Halfway, Self::MinutesLeft(10)];
+
+    /// Returns how much time is left of a session with the given length when the reminder is due.
+    pub fn time_left_when_due(self, session_length: Milliseconds) -> Milliseconds {
+        match self {
+            Self::Halfway => session_length / 2,
+            Self::MinutesLeft(minutes) => Milliseconds::from(minutes) * 60_000,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// The top-level sections of a [`GameState`] that a client renders separately. Used to tell the client which parts of its UI it needs to update, and to let a client ask for only the sections it renders.
+///
+/// [`GameState`]: ../../structs/gamestate/struct.GameState.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StateSection {
+    /// The players, including their positions, roles, remaining moves and objectives, and the legal nodes.
+    Players,
+    /// The district modifiers, the congestion zone, the measure ledger and the dead letters.
+    Modifiers,
+    /// The edge restrictions.
+    Edges,
+    /// Whose turn it is, the turn number, if the game has started or finished and the rule warnings of the turn.
+    Turn,
+    /// The name of the game, the lobby settings and the situation card.
+    Settings,
+    /// The statistics of the game.
+    Statistics,
+    /// The chat messages of the game.
+    Chat,
+}
+
+impl StateSection {
+    /// All the sections.
+    pub const ALL: [Self; 7] = [Self::Players, Self::Modifiers, Self::Edges, Self::Turn, Self::Settings, Self::Statistics, Self::Chat];
+
+    /// Parses a comma separated list of section names, like `Players,Turn`. Will return an error if one of the names is not a section.
+    pub fn parse_list(names: &str) -> Result<Vec<Self>, String> {
+        names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                Self::ALL
+                    .iter()
+                    .find(|section| format!("{:?}", section) == name)
+                    .copied()
+                    .ok_or_else(|| format!("There is no section called {}!", name))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::constants::{MAX_CHAT_MESSAGE_LENGTH, MAX_LOBBY_NAME_LENGTH, MAX_NOTE_LENGTH, MAX_PLAYER_NAME_LENGTH, MAX_PLAYER_TEXT_LENGTH};
+
+/// What a text sent by a player is used for, which decides how long it can be.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TextKind {
+    PlayerName,
+    LobbyName,
+    /// A note the orchestrator attaches to the map.
+    Note,
+    /// A message in the chat of a game.
+    ChatMessage,
+    /// Any other text, like the label of a checkpoint.
+    Other,
+}
+
+impl TextKind {
+    /// Returns the most characters a text of this kind can have.
+    pub const fn max_length(self) -> usize {
+        match self {
+            Self::PlayerName => MAX_PLAYER_NAME_LENGTH,
+            Self::LobbyName => MAX_LOBBY_NAME_LENGTH,
+            Self::Note => MAX_NOTE_LENGTH,
+            Self::ChatMessage => MAX_CHAT_MESSAGE_LENGTH,
+            Self::Other => MAX_PLAYER_TEXT_LENGTH,
+        }
+    }
+}
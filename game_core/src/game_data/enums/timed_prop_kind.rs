@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::MeasureTemplateID, structs::edge_restriction::EdgeRestriction};
+
+/// The TimedPropKind enum describes what a timed prop does to the game every time it ticks.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum TimedPropKind {
+    /// The edge restriction is put on the edge at one tick and taken off at the next, like a ferry that can only be used while it's docked.
+    ToggleEdgeRestriction(EdgeRestriction),
+    /// Counts down the given amount of ticks, and enacts the measure template (if it's set) when the countdown ends. The prop stops ticking after that.
+    Countdown {
+        ticks: u32,
+        #[serde(default)]
+        measure_template_id: Option<MeasureTemplateID>,
+    },
+}
@@ -1,7 +1,7 @@
-use serde::{Deserialize, Serialize};
+use crate::wire_codes;
 
 use crate::game_data::custom_types::MovementCost;
-#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord)]
 pub enum Traffic {
     LevelOne,
     LevelTwo,
@@ -10,6 +10,14 @@ pub enum Traffic {
     LevelFive,
 }
 
+wire_codes!(Traffic {
+    LevelOne = 0,
+    LevelTwo = 1,
+    LevelThree = 2,
+    LevelFour = 3,
+    LevelFive = 4,
+});
+
 impl Traffic {
     pub const fn get_movement_cost(&self) -> MovementCost {
         match self {
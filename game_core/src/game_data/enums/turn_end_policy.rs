@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Decides when the players can end their turn with a `NextTurn` input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum TurnEndPolicy {
+    /// The player whose turn it is can end the turn at any time.
+    #[default]
+    Free,
+    /// The player whose turn it is has to pass (or use all their moves) before ending the turn. The orchestrator can always force the turn to end.
+    RequirePass,
+}
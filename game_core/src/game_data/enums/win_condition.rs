@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{Emissions, TurnNumber};
+
+/// Decides what success means in a scenario.
+///
+/// The game is always finished when every player has dropped off what their objective card asks for or the turns run out, and the win condition decides who won it then. The win conditions that can be lost before that finish the game at the end of the turn they are lost in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum WinCondition {
+    /// The players win when every one of them has dropped off what their objective card asks for, and nobody wins if the turns run out before that.
+    #[default]
+    AllObjectivesReached,
+    /// Everyone wins if the total emissions of the players stay at or below the threshold until the game is finished, and nobody wins if they go over it.
+    EmissionsBelow { max_total_emissions: Emissions },
+    /// The players win if none of them spends more than their budget until the game is finished, and nobody wins if one of them does.
+    BudgetNotExceeded,
+    /// Everyone, including the orchestrator, wins or loses together against the group targets: the total emissions of the travellers and their average commute time in turns, see [`GroupMetrics`].
+    /// Nobody wins as soon as a target can no longer be met, and everyone wins if the targets are met when the game is finished. A target that is not set is not checked.
+    ///
+    /// [`GroupMetrics`]: ../../structs/group_metrics/struct.GroupMetrics.html
+    Cooperative {
+        #[serde(default)]
+        max_total_emissions: Option<Emissions>,
+        #[serde(default)]
+        max_average_commute_turns: Option<TurnNumber>,
+    },
+}
+
+impl WinCondition {
+    /// Returns `true` if this is the default win condition, where every objective has to be reached.
+    pub fn is_all_objectives_reached(&self) -> bool {
+        *self == Self::AllObjectivesReached
+    }
+
+    /// Returns `true` if this is the cooperative win condition, where everyone plays against the group targets.
+    pub const fn is_cooperative(&self) -> bool {
+        matches!(self, Self::Cooperative { .. })
+    }
+}
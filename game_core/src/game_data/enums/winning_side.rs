@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Which side won a finished game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum WinningSide {
+    /// The players that travel, which is everyone except the orchestrator.
+    #[default]
+    Players,
+    /// The orchestrator.
+    Orchestrator,
+    /// The players and the orchestrator together.
+    Everyone,
+    /// The game was lost.
+    Nobody,
+}
@@ -1,30 +1,154 @@
 //! Contains most the structs used in the game.
 
+/// The access_tokens module contains the AccessTokens struct which contains the tokens that are allowed to use the server and their access levels.
+pub mod access_tokens;
+/// The accessibility module contains the Accessibility struct which describes how accessible a node or district is.
+pub mod accessibility;
+/// The achievement_progress module contains the AchievementProgress struct which keeps what is needed to hand out the achievements that are not earned with a single move.
+pub mod achievement_progress;
+/// The applied_actions module contains the AppliedActions struct which keeps a game with its staged actions applied.
+pub mod applied_actions;
+/// The bot_seat module contains the BotSeat struct which describes a seat of a game that is filled with a bot player.
+pub mod bot_seat;
+/// The campaign module contains the Campaign struct which describes a session where the same players play several scenarios in a row.
+pub mod campaign;
+/// The chat_message module contains the ChatMessage struct which describes a message a player has sent to the chat of a game.
+pub mod chat_message;
+/// The checkpoint module contains the Checkpoint struct which describes a named copy of a game the orchestrator can restore the game to, and the ArchivedBranch struct which describes what happened in a game before it was restored.
+pub mod checkpoint;
+/// The congestion_zone module contains the CongestionZone struct which describes a cordon-pricing measure.
+pub mod congestion_zone;
+/// The construction_work module contains the ConstructionWork struct which describes construction works on some edges, and the ScheduledConstruction struct which tracks them in a game.
+pub mod construction_work;
+/// The content_pack module contains the ContentPack struct which contains the maps, cards, scenarios, rule scripts and texts of an uploaded content pack, and the structs describing its manifest and validation report.
+pub mod content_pack;
 /// The cost_tuple module contains the CostTuple struct which describes the Traffic in a District.
 pub mod cost_tuple;
+/// The dead_letter module contains the DeadLetter struct which keeps an input that passed the rules but could not be applied, so the orchestrator can retry or discard it.
+pub mod dead_letter;
+/// The deal_constraints module contains the DealConstraints struct which describes the constraints a scenario can put on how the objective cards are dealt.
+pub mod deal_constraints;
 /// The district_modifier module contains the DistrictModifier struct which describes a DistrictModifier.
 pub mod district_modifier;
 /// The edge_restriction module contains the EdgeRestriction struct which describes an EdgeRestriction.
 pub mod edge_restriction;
+/// The demo_mode module contains the DemoMode struct which describes the limits of a server that runs the public demo.
+pub mod demo_mode;
+/// The enacted_measure module contains the EnactedMeasure struct which describes an entry in the measure ledger of a game, and the Measure enum.
+pub mod enacted_measure;
+/// The ferry_schedule module contains the FerrySchedule struct which describes when a ferry edge can be used, and the FerryCrossing struct which records that a player took a ferry.
+pub mod ferry_schedule;
+/// The fork_origin module contains the ForkOrigin struct which describes which game and turn a forked game was copied from.
+pub mod fork_origin;
+/// The game_comparison module contains the GameComparison struct which compares the outcomes of two games or branches, and the structs it's made of.
+pub mod game_comparison;
+/// The game_event module contains the GameEvent struct which describes something that happened in a game, and the GameEventKind enum.
+pub mod game_event;
+/// The game_map module contains the GameMap struct which describes the map of a game as the shared base map together with the changes made while the game is played.
+pub mod game_map;
+/// The game_history module contains the GameHistory struct which has the copies of a game that are kept to go back in it.
+pub mod game_history;
+/// The game_overview module contains the GameOverview struct which is a compact description of the state of a game for facilitators overseeing several games.
+pub mod game_overview;
+/// The game_state_view module contains the GameStateView struct which serializes only some of the sections of a game.
+pub mod game_state_view;
+/// The game_statistics module contains the GameStatistics struct which describes the statistics of a game.
+pub mod game_statistics;
+/// The game_summary module contains the GameSummary struct which describes the outcome of a game and is used for exporting it.
+pub mod game_summary;
+/// The game_snapshot module contains the GameSnapshot struct which contains everything needed to recreate a game.
+pub mod game_snapshot;
 /// The game_state module contains the GameState struct which describes the state of the game.
 pub mod gamestate;
+/// The group_metrics module contains the GroupMetrics struct which describes how the whole group is doing against the group targets of a cooperative game.
+pub mod group_metrics;
+/// The input_batch module contains the InputBatchExecution struct which describes the outcome of handling a batch of inputs from one player.
+pub mod input_batch;
+/// The input_record module contains the InputRecord struct which describes an input a player has sent to a game and its outcome, and the InputOutcome enum.
+pub mod input_record;
+/// The local_players module contains the LocalPlayers struct which describes the players played from the same client, and the structs used to send their views.
+pub mod local_players;
+/// The leaderboard module contains the Leaderboard struct which ranks the players of a session by their cumulative score across the rounds.
+pub mod leaderboard;
+/// The lobby_settings module contains the LobbySettings struct which describes the settings chosen for a game.
+pub mod lobby_settings;
+/// The map_annotation module contains the MapAnnotation struct which describes a note the orchestrator has attached to a district or an edge.
+pub mod map_annotation;
+/// The map_library module contains the MapLibrary struct which contains the maps the server has loaded and the validation reports of them.
+pub mod map_library;
+/// The map_overlay module contains the MapOverlay struct which contains the changes made to the map of a game, and the RuntimeEdgeState struct which describes how an edge has been changed.
+pub mod map_overlay;
+/// The map_report module contains the MapReport struct which describes the outcome of validating a map.
+pub mod map_report;
+/// The measure_template module contains the MeasureTemplate struct which describes a predefined measure made of district modifiers and edge restrictions.
+pub mod measure_template;
+/// The modifier_slots module contains the ModifierSlots struct which describes how many more modifiers of a type can be added to a district.
+pub mod modifier_slots;
+/// The move_plan module contains the MovePlan struct which describes the moves a player plans to make in their next turn, and the outcome of executing a plan.
+pub mod move_plan;
+/// The movement_outcome module contains the MovementOutcome struct which describes what happened when a player moved.
+pub mod movement_outcome;
 /// The neighbour_relationship module contains the NeighbourRelationship struct which describes the relationship between two nodes.
 pub mod neighbour_relationship;
 /// The new_game_info module contains the NewGameInfo struct which describes the information needed to create a new game.
 pub mod new_game_info;
-/// The node_map module contains the NodeMap struct which describes the map of the game.
+/// The node_map module contains the NodeMap struct which describes the base map that is shared by all the games.
 pub mod node_map;
 /// The node module contains the Node struct which describes a node.
 pub mod node;
+/// The node_coordinates module contains the NodeCoordinates struct which describes where a node is drawn on the map.
+pub mod node_coordinates;
+/// The node_search_result module contains the NodeSearchResult struct which describes a node that matched a search of the map.
+pub mod node_search_result;
+/// The objective_deck module contains the ObjectiveDeck struct which is a named set of objective cards made by a facilitator, and the report of validating a deck.
+pub mod objective_deck;
+/// The objective_progress module contains the ObjectiveProgress struct which estimates how far a player has come with the objective card, shown only to the orchestrator.
+pub mod objective_progress;
+/// The objective_reveal module contains the ObjectiveReveal struct which shows where a player was heading and how far the player got, revealed when the game ends.
+pub mod objective_reveal;
+/// The orchestrator_targets module contains the OrchestratorTargets struct which describes what the orchestrator has to achieve to win the game.
+pub mod orchestrator_targets;
 /// The player_input module contains the PlayerInput struct which describes the input of a player.
 pub mod player_input;
+/// The player_connection module contains the PlayerConnection struct which keeps track of how often and when a player last checked in.
+pub mod player_connection;
+/// The player_session module contains the PlayerSession struct which lets a player reconnect with the same id after the id was removed for being inactive.
+pub mod player_session;
 /// The player_objective_card module contains the PlayerObjectiveCard struct which describes a player objective card.
 pub mod player_objective_card;
+/// The player_view_cache module contains the PlayerViewCache struct which keeps the legal nodes computed for the players, so that they are not computed again while the game is unchanged.
+pub mod player_view_cache;
 /// The player module contains the Player struct which describes a player.
 pub mod player;
+/// The quarantined_input module contains the QuarantinedInput struct which is an input that made the server panic, saved together with the game it was for.
+pub mod quarantined_input;
+/// The render_model module contains the RenderModel struct which is a compact description of how the board of a game looks, meant for drawing images of games for reports.
+pub mod render_model;
+/// The rule_warning module contains the RuleWarning struct which keeps an input that only broke lenient rules until the orchestrator accepts it.
+pub mod rule_warning;
+/// The rule_override module contains the RuleOverride struct which records that the orchestrator let an input through even though it broke some of the rules.
+pub mod rule_override;
+/// The rule_set module contains the RuleSet struct which describes which of the built-in rules are checked and their parameters, so that house-rule variants can be run without recompiling the server.
+pub mod rule_set;
+/// The rule_violation module contains the RuleViolation struct which describes why an input was rejected and the RuleViolationCount struct used for statistics.
+pub mod rule_violation;
+/// The scenario_template module contains the ScenarioTemplate struct which describes a ready made setup of a game from a content pack.
+pub mod scenario_template;
+/// The scoreboard module contains the Scoreboard struct which tallies how effective the policy of the orchestrator is turn by turn, and the TurnScore struct.
+pub mod scoreboard;
+/// The score_weights module contains the ScoreWeights struct which describes how much the objective points, the emissions and the budget count on the leaderboard.
+pub mod score_weights;
+/// The session_clock module contains the SessionClock struct which keeps track of how long a game has been played compared to the planned session length.
+pub mod session_clock;
 /// The situation_card_list module contains the SituationCardList struct which describes a list of situation cards.
 pub mod situation_card_list;
 /// The situation_card module contains the SituationCard struct which describes a situation card for the game, it also includes [`PlayerObjectiveCard`].
 /// 
 /// [`PlayerObjectiveCard`]: ./player_objective_card/struct.PlayerObjectiveCard.html
-pub mod situation_card;
\ No newline at end of file
+pub mod situation_card;
+/// The text_filter module contains the TextFilter struct which cleans up and checks the texts the players send.
+pub mod text_filter;
+/// The timed_prop module contains the TimedProp struct which describes a prop of a scenario that changes the game as time passes, and the TimedPropState struct which keeps track of when it ticks.
+pub mod timed_prop;
+/// The turn_audit module contains the TurnAudit struct which describes the outcome of checking the staged actions of a game again, and the ActionAudit struct.
+pub mod turn_audit;
\ No newline at end of file
@@ -0,0 +1,67 @@
+use rand::{distributions::Alphanumeric, Rng};
+
+use crate::game_data::{constants::ACCESS_TOKEN_LENGTH, enums::access_level::AccessLevel};
+
+/// The AccessTokens struct contains the tokens that are allowed to use the server and the access level of each of them.
+///
+/// Access control is disabled by default, in which case anyone can use every endpoint except the ones that need the admin token.
+/// The tokens are never serialized, and the tokens issued while the server runs are forgotten when it's restarted.
+#[derive(Clone, Default, Debug)]
+pub struct AccessTokens {
+    is_enabled: bool,
+    tokens: Vec<(String, AccessLevel)>,
+}
+
+impl AccessTokens {
+    /// Parses a comma separated list of access levels and tokens, like `facilitator:some_token,participant:another_token`, and enables access control.
+    /// The list can be empty, in which case only the admin token and the tokens issued later can be used.
+    pub fn parse(config: &str) -> Result<Self, String> {
+        let mut access_tokens = Self { is_enabled: true, tokens: Vec::new() };
+        for entry in config.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let Some((level, token)) = entry.split_once(':') else {
+                return Err(format!("The access token entry {entry} should be written as <access level>:<token>!"));
+            };
+            let token = token.trim();
+            if token.is_empty() {
+                return Err(format!("The access token of the entry {entry} is empty!"));
+            }
+            access_tokens.add_token(token.to_string(), AccessLevel::parse(level)?);
+        }
+        Ok(access_tokens)
+    }
+
+    /// Returns `true` if the requests to the server have to be sent with an access token.
+    pub const fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Adds a token with the given access level. If the token is already added, its access level is replaced.
+    pub fn add_token(&mut self, token: String, level: AccessLevel) {
+        match self.tokens.iter_mut().find(|(existing_token, _)| *existing_token == token) {
+            Some((_, existing_level)) => *existing_level = level,
+            None => self.tokens.push((token, level)),
+        }
+    }
+
+    /// Creates a new random token with the given access level. Returns an error if access control is disabled, since the token would not be needed.
+    pub fn issue_token(&mut self, level: AccessLevel) -> Result<String, String> {
+        if !self.is_enabled {
+            return Err("Access control is disabled, so no tokens are needed!".to_string());
+        }
+        let mut token = Self::random_token();
+        while self.level_of(&token).is_some() {
+            token = Self::random_token();
+        }
+        self.tokens.push((token.clone(), level));
+        Ok(token)
+    }
+
+    /// Returns the access level of the given token, or `None` if the token is not known.
+    pub fn level_of(&self, token: &str) -> Option<AccessLevel> {
+        self.tokens.iter().find(|(existing_token, _)| existing_token == token).map(|(_, level)| *level)
+    }
+
+    fn random_token() -> String {
+        rand::thread_rng().sample_iter(&Alphanumeric).take(ACCESS_TOKEN_LENGTH).map(char::from).collect()
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The Accessibility struct describes how accessible a node or district is, used for universal design scenarios.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Accessibility {
+    pub has_elevator: bool,
+    pub is_step_free: bool,
+    pub is_lit: bool,
+}
+
+impl Default for Accessibility {
+    /// Most places on the map are accessible, so that is the default.
+    fn default() -> Self {
+        Self {
+            has_elevator: false,
+            is_step_free: true,
+            is_lit: true,
+        }
+    }
+}
+
+impl Accessibility {
+    /// Creates an Accessibility for a place that can not be reached without using steps.
+    #[must_use]
+    pub const fn not_step_free() -> Self {
+        Self {
+            has_elevator: false,
+            is_step_free: false,
+            is_lit: true,
+        }
+    }
+
+    /// Returns `true` if a person using a wheelchair can get here, either because it's step-free or because there is an elevator.
+    pub const fn is_accessible(&self) -> bool {
+        self.is_step_free || self.has_elevator
+    }
+}
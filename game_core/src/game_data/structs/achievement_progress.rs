@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::PlayerID;
+
+/// The AchievementProgress struct keeps what the AchievementExtension needs to know to hand out the achievements that are not earned with a single move.
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct AchievementProgress {
+    /// The amount of moves each player has made by bus in the game.
+    pub bus_moves: Vec<(PlayerID, u32)>,
+    /// The amount of moves made in the current turn.
+    pub moves_this_turn: u32,
+    /// The amount of moves made by car in the current turn, which are the moves that make emissions.
+    pub car_moves_this_turn: u32,
+}
+
+impl AchievementProgress {
+    /// Adds a bus move to the player with the given id.
+    pub fn add_bus_move(&mut self, player_id: PlayerID) {
+        match self.bus_moves.iter_mut().find(|(id, _)| *id == player_id) {
+            Some((_, moves)) => *moves += 1,
+            None => self.bus_moves.push((player_id, 1)),
+        }
+    }
+
+    /// Returns the ids of the players that have made the most moves by bus, or nothing if no one has moved by bus.
+    pub fn most_bus_moves(&self) -> Vec<PlayerID> {
+        let most_moves = self.bus_moves.iter().map(|(_, moves)| *moves).max().unwrap_or(0);
+        if most_moves == 0 {
+            return Vec::new();
+        }
+        self.bus_moves
+            .iter()
+            .filter(|(_, moves)| *moves == most_moves)
+            .map(|(player_id, _)| *player_id)
+            .collect()
+    }
+
+    /// Forgets the moves of the turn that ended.
+    pub const fn end_turn(&mut self) {
+        self.moves_this_turn = 0;
+        self.car_moves_this_turn = 0;
+    }
+}
@@ -0,0 +1,38 @@
+use crate::game_data::custom_types::StateVersion;
+
+use super::gamestate::GameState;
+
+/// The AppliedActions struct keeps the game with its staged actions applied, so that a new action only has to be applied to this running state instead of applying every action of the turn again.
+///
+/// The applied state is only used while the game has the same version and the same amount of staged actions as when it was kept. It's not cloned with the game, since only the stored game needs it.
+#[derive(Debug, Default)]
+pub struct AppliedActions {
+    state: Option<(StateVersion, usize, Box<GameState>)>,
+}
+
+impl Clone for AppliedActions {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl AppliedActions {
+    /// Keeps the applied state of a game with the given version and amount of staged actions.
+    #[must_use]
+    pub fn new(version: StateVersion, action_count: usize, applied_game: GameState) -> Self {
+        Self { state: Some((version, action_count, Box::new(applied_game))) }
+    }
+
+    /// Returns the applied state if it was kept for the given version and amount of staged actions.
+    #[must_use]
+    pub fn get(&self, version: StateVersion, action_count: usize) -> Option<&GameState> {
+        match &self.state {
+            Some((kept_version, kept_action_count, applied_game)) if *kept_version == version && *kept_action_count == action_count => Some(applied_game),
+            _ => None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.state = None;
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::enums::{bot_difficulty::BotDifficulty, in_game_id::InGameID};
+
+/// The BotSeat struct describes a seat of the game the orchestrator has filled with a bot player, so that small groups can play.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct BotSeat {
+    /// The role of the bot, which has to be one of the roles of the players that travel.
+    pub role: InGameID,
+    #[serde(default)]
+    pub difficulty: BotDifficulty,
+}
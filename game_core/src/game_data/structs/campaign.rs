@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{PlayerID, Score};
+
+use super::{game_summary::GameSummary, scenario_template::ScenarioTemplate};
+
+/// The Campaign struct describes a session where the same players play several scenarios in a row.
+///
+/// When a scenario is over, the orchestrator starts the next one with a `NextScenario` input, which brings the game back to the lobby with the settings of the next scenario while the players keep their names, roles and scores.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Campaign {
+    /// The scenarios of the campaign, in the order they are played.
+    pub scenarios: Vec<ScenarioTemplate>,
+    /// The index of the scenario that is being played.
+    pub current_scenario: usize,
+    /// The sum of the scores each player got in the scenarios that are over.
+    pub cumulative_scores: Vec<(PlayerID, Score)>,
+    /// The summaries of the scenarios that are over, in the order they were played.
+    pub completed_scenarios: Vec<GameSummary>,
+}
+
+impl Campaign {
+    /// Creates a new Campaign that starts with the first of the given scenarios. Will return an error if there are no scenarios.
+    pub fn new(scenarios: Vec<ScenarioTemplate>) -> Result<Self, String> {
+        if scenarios.is_empty() {
+            return Err("A campaign has to have at least one scenario!".to_string());
+        }
+        Ok(Self {
+            scenarios,
+            current_scenario: 0,
+            cumulative_scores: Vec::new(),
+            completed_scenarios: Vec::new(),
+        })
+    }
+
+    /// Returns the scenario that is being played.
+    pub fn current_scenario(&self) -> Option<&ScenarioTemplate> {
+        self.scenarios.get(self.current_scenario)
+    }
+
+    /// Returns the scenario that is played after the current one, if there is one.
+    pub fn next_scenario(&self) -> Option<&ScenarioTemplate> {
+        self.scenarios.get(self.current_scenario + 1)
+    }
+
+    /// Adds the score to the cumulative score of the player.
+    pub fn add_score(&mut self, player_id: PlayerID, score: Score) {
+        match self.cumulative_scores.iter_mut().find(|(id, _)| *id == player_id) {
+            Some((_, cumulative_score)) => *cumulative_score += score,
+            None => self.cumulative_scores.push((player_id, score)),
+        }
+    }
+}
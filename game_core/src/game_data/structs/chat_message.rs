@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{ChatMessageID, PlayerID, Timestamp, TurnNumber}, enums::in_game_id::InGameID};
+
+/// The ChatMessage struct describes a message a player has sent to the chat of a game, so that the players can coordinate without a third-party tool.
+///
+/// The name and role of the sender are kept as they were when the message was sent, so the message still makes sense after the player has left or changed role.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ChatMessage {
+    /// The ids of the messages of a game go up by one for every message, so a client can ask for the messages after the last one it has.
+    pub id: ChatMessageID,
+    pub sender: PlayerID,
+    pub sender_name: String,
+    pub sender_role: InGameID,
+    pub text: String,
+    pub turn_number: TurnNumber,
+    /// When the message was sent, in milliseconds since the unix epoch.
+    pub sent_at: Timestamp,
+}
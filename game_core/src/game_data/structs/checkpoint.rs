@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{RoundNumber, Timestamp, TurnNumber};
+
+use super::{enacted_measure::EnactedMeasure, game_event::GameEvent, game_summary::GameSummary, input_record::InputRecord, session_clock::current_timestamp};
+
+/// The Checkpoint struct describes a named copy of a game that the orchestrator can restore the game to later. The copy itself is kept by the server and not sent to the clients.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub label: String,
+    pub turn_number: TurnNumber,
+    pub round_number: RoundNumber,
+    /// When the checkpoint was created, in milliseconds since the unix epoch.
+    pub created_at: Timestamp,
+}
+
+impl Checkpoint {
+    /// Creates a new Checkpoint with the current time as the creation time.
+    #[must_use]
+    pub fn new(label: String, turn_number: TurnNumber, round_number: RoundNumber) -> Self {
+        Self {
+            label,
+            turn_number,
+            round_number,
+            created_at: current_timestamp(),
+        }
+    }
+}
+
+/// The ArchivedBranch struct describes what happened in a game after a checkpoint was created and before the game was restored to it, so that it's still part of the export of the game.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ArchivedBranch {
+    /// The label of the checkpoint the game was restored to.
+    pub checkpoint_label: String,
+    /// When the game was restored to the checkpoint, in milliseconds since the unix epoch.
+    pub archived_at: Timestamp,
+    /// The game as it was right before it was restored.
+    pub summary: GameSummary,
+    /// The events, inputs and measures from after the checkpoint was created.
+    pub events: Vec<GameEvent>,
+    pub input_history: Vec<InputRecord>,
+    pub measure_ledger: Vec<EnactedMeasure>,
+}
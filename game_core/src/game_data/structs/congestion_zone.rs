@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{Money, RoundNumber}, enums::district::District};
+
+/// The CongestionZone struct describes a cordon-pricing measure: cars are charged every time they drive into one of the districts of the zone from outside of it.
+///
+/// The charge depends on the round, so that rounds can be used as the time of day with peak and off-peak prices.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct CongestionZone {
+    pub districts: Vec<District>,
+    /// The charge for entering the zone in one of the peak rounds.
+    pub peak_charge: Money,
+    /// The charge for entering the zone in all the other rounds.
+    pub off_peak_charge: Money,
+    /// The rounds that are peak rounds.
+    pub peak_rounds: Vec<RoundNumber>,
+}
+
+impl CongestionZone {
+    /// Creates a new CongestionZone.
+    #[must_use]
+    pub const fn new(districts: Vec<District>, peak_charge: Money, off_peak_charge: Money, peak_rounds: Vec<RoundNumber>) -> Self {
+        Self {
+            districts,
+            peak_charge,
+            off_peak_charge,
+            peak_rounds,
+        }
+    }
+
+    /// Returns `true` if the district is a part of the zone.
+    pub fn contains(&self, district: District) -> bool {
+        self.districts.contains(&district)
+    }
+
+    /// Returns the charge for entering the zone in the given round.
+    pub fn charge_in_round(&self, round: RoundNumber) -> Money {
+        if self.peak_rounds.contains(&round) {
+            self.peak_charge
+        } else {
+            self.off_peak_charge
+        }
+    }
+}
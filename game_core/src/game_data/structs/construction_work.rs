@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{ConstructionID, EdgeID, MovementCost, PlayerID, TurnNumber}, enums::construction_status::ConstructionStatus};
+
+/// The ConstructionWork struct describes construction works the orchestrator schedules on some edges, which raise the movement cost of the edges (or close them) for a number of turns.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ConstructionWork {
+    pub edge_ids: Vec<EdgeID>,
+    /// The turn the works start in. The players are told about the works the turn before they start.
+    pub start_turn: TurnNumber,
+    /// How many turns the works last.
+    pub duration: TurnNumber,
+    /// How much more it costs to move along the edges while the works last.
+    #[serde(default)]
+    pub extra_movement_cost: MovementCost,
+    /// If true the edges can't be used at all while the works last.
+    #[serde(default)]
+    pub closes_edges: bool,
+}
+
+impl ConstructionWork {
+    /// Returns the first turn after the works, when the edges are as they were before.
+    #[must_use]
+    pub const fn end_turn(&self) -> TurnNumber {
+        self.start_turn.saturating_add(self.duration)
+    }
+}
+
+/// The ScheduledConstruction struct is construction work that has been scheduled in a game, and how far along it is.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ScheduledConstruction {
+    pub id: ConstructionID,
+    pub work: ConstructionWork,
+    /// The orchestrator that scheduled the works.
+    pub scheduled_by: PlayerID,
+    pub status: ConstructionStatus,
+}
+
+impl ScheduledConstruction {
+    /// Returns what the status of the works should be in the given turn.
+    #[must_use]
+    pub const fn status_in_turn(&self, turn_number: TurnNumber) -> ConstructionStatus {
+        if turn_number >= self.work.end_turn() {
+            ConstructionStatus::Finished
+        } else if turn_number >= self.work.start_turn {
+            ConstructionStatus::InProgress
+        } else if turn_number + 1 >= self.work.start_turn {
+            ConstructionStatus::Announced
+        } else {
+            ConstructionStatus::Scheduled
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::SituationCardID;
+
+use super::{node_map::NodeMap, scenario_template::ScenarioTemplate, situation_card::SituationCard};
+
+/// The ContentPackManifest struct is the `manifest.json` file of a content pack, which names the pack.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ContentPackManifest {
+    /// The name of the pack. A pack that is uploaded with the same name as another pack replaces it.
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// The ContentPack struct contains workshop content that can be added to the server while it runs, see the [`content_pack_archive`](../../../content_pack_archive/index.html) module for the format.
+#[derive(Clone, Debug)]
+pub struct ContentPack {
+    pub manifest: ContentPackManifest,
+    /// The maps of the pack, named after their files without the extension.
+    pub maps: Vec<(String, NodeMap)>,
+    pub situation_cards: Vec<SituationCard>,
+    pub scenarios: Vec<ScenarioTemplate>,
+    /// The rule scripts of the pack, as pairs of their path in the `rule_scripts` folder of the pack and the script.
+    pub rule_scripts: Vec<(String, String)>,
+    /// The texts of the pack for each language, as pairs of the language and the texts by their key.
+    pub localizations: Vec<(String, HashMap<String, String>)>,
+}
+
+/// The ContentPackReport struct describes what a content pack contains and the issues found when it was validated. A pack with any issues can not be activated.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ContentPackReport {
+    pub manifest: ContentPackManifest,
+    pub maps: Vec<String>,
+    pub situation_card_ids: Vec<SituationCardID>,
+    pub scenarios: Vec<String>,
+    pub rule_scripts: Vec<String>,
+    pub languages: Vec<String>,
+    /// If the pack is the one the server uses now.
+    pub is_active: bool,
+    pub issues: Vec<String>,
+}
+
+impl ContentPackReport {
+    /// Creates a report of what the pack contains, without any issues.
+    #[must_use]
+    pub fn new(pack: &ContentPack, is_active: bool) -> Self {
+        Self {
+            manifest: pack.manifest.clone(),
+            maps: pack.maps.iter().map(|(name, _)| name.clone()).collect(),
+            situation_card_ids: pack.situation_cards.iter().map(|card| card.card_id).collect(),
+            scenarios: pack.scenarios.iter().map(|scenario| scenario.name.clone()).collect(),
+            rule_scripts: pack.rule_scripts.iter().map(|(path, _)| path.clone()).collect(),
+            languages: pack.localizations.iter().map(|(language, _)| language.clone()).collect(),
+            is_active,
+            issues: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the pack has no issues.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{DeadLetterID, StateVersion, Timestamp, TurnNumber};
+
+use super::{player_input::PlayerInput, session_clock::current_timestamp};
+
+/// The DeadLetter struct keeps an input that passed the rules but could not be applied to the game, like a measure that conflicts with a modifier enacted in the meantime.
+///
+/// The orchestrator can retry the input once the problem is fixed, or discard it.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct DeadLetter {
+    pub id: DeadLetterID,
+    pub input: PlayerInput,
+    /// Why the input could not be applied.
+    pub error: String,
+    /// The turn the input failed in.
+    pub turn_number: TurnNumber,
+    /// The version of the game when the input failed.
+    pub state_version: StateVersion,
+    /// When the input failed, in milliseconds since the unix epoch.
+    pub failed_at: Timestamp,
+}
+
+impl DeadLetter {
+    /// Creates a new DeadLetter that failed now.
+    #[must_use]
+    pub fn new(id: DeadLetterID, input: PlayerInput, error: String, turn_number: TurnNumber, state_version: StateVersion) -> Self {
+        Self {
+            id,
+            input,
+            error,
+            turn_number,
+            state_version,
+            failed_at: current_timestamp(),
+        }
+    }
+}
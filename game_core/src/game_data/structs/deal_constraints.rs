@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// The DealConstraints struct describes the constraints a scenario can put on how the objective cards are dealt when the game starts. No constraints are used by default.
+///
+/// If no set of objective cards meets all the constraints, the cards are dealt so that as many constraints as possible are met, and the constraints that were not met are added to the `objective_deal_warnings` of the game.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+pub struct DealConstraints {
+    /// If true every district that one of the available objective cards has its drop off node in gets at least one of the dealt objectives.
+    #[serde(default)]
+    pub objective_in_every_district: bool,
+    /// If true no two players get objective cards with the same drop off node.
+    #[serde(default)]
+    pub unique_destinations: bool,
+    /// The least amount of objective cards of each difficulty that should be dealt, if any.
+    #[serde(default)]
+    pub difficulty_mix: Option<DifficultyMix>,
+}
+
+/// The DifficultyMix struct describes the least amount of objective cards of each [`ObjectiveDifficulty`](../../enums/objective_difficulty/enum.ObjectiveDifficulty.html) that should be dealt.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+pub struct DifficultyMix {
+    #[serde(default)]
+    pub easy: u32,
+    #[serde(default)]
+    pub medium: u32,
+    #[serde(default)]
+    pub hard: u32,
+}
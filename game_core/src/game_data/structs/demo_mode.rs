@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{
+    constants::{DEMO_EXPIRY_WARNING, DEMO_GAME_EXPIRY, DEMO_MAX_GAMES},
+    custom_types::{Milliseconds, Timestamp},
+};
+
+/// The DemoMode struct describes the limits of a server that is left open on the internet for the public demo build, so that it can run without anyone looking after it.
+///
+/// The players are warned with a `GameExpiring` event before an inactive game is removed.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct DemoMode {
+    /// The most games that can exist at the same time.
+    pub max_games: usize,
+    /// How long a game can go without any inputs before it's removed, in milliseconds.
+    pub game_expiry: Milliseconds,
+    /// How long before an inactive game is removed the players are warned, in milliseconds.
+    pub expiry_warning: Milliseconds,
+}
+
+impl Default for DemoMode {
+    fn default() -> Self {
+        Self {
+            max_games: DEMO_MAX_GAMES,
+            game_expiry: DEMO_GAME_EXPIRY,
+            expiry_warning: DEMO_EXPIRY_WARNING,
+        }
+    }
+}
+
+impl DemoMode {
+    /// Returns how long is left at the given time before a game whose latest activity was at `last_activity` is removed. Zero if the game has expired.
+    pub const fn time_left(&self, last_activity: Timestamp, now: Timestamp) -> Milliseconds {
+        self.game_expiry.saturating_sub(now.saturating_sub(last_activity))
+    }
+}
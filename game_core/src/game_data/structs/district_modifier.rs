@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::game_data::{enums::{district::District, district_modifier_type::DistrictModifierType, restriction_type::RestrictionType}, custom_types::{MovementValue, Money}};
+use crate::game_data::{enums::{district::District, district_modifier_type::DistrictModifierType, restriction_type::RestrictionType}, custom_types::{MovementValue, Money, TurnNumber, MeasureID}};
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct DistrictModifier {
@@ -10,4 +10,43 @@ pub struct DistrictModifier {
     pub associated_movement_value: Option<MovementValue>,
     pub associated_money_value: Option<Money>,
     pub delete: bool,
+    /// The turn the modifier was enacted in. This is set by the server.
+    #[serde(default)]
+    pub enacted_turn: Option<TurnNumber>,
+    /// The id of the measure in the measure ledger of the game. This is set by the server.
+    #[serde(default)]
+    pub measure_id: Option<MeasureID>,
+}
+
+impl DistrictModifier {
+    /// Creates a new DistrictModifier without any associated values.
+    #[must_use]
+    pub const fn new(district: District, modifier: DistrictModifierType, vehicle_type: Option<RestrictionType>) -> Self {
+        Self {
+            district,
+            modifier,
+            vehicle_type,
+            associated_movement_value: None,
+            associated_money_value: None,
+            delete: false,
+            enacted_turn: None,
+            measure_id: None,
+        }
+    }
+
+    /// Sets the amount of money associated with the modifier, for example the price of a toll.
+    #[must_use]
+    pub const fn with_money_value(mut self, money_value: Money) -> Self {
+        self.associated_money_value = Some(money_value);
+        self
+    }
+
+    /// Returns `true` if the other modifier describes the same measure, ignoring the `delete`, `enacted_turn` and `measure_id` values.
+    pub fn is_same_modifier(&self, other: &Self) -> bool {
+        self.district == other.district
+            && self.modifier == other.modifier
+            && self.vehicle_type == other.vehicle_type
+            && self.associated_movement_value == other.associated_movement_value
+            && self.associated_money_value == other.associated_money_value
+    }
 }
\ No newline at end of file
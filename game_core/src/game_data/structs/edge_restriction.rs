@@ -1,22 +1,44 @@
 use serde::{Deserialize, Serialize};
 
-use crate::game_data::{custom_types::NodeID, enums::restriction_type::RestrictionType};
+use crate::game_data::{custom_types::{EdgeID, NodeID, TurnNumber, MeasureID}, enums::restriction_type::RestrictionType};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct EdgeRestriction {
+    /// The id of the edge the restriction is on. Can be left out if there is only one edge between the two nodes, in which case it's set by the server.
+    #[serde(default)]
+    pub edge_id: Option<EdgeID>,
+    /// The node the edge goes from. For one way restrictions, the edge can only be used from this node to node_two.
     pub node_one: NodeID,
     pub node_two: NodeID,
     pub edge_restriction: RestrictionType,
     pub delete: bool,
+    /// The turn the restriction was enacted in by the orchestrator. This is set by the server and is `None` for restrictions that are a part of the situation card.
+    #[serde(default)]
+    pub enacted_turn: Option<TurnNumber>,
+    /// The id of the measure in the measure ledger of the game. This is set by the server and is `None` for restrictions that are a part of the situation card.
+    #[serde(default)]
+    pub measure_id: Option<MeasureID>,
 }
 
 impl EdgeRestriction {
     pub const fn new(node_id_one: NodeID, node_id_two: NodeID, edge_restriction: RestrictionType) -> Self {
         Self {
+            edge_id: None,
             node_one: node_id_one,
             node_two: node_id_two,
             delete: false,
             edge_restriction,
+            enacted_turn: None,
+            measure_id: None,
         }
     }
+
+    /// Returns `true` if the other restriction is on the same edge. The edge ids are compared if both are set, otherwise the nodes are.
+    pub fn is_on_same_edge(&self, other: &Self) -> bool {
+        if let (Some(edge_id), Some(other_edge_id)) = (self.edge_id, other.edge_id) {
+            return edge_id == other_edge_id;
+        }
+        (self.node_one == other.node_one && self.node_two == other.node_two)
+            || (self.node_one == other.node_two && self.node_two == other.node_one)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{MeasureID, PlayerID, TurnNumber};
+
+use super::{district_modifier::DistrictModifier, edge_restriction::EdgeRestriction};
+
+/// A measure the orchestrator can enact to change the game.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum Measure {
+    DistrictModifier(DistrictModifier),
+    EdgeRestriction(EdgeRestriction),
+}
+
+impl Measure {
+    /// Returns `true` if the other measure does the same to the game, ignoring when it was enacted and its id.
+    pub fn is_same_measure(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::DistrictModifier(modifier), Self::DistrictModifier(other_modifier)) => modifier.is_same_modifier(other_modifier) && modifier.delete == other_modifier.delete,
+            (Self::EdgeRestriction(restriction), Self::EdgeRestriction(other_restriction)) => {
+                restriction.is_on_same_edge(other_restriction)
+                    && restriction.edge_restriction == other_restriction.edge_restriction
+                    && restriction.delete == other_restriction.delete
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The EnactedMeasure struct is an entry in the measure ledger of a game. It describes a measure, when and by whom it was enacted and when it was removed.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct EnactedMeasure {
+    pub id: MeasureID,
+    pub measure: Measure,
+    pub enacted_turn: TurnNumber,
+    pub enacting_player: PlayerID,
+    /// The turn the measure was removed in, either because it was revoked, deleted or expired. `None` if the measure is still in use.
+    pub removed_turn: Option<TurnNumber>,
+}
+
+impl EnactedMeasure {
+    /// Creates a new EnactedMeasure that is still in use.
+    #[must_use]
+    pub const fn new(id: MeasureID, measure: Measure, enacted_turn: TurnNumber, enacting_player: PlayerID) -> Self {
+        Self {
+            id,
+            measure,
+            enacted_turn,
+            enacting_player,
+            removed_turn: None,
+        }
+    }
+
+    /// Returns `true` if the measure has not been removed.
+    pub const fn is_active(&self) -> bool {
+        self.removed_turn.is_none()
+    }
+}
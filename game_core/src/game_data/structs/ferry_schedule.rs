@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{EdgeID, NodeID, PlayerID, RoundNumber};
+
+/// The FerrySchedule struct describes when a ferry edge (a water crossing) can be used and how many vehicles the ferry takes. A ferry edge can only be used in the rounds the ferry departs in.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct FerrySchedule {
+    /// The ferry departs every this many rounds.
+    pub departs_every_rounds: RoundNumber,
+    /// The first round the ferry departs in.
+    #[serde(default)]
+    pub first_departure_round: RoundNumber,
+    /// How many vehicles the ferry takes on each departure, in each direction. Passengers riding with a driver don't take up any room.
+    pub capacity: u32,
+}
+
+impl FerrySchedule {
+    /// Returns how many rounds there are from the given round until the ferry departs, which is 0 if it departs in the given round. Returns `None` if the ferry never departs.
+    #[must_use]
+    pub const fn rounds_until_departure(&self, round_number: RoundNumber) -> Option<RoundNumber> {
+        if self.departs_every_rounds == 0 {
+            return None;
+        }
+        if round_number < self.first_departure_round {
+            return Some(self.first_departure_round - round_number);
+        }
+        let rounds_since_departure = (round_number - self.first_departure_round) % self.departs_every_rounds;
+        Some((self.departs_every_rounds - rounds_since_departure) % self.departs_every_rounds)
+    }
+
+    /// Returns `true` if the ferry departs in the given round.
+    #[must_use]
+    pub const fn departs_in_round(&self, round_number: RoundNumber) -> bool {
+        matches!(self.rounds_until_departure(round_number), Some(0))
+    }
+
+    /// Returns the issues with the schedule, like a ferry that never departs or can't take any vehicles.
+    #[must_use]
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+        if self.departs_every_rounds == 0 {
+            issues.push("the ferry never departs, since it departs every 0 rounds".to_string());
+        }
+        if self.capacity == 0 {
+            issues.push("the ferry can not take any vehicles".to_string());
+        }
+        issues
+    }
+}
+
+/// The FerryCrossing struct records that a player took a ferry, so that the ferry is not filled over its capacity.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct FerryCrossing {
+    pub edge_id: EdgeID,
+    /// The node the player took the ferry from.
+    pub from_node_id: NodeID,
+    pub round_number: RoundNumber,
+    pub player_id: PlayerID,
+}
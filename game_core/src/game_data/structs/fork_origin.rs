@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{GameID, TurnNumber};
+
+/// The ForkOrigin struct describes which game and turn a forked game was copied from.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ForkOrigin {
+    pub game_id: GameID,
+    pub turn_number: TurnNumber,
+    /// If the forked game has been started. Until then the players claim the open seats copied from the players of the other game.
+    pub has_started: bool,
+}
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{Emissions, GameID, Money, Score, TurnNumber}, enums::in_game_id::InGameID};
+
+use super::{enacted_measure::EnactedMeasure, game_summary::GameSummary, player::Player};
+
+/// The GameReference struct points to a game, or to one of the archived branches of a game, that should be compared.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct GameReference {
+    pub game_id: GameID,
+    /// The index of the archived branch of the game, oldest first. The game itself is used if it's not set.
+    #[serde(default)]
+    pub branch: Option<usize>,
+}
+
+/// The GameOutcome struct describes the outcome of one of the compared games.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GameOutcome {
+    pub reference: GameReference,
+    pub name: String,
+    pub turn_number: TurnNumber,
+    /// The turn the game ended in, if it has ended.
+    pub ended_in_turn: Option<TurnNumber>,
+    pub total_emissions: Emissions,
+    pub total_congestion_charges: Money,
+}
+
+/// The PlayerOutcome struct describes how it went for a player in one of the compared games.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PlayerOutcome {
+    pub name: String,
+    /// The amount of moves the player made.
+    pub moves: u32,
+    pub emissions: Emissions,
+    pub congestion_charges_paid: Money,
+    /// The turn the player dropped off what the objective card asks for, if the player has done it.
+    pub objective_completed_in_turn: Option<TurnNumber>,
+    pub score: Score,
+}
+
+/// The PlayerComparison struct compares the players with the same role in the two games. The players are matched by role, since the players of a forked game have other ids.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PlayerComparison {
+    pub role: InGameID,
+    /// The outcome of the player with the role in the first game, if there is one.
+    pub first: Option<PlayerOutcome>,
+    /// The outcome of the player with the role in the second game, if there is one.
+    pub second: Option<PlayerOutcome>,
+}
+
+/// The GameComparison struct compares the outcomes of two games, like a forked game and the game it was forked from, so that researchers can see what the different measures led to.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GameComparison {
+    pub first: GameOutcome,
+    pub second: GameOutcome,
+    /// The players of both games, except the orchestrators.
+    pub players: Vec<PlayerComparison>,
+    /// The measures that were enacted in the first game, but not in the second.
+    pub measures_only_in_first: Vec<EnactedMeasure>,
+    /// The measures that were enacted in the second game, but not in the first.
+    pub measures_only_in_second: Vec<EnactedMeasure>,
+}
+
+impl GameComparison {
+    /// Compares the summaries of the two referenced games.
+    #[must_use]
+    pub fn new(first_reference: GameReference, first: &GameSummary, second_reference: GameReference, second: &GameSummary) -> Self {
+        let mut roles: Vec<InGameID> = Vec::new();
+        first
+            .players
+            .iter()
+            .chain(second.players.iter())
+            .map(|player| player.in_game_id)
+            .filter(|role| *role != InGameID::Orchestrator && *role != InGameID::Undecided)
+            .for_each(|role| {
+                if !roles.contains(&role) {
+                    roles.push(role);
+                }
+            });
+        let players = roles
+            .into_iter()
+            .map(|role| PlayerComparison {
+                role,
+                first: Self::player_outcome(first, role),
+                second: Self::player_outcome(second, role),
+            })
+            .collect();
+
+        Self {
+            first: Self::game_outcome(first_reference, first),
+            second: Self::game_outcome(second_reference, second),
+            players,
+            measures_only_in_first: Self::measures_not_in(&first.measure_ledger, &second.measure_ledger),
+            measures_only_in_second: Self::measures_not_in(&second.measure_ledger, &first.measure_ledger),
+        }
+    }
+
+    fn game_outcome(reference: GameReference, summary: &GameSummary) -> GameOutcome {
+        GameOutcome {
+            reference,
+            name: summary.name.clone(),
+            turn_number: summary.turn_number,
+            ended_in_turn: summary.statistics.ended_in_turn,
+            total_emissions: summary.total_emissions,
+            total_congestion_charges: summary.total_congestion_charges,
+        }
+    }
+
+    fn player_outcome(summary: &GameSummary, role: InGameID) -> Option<PlayerOutcome> {
+        let player: &Player = summary.players.iter().find(|player| player.in_game_id == role)?;
+        Some(PlayerOutcome {
+            name: player.name.clone(),
+            moves: summary.statistics.moves_of_role(role),
+            emissions: player.emissions,
+            congestion_charges_paid: player.congestion_charges_paid,
+            objective_completed_in_turn: summary.statistics.objective_completion_turn_of_role(role),
+            score: player.score,
+        })
+    }
+
+    fn measures_not_in(measures: &[EnactedMeasure], other_measures: &[EnactedMeasure]) -> Vec<EnactedMeasure> {
+        measures
+            .iter()
+            .filter(|measure| !other_measures.iter().any(|other_measure| measure.measure.is_same_measure(&other_measure.measure)))
+            .cloned()
+            .collect()
+    }
+}
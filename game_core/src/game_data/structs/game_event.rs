@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{ConstructionID, EventID, RuleWarningID, MeasureID, PlayerID, TurnNumber}, enums::{achievement::Achievement, district::District, district_modifier_type::DistrictModifierType, in_game_id::InGameID, notification_class::NotificationClass}, structs::rule_override::RuleOverride};
+
+/// Something that happened in a game that the clients might want to notify the players about.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum GameEventKind {
+    PlayerJoined(PlayerID),
+    PlayerLeft(PlayerID),
+    GameStarted,
+    /// It's now the turn of the player with the given in game id.
+    TurnChanged(InGameID),
+    /// The turn of the role was skipped because its player has left the game.
+    TurnSkipped(InGameID),
+    MeasureEnacted(MeasureID),
+    /// The measure was revoked, deleted or expired.
+    MeasureRemoved(MeasureID),
+    /// All the players have delivered what their objective card asks for.
+    GameEnded,
+    /// A reminder of how many minutes are left of the session, rounded up.
+    SessionReminder(u32),
+    /// The orchestrator created a checkpoint with the given label.
+    CheckpointCreated(String),
+    /// The game was restored to the checkpoint with the given label.
+    CheckpointRestored(String),
+    /// The objective cards were dealt without meeting all the deal constraints of the game. Has a description of every constraint that was not met.
+    DealConstraintsUnmet(Vec<String>),
+    /// The player with the given id passed, ending their movement for the rest of the turn.
+    PlayerPassed(PlayerID),
+    /// Nobody has sent an input to the game for a while, and it will be removed in the given amount of minutes (rounded up) unless someone does. Only used on servers in demo mode.
+    GameExpiring(u32),
+    /// The player with the given id has not checked in for a while, so the facilitators know the player might not be there when it's their turn.
+    PlayerConnectionStale(PlayerID),
+    /// The player with the given id has not checked in for most of the player timeout, and will be removed from the game in the given amount of seconds (rounded up) unless the player checks in.
+    /// The client of the player should ask whether the player is still there, and the orchestrator might want to wait for the player.
+    PlayerInactive(PlayerID, u32),
+    /// The player with the given id was removed from the game for not checking in, after being asked whether they were still there. The player can reconnect with the session token for a while.
+    PlayerTimedOut(PlayerID),
+    /// The orchestrator started the next scenario of the campaign, which has the given name. The game is back in the lobby.
+    ScenarioStarted(String),
+    /// The timed prop with the given name ticked.
+    TimedPropTicked(String),
+    /// The construction works with the given id start in the next turn.
+    ConstructionAnnounced(ConstructionID),
+    /// The construction works with the given id have started, and the edges cost more to use or are closed.
+    ConstructionStarted(ConstructionID),
+    /// The construction works with the given id are done, and the edges are as they were before.
+    ConstructionEnded(ConstructionID),
+    /// The player with the given id earned the achievement.
+    AchievementEarned(PlayerID, Achievement),
+    /// An input only broke lenient rules, and the orchestrator can accept it with the rule warning with the given id.
+    RuleWarningRaised(RuleWarningID),
+    /// The orchestrator let an input through without checking some of the rules.
+    RuleOverridden(RuleOverride),
+    /// The district has as many modifiers of the type as it can have, so no more can be added until one is removed.
+    ModifierCapReached(District, DistrictModifierType),
+}
+
+impl GameEventKind {
+    /// Returns the notification class the clients should use for this kind of event.
+    pub const fn notification_class(&self) -> NotificationClass {
+        match self {
+            Self::TurnChanged(_) => NotificationClass::YourTurn,
+            Self::GameStarted => NotificationClass::GameStarted,
+            Self::MeasureEnacted(_) => NotificationClass::MeasureEnacted,
+            Self::GameEnded => NotificationClass::GameEnded,
+            Self::SessionReminder(_) | Self::GameExpiring(_) | Self::PlayerInactive(_, _) => NotificationClass::Reminder,
+            Self::PlayerJoined(_)
+            | Self::PlayerLeft(_)
+            | Self::MeasureRemoved(_)
+            | Self::CheckpointCreated(_)
+            | Self::CheckpointRestored(_)
+            | Self::DealConstraintsUnmet(_)
+            | Self::TurnSkipped(_)
+            | Self::PlayerPassed(_)
+            | Self::PlayerConnectionStale(_)
+            | Self::PlayerTimedOut(_)
+            | Self::ScenarioStarted(_)
+            | Self::TimedPropTicked(_)
+            | Self::ConstructionAnnounced(_)
+            | Self::ConstructionStarted(_)
+            | Self::ConstructionEnded(_)
+            | Self::AchievementEarned(_, _)
+            | Self::RuleWarningRaised(_)
+            | Self::RuleOverridden(_)
+            | Self::ModifierCapReached(_, _) => NotificationClass::Info,
+        }
+    }
+}
+
+/// The GameEvent struct describes an event in a game and how the clients should notify the players about it.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct GameEvent {
+    /// Increases by one for every event in the game, so the clients can tell which events they have already handled.
+    pub id: EventID,
+    pub turn_number: TurnNumber,
+    pub kind: GameEventKind,
+    pub notification_class: NotificationClass,
+}
+
+impl GameEvent {
+    /// Creates a new GameEvent with the notification class of the given kind of event.
+    #[must_use]
+    pub const fn new(id: EventID, turn_number: TurnNumber, kind: GameEventKind) -> Self {
+        let notification_class = kind.notification_class();
+        Self {
+            id,
+            turn_number,
+            kind,
+            notification_class,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{game_data::{constants::MAX_STORED_TURN_SNAPSHOTS, custom_types::TurnNumber}, replay::ReplayLog};
+
+use super::{game_snapshot::GameSnapshot, gamestate::GameState};
+
+/// The GameHistory struct has the copies of a game that are kept to go back in it: the copies saved by the checkpoints, the copies from the start of the latest turns and the replay log.
+///
+/// The game controller keeps it next to the [`GameState`] instead of in it, so that the copies are not cloned with every clone of the game or sent with every change of it.
+///
+/// [`GameState`]: ../gamestate/struct.GameState.html
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct GameHistory {
+    /// The copies of the game saved by the checkpoints, by the label of the checkpoint.
+    #[serde(default)]
+    pub checkpoint_snapshots: Vec<(String, GameSnapshot)>,
+    /// Copies of the game from the start of the latest turns, by turn number. Used to fork the game from an earlier turn.
+    #[serde(default)]
+    pub turn_snapshots: Vec<(TurnNumber, GameSnapshot)>,
+    /// Every input that has been accepted since the game was started, used to reconstruct the game as it was at any turn.
+    #[serde(default)]
+    pub replay_log: ReplayLog,
+}
+
+impl GameHistory {
+    /// Creates a checkpoint with the given label in the game, see [`GameState::create_checkpoint`], and keeps the copy of the game it saves.
+    pub fn create_checkpoint(&mut self, game: &mut GameState, label: String) -> Result<(), String> {
+        let snapshot = game.create_checkpoint(label.clone())?;
+        self.checkpoint_snapshots.push((label, snapshot));
+        Ok(())
+    }
+
+    /// Restores the game to the checkpoint with the given label, see [`GameState::restore_checkpoint`].
+    pub fn restore_checkpoint(&self, game: &mut GameState, label: &str) -> Result<(), String> {
+        let snapshot = self.checkpoint_snapshots.iter().find(|(snapshot_label, _)| snapshot_label == label).map(|(_, snapshot)| snapshot);
+        game.restore_checkpoint(label, snapshot)
+    }
+
+    /// Saves a copy of the game as it is now under its current turn number, so that the game can be forked from this turn later.
+    /// Only the copies of the latest [`MAX_STORED_TURN_SNAPSHOTS`](../../constants/constant.MAX_STORED_TURN_SNAPSHOTS.html) turns are kept, and they leave out the input history, events and chat of the game, which a fork does not get.
+    pub fn record_turn_snapshot(&mut self, game: &GameState) {
+        let turn_number = game.turn_number;
+        let mut snapshot = game.saved_copy();
+        snapshot.input_history.clear();
+        snapshot.game.input_history.clear();
+        snapshot.game.events.clear();
+        snapshot.game.chat.clear();
+        self.turn_snapshots.retain(|(snapshot_turn_number, _)| *snapshot_turn_number != turn_number);
+        self.turn_snapshots.push((turn_number, snapshot));
+        if self.turn_snapshots.len() > MAX_STORED_TURN_SNAPSHOTS {
+            let amount_to_remove = self.turn_snapshots.len() - MAX_STORED_TURN_SNAPSHOTS;
+            self.turn_snapshots.drain(..amount_to_remove);
+        }
+    }
+
+    /// Returns the copy of the game from the start of the given turn, if it's one of the latest turns.
+    pub fn turn_snapshot(&self, turn_number: TurnNumber) -> Option<&GameSnapshot> {
+        self.turn_snapshots.iter().find(|(snapshot_turn_number, _)| *snapshot_turn_number == turn_number).map(|(_, snapshot)| snapshot)
+    }
+}
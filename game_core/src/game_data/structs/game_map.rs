@@ -0,0 +1,179 @@
+use std::sync::{Arc, OnceLock};
+
+use crate::{content_hash::content_hash, game_data::{custom_types::{EdgeID, MovementCost, NodeID, StateHash}, enums::restriction_type::RestrictionType}};
+
+use super::{edge_restriction::EdgeRestriction, map_overlay::{MapOverlay, RuntimeEdgeState}, neighbour_relationship::NeighbourRelationship, node::Node, node_map::NodeMap, situation_card::SituationCard};
+
+/// The GameMap struct is the map of a game. It consists of a base map that never changes and is shared between the games, and a [`MapOverlay`] with the changes made while the game is played.
+///
+/// All the lookups combine the base map and the overlay, so the rest of the game does not have to know about the overlay.
+///
+/// [`MapOverlay`]: ../map_overlay/struct.MapOverlay.html
+#[derive(Clone, Debug)]
+pub struct GameMap {
+    base: Arc<NodeMap>,
+    pub overlay: MapOverlay,
+    /// The content hash of the base map, made the first time it's needed.
+    base_hash: OnceLock<Option<StateHash>>,
+}
+
+impl Default for GameMap {
+    fn default() -> Self {
+        Self::new(NodeMap::shared_default(), MapOverlay::default())
+    }
+}
+
+impl GameMap {
+    #[must_use]
+    pub const fn new(base: Arc<NodeMap>, overlay: MapOverlay) -> Self {
+        Self { base, overlay, base_hash: OnceLock::new() }
+    }
+
+    /// Returns the base map, which does not include any of the changes made while the game is played.
+    pub fn base(&self) -> &NodeMap {
+        &self.base
+    }
+
+    /// Returns the base map so that it can be shared with another copy of the game, like one restored from a snapshot of it.
+    pub fn shared_base(&self) -> Arc<NodeMap> {
+        self.base.clone()
+    }
+
+    /// Returns the content hash of the base map, which tells exactly which version of the map the game is played on. `None` if the map could not be hashed.
+    pub fn base_hash(&self) -> Option<StateHash> {
+        *self.base_hash.get_or_init(|| content_hash(self.base.as_ref()).ok())
+    }
+
+    /// Removes all the changes made to the map, so it's the same as the base map.
+    pub fn reset(&mut self) {
+        self.overlay = MapOverlay::default();
+    }
+
+    /// Gets the node with the given ID. Returns an error if there is no node with the given ID.
+    pub fn get_node_by_id(&self, node_id: NodeID) -> Result<Node, String> {
+        let mut node = self.base.get_node_by_id(node_id)?;
+        if self.overlay.toggled_rail_nodes.contains(&node_id) {
+            node.toggle_rail_connection();
+        }
+        Ok(node)
+    }
+
+    /// Gets all the neighbouring edges of the node with the given ID, with the restrictions they have in the game. Returns none if there are no edges for the given node.
+    pub fn get_neighbour_relationships_of_node_with_id(&self, node_id: NodeID) -> Option<Vec<NeighbourRelationship>> {
+        let mut neighbours = self.base.get_neighbour_relationships_of_node_with_id(node_id)?;
+        for neighbour in &mut neighbours {
+            if let Some(edge_state) = self.overlay.get_edge_state(neighbour.edge_id, node_id) {
+                neighbour.restriction = edge_state.restriction;
+                neighbour.is_modifiable = edge_state.is_modifiable;
+            }
+            if let Some(extra_cost) = self.overlay.extra_edge_costs.get(&neighbour.edge_id) {
+                neighbour.movement_cost = neighbour.movement_cost.saturating_add(*extra_cost);
+            }
+        }
+        Some(neighbours)
+    }
+
+    /// Gets the edge from the first node to the second node, with the restrictions it has in the game.
+    /// The id of the edge only has to be given if there are several edges between the nodes, like a road and a railway. Returns an error if there is no such edge or if the edge is ambiguous.
+    pub fn get_edge_between(&self, from_node_id: NodeID, to_node_id: NodeID, edge_id: Option<EdgeID>) -> Result<NeighbourRelationship, String> {
+        let Some(neighbours) = self.get_neighbour_relationships_of_node_with_id(from_node_id) else {
+            return Err(format!("There was no node with id {}!", from_node_id));
+        };
+        let mut edges_between = neighbours.into_iter().filter(|relationship| relationship.to == to_node_id);
+        if let Some(edge_id) = edge_id {
+            return edges_between
+                .find(|relationship| relationship.edge_id == edge_id)
+                .ok_or_else(|| format!("The edge with id {} does not go from node {} to node {}!", edge_id, from_node_id, to_node_id));
+        }
+        match (edges_between.next(), edges_between.next()) {
+            (Some(relationship), None) => Ok(relationship),
+            (Some(_), Some(_)) => Err(format!("There are several edges between node {} and node {}, so the id of the edge has to be given!", from_node_id, to_node_id)),
+            (None, _) => Err(format!("The node {} is not a neighbour of the node {}!", to_node_id, from_node_id)),
+        }
+    }
+
+    /// Checks if the given node IDs are neighbours. Returns an error if something went wrong.
+    pub fn are_nodes_neighbours(&self, node_1: NodeID, node_2: NodeID) -> Result<bool, String> {
+        self.base.are_nodes_neighbours(node_1, node_2)
+    }
+
+    /// Get's the cost of moving within the district (not counting moving along the edge itself). Returns an error if something went wrong.
+    pub fn first_time_in_district_cost(&self, neighbour_relationship: NeighbourRelationship) -> Result<MovementCost, String> {
+        self.overlay
+            .neighbourhood_cost
+            .get(&neighbour_relationship.neighbourhood)
+            .map_or_else(|| self.base.first_time_in_district_cost(neighbour_relationship), |neighbourhood_cost| Ok(*neighbourhood_cost))
+    }
+
+    /// Updates the district movement penalty of a district based on the situation card.
+    pub fn update_neighbourhood_cost(&mut self, situation_card: &SituationCard) {
+        for cost_tuple in &situation_card.costs {
+            self.overlay
+                .neighbourhood_cost
+                .insert(cost_tuple.neighbourhood, cost_tuple.traffic.get_movement_cost());
+        }
+    }
+
+    /// Returns `true` if both nodes of the edge and the district of the edge are accessible. Returns an error if one of the nodes does not exist.
+    pub fn is_edge_accessible(&self, from_node_id: NodeID, relationship: &NeighbourRelationship) -> Result<bool, String> {
+        self.base.is_edge_accessible(from_node_id, relationship)
+    }
+
+    /// Toggles the `is_connected_to_rail` bool of the node with the given ID.
+    pub fn toggle_rail_connection_on_node_with_id(&mut self, node_id: NodeID) -> Result<(), String> {
+        self.base.get_node_by_id(node_id)?;
+        self.overlay.toggle_rail_connection(node_id);
+        Ok(())
+    }
+
+    /// Returns `true` if there is an edge with the given id in the map.
+    pub fn has_edge(&self, edge_id: EdgeID) -> bool {
+        self.base
+            .edges
+            .values()
+            .any(|neighbours| neighbours.iter().any(|neighbour| neighbour.edge_id == edge_id))
+    }
+
+    /// Finds the id of the edge the given edge restriction is on. Returns an error if the edge can't be found or if the edge is ambiguous.
+    pub fn find_edge_id(&self, edge_restriction: &EdgeRestriction) -> Result<EdgeID, String> {
+        self.base.find_edge_id(edge_restriction.node_one, edge_restriction.node_two, edge_restriction.edge_id)
+    }
+
+    /// Adds the given edge restriction to the map and if the edge restriction is modifiable (removable), and returns an error if something went wrong.
+    pub fn set_restriction_on_edge(&mut self, edge_restriction: &EdgeRestriction, modifiable: bool) -> Result<(), String> {
+        let edge_id = self.find_edge_id(edge_restriction)?;
+        // A one way restriction only applies to the direction from node one to node two
+        let from_node_id = (edge_restriction.edge_restriction == RestrictionType::OneWay).then_some(edge_restriction.node_one);
+        self.overlay.set_edge_state(
+            edge_id,
+            RuntimeEdgeState {
+                restriction: Some(edge_restriction.edge_restriction),
+                is_modifiable: modifiable,
+                from_node_id,
+            },
+        );
+        Ok(())
+    }
+
+    /// Tries to remove the given edge restriction from the map and returns an error if something went wrong.
+    pub fn remove_restriction_from_edge(&mut self, edge_restriction: &EdgeRestriction) -> Result<(), String> {
+        let edge_id = self.find_edge_id(edge_restriction)?;
+        for from_node_id in [edge_restriction.node_one, edge_restriction.node_two] {
+            let is_modifiable = self
+                .get_neighbour_relationships_of_node_with_id(from_node_id)
+                .is_some_and(|neighbours| neighbours.iter().any(|neighbour| neighbour.edge_id == edge_id && neighbour.is_modifiable));
+            if !is_modifiable {
+                return Err(format!("The edge {} between node {} and node {} is not modifiable!", edge_id, edge_restriction.node_one, edge_restriction.node_two));
+            }
+        }
+        self.overlay.clear_edge_state(edge_id);
+        let has_base_restriction = self
+            .base
+            .get_neighbour_relationships_of_node_with_id(edge_restriction.node_one)
+            .is_some_and(|neighbours| neighbours.iter().any(|neighbour| neighbour.edge_id == edge_id && neighbour.restriction.is_some()));
+        if has_base_restriction {
+            self.overlay.set_edge_state(edge_id, RuntimeEdgeState { restriction: None, is_modifiable: true, from_node_id: None });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{
+    custom_types::{GameID, RoundNumber, StateVersion, Timestamp, TurnNumber},
+    enums::{game_phase::GamePhase, in_game_id::InGameID, state_section::StateSection},
+};
+
+use super::{gamestate::GameState, group_metrics::GroupMetrics};
+
+/// The GameOverview struct is a compact description of the state of a game, meant for a facilitator that oversees several games at once.
+///
+/// It's made from the stored game without applying the staged actions, so it's cheap to make for many games.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GameOverview {
+    pub game_id: GameID,
+    pub name: String,
+    pub phase: GamePhase,
+    pub turn_number: TurnNumber,
+    pub round_number: RoundNumber,
+    pub current_players_turn: InGameID,
+    /// The name of the player whose turn it is, if the role has a player.
+    pub current_player_name: Option<String>,
+    pub player_count: usize,
+    /// The amount of actions the player whose turn it is has staged.
+    pub staged_action_count: usize,
+    /// When the latest input was sent to the game, in milliseconds since the unix epoch. `None` if no input has been sent yet.
+    pub last_activity: Option<Timestamp>,
+    pub version: StateVersion,
+    /// The sections that have changed since the version the facilitator said it had, or all sections if no version was given.
+    pub changed_sections: Vec<StateSection>,
+    /// How the whole group is doing against the group targets in the current round. Left out if the game is not cooperative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_metrics: Option<GroupMetrics>,
+}
+
+impl GameOverview {
+    /// Creates an overview of the given game, with the sections that have changed since `known_version`.
+    #[must_use]
+    pub fn new(game: &GameState, known_version: Option<StateVersion>) -> Self {
+        Self {
+            game_id: game.id,
+            name: game.name.clone(),
+            phase: game.phase(),
+            turn_number: game.turn_number,
+            round_number: game.round_number,
+            current_players_turn: game.current_players_turn,
+            current_player_name: game
+                .players
+                .iter()
+                .find(|player| player.in_game_id == game.current_players_turn)
+                .map(|player| player.name.clone()),
+            player_count: game.players.len(),
+            staged_action_count: game.actions.len(),
+            last_activity: game.input_history.last().map(|record| record.timestamp),
+            version: game.version,
+            changed_sections: game.sections_changed_since(known_version),
+            group_metrics: game.group_metrics.last().cloned(),
+        }
+    }
+
+    /// Parses a comma separated list of game ids, where each id can be followed by `:` and the version of the game the facilitator has, like `12:40,13`. Will return an error if an id or version is not a number.
+    pub fn parse_game_list(list: &str) -> Result<Vec<(GameID, Option<StateVersion>)>, String> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (id, version) = match entry.split_once(':') {
+                    Some((id, version)) => (id, Some(version)),
+                    None => (entry, None),
+                };
+                let game_id = id.trim().parse::<GameID>().map_err(|_| format!("{} is not a game id!", id))?;
+                let known_version = match version {
+                    Some(version) => Some(version.trim().parse::<StateVersion>().map_err(|_| format!("{} is not a version of a game!", version))?),
+                    None => None,
+                };
+                Ok((game_id, known_version))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::StateVersion, enums::{district::District, state_section::StateSection}};
+
+use super::{checkpoint::ArchivedBranch, gamestate::GameState, game_map::GameMap, map_overlay::MapOverlay, node_map::NodeMap, input_record::InputRecord, player_input::PlayerInput, rule_violation::RuleViolationCount};
+
+/// The GameSnapshot struct contains everything needed to recreate a [`GameState`] exactly, including the values that are not sent to the clients.
+///
+/// The copies of the game kept to go back in it are not part of it, but of the [`GameHistory`] of the game.
+///
+/// [`GameHistory`]: ../game_history/struct.GameHistory.html
+/// [`GameState`]: ../gamestate/struct.GameState.html
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GameSnapshot {
+    pub game: GameState,
+    pub actions: Vec<PlayerInput>,
+    pub accessed_districts: Vec<District>,
+    /// The changes made to the map of the game. The base map is the map in the map library with the `map_name` of the game, see [`GameSnapshot::into_game_state_on`].
+    #[serde(default)]
+    pub map_overlay: MapOverlay,
+    pub rule_violations: Vec<RuleViolationCount>,
+    #[serde(default)]
+    pub input_history: Vec<InputRecord>,
+    pub section_versions: Vec<(StateSection, StateVersion)>,
+    #[serde(default)]
+    pub archived_branches: Vec<ArchivedBranch>,
+}
+
+impl GameSnapshot {
+    /// Creates a snapshot of the given game.
+    #[must_use]
+    pub fn new(game: &GameState) -> Self {
+        Self {
+            game: game.clone(),
+            actions: game.actions.clone(),
+            accessed_districts: game.accessed_districts.clone(),
+            map_overlay: game.map.overlay.clone(),
+            rule_violations: game.rule_violations.clone(),
+            input_history: game.input_history.clone(),
+            section_versions: game.section_versions.clone(),
+            archived_branches: game.archived_branches.clone(),
+        }
+    }
+
+    /// Recreates the game the snapshot was made from, on the default map.
+    #[must_use]
+    pub fn into_game_state(self) -> GameState {
+        self.into_game_state_on(NodeMap::shared_default())
+    }
+
+    /// Recreates the game the snapshot was made from, on the given base map. The base map should be the map the game was played on, since the snapshot only has the changes made to it.
+    #[must_use]
+    pub fn into_game_state_on(self, base_map: Arc<NodeMap>) -> GameState {
+        let mut game = self.game;
+        game.actions = self.actions;
+        game.accessed_districts = self.accessed_districts;
+        game.map = GameMap::new(base_map, self.map_overlay);
+        game.rule_violations = self.rule_violations;
+        game.input_history = self.input_history;
+        game.section_versions = self.section_versions;
+        game.archived_branches = self.archived_branches;
+        game
+    }
+}
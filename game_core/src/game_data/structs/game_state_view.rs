@@ -0,0 +1,88 @@
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::game_data::enums::state_section::StateSection;
+
+use super::gamestate::GameState;
+
+/// The GameStateView struct serializes a [`GameState`] with only the fields of the given sections.
+///
+/// A client that only renders some of the sections (like a projector that only shows the positions and the turn) does not have to receive the rest.
+/// The fields are left out while serializing, so the game is never copied. The id, version, update hints (limited to the given sections), events and session clock of the game are always included.
+///
+/// [`GameState`]: ../gamestate/struct.GameState.html
+pub struct GameStateView<'a> {
+    game: &'a GameState,
+    sections: Vec<StateSection>,
+}
+
+impl<'a> GameStateView<'a> {
+    #[must_use]
+    pub const fn new(game: &'a GameState, sections: Vec<StateSection>) -> Self {
+        Self { game, sections }
+    }
+
+    fn includes(&self, section: StateSection) -> bool {
+        self.sections.contains(&section)
+    }
+}
+
+impl Serialize for GameStateView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let game = self.game;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("id", &game.id)?;
+        if self.includes(StateSection::Settings) {
+            map.serialize_entry("name", &game.name)?;
+            map.serialize_entry("situation_card", &game.situation_card)?;
+            map.serialize_entry("lobby_settings", &game.lobby_settings)?;
+            map.serialize_entry("checkpoints", &game.checkpoints)?;
+            map.serialize_entry("fork_origin", &game.fork_origin)?;
+            map.serialize_entry("campaign", &game.campaign)?;
+            map.serialize_entry("score_weights", &game.score_weights)?;
+        }
+        if self.includes(StateSection::Players) {
+            map.serialize_entry("players", &game.players)?;
+            map.serialize_entry("legal_nodes", &game.legal_nodes)?;
+            map.serialize_entry("open_seats", &game.open_seats)?;
+            map.serialize_entry("last_movement", &game.last_movement)?;
+            map.serialize_entry("objective_reveals", &game.objective_reveals)?;
+            map.serialize_entry("player_connections", &game.player_connections)?;
+        }
+        if self.includes(StateSection::Turn) {
+            map.serialize_entry("is_lobby", &game.is_lobby)?;
+            map.serialize_entry("current_players_turn", &game.current_players_turn)?;
+            map.serialize_entry("turn_number", &game.turn_number)?;
+            map.serialize_entry("round_number", &game.round_number)?;
+            map.serialize_entry("timed_props", &game.timed_props)?;
+            map.serialize_entry("rule_warnings", &game.rule_warnings)?;
+            map.serialize_entry("status", &game.status)?;
+        }
+        if self.includes(StateSection::Modifiers) {
+            map.serialize_entry("district_modifiers", &game.district_modifiers)?;
+            map.serialize_entry("modifier_slots", &game.modifier_slots)?;
+            map.serialize_entry("congestion_zone", &game.congestion_zone)?;
+            map.serialize_entry("measure_ledger", &game.measure_ledger)?;
+            map.serialize_entry("dead_letters", &game.dead_letters)?;
+            map.serialize_entry("map_annotations", &game.map_annotations)?;
+        }
+        if self.includes(StateSection::Edges) {
+            map.serialize_entry("edge_restrictions", &game.edge_restrictions)?;
+            map.serialize_entry("ferry_crossings", &game.ferry_crossings)?;
+            map.serialize_entry("construction_works", &game.construction_works)?;
+        }
+        if self.includes(StateSection::Statistics) {
+            map.serialize_entry("statistics", &game.statistics)?;
+            map.serialize_entry("scoreboard", &game.scoreboard)?;
+            map.serialize_entry("group_metrics", &game.group_metrics)?;
+        }
+        if self.includes(StateSection::Chat) {
+            map.serialize_entry("chat", &game.chat)?;
+        }
+        map.serialize_entry("events", &game.events)?;
+        map.serialize_entry("session_clock", &game.session_clock)?;
+        map.serialize_entry("version", &game.version)?;
+        let update_hints: Vec<StateSection> = game.update_hints.iter().filter(|section| self.includes(**section)).copied().collect();
+        map.serialize_entry("update_hints", &update_hints)?;
+        map.end()
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::TurnNumber, enums::in_game_id::InGameID};
+
+/// The GameStatistics struct contains the statistics of a game since it was started. It's kept up to date by the StatisticsExtension.
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct GameStatistics {
+    /// The amount of moves all the players have made.
+    pub moves_made: u32,
+    /// The amount of measures the orchestrator has enacted, including the ones that have been removed later.
+    pub measures_enacted: u32,
+    /// The amount of turns that have ended.
+    pub turns_played: u32,
+    /// The turn the game ended in, if it has ended.
+    pub ended_in_turn: Option<TurnNumber>,
+    /// The amount of moves the player with each role has made. The roles are used instead of the player ids so that the statistics can be compared with forks of the game.
+    #[serde(default)]
+    pub moves_per_role: Vec<(InGameID, u32)>,
+    /// The turn the player with each role dropped off what the objective card asks for.
+    #[serde(default)]
+    pub objective_completion_turns: Vec<(InGameID, TurnNumber)>,
+    /// The turn the game was started in, which the commute times of the players are counted from.
+    #[serde(default)]
+    pub started_in_turn: TurnNumber,
+}
+
+impl GameStatistics {
+    /// Returns the amount of moves the player with the given role has made.
+    pub fn moves_of_role(&self, role: InGameID) -> u32 {
+        self.moves_per_role.iter().find(|(r, _)| *r == role).map_or(0, |(_, moves)| *moves)
+    }
+
+    /// Returns the turn the player with the given role dropped off what the objective card asks for, if the player has done it.
+    pub fn objective_completion_turn_of_role(&self, role: InGameID) -> Option<TurnNumber> {
+        self.objective_completion_turns.iter().find(|(r, _)| *r == role).map(|(_, turn_number)| *turn_number)
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{game_data::{custom_types::{GameID, SituationCardID, TurnNumber, Emissions, Money}, enums::game_status::GameStatus}, win_conditions::headline};
+
+use super::{checkpoint::ArchivedBranch, congestion_zone::CongestionZone, enacted_measure::EnactedMeasure, game_statistics::GameStatistics, leaderboard::Leaderboard, district_modifier::DistrictModifier, edge_restriction::EdgeRestriction, gamestate::GameState, map_annotation::MapAnnotation, objective_reveal::ObjectiveReveal, player::Player, rule_violation::RuleViolationCount, scoreboard::Scoreboard, group_metrics::GroupMetrics};
+
+/// The GameSummary struct describes the outcome of a game. It's meant to be exported after (or during) a game, so that facilitators and researchers can look at what happened in the game.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct GameSummary {
+    pub game_id: GameID,
+    pub name: String,
+    pub situation_card_id: Option<SituationCardID>,
+    pub turn_number: TurnNumber,
+    /// Whether the game is finished, and if so why and who won it.
+    #[serde(default)]
+    pub status: GameStatus,
+    /// A sentence that sums up how the game ended, which is only set when the game is finished.
+    #[serde(default)]
+    pub headline: Option<String>,
+    pub players: Vec<Player>,
+    pub district_modifiers: Vec<DistrictModifier>,
+    pub edge_restrictions: Vec<EdgeRestriction>,
+    /// Every measure the orchestrator has enacted in the game, including the ones that have been removed.
+    pub measure_ledger: Vec<EnactedMeasure>,
+    /// The notes the orchestrator has attached to the map, like why a measure was enacted.
+    #[serde(default)]
+    pub map_annotations: Vec<MapAnnotation>,
+    /// How many times each rule has rejected the inputs of each player.
+    pub rule_violations: Vec<RuleViolationCount>,
+    /// The sum of the emissions of all the players. Each player's share is found in the player's `emissions`.
+    pub total_emissions: Emissions,
+    /// The congestion zone at the time of the summary, if there is one.
+    pub congestion_zone: Option<CongestionZone>,
+    /// The sum of the congestion charges paid by all the players. Each player's part is found in the player's `congestion_charges_paid`.
+    pub total_congestion_charges: Money,
+    pub statistics: GameStatistics,
+    /// How effective the policy of the orchestrator was, turn by turn.
+    #[serde(default)]
+    pub scoreboard: Scoreboard,
+    /// How the whole group did against the group targets in each round, if the game is cooperative.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_metrics: Vec<GroupMetrics>,
+    /// What happened in the game before it was restored to a checkpoint, oldest first.
+    pub archived_branches: Vec<ArchivedBranch>,
+    /// The objective cards of all the players and how far they got. Empty unless the game is over.
+    pub objective_reveals: Vec<ObjectiveReveal>,
+    /// The players ranked by their cumulative score across the rounds of the session, including this game.
+    pub leaderboard: Leaderboard,
+}
+
+impl GameSummary {
+    /// Creates a summary of the given game.
+    #[must_use]
+    pub fn new(game: &GameState) -> Self {
+        Self {
+            game_id: game.id,
+            name: game.name.clone(),
+            situation_card_id: game.situation_card.as_ref().map(|card| card.card_id),
+            turn_number: game.turn_number,
+            status: game.status.clone(),
+            headline: headline(game),
+            players: game.players.clone(),
+            district_modifiers: game.district_modifiers.clone(),
+            edge_restrictions: game.edge_restrictions.clone(),
+            measure_ledger: game.measure_ledger.clone(),
+            map_annotations: game.map_annotations.clone(),
+            rule_violations: game.rule_violations.clone(),
+            total_emissions: game.players.iter().map(|player| player.emissions).sum(),
+            congestion_zone: game.congestion_zone.clone(),
+            total_congestion_charges: game.players.iter().map(|player| player.congestion_charges_paid).sum(),
+            statistics: game.statistics.clone(),
+            scoreboard: game.scoreboard.clone(),
+            group_metrics: game.group_metrics.clone(),
+            archived_branches: game.archived_branches.clone(),
+            objective_reveals: game.objective_reveals.clone(),
+            leaderboard: Leaderboard::new(game),
+        }
+    }
+}
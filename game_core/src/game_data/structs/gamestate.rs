@@ -1,11 +1,27 @@
-use std::{cmp, mem};
+use std::cmp;
 
-use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::{game_data::{custom_types::{GameID, NodeID, PlayerID, MovementCost, MovementValue}, enums::{in_game_id::InGameID, district::District, restriction_type::RestrictionType, district_modifier_type::DistrictModifierType, traffic::Traffic}, constants::{MAX_PLAYER_COUNT, START_MOVEMENT_AMOUNT, MAX_ACCESS_MODIFIER_COUNT, MAX_PRIORITY_MODIFIER_COUNT, MAX_TOLL_MODIFIER_COUNT}}, situation_card_list::situation_card_list};
+use crate::{game_data::{custom_types::{GameID, NodeID, EdgeID, PlayerID, MovementCost, MovementValue, TurnNumber, StateVersion, StateHash, MeasureID, MeasureTemplateID, EventID, Money, RoundNumber, Timestamp, Milliseconds, DeadLetterID, RuleWarningID, ChatMessageID}, enums::{in_game_id::InGameID, player_input_type::PlayerInputType, state_section::StateSection, district::District, restriction_type::RestrictionType, district_modifier_type::DistrictModifierType, traffic::Traffic, modifier_persistence::ModifierPersistence, cargo_state::CargoState, move_refill::MoveRefill, timed_prop_kind::TimedPropKind, construction_status::ConstructionStatus, game_phase::GamePhase, game_status::GameStatus, annotation_target::AnnotationTarget}, constants::{START_PLAYER_BUDGET, MAX_PLAYER_COUNT, MAX_STORED_EVENTS, MAX_STORED_INPUT_RECORDS, MAX_DEAD_LETTERS, MAX_STORED_CHAT_MESSAGES, MAX_CHECKPOINTS, START_MOVEMENT_AMOUNT}}, situation_card_list::situation_card_list, measure_template_list::get_measure_template_by_id, pathfinding::main_district_of_node, objective_deal::deal_objective_cards, content_hash::fnv1a_hash};
 
-use super::{player::Player, player_input::PlayerInput, situation_card::SituationCard, edge_restriction::EdgeRestriction, node_map::NodeMap, neighbour_relationship::NeighbourRelationship, district_modifier::DistrictModifier};
+use super::{player::Player, player_input::PlayerInput, player_objective_card::PlayerObjectiveCard, situation_card::SituationCard, edge_restriction::EdgeRestriction, game_map::GameMap, neighbour_relationship::NeighbourRelationship, district_modifier::DistrictModifier, lobby_settings::LobbySettings, rule_violation::RuleViolationCount, enacted_measure::{EnactedMeasure, Measure}, game_event::{GameEvent, GameEventKind}, congestion_zone::CongestionZone, input_record::{InputOutcome, InputRecord}, game_statistics::GameStatistics, session_clock::{SessionClock, current_timestamp}, checkpoint::{Checkpoint, ArchivedBranch}, game_snapshot::GameSnapshot, game_summary::GameSummary, fork_origin::ForkOrigin, map_library::DEFAULT_MAP_NAME, objective_deck::ObjectiveDeck, movement_outcome::MovementOutcome, move_plan::{MovePlan, PlannedMove}, applied_actions::AppliedActions, objective_reveal::ObjectiveReveal, player_connection::PlayerConnection, campaign::Campaign, score_weights::ScoreWeights, scoreboard::Scoreboard, group_metrics::GroupMetrics, timed_prop::TimedPropState, ferry_schedule::FerryCrossing, construction_work::{ConstructionWork, ScheduledConstruction}, achievement_progress::AchievementProgress, dead_letter::DeadLetter, rule_warning::RuleWarning, chat_message::ChatMessage, map_annotation::MapAnnotation, rule_violation::RuleViolation, modifier_slots::ModifierSlots, scenario_template::ScenarioTemplate, situation_card_list::SituationCardList};
+
+/// The parts of a [`GameState`] the state hash is made from, in the order they are serialized. The bookkeeping of the server (like the events, the versions and the legal nodes) is left out, since the clients do not simulate it.
+#[derive(Serialize)]
+struct HashedGameState<'a> {
+    id: GameID,
+    name: &'a str,
+    players: &'a [Player],
+    is_lobby: bool,
+    current_players_turn: InGameID,
+    turn_number: TurnNumber,
+    round_number: RoundNumber,
+    district_modifiers: &'a [DistrictModifier],
+    edge_restrictions: &'a [EdgeRestriction],
+    congestion_zone: &'a Option<CongestionZone>,
+    situation_card: &'a Option<SituationCard>,
+    lobby_settings: &'a LobbySettings,
+}
 
 /// The GameState struct describes the state of the game.
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -16,15 +32,144 @@ pub struct GameState {
     pub is_lobby: bool,
     pub current_players_turn: InGameID,
     pub district_modifiers: Vec<DistrictModifier>,
+    /// How many more modifiers of each type every district can get. Refreshed whenever the Modifiers section changes.
+    #[serde(default)]
+    pub modifier_slots: Vec<ModifierSlots>,
     #[serde(skip)]
     pub actions: Vec<PlayerInput>,
+    /// The game with the staged actions applied, kept so that a new action does not make the server apply every staged action again.
+    #[serde(skip)]
+    pub applied_actions: AppliedActions,
     #[serde(skip)]
     pub accessed_districts: Vec<District>,
     #[serde(skip)]
-    pub map: NodeMap,
+    pub map: GameMap,
+    /// The name of the map in the map library the game is played on.
+    #[serde(default = "default_map_name")]
+    pub map_name: String,
     pub situation_card: Option<SituationCard>,
+    /// The objective deck made by a facilitator the objective cards are dealt from, if the lobby chose one. The objective cards of the situation card are used otherwise.
+    #[serde(default)]
+    pub objective_deck: Option<ObjectiveDeck>,
+    /// The deal constraints in the lobby settings that the objective cards could not be dealt to meet when the game was started.
+    #[serde(default)]
+    pub objective_deal_warnings: Vec<String>,
     pub edge_restrictions: Vec<EdgeRestriction>,
+    /// The measures the orchestrator has sent during the turn of another player when `async_measures` is set in the lobby settings. They are applied when the turn ends.
+    #[serde(default)]
+    pub pending_measures: Vec<PlayerInput>,
+    /// The moves the players plan to make in their next turn. Only shown to the player that made the plan, see [`MovePlan`](../move_plan/struct.MovePlan.html).
+    #[serde(skip)]
+    pub move_plans: Vec<MovePlan>,
     pub legal_nodes: Vec<NodeID>,
+    /// The amount of turns that have passed since the game was created.
+    pub turn_number: TurnNumber,
+    /// The amount of rounds that have been played, where a round is over when it's the orchestrator's turn again.
+    #[serde(default)]
+    pub round_number: RoundNumber,
+    /// The congestion charging zone the orchestrator has set up. It's kept until the orchestrator removes it.
+    #[serde(default)]
+    pub congestion_zone: Option<CongestionZone>,
+    pub lobby_settings: LobbySettings,
+    /// Every measure the orchestrator has enacted in the game, including the ones that have been removed.
+    pub measure_ledger: Vec<EnactedMeasure>,
+    /// The latest events in the game, at most [`MAX_STORED_EVENTS`](../../constants/constant.MAX_STORED_EVENTS.html) of them.
+    pub events: Vec<GameEvent>,
+    #[serde(skip)]
+    pub rule_violations: Vec<RuleViolationCount>,
+    /// The latest inputs the players have sent to the game and their outcomes, at most [`MAX_STORED_INPUT_RECORDS`](../../constants/constant.MAX_STORED_INPUT_RECORDS.html) of them.
+    #[serde(skip)]
+    pub input_history: Vec<InputRecord>,
+    /// Increased every time one of the sections of the game changes.
+    pub version: StateVersion,
+    /// The statistics of the game since it was started.
+    #[serde(default)]
+    pub statistics: GameStatistics,
+    /// How long the game has been played and how much is left of the planned session.
+    #[serde(default)]
+    pub session_clock: SessionClock,
+    /// When each of the timed props in the lobby settings ticks next. Set when the game is started.
+    #[serde(default)]
+    pub timed_props: Vec<TimedPropState>,
+    /// The ferries the players have taken in the current round, so that the ferries are not filled over their capacity.
+    #[serde(default)]
+    pub ferry_crossings: Vec<FerryCrossing>,
+    /// The construction works the orchestrator has scheduled, including the ones that are done.
+    #[serde(default)]
+    pub construction_works: Vec<ScheduledConstruction>,
+    /// What the AchievementExtension has counted so far to hand out the achievements that are not earned with a single move.
+    #[serde(default)]
+    pub achievement_progress: AchievementProgress,
+    /// The notes the orchestrator has attached to the districts and edges of the map, at most one for each.
+    #[serde(default)]
+    pub map_annotations: Vec<MapAnnotation>,
+    /// The inputs that passed the rules but could not be applied, which the orchestrator can retry or discard.
+    #[serde(default)]
+    pub dead_letters: Vec<DeadLetter>,
+    /// The inputs of the current turn that only broke lenient rules, which the orchestrator can accept as house rules. See [`RuleProfile`].
+    ///
+    /// [`RuleProfile`]: ../../enums/rule_profile/enum.RuleProfile.html
+    #[serde(default)]
+    pub rule_warnings: Vec<RuleWarning>,
+    /// The latest messages in the chat of the game, oldest first and at most [`MAX_STORED_CHAT_MESSAGES`](../../constants/constant.MAX_STORED_CHAT_MESSAGES.html) of them.
+    #[serde(default)]
+    pub chat: Vec<ChatMessage>,
+    /// When the latest input was sent to the game (or the game was created), in milliseconds since the unix epoch.
+    #[serde(default = "current_timestamp")]
+    pub last_activity: Timestamp,
+    /// If true the players have been warned that the game will be removed for being inactive, see [`GameEventKind::GameExpiring`].
+    ///
+    /// [`GameEventKind::GameExpiring`]: ../game_event/enum.GameEventKind.html
+    #[serde(default)]
+    pub expiry_warning_sent: bool,
+    /// The checkpoints the orchestrator has created, oldest first.
+    #[serde(default)]
+    pub checkpoints: Vec<Checkpoint>,
+    /// What happened in the game before it was restored to a checkpoint, oldest first.
+    #[serde(skip)]
+    pub archived_branches: Vec<ArchivedBranch>,
+    /// The roles whose player left the game after it was started, or that were not claimed when the fork was started. Their turns are skipped with a [`GameEventKind::TurnSkipped`] event until a player takes the role.
+    ///
+    /// [`GameEventKind::TurnSkipped`]: ../game_event/enum.GameEventKind.html
+    #[serde(default)]
+    pub vacated_roles: Vec<InGameID>,
+    /// What happened in the latest move made in the game.
+    #[serde(default)]
+    pub last_movement: Option<MovementOutcome>,
+    /// The game and turn this game was forked from, if it's a fork.
+    #[serde(default)]
+    pub fork_origin: Option<ForkOrigin>,
+    /// The players of the game this game was forked from that no player has claimed yet. Only used before a forked game is started.
+    #[serde(default)]
+    pub open_seats: Vec<Player>,
+    /// The version each section was last changed in.
+    #[serde(skip)]
+    pub section_versions: Vec<(StateSection, StateVersion)>,
+    /// The sections that have changed since the version the client said it had. Only set on the game states sent to the clients.
+    #[serde(default)]
+    pub update_hints: Vec<StateSection>,
+    /// The objective cards of all the players and how far they got, revealed when the game ends. Empty until the game is over.
+    #[serde(default)]
+    pub objective_reveals: Vec<ObjectiveReveal>,
+    /// How well the client of each player keeps in touch with the server, so that the facilitators know whether to wait for a player or skip them. Only set on the game states sent to the clients.
+    #[serde(default)]
+    pub player_connections: Vec<PlayerConnection>,
+    /// The campaign the game is part of, if the players play several scenarios in a row.
+    #[serde(default)]
+    pub campaign: Option<Campaign>,
+    /// How much the objective points, the emissions and the budget count on the leaderboard of the session.
+    #[serde(default)]
+    pub score_weights: ScoreWeights,
+    /// Whether the game is still being played, and who won it if it's finished. Updated after every input that is handled, see [`win_conditions`](../../../win_conditions/index.html).
+    /// Left out while the game is ongoing, so that the snapshots of the ongoing games are the same as before the status existed.
+    #[serde(default, skip_serializing_if = "GameStatus::is_ongoing")]
+    pub status: GameStatus,
+    /// How effective the policy of the orchestrator has been, turn by turn. Kept up to date by the ScoreboardExtension.
+    #[serde(default)]
+    pub scoreboard: Scoreboard,
+    /// How the whole group did against the group targets of a cooperative game in each round, where the last one is the current round. Kept up to date by the GroupMetricsExtension, and left out for the games that are not cooperative.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_metrics: Vec<GroupMetrics>,
 }
 
 impl GameState {
@@ -37,14 +182,745 @@ impl GameState {
             players: Vec::new(),
             is_lobby: true,
             actions: Vec::new(),
+            applied_actions: AppliedActions::default(),
             current_players_turn: InGameID::Orchestrator,
             district_modifiers: Vec::new(),
+            modifier_slots: ModifierSlots::count(&[]),
             accessed_districts: Vec::new(),
-            map: NodeMap::new_default(),
+            map: GameMap::default(),
+            map_name: DEFAULT_MAP_NAME.to_string(),
             situation_card: None,
+            objective_deck: None,
+            objective_deal_warnings: Vec::new(),
             edge_restrictions: Vec::new(),
+            pending_measures: Vec::new(),
+            move_plans: Vec::new(),
             legal_nodes: Vec::new(),
+            turn_number: 0,
+            round_number: 0,
+            congestion_zone: None,
+            lobby_settings: LobbySettings::default(),
+            measure_ledger: Vec::new(),
+            events: Vec::new(),
+            rule_violations: Vec::new(),
+            input_history: Vec::new(),
+            statistics: GameStatistics::default(),
+            session_clock: SessionClock::default(),
+            timed_props: Vec::new(),
+            ferry_crossings: Vec::new(),
+            construction_works: Vec::new(),
+            achievement_progress: AchievementProgress::default(),
+            map_annotations: Vec::new(),
+            dead_letters: Vec::new(),
+            rule_warnings: Vec::new(),
+            chat: Vec::new(),
+            last_activity: current_timestamp(),
+            expiry_warning_sent: false,
+            checkpoints: Vec::new(),
+            archived_branches: Vec::new(),
+            last_movement: None,
+            vacated_roles: Vec::new(),
+            fork_origin: None,
+            open_seats: Vec::new(),
+            version: 0,
+            section_versions: StateSection::ALL.iter().map(|section| (*section, 0)).collect(),
+            update_hints: Vec::new(),
+            objective_reveals: Vec::new(),
+            player_connections: Vec::new(),
+            campaign: None,
+            score_weights: ScoreWeights::default(),
+            status: GameStatus::Ongoing,
+            scoreboard: Scoreboard::default(),
+            group_metrics: Vec::new(),
+        }
+    }
+
+    /// Returns the sections that are different in the two game states.
+    pub fn changed_sections(before: &Self, after: &Self) -> Vec<StateSection> {
+        let mut changed_sections = Vec::new();
+        if before.players != after.players || before.open_seats != after.open_seats || before.objective_reveals != after.objective_reveals {
+            changed_sections.push(StateSection::Players);
+        }
+        if before.district_modifiers != after.district_modifiers
+            || before.congestion_zone != after.congestion_zone
+            || before.measure_ledger != after.measure_ledger
+            || before.pending_measures.len() != after.pending_measures.len()
+            || before.dead_letters.len() != after.dead_letters.len()
+            || before.map_annotations != after.map_annotations
+        {
+            changed_sections.push(StateSection::Modifiers);
+        }
+        if before.edge_restrictions != after.edge_restrictions || before.ferry_crossings != after.ferry_crossings || before.construction_works != after.construction_works {
+            changed_sections.push(StateSection::Edges);
+        }
+        if before.current_players_turn != after.current_players_turn
+            || before.turn_number != after.turn_number
+            || before.round_number != after.round_number
+            || before.is_lobby != after.is_lobby
+            || before.timed_props != after.timed_props
+            || before.rule_warnings.len() != after.rule_warnings.len()
+            || before.status != after.status
+        {
+            changed_sections.push(StateSection::Turn);
+        }
+        if before.name != after.name
+            || before.lobby_settings != after.lobby_settings
+            || before.situation_card != after.situation_card
+            || before.checkpoints != after.checkpoints
+            || before.fork_origin != after.fork_origin
+        {
+            changed_sections.push(StateSection::Settings);
+        }
+        if before.statistics != after.statistics || before.scoreboard != after.scoreboard || before.group_metrics != after.group_metrics {
+            changed_sections.push(StateSection::Statistics);
+        }
+        if before.chat != after.chat {
+            changed_sections.push(StateSection::Chat);
+        }
+        changed_sections
+    }
+
+    /// Records the events that happened between the two game states, see [`GameEventKind`].
+    ///
+    /// [`GameEventKind`]: ../game_event/enum.GameEventKind.html
+    pub fn record_events_between(&mut self, before: &Self, after: &Self) {
+        after
+            .players
+            .iter()
+            .filter(|player| !before.contains_player_with_unique_id(player.unique_id))
+            .for_each(|player| self.record_event(GameEventKind::PlayerJoined(player.unique_id)));
+        before
+            .players
+            .iter()
+            .filter(|player| !after.contains_player_with_unique_id(player.unique_id))
+            .for_each(|player| self.record_event(GameEventKind::PlayerLeft(player.unique_id)));
+        if before.is_lobby && !after.is_lobby {
+            self.record_event(GameEventKind::GameStarted);
+            if !after.objective_deal_warnings.is_empty() {
+                self.record_event(GameEventKind::DealConstraintsUnmet(after.objective_deal_warnings.clone()));
+            }
+        }
+        if before.current_players_turn != after.current_players_turn || before.turn_number != after.turn_number {
+            self.record_event(GameEventKind::TurnChanged(after.current_players_turn));
+        }
+        for measure in after.measure_ledger.iter() {
+            let measure_before = before.measure_ledger.iter().find(|m| m.id == measure.id);
+            if measure_before.is_none() && measure.is_active() {
+                self.record_event(GameEventKind::MeasureEnacted(measure.id));
+            }
+            if measure_before.is_none_or(EnactedMeasure::is_active) && !measure.is_active() {
+                self.record_event(GameEventKind::MeasureRemoved(measure.id));
+            }
+        }
+        before
+            .measure_ledger
+            .iter()
+            .filter(|measure| measure.is_active() && after.measure_ledger.iter().all(|m| m.id != measure.id))
+            .for_each(|measure| self.record_event(GameEventKind::MeasureRemoved(measure.id)));
+        after
+            .actions
+            .iter()
+            .filter(|action| action.input_type == PlayerInputType::Pass && !before.has_passed_this_turn(action.player_id))
+            .for_each(|action| self.record_event(GameEventKind::PlayerPassed(action.player_id)));
+        let slots_before = ModifierSlots::count(&before.district_modifiers);
+        ModifierSlots::count(&after.district_modifiers)
+            .into_iter()
+            .filter(|slots| slots.is_full() && slots_before.iter().any(|before| before.district == slots.district && before.modifier == slots.modifier && !before.is_full()))
+            .for_each(|slots| self.record_event(GameEventKind::ModifierCapReached(slots.district, slots.modifier)));
+        if !before.is_game_over() && after.is_game_over() {
+            self.record_event(GameEventKind::GameEnded);
+        }
+    }
+
+    /// Adds an event to the game. Only the latest [`MAX_STORED_EVENTS`](../../constants/constant.MAX_STORED_EVENTS.html) events are kept.
+    pub fn record_event(&mut self, kind: GameEventKind) {
+        let id: EventID = self.events.last().map_or(1, |event| event.id + 1);
+        self.events.push(GameEvent::new(id, self.turn_number, kind));
+        if self.events.len() > MAX_STORED_EVENTS {
+            let amount_to_remove = self.events.len() - MAX_STORED_EVENTS;
+            self.events.drain(..amount_to_remove);
+        }
+    }
+
+    /// Adds an input that was received in the given turn and its outcome to the input history of the game. Only the latest [`MAX_STORED_INPUT_RECORDS`](../../constants/constant.MAX_STORED_INPUT_RECORDS.html) inputs are kept.
+    pub fn record_input(&mut self, turn_number: TurnNumber, input: PlayerInput, outcome: InputOutcome) {
+        self.input_history.push(InputRecord::new(turn_number, input, outcome));
+        self.last_activity = current_timestamp();
+        self.expiry_warning_sent = false;
+        if self.input_history.len() > MAX_STORED_INPUT_RECORDS {
+            let amount_to_remove = self.input_history.len() - MAX_STORED_INPUT_RECORDS;
+            self.input_history.drain(..amount_to_remove);
+        }
+    }
+
+    /// Keeps the input that could not be applied because of the given error in the dead letters, if inputs of its type can be retried. The oldest dead letters are forgotten when there are more than [`MAX_DEAD_LETTERS`].
+    ///
+    /// [`MAX_DEAD_LETTERS`]: ../../constants/constant.MAX_DEAD_LETTERS.html
+    pub fn add_dead_letter(&mut self, input: PlayerInput, error: String) {
+        if !input.input_type.can_be_retried() {
+            return;
+        }
+        let id = self.dead_letters.iter().map(|dead_letter| dead_letter.id).max().map_or(1, |id| id + 1);
+        self.dead_letters.push(DeadLetter::new(id, input, error, self.turn_number, self.version));
+        if self.dead_letters.len() > MAX_DEAD_LETTERS {
+            let amount_to_remove = self.dead_letters.len() - MAX_DEAD_LETTERS;
+            self.dead_letters.drain(..amount_to_remove);
+        }
+    }
+
+    /// Adds the message to the chat of the game as sent by the player with the given id in the current turn, and returns its id. The oldest messages are forgotten when there are more than [`MAX_STORED_CHAT_MESSAGES`].
+    /// Will return an error if the player is not in the game.
+    ///
+    /// [`MAX_STORED_CHAT_MESSAGES`]: ../../constants/constant.MAX_STORED_CHAT_MESSAGES.html
+    pub fn add_chat_message(&mut self, sender: PlayerID, text: String) -> Result<ChatMessageID, String> {
+        let player = self.get_player_with_unique_id(sender)?;
+        let (sender_name, sender_role) = (player.name.clone(), player.in_game_id);
+        let id = self.chat.last().map_or(1, |message| message.id + 1);
+        self.chat.push(ChatMessage { id, sender, sender_name, sender_role, text, turn_number: self.turn_number, sent_at: current_timestamp() });
+        if self.chat.len() > MAX_STORED_CHAT_MESSAGES {
+            let amount_to_remove = self.chat.len() - MAX_STORED_CHAT_MESSAGES;
+            self.chat.drain(..amount_to_remove);
+        }
+        Ok(id)
+    }
+
+    /// Returns the messages in the chat of the game that were sent after the message with the given id, or all of them if no id is given.
+    pub fn chat_messages_since(&self, since_id: Option<ChatMessageID>) -> &[ChatMessage] {
+        let start = since_id.map_or(0, |since_id| self.chat.partition_point(|message| message.id <= since_id));
+        &self.chat[start..]
+    }
+
+    /// Returns the dead letter with the given id. Will return an error if there is no such dead letter.
+    pub fn get_dead_letter(&self, dead_letter_id: DeadLetterID) -> Result<&DeadLetter, String> {
+        self.dead_letters
+            .iter()
+            .find(|dead_letter| dead_letter.id == dead_letter_id)
+            .ok_or_else(|| format!("There is no dead letter with id {dead_letter_id} in the game!"))
+    }
+
+    /// Removes the dead letter with the given id and returns it. Will return an error if there is no such dead letter.
+    pub fn remove_dead_letter(&mut self, dead_letter_id: DeadLetterID) -> Result<DeadLetter, String> {
+        let Some(position) = self.dead_letters.iter().position(|dead_letter| dead_letter.id == dead_letter_id) else {
+            return Err(format!("There is no dead letter with id {dead_letter_id} in the game!"));
+        };
+        Ok(self.dead_letters.remove(position))
+    }
+
+    /// Keeps the input that only broke the given lenient rules as a rule warning the orchestrator can accept, and records a [`GameEventKind::RuleWarningRaised`] event. Returns the id of the warning.
+    ///
+    /// [`GameEventKind::RuleWarningRaised`]: ../game_event/enum.GameEventKind.html
+    pub fn add_rule_warning(&mut self, input: PlayerInput, violations: Vec<RuleViolation>) -> RuleWarningID {
+        let id = self.rule_warnings.iter().map(|warning| warning.id).max().map_or(1, |id| id + 1);
+        self.rule_warnings.push(RuleWarning { id, input, violations, turn_number: self.turn_number });
+        self.record_event(GameEventKind::RuleWarningRaised(id));
+        id
+    }
+
+    /// Returns the rule warning with the given id. Will return an error if there is no such warning.
+    pub fn get_rule_warning(&self, rule_warning_id: RuleWarningID) -> Result<&RuleWarning, String> {
+        self.rule_warnings
+            .iter()
+            .find(|warning| warning.id == rule_warning_id)
+            .ok_or_else(|| format!("There is no rule warning with id {rule_warning_id} in the current turn of the game!"))
+    }
+
+    /// Removes the rule warning with the given id and returns it. Will return an error if there is no such warning.
+    pub fn remove_rule_warning(&mut self, rule_warning_id: RuleWarningID) -> Result<RuleWarning, String> {
+        let Some(position) = self.rule_warnings.iter().position(|warning| warning.id == rule_warning_id) else {
+            return Err(format!("There is no rule warning with id {rule_warning_id} in the current turn of the game!"));
+        };
+        Ok(self.rule_warnings.remove(position))
+    }
+
+    /// Marks the latest accepted or overridden input that was added to the staged actions as undone.
+    pub fn mark_last_staged_input_undone(&mut self) {
+        if let Some(record) = self
+            .input_history
+            .iter_mut()
+            .rev()
+            .find(|record| matches!(record.outcome, InputOutcome::Accepted | InputOutcome::Overridden(_)) && record.input.input_type.is_staged())
+        {
+            record.outcome = InputOutcome::Undone;
+        }
+    }
+
+    /// Returns the inputs the player with the given id has sent to the game in or after the given turn, oldest first.
+    pub fn player_inputs(&self, player_id: PlayerID, since_turn: TurnNumber) -> Vec<InputRecord> {
+        self.input_history
+            .iter()
+            .filter(|record| record.input.player_id == player_id && record.turn_number >= since_turn)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the phase the game is in.
+    pub fn phase(&self) -> GamePhase {
+        if self.is_lobby {
+            GamePhase::Lobby
+        } else if self.is_game_over() {
+            GamePhase::GameOver
+        } else {
+            GamePhase::Playing
+        }
+    }
+
+    /// Returns `true` if the game is finished, or if every player (except the orchestrator) has dropped off what their objective card asks for, which finishes the game when the turn ends.
+    pub fn is_game_over(&self) -> bool {
+        self.status.is_finished() || self.have_all_players_completed_their_objectives()
+    }
+
+    /// Returns `true` if every player (except the orchestrator) has dropped off what their objective card asks for.
+    pub fn have_all_players_completed_their_objectives(&self) -> bool {
+        let mut players = self
+            .players
+            .iter()
+            .filter(|player| player.in_game_id != InGameID::Orchestrator)
+            .peekable();
+        players.peek().is_some()
+            && players.all(|player| {
+                player
+                    .objective_card
+                    .as_ref()
+                    .is_some_and(|objective_card| objective_card.dropped_package_off)
+            })
+    }
+
+    /// Pauses the session clock when the orchestrator corrects the game (see [`PlayerInputType::is_corrective`]), and resumes it when an input of another type is accepted.
+    /// The timed props don't tick while the clock is paused, so their next ticks are pushed back by the length of the pause when it's resumed.
+    pub fn pause_or_resume_session_clock(&mut self, input_type: &PlayerInputType, now: Timestamp) {
+        if input_type.is_corrective() {
+            self.session_clock.pause(now);
+            return;
+        }
+        let pause_length = self.session_clock.resume(now);
+        for prop_state in self.timed_props.iter_mut() {
+            prop_state.next_tick_at = prop_state.next_tick_at.map(|next_tick_at| next_tick_at.saturating_add(pause_length));
+        }
+    }
+
+    /// Records a reminder event for every session reminder that is due at the given time and has not been sent yet. Returns `true` if any reminders were recorded.
+    pub fn record_due_session_reminders(&mut self, now: Timestamp) -> bool {
+        let due_reminders = self.session_clock.take_due_reminders(now, self.lobby_settings.session_reminders());
+        let Some(session_length) = self.session_clock.session_length else {
+            return false;
+        };
+        for reminder in due_reminders.iter() {
+            let minutes_left = reminder.time_left_when_due(session_length).div_ceil(60_000);
+            self.record_event(GameEventKind::SessionReminder(u32::try_from(minutes_left).unwrap_or(u32::MAX)));
+        }
+        !due_reminders.is_empty()
+    }
+
+    /// Starts ticking the timed props in the lobby settings, where each prop first ticks one interval after the given time.
+    pub fn start_timed_props(&mut self, now: Timestamp) {
+        self.timed_props = self.lobby_settings.timed_props.iter().map(|prop| TimedPropState::started(prop, now)).collect();
+    }
+
+    /// Returns the names of the timed props that are due to tick at the given time. The props do not tick while the game is in the lobby or the session clock is paused.
+    pub fn due_timed_props(&self, now: Timestamp) -> Vec<String> {
+        if self.is_lobby || self.session_clock.is_paused() {
+            return Vec::new();
+        }
+        self.timed_props
+            .iter()
+            .filter(|prop| prop.next_tick_at.is_some_and(|next_tick_at| next_tick_at <= now))
+            .map(|prop| prop.name.clone())
+            .collect()
+    }
+
+    /// Ticks the timed prop with the given name, on behalf of the player with the given id (the orchestrator), and schedules the next tick.
+    /// The next tick is scheduled even if what the prop does fails, so that a prop that can't be applied doesn't tick again right away. Will return an error if there is no such prop or what it does failed.
+    pub fn tick_timed_prop(&mut self, player_id: PlayerID, name: &str) -> Result<(), String> {
+        let Some(prop) = self.lobby_settings.timed_props.iter().find(|prop| prop.name == name).cloned() else {
+            return Err(format!("There is no timed prop called {name} in the game!"));
+        };
+        let Some(prop_state) = self.timed_props.iter_mut().find(|prop_state| prop_state.name == name) else {
+            return Err(format!("The timed prop called {name} has not been started!"));
+        };
+        let Some(tick_at) = prop_state.next_tick_at else {
+            return Err(format!("The timed prop called {name} has stopped ticking!"));
+        };
+        prop_state.ticks += 1;
+        prop_state.next_tick_at = Some(tick_at.saturating_add(prop.interval()));
+        let ticks = prop_state.ticks;
+        self.record_event(GameEventKind::TimedPropTicked(name.to_string()));
+
+        match prop.kind {
+            TimedPropKind::ToggleEdgeRestriction(mut edge_restriction) => {
+                edge_restriction.edge_id = Some(self.map.find_edge_id(&edge_restriction)?);
+                let is_restricted = self
+                    .edge_restrictions
+                    .iter()
+                    .any(|restriction| restriction.is_on_same_edge(&edge_restriction) && restriction.edge_restriction == edge_restriction.edge_restriction);
+                if is_restricted {
+                    self.remove_restriction_from_edge(&edge_restriction)
+                } else {
+                    self.add_edge_restriction(&edge_restriction, true)
+                }
+            }
+            TimedPropKind::Countdown { ticks: countdown_ticks, measure_template_id } => {
+                if ticks < countdown_ticks {
+                    return Ok(());
+                }
+                if let Some(prop_state) = self.timed_props.iter_mut().find(|prop_state| prop_state.name == name) {
+                    prop_state.next_tick_at = None;
+                }
+                measure_template_id.map_or(Ok(()), |template_id| self.enact_measure_template(player_id, template_id))
+            }
+        }
+    }
+
+    /// Warns the players that the game will be removed for being inactive in the given amount of milliseconds. The players are only warned once until someone sends an input.
+    /// Returns `true` if the warning was recorded.
+    pub fn record_expiry_warning(&mut self, time_left: Milliseconds) -> bool {
+        if self.expiry_warning_sent {
+            return false;
+        }
+        self.expiry_warning_sent = true;
+        self.record_event(GameEventKind::GameExpiring(u32::try_from(time_left.div_ceil(60_000)).unwrap_or(u32::MAX)));
+        true
+    }
+
+    /// Attaches the note to the district or edge, replacing the note that was there. The note is removed if the text is `None` or empty.
+    /// Will return an error if the edge is not on the map of the game, or there is no note to remove.
+    pub fn annotate_map(&mut self, target: AnnotationTarget, text: Option<String>) -> Result<(), String> {
+        if let AnnotationTarget::Edge(edge_id) = target {
+            if !self.map.has_edge(edge_id) {
+                return Err(format!("There is no edge with id {edge_id} on the map of the game!"));
+            }
+        }
+        let position = self.map_annotations.iter().position(|annotation| annotation.target == target);
+        match (text.filter(|text| !text.trim().is_empty()), position) {
+            (Some(text), Some(position)) => {
+                self.map_annotations[position].text = text;
+                self.map_annotations[position].turn_number = self.turn_number;
+            }
+            (Some(text), None) => self.map_annotations.push(MapAnnotation { target, text, turn_number: self.turn_number }),
+            (None, Some(position)) => {
+                self.map_annotations.remove(position);
+            }
+            (None, None) => return Err(format!("There is no note on {target:?} to remove!")),
+        }
+        Ok(())
+    }
+
+    /// Creates a checkpoint with the given label, which the game can be restored to later with [`GameState::restore_checkpoint`]. Returns the copy of the game the checkpoint saves, which is kept in the [`GameHistory`] of the game.
+    ///
+    /// [`GameHistory`]: ../game_history/struct.GameHistory.html
+    pub fn create_checkpoint(&mut self, label: String) -> Result<GameSnapshot, String> {
+        if label.trim().is_empty() {
+            return Err("The label of a checkpoint can not be empty!".to_string());
+        }
+        if self.checkpoints.iter().any(|checkpoint| checkpoint.label == label) {
+            return Err(format!("There is already a checkpoint called {label}!"));
+        }
+        if self.checkpoints.len() >= MAX_CHECKPOINTS {
+            return Err(format!("A game can not have more than {MAX_CHECKPOINTS} checkpoints!"));
+        }
+        let snapshot = self.saved_copy();
+        self.checkpoints.push(Checkpoint::new(label.clone(), self.turn_number, self.round_number));
+        self.record_event(GameEventKind::CheckpointCreated(label));
+        Ok(snapshot)
+    }
+
+    /// Restores the game to the checkpoint with the given label, from the copy of the game the checkpoint saved. What happened after the checkpoint was created is discarded from the game, but kept in an [`ArchivedBranch`] for the export.
+    ///
+    /// The checkpoints, the archived branches, the version and the session clock of the game are kept, and players who have left the game since the checkpoint are not brought back.
+    ///
+    /// [`ArchivedBranch`]: ../checkpoint/struct.ArchivedBranch.html
+    pub fn restore_checkpoint(&mut self, label: &str, snapshot: Option<&GameSnapshot>) -> Result<(), String> {
+        let Some(checkpoint) = self.checkpoints.iter().find(|checkpoint| checkpoint.label == label).cloned() else {
+            return Err(format!("There is no checkpoint called {label}!"));
+        };
+        let Some(snapshot) = snapshot else {
+            return Err(format!("The copy of the game saved by the checkpoint {label} is missing!"));
+        };
+        let mut restored_game = snapshot.clone().into_game_state_on(self.map.shared_base());
+
+        let last_restored_event_id = restored_game.events.last().map_or(0, |event| event.id);
+        let archived_branch = ArchivedBranch {
+            checkpoint_label: label.to_string(),
+            archived_at: current_timestamp(),
+            summary: GameSummary::new(&self.without_saved_copies()),
+            events: self.events.iter().filter(|event| event.id > last_restored_event_id).cloned().collect(),
+            input_history: self.input_history.iter().filter(|record| record.timestamp >= checkpoint.created_at).cloned().collect(),
+            measure_ledger: self
+                .measure_ledger
+                .iter()
+                .filter(|measure| restored_game.measure_ledger.iter().all(|restored_measure| restored_measure.id != measure.id))
+                .cloned()
+                .collect(),
+        };
+
+        restored_game.players.retain(|player| self.contains_player_with_unique_id(player.unique_id));
+        restored_game.checkpoints = std::mem::take(&mut self.checkpoints);
+        restored_game.archived_branches = std::mem::take(&mut self.archived_branches);
+        restored_game.archived_branches.push(archived_branch);
+        restored_game.version = self.version;
+        restored_game.section_versions = std::mem::take(&mut self.section_versions);
+        restored_game.session_clock = self.session_clock.clone();
+        // The event ids keep counting from the discarded events, so that the clients don't mistake the new events for ones they have already handled.
+        let next_event_id: EventID = self.events.last().map_or(1, |event| event.id + 1);
+        restored_game.events.push(GameEvent::new(next_event_id, restored_game.turn_number, GameEventKind::CheckpointRestored(label.to_string())));
+        *self = restored_game;
+        Ok(())
+    }
+
+    /// Returns a copy of the game without the checkpoints and archived branches, so that the saved copies of the game don't contain each other.
+    fn without_saved_copies(&self) -> Self {
+        let mut game = self.clone();
+        game.checkpoints.clear();
+        game.archived_branches.clear();
+        game
+    }
+
+    /// Returns a copy of the game as it is now to keep in its [`GameHistory`], like the copy a checkpoint saves or the copy the replay log starts from.
+    ///
+    /// [`GameHistory`]: ../game_history/struct.GameHistory.html
+    #[must_use]
+    pub fn saved_copy(&self) -> GameSnapshot {
+        GameSnapshot::new(&self.without_saved_copies())
+    }
+
+    /// Returns the objective card of every player in the game, by the unique id of the player.
+    pub fn objective_cards(&self) -> Vec<(PlayerID, Option<PlayerObjectiveCard>)> {
+        self.players.iter().map(|player| (player.unique_id, player.objective_card.clone())).collect()
+    }
+
+    /// Creates a new lobby with the given name and id from this game as it was in the given copy of it (like the copy from the start of an earlier turn kept in its [`GameHistory`]), or as it is now if no copy is given.
+    ///
+    /// The new lobby has the situation card, settings, measures, map and turn of this game, while the players of this game are copied as open seats.
+    /// The players who join the new lobby claim the seats, and unclaimed seats are left out when it's started. Rides are not copied.
+    ///
+    /// [`GameHistory`]: ../game_history/struct.GameHistory.html
+    pub fn fork(&self, name: String, game_id: GameID, earlier_copy: Option<&GameSnapshot>) -> Result<Self, String> {
+        if self.players.iter().all(|player| player.objective_card.is_none()) {
+            return Err("The game has not been started and can therefore not be forked!".to_string());
+        }
+        let source = earlier_copy.map_or_else(|| self.without_saved_copies(), |snapshot| snapshot.clone().into_game_state_on(self.map.shared_base()));
+
+        let mut forked_game = Self::new(name, game_id);
+        forked_game.situation_card = source.situation_card;
+        forked_game.objective_deck = source.objective_deck;
+        forked_game.lobby_settings = source.lobby_settings;
+        forked_game.district_modifiers = source.district_modifiers;
+        forked_game.edge_restrictions = source.edge_restrictions;
+        forked_game.congestion_zone = source.congestion_zone;
+        forked_game.measure_ledger = source.measure_ledger;
+        forked_game.map = source.map;
+        forked_game.map_name = source.map_name;
+        forked_game.accessed_districts = source.accessed_districts;
+        forked_game.current_players_turn = source.current_players_turn;
+        forked_game.turn_number = source.turn_number;
+        forked_game.round_number = source.round_number;
+        forked_game.statistics = source.statistics;
+        forked_game.open_seats = source
+            .players
+            .into_iter()
+            .map(|mut player| {
+                player.connected_game_id = Some(game_id);
+                player.ride_offered_to = None;
+                player.riding_with = None;
+                player
+            })
+            .collect();
+        forked_game.fork_origin = Some(ForkOrigin {
+            game_id: self.id,
+            turn_number: source.turn_number,
+            has_started: false,
+        });
+        Ok(forked_game)
+    }
+
+    /// Sets the situation card and the lobby settings of the game to the ones the scenario decides. The map of the scenario is not used, since the map of a game is chosen when it's created.
+    pub fn apply_scenario(&mut self, scenario: &ScenarioTemplate) -> Result<(), String> {
+        if let Some(lobby_settings) = &scenario.lobby_settings {
+            self.lobby_settings = lobby_settings.clone();
+        }
+        if let Some(situation_card_id) = scenario.situation_card_id {
+            self.situation_card = Some(SituationCardList::get_default_situation_card_by_id(situation_card_id)?);
+            self.update_node_map_with_situation_card()?;
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the next scenario of the campaign can not be started, because the game is not part of a campaign, the campaign has no more scenarios or the current scenario is not over.
+    pub fn check_can_start_next_scenario(&self) -> Result<(), String> {
+        let Some(campaign) = &self.campaign else {
+            return Err("The game is not part of a campaign!".to_string());
+        };
+        if campaign.next_scenario().is_none() {
+            return Err("The campaign has no more scenarios!".to_string());
+        }
+        if !self.is_game_over() {
+            return Err("The next scenario can only be started when the current scenario is finished, because every player has delivered what their objective card asks for or the targets of the orchestrator are met, and the turn has ended!".to_string());
+        }
+        Ok(())
+    }
+
+    /// Starts the next scenario of the campaign, bringing the game back to the lobby with the settings of the next scenario. The players keep their names and roles, and their scores are added to the cumulative scores of the campaign.
+    ///
+    /// The summary of the scenario that is over is kept in the campaign for the debrief. The events, the version, the session clock and the map (without the changes made to it) of the game are kept, while the rest of the game is started over.
+    pub fn start_next_scenario(&mut self) -> Result<(), String> {
+        self.check_can_start_next_scenario()?;
+        let Some(mut campaign) = self.campaign.clone() else {
+            return Err("The game is not part of a campaign!".to_string());
+        };
+        self.players
+            .iter()
+            .filter(|player| player.in_game_id != InGameID::Orchestrator)
+            .for_each(|player| campaign.add_score(player.unique_id, player.score));
+        campaign.completed_scenarios.push(GameSummary::new(&self.without_saved_copies()));
+        campaign.current_scenario += 1;
+        let Some(scenario) = campaign.current_scenario().cloned() else {
+            return Err("The campaign has no more scenarios!".to_string());
+        };
+
+        let mut next_game = Self::new(self.name.clone(), self.id);
+        next_game.map = self.map.clone();
+        next_game.map.reset();
+        next_game.map_name = self.map_name.clone();
+        next_game.lobby_settings = self.lobby_settings.clone();
+        next_game.apply_scenario(&scenario)?;
+        next_game.players = self.players.clone();
+        next_game.reset_player_in_game_data();
+        next_game.objective_deck = self.objective_deck.clone();
+        next_game.campaign = Some(campaign);
+        next_game.score_weights = self.score_weights;
+        next_game.events = std::mem::take(&mut self.events);
+        next_game.version = self.version;
+        next_game.section_versions = std::mem::take(&mut self.section_versions);
+        next_game.session_clock = self.session_clock.clone();
+        next_game.record_event(GameEventKind::ScenarioStarted(scenario.name));
+        next_game.mark_sections_changed(&StateSection::ALL);
+        *self = next_game;
+        Ok(())
+    }
+
+    /// Returns `true` if the game is a fork that has not been started yet, where the players claim the open seats instead of choosing a role.
+    pub fn is_unstarted_fork(&self) -> bool {
+        self.fork_origin.as_ref().is_some_and(|fork_origin| !fork_origin.has_started)
+    }
+
+    /// Gives the player the role and everything else the player of the open seat with the given role had in the game this game was forked from.
+    pub fn claim_seat(&mut self, player_id: PlayerID, seat_role: InGameID) -> Result<(), String> {
+        let Some(seat_position) = self.open_seats.iter().position(|seat| seat.in_game_id == seat_role) else {
+            return Err(format!("There is no open seat with the role {:?}!", seat_role));
+        };
+        let Some(player) = self.players.iter_mut().find(|player| player.unique_id == player_id) else {
+            return Err(format!("There is no player with the id {} in the game!", player_id));
+        };
+        if player.in_game_id != InGameID::Undecided {
+            return Err(format!("The player {} has already claimed a seat!", player.name));
+        }
+        let seat = self.open_seats.remove(seat_position);
+        player.in_game_id = seat.in_game_id;
+        player.position_node_id = seat.position_node_id;
+        player.remaining_moves = seat.remaining_moves;
+        player.objective_card = seat.objective_card;
+        player.is_bus = seat.is_bus;
+        player.cargo = seat.cargo;
+        player.emissions = seat.emissions;
+        player.budget = seat.budget;
+        player.congestion_charges_paid = seat.congestion_charges_paid;
+        player.score = seat.score;
+        Ok(())
+    }
+
+    /// Starts a forked game from where the game it was forked from was. The open seats that have not been claimed are left out.
+    fn start_forked_game(&mut self) -> Result<(), String> {
+        if let Some(player) = self.players.iter().find(|player| player.in_game_id == InGameID::Undecided) {
+            return Err(format!("Unable to start game because player with id {} and name {} has not claimed a seat", player.unique_id, player.name));
+        }
+        if !self.players.iter().any(|player| player.in_game_id == InGameID::Orchestrator) {
+            return Err("Unable to start game because lobby does not have an orchestrator".to_string());
+        }
+        if self.players.len() < 2 {
+            return Err("Unable to start game because there are not enough players".to_string());
+        }
+        self.vacated_roles = self.open_seats.drain(..).map(|seat| seat.in_game_id).collect();
+        self.current_players_turn = self.first_role_with_player_from(self.current_players_turn);
+        self.is_lobby = false;
+        if let Some(fork_origin) = self.fork_origin.as_mut() {
+            fork_origin.has_started = true;
+        }
+        self.session_clock = SessionClock::started(current_timestamp(), self.lobby_settings.session_length_minutes);
+        self.start_timed_props(current_timestamp());
+        Ok(())
+    }
+
+    /// Increases the version of the game and marks the given sections as changed in the new version. Nothing happens if no sections are given.
+    pub fn mark_sections_changed(&mut self, sections: &[StateSection]) {
+        if sections.is_empty() {
+            return;
+        }
+        if sections.contains(&StateSection::Modifiers) {
+            self.modifier_slots = ModifierSlots::count(&self.district_modifiers);
+        }
+        self.version += 1;
+        for section in sections {
+            match self.section_versions.iter_mut().find(|(s, _)| s == section) {
+                Some(section_version) => section_version.1 = self.version,
+                None => self.section_versions.push((*section, self.version)),
+            }
+        }
+    }
+
+    /// Sets the update hints to the sections that have changed since the given version. If no version is given, or the version is unknown, all the sections are hinted.
+    pub fn set_update_hints(&mut self, known_version: Option<StateVersion>) {
+        self.update_hints = self.sections_changed_since(known_version);
+    }
+
+    /// Returns the sections that have changed since the given version. If no version is given, or the version is unknown, all the sections are returned.
+    pub fn sections_changed_since(&self, known_version: Option<StateVersion>) -> Vec<StateSection> {
+        match known_version {
+            Some(known_version) if known_version <= self.version => StateSection::ALL
+                .iter()
+                .filter(|section| {
+                    self.section_versions
+                        .iter()
+                        .find(|(s, _)| s == *section)
+                        .is_none_or(|(_, version)| *version > known_version)
+                })
+                .copied()
+                .collect(),
+            _ => StateSection::ALL.to_vec(),
+        }
+    }
+
+    /// Returns a hash of the state of the game that is the same on every platform, so a client can check that its own simulation of the game has not diverged from the server.
+    ///
+    /// The hash is the 64-bit FNV-1a hash of the compact JSON (without any whitespace) of these fields of the game, in this order: `id`, `name`, `players`, `is_lobby`, `current_players_turn`, `turn_number`, `round_number`, `district_modifiers`, `edge_restrictions`, `congestion_zone`, `situation_card` and `lobby_settings`.
+    /// Will return an error if the game could not be serialized.
+    pub fn state_hash(&self) -> Result<StateHash, String> {
+        let hashed_state = HashedGameState {
+            id: self.id,
+            name: &self.name,
+            players: &self.players,
+            is_lobby: self.is_lobby,
+            current_players_turn: self.current_players_turn,
+            turn_number: self.turn_number,
+            round_number: self.round_number,
+            district_modifiers: &self.district_modifiers,
+            edge_restrictions: &self.edge_restrictions,
+            congestion_zone: &self.congestion_zone,
+            situation_card: &self.situation_card,
+            lobby_settings: &self.lobby_settings,
+        };
+        let bytes = serde_json::to_vec(&hashed_state).map_err(|e| format!("Failed to serialize the game to hash it because: {e}"))?;
+        Ok(fnv1a_hash(&bytes))
+    }
+
+    /// Returns the hash of the base map and the state hash of the game, for log entries that should tell exactly which map and state they are about. A hash that could not be made is left out.
+    pub fn log_references(&self) -> Vec<(&'static str, StateHash)> {
+        let mut references = Vec::new();
+        if let Some(map_hash) = self.map.base_hash() {
+            references.push(("map", map_hash));
+        }
+        if let Ok(state_hash) = self.state_hash() {
+            references.push(("state", state_hash));
         }
+        references
     }
 
     /// Set's the player with the given unique_id to a bus. If there is no player in the game with the given unique_id, nothing happens.
@@ -57,6 +933,34 @@ impl GameState {
         }
     }
 
+    /// Counts that an input from the player with the given unique_id was rejected by the rule with the given name.
+    pub fn record_rule_violation(&mut self, player_id: PlayerID, rule_name: &str) {
+        match self
+            .rule_violations
+            .iter_mut()
+            .find(|violation| violation.player_id == player_id && violation.rule_name == rule_name)
+        {
+            Some(violation) => violation.count += 1,
+            None => self.rule_violations.push(RuleViolationCount {
+                rule_name: rule_name.to_string(),
+                player_id,
+                count: 1,
+            }),
+        }
+    }
+
+    /// Removes the objective cards of all the players except the given one until the game is finished, so that the game can be sent to that player without telling where the others are heading.
+    /// The cards of all the players are revealed in the `objective_reveals` when the game is finished. If no player is given, all the cards are removed.
+    pub fn hide_objective_cards_from_others(&mut self, viewer: Option<PlayerID>) {
+        if self.status.is_finished() {
+            return;
+        }
+        self.players
+            .iter_mut()
+            .filter(|player| Some(player.unique_id) != viewer)
+            .for_each(|player| player.objective_card = None);
+    }
+
     /// Returns `true` if the player with the given unique_id is a participant in the game, else it will return `false`.
     pub fn contains_player_with_unique_id(&self, unique_id: PlayerID) -> bool {
         for player in &self.players {
@@ -81,10 +985,65 @@ impl GameState {
 
         player.in_game_id = InGameID::Undecided;
         player.connected_game_id = Some(self.id);
+        player.name = self.create_unique_player_name(&player.name);
         self.players.push(player);
         Ok(())
     }
 
+    /// Puts a player that was removed from the game for being inactive back into it, with the role and everything else the player had. Fails if the game is full or another player has taken the role.
+    pub fn rejoin_player(&mut self, mut player: Player) -> Result<(), String> {
+        if self.players.len() >= MAX_PLAYER_COUNT {
+            return Err("The game is full".to_string());
+        }
+        if self.contains_player_with_unique_id(player.unique_id) {
+            return Err("The player is already in the game".to_string());
+        }
+        if player.in_game_id != InGameID::Undecided && self.players.iter().any(|p| p.in_game_id == player.in_game_id) {
+            return Err(format!("Another player has taken the role {:?}", player.in_game_id));
+        }
+        player.connected_game_id = Some(self.id);
+        player.name = self.create_unique_player_name(&player.name);
+        self.vacated_roles.retain(|role| *role != player.in_game_id);
+        self.players.push(player);
+        Ok(())
+    }
+
+    /// Returns `true` if another player than the one with the given unique_id already uses the given name. Names are compared without caring about upper/lower case or surrounding whitespace.
+    pub fn is_player_name_taken(&self, name: &str, ignored_player_id: Option<PlayerID>) -> bool {
+        let name = name.trim().to_lowercase();
+        self.players
+            .iter()
+            .filter(|player| Some(player.unique_id) != ignored_player_id)
+            .any(|player| player.name.trim().to_lowercase() == name)
+    }
+
+    /// Returns the given name if no other player in the game uses it, otherwise it returns the name with the first free suffix (e.g. "Anna-2").
+    pub fn create_unique_player_name(&self, name: &str) -> String {
+        if !self.is_player_name_taken(name, None) {
+            return name.to_string();
+        }
+        let mut suffix = 2;
+        loop {
+            let suggested_name = format!("{name}-{suffix}");
+            if !self.is_player_name_taken(&suggested_name, None) {
+                return suggested_name;
+            }
+            suffix += 1;
+        }
+    }
+
+    /// Changes the name of the player with the given unique_id. Will return an error if another player in the game already uses the name or there is no player with the given id.
+    pub fn rename_player(&mut self, player_id: PlayerID, new_name: String) -> Result<(), String> {
+        if self.is_player_name_taken(&new_name, Some(player_id)) {
+            return Err(format!("There is already a player with the name {new_name} in the game!"));
+        }
+        let Some(player) = self.players.iter_mut().find(|p| p.unique_id == player_id) else {
+            return Err("There were no players in this game that match the player to rename".to_string());
+        };
+        player.name = new_name;
+        Ok(())
+    }
+
     fn node_is_in_district (neighbour_list: Vec<NeighbourRelationship>, district: District) -> bool {
         let mut node_is_in_district = false;
         neighbour_list.into_iter().for_each(|edge|{
@@ -95,12 +1054,15 @@ impl GameState {
         node_is_in_district
     }
 
-    /// Moves the player to the given node id. Will return an error string if something went wrong while trying to move the player.
+    /// Moves the player to the given node id along the edge with the given id. The id of the edge only has to be given if there are several edges between the nodes.
+    /// Returns what the move cost the player, or an error string if something went wrong while trying to move the player. The objective of the player is not updated, see [`update_objective_status`](#method.update_objective_status).
     pub fn move_player_with_id(
         &mut self,
         player_id: PlayerID,
         to_node_id: NodeID,
-    ) -> Result<(), String> {
+        edge_id: Option<EdgeID>,
+    ) -> Result<MovementOutcome, String> {
+        let has_passengers = self.has_passengers(player_id);
         for player in self.players.iter_mut() {
             if player.unique_id != player_id {
                 continue;
@@ -110,17 +1072,20 @@ impl GameState {
                 return Err("The player is not at any node!".to_string());
             };
 
-            let Some(neighbours) = self.map.get_neighbour_relationships_of_node_with_id(current_node_id) else {
-                return Err(format!("There was no node with id {}!", current_node_id));
-            };
+            let cargo_cost = player.cargo_movement_cost();
 
-            let Some(neighbour_relationship) = neighbours.iter().find(|relationship| relationship.to == to_node_id) else {
-                return Err(format!("The node you are trying to go to is not a neighbour. From node with id {} to {}", current_node_id, to_node_id));
+            let neighbour_relationship = match self.map.get_edge_between(current_node_id, to_node_id, edge_id) {
+                Ok(relationship) => relationship,
+                Err(e) => return Err(format!("The node you are trying to go to can not be reached from node {} because: {}", current_node_id, e)),
             };
+            let mut outcome = MovementOutcome::new(player_id, current_node_id, to_node_id);
+            outcome.restriction_crossed = neighbour_relationship.restriction;
+            outcome.by_rail = neighbour_relationship.is_connected_through_rail;
 
             if neighbour_relationship.is_connected_through_rail {
-                Self::move_player_to_node(player, to_node_id, 1);
-                return Ok(());
+                Self::move_player_to_node(player, to_node_id, 1 + cargo_cost);
+                outcome.moves_paid = 1 + cargo_cost;
+                return Ok(outcome);
             }
 
             if player.is_bus {
@@ -128,35 +1093,38 @@ impl GameState {
                     return Err(format!("The node (with id {}) you are trying to go to does not have a restriction and you can therefore not move there as a bus!", to_node_id));
                 };
 
-                if edge_restriction != RestrictionType::ParkAndRide {
-                    return Err(format!("The node (with id {}) you are trying to go to is not a part of the park & ride roads and you can therefore not move there as a bus!", to_node_id));
+                if edge_restriction != RestrictionType::ParkAndRide && edge_restriction != RestrictionType::BusLane {
+                    return Err(format!("The node (with id {}) you are trying to go to is not a part of the park & ride roads or a bus lane and you can therefore not move there as a bus!", to_node_id));
                 }
                 
-                Self::move_player_to_node(player, to_node_id, 1);
-                return Ok(());
+                Self::move_player_to_node(player, to_node_id, 1 + cargo_cost);
+                outcome.moves_paid = 1 + cargo_cost;
+                return Ok(outcome);
             }
 
             if let Some(restriction) = neighbour_relationship.restriction {
                 if restriction == RestrictionType::ParkAndRide {
                     return Err(format!("The node (with id {}) you are trying to go to is a part of the park & ride roads and you can therefore not move there unless you are a buss!", to_node_id));
                 }
-                Self::move_player_to_node(player, to_node_id, 1);
-                return Ok(());
+                if restriction == RestrictionType::BusLane && !has_passengers {
+                    return Err(format!("The edge to the node (with id {}) is a bus lane and can therefore only be used by buses and cars with passengers!", to_node_id));
+                }
+                Self::move_player_to_node(player, to_node_id, 1 + cargo_cost);
+                outcome.moves_paid = 1 + cargo_cost;
+                return Ok(outcome);
             }
 
+            let moves_before = player.remaining_moves;
+
             if !self
                 .accessed_districts
                 .contains(&neighbour_relationship.neighbourhood)
             {
                 self.accessed_districts
                     .push(neighbour_relationship.neighbourhood);
-                player.remaining_moves -= match self
+                player.remaining_moves -= self
                     .map
-                    .first_time_in_district_cost(neighbour_relationship.clone())
-                {
-                    Ok(cost) => cost,
-                    Err(e) => return Err(e),
-                };
+                    .first_time_in_district_cost(neighbour_relationship.clone())?;
 
                 let mut bonus_moves = 0;
 
@@ -197,15 +1165,16 @@ impl GameState {
                 }
                 player.remaining_moves += bonus_moves;
             }
-            player.remaining_moves -= neighbour_relationship.movement_cost;
+            player.remaining_moves -= neighbour_relationship.movement_cost + cargo_cost;
             player.position_node_id = Some(to_node_id);
-            return Ok(());
+            outcome.moves_paid = moves_before - player.remaining_moves;
+            return Ok(outcome);
         }
         Err("There were no players in this game that match the player to update".to_string())
     }
 
     /// Checks if the player has an objective card in the given district.
-    pub fn player_has_objective_in_district(map: &NodeMap, player: &Player, district: District) -> bool {
+    pub fn player_has_objective_in_district(map: &GameMap, player: &Player, district: District) -> bool {
         let Some(objectivecard) = &player.objective_card else {
             return false;
         };
@@ -219,11 +1188,147 @@ impl GameState {
     }
 
     /// Moves the player to a node without checking if the move is valid.
-    pub fn move_player_to_node(player: &mut Player, to_node_id: NodeID, cost: MovementCost) {
+    pub const fn move_player_to_node(player: &mut Player, to_node_id: NodeID, cost: MovementCost) {
         player.remaining_moves -= cost;
         player.position_node_id = Some(to_node_id);
     }
 
+    /// Moves the player with the given id like [`move_player_with_id`] and brings the passengers of the player along. The congestion charge of the move is paid by the player and included in the outcome.
+    ///
+    /// [`move_player_with_id`]: #method.move_player_with_id
+    pub fn move_vehicle_with_id(&mut self, player_id: PlayerID, to_node_id: NodeID, edge_id: Option<EdgeID>) -> Result<MovementOutcome, String> {
+        let congestion_charge = self.congestion_charge_for_move(player_id, to_node_id, edge_id)?;
+        let from_node_id = self.get_player_with_unique_id(player_id)?.position_node_id;
+        let ferry_edge_id = from_node_id
+            .and_then(|from_node_id| self.map.get_edge_between(from_node_id, to_node_id, edge_id).ok())
+            .filter(|relationship| relationship.ferry.is_some())
+            .map(|relationship| relationship.edge_id);
+
+        let mut outcome = self.move_player_with_id(player_id, to_node_id, edge_id)?;
+        outcome.congestion_charge = congestion_charge;
+        if let (Some(ferry_edge_id), Some(from_node_id)) = (ferry_edge_id, from_node_id) {
+            self.record_ferry_crossing(player_id, ferry_edge_id, from_node_id);
+        }
+
+        for player in self.players.iter_mut() {
+            if player.unique_id != player_id && player.riding_with != Some(player_id) {
+                continue;
+            }
+            player.position_node_id = Some(to_node_id);
+            if player.unique_id == player_id {
+                player.budget -= congestion_charge;
+                player.congestion_charges_paid += congestion_charge;
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// Records that the player took the ferry on the given edge from the given node in the current round. The crossings of the earlier rounds are forgotten.
+    fn record_ferry_crossing(&mut self, player_id: PlayerID, edge_id: EdgeID, from_node_id: NodeID) {
+        let round_number = self.round_number;
+        self.ferry_crossings.retain(|crossing| crossing.round_number == round_number);
+        self.ferry_crossings.push(FerryCrossing { edge_id, from_node_id, round_number, player_id });
+    }
+
+    /// Returns how many vehicles have taken the ferry on the given edge from the given node in the current round.
+    pub fn ferry_crossings_this_round(&self, edge_id: EdgeID, from_node_id: NodeID) -> usize {
+        self.ferry_crossings
+            .iter()
+            .filter(|crossing| crossing.edge_id == edge_id && crossing.from_node_id == from_node_id && crossing.round_number == self.round_number)
+            .count()
+    }
+
+    /// Returns the congestion charge the player with the given id has to pay to move to the given node. A car is charged when it moves from a node outside of the congestion zone to a node inside of it, based on the main district of the nodes.
+    /// Buses and moves by rail are never charged.
+    pub fn congestion_charge_for_move(&self, player_id: PlayerID, to_node_id: NodeID, edge_id: Option<EdgeID>) -> Result<Money, String> {
+        let Some(congestion_zone) = &self.congestion_zone else {
+            return Ok(0);
+        };
+        let player = self.get_player_with_unique_id(player_id)?;
+        let Some(from_node_id) = player.position_node_id else {
+            return Err("The player is not at any node!".to_string());
+        };
+        let is_rail_move = self
+            .map
+            .get_edge_between(from_node_id, to_node_id, edge_id)
+            .is_ok_and(|relationship| relationship.is_connected_through_rail);
+        if player.is_bus || is_rail_move {
+            return Ok(0);
+        }
+
+        let is_in_zone = |node_id| main_district_of_node(self.map.base(), node_id).is_some_and(|district| congestion_zone.contains(district));
+        if is_in_zone(from_node_id) || !is_in_zone(to_node_id) {
+            return Ok(0);
+        }
+        Ok(congestion_zone.charge_in_round(self.round_number))
+    }
+
+    /// Sets up the congestion charging zone, or removes it if `None` is given.
+    pub fn set_congestion_zone(&mut self, congestion_zone: Option<CongestionZone>) {
+        self.congestion_zone = congestion_zone;
+    }
+
+    /// Offers the player with the id to_player_id a ride in the car of the player with the id driver_id. Both players have to be at the same node.
+    pub fn offer_ride(&mut self, driver_id: PlayerID, to_player_id: PlayerID) -> Result<(), String> {
+        let passenger = self.get_player_with_unique_id(to_player_id)?;
+        let Some(driver) = self.players.iter_mut().find(|player| player.unique_id == driver_id) else {
+            return Err("There were no players in this game that match the player offering the ride".to_string());
+        };
+        if driver.position_node_id.is_none() || driver.position_node_id != passenger.position_node_id {
+            return Err(format!("The player {} has to be at the same node as {} to offer a ride!", driver.name, passenger.name));
+        }
+        driver.ride_offered_to = Some(to_player_id);
+        Ok(())
+    }
+
+    /// Lets the player with the id passenger_id accept the ride offered by the player with the id driver_id.
+    pub fn accept_ride(&mut self, passenger_id: PlayerID, driver_id: PlayerID) -> Result<(), String> {
+        let Some(driver) = self.players.iter_mut().find(|player| player.unique_id == driver_id) else {
+            return Err("There were no players in this game that match the driver of the ride".to_string());
+        };
+        if driver.ride_offered_to != Some(passenger_id) {
+            return Err(format!("The player {} has not offered a ride to this player!", driver.name));
+        }
+        driver.ride_offered_to = None;
+        let Some(passenger) = self.players.iter_mut().find(|player| player.unique_id == passenger_id) else {
+            return Err("There were no players in this game that match the player accepting the ride".to_string());
+        };
+        passenger.riding_with = Some(driver_id);
+        Ok(())
+    }
+
+    /// Lets the player with the given id exit the vehicle it's riding in at the node the vehicle is at.
+    pub fn exit_vehicle(&mut self, passenger_id: PlayerID, at_node_id: NodeID) -> Result<(), String> {
+        let Some(passenger) = self.players.iter_mut().find(|player| player.unique_id == passenger_id) else {
+            return Err("There were no players in this game that match the player exiting the vehicle".to_string());
+        };
+        if passenger.riding_with.is_none() {
+            return Err(format!("The player {} is not riding with anyone!", passenger.name));
+        }
+        if passenger.position_node_id != Some(at_node_id) {
+            return Err(format!("The player {} can only exit the vehicle at the node the vehicle is at!", passenger.name));
+        }
+        passenger.riding_with = None;
+        Ok(())
+    }
+
+    /// Returns `true` if any player is riding with the player with the given id.
+    pub fn has_passengers(&self, driver_id: PlayerID) -> bool {
+        self.players.iter().any(|player| player.riding_with == Some(driver_id))
+    }
+
+    /// Lets all the passengers of the player with the given id exit the vehicle and removes any ride the player has offered.
+    fn end_rides_of_driver(&mut self, driver_id: PlayerID) {
+        for player in self.players.iter_mut() {
+            if player.riding_with == Some(driver_id) {
+                player.riding_with = None;
+            }
+            if player.unique_id == driver_id {
+                player.ride_offered_to = None;
+            }
+        }
+    }
+
     /// Tries to assign the player to the role specified in the change_info tuple. Will return an error if something went wrong.
     pub fn assign_player_role(&mut self, change_info: (PlayerID, InGameID)) -> Result<(), &str> {
         let (related_player_id, change_to_role) = change_info;
@@ -240,6 +1345,7 @@ impl GameState {
                 continue;
             }
             player.in_game_id = change_to_role;
+            self.vacated_roles.retain(|role| *role != change_to_role);
             return Ok(());
         }
         Err("There were no players in this game that match the player to update")
@@ -263,14 +1369,24 @@ impl GameState {
             Err(_) => return,
         };
         let player_with_turn_removed = self.current_players_turn == player.in_game_id;
+        self.vacate_role(player.in_game_id);
         let mut its_the_next_players_turn = false;
+        self.end_rides_of_driver(player_id);
         self.players.retain(|player| player.unique_id != player_id);
+        self.move_plans.retain(|plan| plan.player_id != player_id);
+        self.players
+            .iter_mut()
+            .filter(|player| player.ride_offered_to == Some(player_id))
+            .for_each(|player| player.ride_offered_to = None);
         if self
             .players
             .iter()
             .all(|player| player.in_game_id != InGameID::Orchestrator)
         {
-            if let Some(mut p) = self.players.first_mut() {
+            if let Some(role) = self.players.first().map(|p| p.in_game_id) {
+                self.vacate_role(role);
+            }
+            if let Some(p) = self.players.first_mut() {
                 if p.in_game_id == self.current_players_turn {
                     its_the_next_players_turn = true;
                 }
@@ -287,52 +1403,79 @@ impl GameState {
         }
     }
 
-    /// Sets the current players turn to the next player in the list of players. This function will also set the is_lobby bool to true if the orchestrator is the next player. 
-    pub fn next_player_turn(&mut self) {
-        let mut next_player_turn = self.current_players_turn.next();
-        let mut counter = 0;
-        while !self
-            .players
-            .iter()
-            .any(|p| p.in_game_id == next_player_turn)
-        {
-            next_player_turn = next_player_turn.next();
-            if counter >= 1000 {
-                next_player_turn = InGameID::Orchestrator;
-                break;
-            }
-            counter += 1;
+    /// Adds the role to the vacated roles if the game has been started, so that its turns are skipped with an event.
+    fn vacate_role(&mut self, role: InGameID) {
+        let game_has_started = !self.is_lobby || self.round_number > 0;
+        if game_has_started && role != InGameID::Undecided && role != InGameID::Orchestrator && !self.vacated_roles.contains(&role) {
+            self.vacated_roles.push(role);
         }
+    }
+
+    /// Sets the current players turn to the next player in the list of players, skipping the roles that have no player. This function will also set the is_lobby bool to true if the orchestrator is the next player. 
+    pub fn next_player_turn(&mut self) {
+        let next_player_turn = self.first_role_with_player_from(self.current_players_turn.next());
         self.accessed_districts.clear();
+        self.rule_warnings.clear();
+        self.turn_number += 1;
         self.current_players_turn = next_player_turn;
         if self.current_players_turn == InGameID::Orchestrator {
             self.is_lobby = true;
+            self.round_number += 1;
         }
     }
 
+    /// Returns the first role in the turn order, starting with the given role, that has a player. The orchestrator is returned if none of the roles before it have a player.
+    /// A [`GameEventKind::TurnSkipped`] event is recorded for every skipped role in `vacated_roles`, while the roles that never had a player are skipped silently.
+    ///
+    /// [`GameEventKind::TurnSkipped`]: ../game_event/enum.GameEventKind.html
+    fn first_role_with_player_from(&mut self, role: InGameID) -> InGameID {
+        let mut role = role;
+        while role != InGameID::Orchestrator && self.players.iter().all(|player| player.in_game_id != role) {
+            if self.vacated_roles.contains(&role) {
+                self.record_event(GameEventKind::TurnSkipped(role));
+            }
+            role = role.next();
+        }
+        role
+    }
+
     /// Returns the starting movement value for the players.
     pub const fn get_starting_player_movement_value() -> MovementValue {
         START_MOVEMENT_AMOUNT
     }
 
-    /// Assigns a random objective card to all the players in the game, based on the chosen situation card. Will return an error if something went wrong.
+    /// Assigns a random objective card to all the players in the game, from the objective deck of the game if it has one and from the chosen situation card otherwise. Will return an error if something went wrong.
+    ///
+    /// The objective cards are dealt so that they meet the deal constraints in the lobby settings if possible, and are balanced so that the players preferably do not get objectives in the same district and the objectives fit the game length. The constraints that could not be met are kept in `objective_deal_warnings`.
     pub fn assign_random_objective_card_to_players(&mut self) -> Result<(), String> {
         let Some(situation_card) = self.situation_card.clone() else {
             return Err("The game does not have a situation card and can therefore not assign objective cards to the players!".to_string());
         };
-        let mut objective_cards = situation_card.objective_cards;
-        let mut rng = rand::thread_rng();
-        for mut player in self.players.iter_mut() {
+        let available_objective_cards = match &self.objective_deck {
+            Some(objective_deck) => objective_deck.cards.clone(),
+            None => situation_card.objective_cards,
+        };
+        let amount_of_players = self
+            .players
+            .iter()
+            .filter(|player| player.in_game_id != InGameID::Orchestrator)
+            .count();
+        if available_objective_cards.len() < amount_of_players {
+            return Err(
+                "There were not enough objective cards for all the players!".to_string()
+            );
+        }
+        let mut objective_deal = deal_objective_cards(self.map.base(), available_objective_cards, amount_of_players, self.lobby_settings.game_length, &self.lobby_settings.deal_constraints);
+        self.objective_deal_warnings = objective_deal.unmet_constraints;
+        for player in self.players.iter_mut() {
             if player.in_game_id == InGameID::Orchestrator {
                 continue;
             }
-            if objective_cards.is_empty() {
+            let Some(objective_card) = objective_deal.cards.pop() else {
                 return Err(
                     "There were not enough objective cards for all the players!".to_string()
                 );
-            }
-            let index = rng.gen_range(0..objective_cards.len());
-            let objective_card = objective_cards.remove(index);
+            };
             player.position_node_id = Some(objective_card.start_node_id);
             player.objective_card = Some(objective_card);
         }
@@ -364,23 +1507,55 @@ impl GameState {
             {
                 objective_card.dropped_package_off = true;
             }
-            mem::swap(&mut player.objective_card, &mut Some(objective_card));
+            if objective_card.is_freight {
+                player.cargo = match player.cargo {
+                    CargoState::Empty if objective_card.picked_package_up => CargoState::Loaded,
+                    CargoState::Loaded if objective_card.dropped_package_off => CargoState::Delivered,
+                    cargo => cargo,
+                };
+            }
+            player.objective_card = Some(objective_card);
         }
         Ok(())
     }
 
     /// Starts the game, which means it goes from lobby to in game. Will return an error if something went wrong.
     pub fn start_game(&mut self) -> Result<(), String> {
+        if self.is_unstarted_fork() {
+            return self.start_forked_game();
+        }
         let mut can_start_game = false;
         let mut errormessage =
             String::from("Unable to start game because lobby does not have an orchestrator");
         self.reset_player_in_game_data();
+        if self.lobby_settings.modifier_persistence == ModifierPersistence::ResetEachRound {
+            self.district_modifiers.clear();
+            self.edge_restrictions.clear();
+        }
+        let kept_edge_restrictions: Vec<EdgeRestriction> = self
+            .edge_restrictions
+            .iter()
+            .filter(|restriction| restriction.enacted_turn.is_some())
+            .cloned()
+            .collect();
         self.edge_restrictions.clear();
-        self.district_modifiers.clear();
         match self.update_node_map_with_situation_card() {
             Ok(_) => (),
             Err(e) => return Err(e),
         };
+        for edge_restriction in kept_edge_restrictions {
+            match self.map.set_restriction_on_edge(&edge_restriction, true) {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            }
+            self.edge_restrictions.push(edge_restriction);
+        }
+        if !self.district_modifiers.is_empty() {
+            match self.update_traffic_levels() {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            }
+        }
         for player in self.players.clone() {
             if player.in_game_id == InGameID::Undecided {
                 errormessage = format!("Unable to start game because player with id {} and name {} is neither player, nor orchestrator (Undecided)", player.unique_id, player.name);
@@ -421,6 +1596,14 @@ impl GameState {
         match can_start_game {
             true => {
                 self.reset_player_movement_values();
+                // The game goes back to the lobby at the start of every round, so the clock is only started the first time.
+                if self.session_clock.started_at.is_none() {
+                    self.session_clock = SessionClock::started(current_timestamp(), self.lobby_settings.session_length_minutes);
+                }
+                // Like the clock, the timed props keep ticking from where they were when the game goes back to the lobby between the rounds.
+                if self.timed_props.is_empty() {
+                    self.start_timed_props(current_timestamp());
+                }
                 Ok(())
             }
             false => Err(errormessage),
@@ -434,12 +1617,21 @@ impl GameState {
             player.remaining_moves = Self::get_starting_player_movement_value();
             player.objective_card = None;
             player.is_bus = false;
+            player.cargo = CargoState::Empty;
+            player.ride_offered_to = None;
+            player.riding_with = None;
+            player.emissions = 0;
+            player.budget = START_PLAYER_BUDGET;
+            player.congestion_charges_paid = 0;
+            player.score = 0;
+            player.achievements.clear();
         }
     }
 
     /// Updates the node map based on the situation card. Will return an error if something went wrong.
     pub fn update_node_map_with_situation_card(&mut self) -> Result<(), String> {
         self.map.reset();
+        self.refresh_construction_overlay();
         match &self.situation_card {
             Some(card) => {
                 self.map.update_neighbourhood_cost(card);
@@ -451,17 +1643,17 @@ impl GameState {
                     2 => {},
                     3 => {},
                     4 => {
-                        match self.add_edge_restriction(&EdgeRestriction { node_one: 19, node_two: 20, edge_restriction: RestrictionType::OneWay, delete: false }, false) {
+                        match self.add_edge_restriction(&EdgeRestriction::new(NodeID(19), NodeID(20), RestrictionType::OneWay), false) {
                             Ok(_) => (),
                             Err(e) => return Err(e),
                         }
                     },
                     5 => {
-                        match self.map.toggle_rail_connection_on_node_with_id(24) {
+                        match self.map.toggle_rail_connection_on_node_with_id(NodeID(24)) {
                             Ok(_) => (),
                             Err(e) => return Err(e),
                         };
-                        match self.map.toggle_rail_connection_on_node_with_id(27) {
+                        match self.map.toggle_rail_connection_on_node_with_id(NodeID(27)) {
                             Ok(_) => (),
                             Err(e) => return Err(e),
                         }
@@ -483,16 +1675,57 @@ impl GameState {
             .for_each(|player| player.remaining_moves = Self::get_starting_player_movement_value());
     }
 
+    /// Refills the remaining moves of the player whose turn it is, based on the [`MoveRefill`] in the lobby settings. Should be called when the player's turn is over.
+    ///
+    /// [`MoveRefill`]: ../../enums/move_refill/enum.MoveRefill.html
+    pub fn refill_moves_of_current_player(&mut self) {
+        let move_refill = self.lobby_settings.move_refill;
+        let current_players_turn = self.current_players_turn;
+        let Some(player) = self.players.iter_mut().find(|player| player.in_game_id == current_players_turn) else {
+            return;
+        };
+        let unused_moves = cmp::max(player.remaining_moves, 0);
+        player.remaining_moves = match move_refill {
+            MoveRefill::Never => player.remaining_moves,
+            MoveRefill::UseItOrLoseIt => Self::get_starting_player_movement_value(),
+            MoveRefill::Banking(max_banked_moves) => Self::get_starting_player_movement_value() + cmp::min(unused_moves, cmp::max(max_banked_moves, 0)),
+        };
+    }
+
+    /// Returns the amount of moves the player with the given id has staged in the current turn.
+    pub fn moves_made_this_turn(&self, player_id: PlayerID) -> usize {
+        self.actions
+            .iter()
+            .filter(|action| action.input_type == PlayerInputType::Movement && action.player_id == player_id)
+            .count()
+    }
+
+    /// Returns `true` if the player with the given id has staged a pass in the current turn.
+    pub fn has_passed_this_turn(&self, player_id: PlayerID) -> bool {
+        self.actions
+            .iter()
+            .any(|action| action.input_type == PlayerInputType::Pass && action.player_id == player_id)
+    }
+
+    /// Ends the movement of the player with the given id for the rest of the turn. The remaining moves of the player are lost if they would be lost when the turn ends anyway, and kept otherwise.
+    /// Will return an error if there is no player with the given id.
+    pub fn pass(&mut self, player_id: PlayerID) -> Result<(), String> {
+        let move_refill = self.lobby_settings.move_refill;
+        let Some(player) = self.players.iter_mut().find(|player| player.unique_id == player_id) else {
+            return Err(format!("There is no player with id {} that can pass!", player_id));
+        };
+        if move_refill == MoveRefill::UseItOrLoseIt {
+            player.remaining_moves = 0;
+        }
+        Ok(())
+    }
+
     /// Adds the wanted district modifier to the game. Will return an error if something went wrong
     pub fn add_district_modifier(
         &mut self,
-        district_modifier: DistrictModifier,
+        mut district_modifier: DistrictModifier,
     ) -> Result<(), String> {
-        let max_amount: usize = match district_modifier.modifier {
-            DistrictModifierType::Access => MAX_ACCESS_MODIFIER_COUNT,
-            DistrictModifierType::Priority => MAX_PRIORITY_MODIFIER_COUNT,
-            DistrictModifierType::Toll => MAX_TOLL_MODIFIER_COUNT,
-        };
+        let max_amount = district_modifier.modifier.max_count();
 
         if max_amount
             <= self
@@ -507,6 +1740,7 @@ impl GameState {
             return Err(format!("Cannot add more modifiers of type {:?} because there are already {} modifiers of that type!", district_modifier.modifier, max_amount));
         }
 
+        district_modifier.enacted_turn = Some(self.turn_number);
         self.district_modifiers.push(district_modifier);
         self.update_traffic_levels()
     }
@@ -516,12 +1750,15 @@ impl GameState {
         &mut self,
         district_modifier: DistrictModifier,
     ) -> Result<(), String> {
-        let mut distr_mod = district_modifier;
-        distr_mod.delete = false;
-        let Some(mod_pos) = self.district_modifiers.iter().position(|d_m| d_m == &distr_mod) else {
+        let Some(mod_pos) = self.district_modifiers.iter().position(|d_m| d_m.is_same_modifier(&district_modifier)) else {
             return Err("There is no modifier like the given one in the game!".to_string());
         };
-        self.district_modifiers.remove(mod_pos);
+        self.remove_district_modifier_at(mod_pos)
+    }
+
+    fn remove_district_modifier_at(&mut self, position: usize) -> Result<(), String> {
+        let removed_modifier = self.district_modifiers.remove(position);
+        self.mark_measure_removed(removed_modifier.measure_id);
         self.update_traffic_levels()
     }
 
@@ -590,8 +1827,11 @@ impl GameState {
             Ok(_) => (),
             Err(e) => return Err(e),
         }
-        self.edge_restrictions
-            .push(edge_restriction.clone());
+        let mut edge_restriction = edge_restriction.clone();
+        edge_restriction.edge_id = Some(self.map.find_edge_id(&edge_restriction)?);
+        edge_restriction.delete = false;
+        edge_restriction.enacted_turn = modifiable.then_some(self.turn_number);
+        self.edge_restrictions.push(edge_restriction);
         Ok(())
     }
 
@@ -600,17 +1840,482 @@ impl GameState {
         &mut self,
         edge_restriction: &EdgeRestriction,
     ) -> Result<(), String> {
+        let mut edge_restriction = edge_restriction.clone();
+        edge_restriction.edge_id = Some(self.map.find_edge_id(&edge_restriction)?);
         match self
             .map
-            .remove_restriction_from_edge(edge_restriction)
+            .remove_restriction_from_edge(&edge_restriction)
         {
             Ok(_) => (),
             Err(e) => return Err(e),
         }
-        self.edge_restrictions.retain(|nodes| {
-            !((nodes.node_one == edge_restriction.node_one && nodes.node_two == edge_restriction.node_two)
-                || (nodes.node_one == edge_restriction.node_two && nodes.node_two == edge_restriction.node_one))
-        });
+        let removed_measure_ids: Vec<Option<MeasureID>> = self
+            .edge_restrictions
+            .iter()
+            .filter(|restriction| restriction.is_on_same_edge(&edge_restriction))
+            .map(|restriction| restriction.measure_id)
+            .collect();
+        self.edge_restrictions.retain(|restriction| !restriction.is_on_same_edge(&edge_restriction));
+        for measure_id in removed_measure_ids {
+            self.mark_measure_removed(measure_id);
+        }
+        Ok(())
+    }
+
+    /// Adds the district modifier as a measure enacted by the player with the given unique_id, and records it in the measure ledger. Will return an error if something went wrong.
+    pub fn enact_district_modifier(&mut self, player_id: PlayerID, mut district_modifier: DistrictModifier) -> Result<(), String> {
+        let measure_id = self.next_measure_id();
+        district_modifier.measure_id = Some(measure_id);
+        match self.add_district_modifier(district_modifier.clone()) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        }
+        district_modifier.enacted_turn = Some(self.turn_number);
+        self.measure_ledger.push(EnactedMeasure::new(measure_id, Measure::DistrictModifier(district_modifier), self.turn_number, player_id));
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Adds the edge restriction as a measure enacted by the player with the given unique_id, and records it in the measure ledger. Will return an error if something went wrong.
+    pub fn enact_edge_restriction(&mut self, player_id: PlayerID, edge_restriction: &EdgeRestriction) -> Result<(), String> {
+        let measure_id = self.next_measure_id();
+        let mut edge_restriction = edge_restriction.clone();
+        edge_restriction.measure_id = Some(measure_id);
+        edge_restriction.edge_id = Some(self.map.find_edge_id(&edge_restriction)?);
+        match self.add_edge_restriction(&edge_restriction, true) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        }
+        edge_restriction.delete = false;
+        edge_restriction.enacted_turn = Some(self.turn_number);
+        self.measure_ledger.push(EnactedMeasure::new(measure_id, Measure::EdgeRestriction(edge_restriction), self.turn_number, player_id));
+        Ok(())
+    }
+
+    /// Enacts every district modifier and edge restriction of the measure template with the given id as measures of the player with the given unique_id. Each of them gets its own entry in the measure ledger.
+    /// Either all of them are enacted or, if one fails, none of them. Will return an error if there is no such template or something went wrong.
+    pub fn enact_measure_template(&mut self, player_id: PlayerID, template_id: MeasureTemplateID) -> Result<(), String> {
+        let template = get_measure_template_by_id(template_id)?;
+        let mut game_clone = self.clone();
+        for district_modifier in template.district_modifiers {
+            game_clone.enact_district_modifier(player_id, district_modifier)?;
+        }
+        for edge_restriction in &template.edge_restrictions {
+            game_clone.enact_edge_restriction(player_id, edge_restriction)?;
+        }
+        *self = game_clone;
+        Ok(())
+    }
+
+    /// Returns a copy of the game with its staged actions applied, if one was kept for the current version and staged actions of the game.
+    /// The events, input history, rule violations and move plans are taken from this game, since they can change without the version changing.
+    pub fn kept_applied_actions(&self) -> Option<Self> {
+        let mut applied_game = self.applied_actions.get(self.version, self.actions.len())?.clone();
+        applied_game.actions = self.actions.clone();
+        applied_game.events = self.events.clone();
+        applied_game.input_history = self.input_history.clone();
+        applied_game.rule_violations = self.rule_violations.clone();
+        applied_game.move_plans = self.move_plans.clone();
+        Some(applied_game)
+    }
+
+    /// Keeps the given copy of the game with its staged actions applied for the current version and staged actions of the game.
+    pub fn keep_applied_actions(&mut self, applied_game: Self) {
+        self.applied_actions = AppliedActions::new(self.version, self.actions.len(), applied_game);
+    }
+
+    /// Returns the moves the player with the given id plans to make in their next turn, which is empty if the player has no plan.
+    pub fn move_plan_of_player(&self, player_id: PlayerID) -> Vec<PlannedMove> {
+        self.move_plans
+            .iter()
+            .find(|plan| plan.player_id == player_id)
+            .map(|plan| plan.moves.clone())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the plan of the player with the given moves, or removes it if there are no moves.
+    pub fn set_move_plan(&mut self, player_id: PlayerID, moves: Vec<PlannedMove>) {
+        self.move_plans.retain(|plan| plan.player_id != player_id);
+        if !moves.is_empty() {
+            self.move_plans.push(MovePlan { player_id, moves });
+        }
+    }
+
+    /// Returns `true` if the input is a district modifier or edge restriction the orchestrator sends during the turn of another player, and the lobby settings allow it. Such measures are kept in `pending_measures` until the turn ends.
+    pub fn is_async_measure(&self, input: &PlayerInput) -> bool {
+        self.lobby_settings.async_measures
+            && !self.is_lobby
+            && self.current_players_turn != InGameID::Orchestrator
+            && matches!(input.input_type, PlayerInputType::ModifyDistrict | PlayerInputType::ModifyEdgeRestrictions)
+            && self.players.iter().any(|player| player.unique_id == input.player_id && player.in_game_id == InGameID::Orchestrator)
+    }
+
+    /// Applies the measure in an input from the orchestrator (ModifyDistrict, ModifyEdgeRestrictions, RevokeMeasure, SetCongestionZone, EnactMeasureTemplate or ScheduleConstruction). Will return an error if the input is not a measure or something went wrong.
+    pub fn apply_measure_input(&mut self, input: PlayerInput) -> Result<(), String> {
+        match input.input_type {
+            PlayerInputType::ModifyDistrict => {
+                let Some(district_modifier) = input.district_modifier else {
+                    return Err("There was no district in the input modifier even though it was marked as a district input".to_string());
+                };
+                if district_modifier.delete {
+                    return self.remove_district_modifier(district_modifier);
+                }
+                self.enact_district_modifier(input.player_id, district_modifier)
+            }
+            PlayerInputType::ModifyEdgeRestrictions => {
+                let Some(edge_mod) = input.edge_modifier else {
+                    return Err("There was no park and ride modifier when wanting to modify park and ride!".to_string());
+                };
+                if edge_mod.delete {
+                    return self.remove_restriction_from_edge(&edge_mod);
+                }
+                self.enact_edge_restriction(input.player_id, &edge_mod)
+            }
+            PlayerInputType::RevokeMeasure => {
+                let Some(measure_id) = input.measure_id else {
+                    return Err("There was no measure id in the input, and it's therefore not known which measure to revoke!".to_string());
+                };
+                self.revoke_measure(measure_id)
+            }
+            PlayerInputType::SetCongestionZone => {
+                self.set_congestion_zone(input.congestion_zone);
+                Ok(())
+            }
+            PlayerInputType::EnactMeasureTemplate => {
+                let Some(template_id) = input.measure_template_id else {
+                    return Err("There was no measure template id in the input, and it's therefore not known which measure to enact!".to_string());
+                };
+                self.enact_measure_template(input.player_id, template_id)
+            }
+            PlayerInputType::ScheduleConstruction => {
+                let Some(work) = input.construction_work else {
+                    return Err("There was no construction work in the input, and it's therefore not known what to schedule!".to_string());
+                };
+                self.schedule_construction(input.player_id, work)
+            }
+            _ => Err(format!("The input type {:?} is not a measure!", input.input_type)),
+        }
+    }
+
+    /// Returns the measure in the ledger with the given id if it has not been removed.
+    pub fn get_active_measure(&self, measure_id: MeasureID) -> Result<&EnactedMeasure, String> {
+        let Some(measure) = self.measure_ledger.iter().find(|measure| measure.id == measure_id) else {
+            return Err(format!("There is no measure with id {measure_id} in the game!"));
+        };
+        if !measure.is_active() {
+            return Err(format!("The measure with id {measure_id} has already been removed!"));
+        }
+        Ok(measure)
+    }
+
+    /// Reverses the measure with the given id, no matter which turn it was enacted in. Will return an error if there is no such measure or it has already been removed.
+    pub fn revoke_measure(&mut self, measure_id: MeasureID) -> Result<(), String> {
+        let measure = match self.get_active_measure(measure_id) {
+            Ok(measure) => measure.measure.clone(),
+            Err(e) => return Err(e),
+        };
+        match measure {
+            Measure::DistrictModifier(_) => {
+                let Some(position) = self.district_modifiers.iter().position(|modifier| modifier.measure_id == Some(measure_id)) else {
+                    return Err(format!("The district modifier of the measure with id {measure_id} is not in the game!"));
+                };
+                self.remove_district_modifier_at(position)
+            }
+            Measure::EdgeRestriction(edge_restriction) => self.remove_restriction_from_edge(&edge_restriction),
+        }
+    }
+
+    /// Schedules the construction works as a measure of the player with the given unique_id. Will return an error if one of the edges does not exist.
+    pub fn schedule_construction(&mut self, player_id: PlayerID, work: ConstructionWork) -> Result<(), String> {
+        if let Some(edge_id) = work.edge_ids.iter().find(|edge_id| !self.map.has_edge(**edge_id)) {
+            return Err(format!("There is no edge with id {edge_id} in the map!"));
+        }
+        let id = self
+            .construction_works
+            .iter()
+            .map(|construction| construction.id)
+            .max()
+            .map_or(1, |id| id + 1);
+        let mut construction = ScheduledConstruction { id, work, scheduled_by: player_id, status: ConstructionStatus::Scheduled };
+        construction.status = construction.status_in_turn(self.turn_number);
+        self.construction_works.push(construction);
+        self.refresh_construction_overlay();
+        Ok(())
+    }
+
+    /// Moves the construction works along to the current turn. The players are told about the works the turn before they start, and the edges cost more (or are closed) from the start turn until the works are done.
+    pub fn update_construction_works(&mut self) {
+        let mut changed_works = Vec::new();
+        for construction in &mut self.construction_works {
+            let status = construction.status_in_turn(self.turn_number);
+            if status != construction.status {
+                construction.status = status;
+                changed_works.push((construction.id, status));
+            }
+        }
+        for (id, status) in changed_works {
+            match status {
+                ConstructionStatus::Scheduled => (),
+                ConstructionStatus::Announced => self.record_event(GameEventKind::ConstructionAnnounced(id)),
+                ConstructionStatus::InProgress => self.record_event(GameEventKind::ConstructionStarted(id)),
+                ConstructionStatus::Finished => self.record_event(GameEventKind::ConstructionEnded(id)),
+            }
+        }
+        self.refresh_construction_overlay();
+    }
+
+    /// Sets the extra costs and the closed edges of the map to those of the construction works in progress, so the edges of the works that are done are as they were before.
+    fn refresh_construction_overlay(&mut self) {
+        self.map.overlay.extra_edge_costs.clear();
+        self.map.overlay.closed_edges.clear();
+        for construction in self.construction_works.iter().filter(|construction| construction.status == ConstructionStatus::InProgress) {
+            for edge_id in &construction.work.edge_ids {
+                if construction.work.closes_edges && !self.map.overlay.closed_edges.contains(edge_id) {
+                    self.map.overlay.closed_edges.push(*edge_id);
+                }
+                if construction.work.extra_movement_cost > 0 {
+                    *self.map.overlay.extra_edge_costs.entry(*edge_id).or_insert(0) += construction.work.extra_movement_cost;
+                }
+            }
+        }
+    }
+
+    fn next_measure_id(&self) -> MeasureID {
+        self.measure_ledger
+            .iter()
+            .map(|measure| measure.id)
+            .max()
+            .map_or(1, |id| id + 1)
+    }
+
+    fn mark_measure_removed(&mut self, measure_id: Option<MeasureID>) {
+        let Some(measure_id) = measure_id else {
+            return;
+        };
+        let current_turn = self.turn_number;
+        if let Some(measure) = self
+            .measure_ledger
+            .iter_mut()
+            .find(|measure| measure.id == measure_id && measure.is_active())
+        {
+            measure.removed_turn = Some(current_turn);
+        }
+    }
+
+    /// Removes the orchestrator's measures (district modifiers and edge restrictions) that should no longer be kept based on the [`ModifierPersistence`] in the lobby settings. Should be called every time the turn changes. Will return an error if something went wrong.
+    ///
+    /// [`ModifierPersistence`]: ../../enums/modifier_persistence/enum.ModifierPersistence.html
+    pub fn apply_modifier_persistence(&mut self) -> Result<(), String> {
+        let current_turn = self.turn_number;
+        match self.lobby_settings.modifier_persistence {
+            ModifierPersistence::Persistent => Ok(()),
+            ModifierPersistence::ResetEachRound => {
+                if self.current_players_turn != InGameID::Orchestrator {
+                    return Ok(());
+                }
+                self.remove_measures_where(|_| true)
+            }
+            ModifierPersistence::DecayAfterNTurns(turns) => {
+                self.remove_measures_where(|enacted_turn| current_turn.saturating_sub(enacted_turn) > turns)
+            }
+        }
+    }
+
+    fn remove_measures_where(&mut self, should_remove: impl Fn(TurnNumber) -> bool) -> Result<(), String> {
+        let edge_restrictions_to_remove: Vec<EdgeRestriction> = self
+            .edge_restrictions
+            .iter()
+            .filter(|restriction| restriction.enacted_turn.is_some_and(&should_remove))
+            .cloned()
+            .collect();
+        for edge_restriction in edge_restrictions_to_remove {
+            match self.remove_restriction_from_edge(&edge_restriction) {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            }
+        }
+
+        let district_modifiers_to_remove: Vec<DistrictModifier> = self
+            .district_modifiers
+            .iter()
+            .filter(|modifier| modifier.enacted_turn.is_some_and(&should_remove))
+            .cloned()
+            .collect();
+        for district_modifier in district_modifiers_to_remove {
+            match self.remove_district_modifier(district_modifier) {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn default_map_name() -> String {
+    DEFAULT_MAP_NAME.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_data::{enums::type_entities_to_transport::TypeEntitiesToTransport, structs::{game_history::GameHistory, game_state_view::GameStateView, timed_prop::TimedProp}};
+
+    /// Creates a started game in its first round, with a player in each of the given roles. The player ids are the role numbers.
+    fn started_game_with_roles(roles: &[InGameID]) -> GameState {
+        let mut game = GameState::new("Test game".to_string(), GameID(1));
+        for role in roles {
+            let mut player = Player::new(PlayerID(*role as i32), format!("{:?}", role));
+            player.in_game_id = *role;
+            game.players.push(player);
+        }
+        game.is_lobby = false;
+        game.round_number = 1;
+        game
+    }
+
+    fn skipped_roles(game: &GameState) -> Vec<InGameID> {
+        game.events
+            .iter()
+            .filter_map(|event| match event.kind {
+                GameEventKind::TurnSkipped(role) => Some(role),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn vacating_the_role_with_the_turn_gives_the_turn_to_the_next_role() {
+        let mut game = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne, InGameID::PlayerTwo, InGameID::PlayerThree]);
+        game.current_players_turn = InGameID::PlayerTwo;
+
+        game.remove_player_with_id(PlayerID(InGameID::PlayerTwo as i32));
+
+        assert_eq!(game.current_players_turn, InGameID::PlayerThree);
+        assert_eq!(game.vacated_roles, vec![InGameID::PlayerTwo]);
+
+        game.next_player_turn();
+        game.next_player_turn();
+        game.next_player_turn();
+
+        assert_eq!(game.current_players_turn, InGameID::PlayerThree);
+        assert_eq!(skipped_roles(&game), vec![InGameID::PlayerTwo]);
+    }
+
+    #[test]
+    fn vacating_every_role_except_the_orchestrator_gives_the_turn_to_the_orchestrator() {
+        let mut game = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne, InGameID::PlayerTwo]);
+        game.current_players_turn = InGameID::PlayerOne;
+
+        game.remove_player_with_id(PlayerID(InGameID::PlayerOne as i32));
+        game.remove_player_with_id(PlayerID(InGameID::PlayerTwo as i32));
+
+        assert_eq!(game.current_players_turn, InGameID::Orchestrator);
+        assert_eq!(game.round_number, 2);
+
+        game.next_player_turn();
+
+        assert_eq!(game.current_players_turn, InGameID::Orchestrator);
+        assert_eq!(game.round_number, 3);
+        assert_eq!(game.first_role_with_player_from(InGameID::PlayerOne), InGameID::Orchestrator);
+        assert_eq!(
+            skipped_roles(&game),
+            vec![InGameID::PlayerOne, InGameID::PlayerTwo, InGameID::PlayerOne, InGameID::PlayerTwo]
+        );
+    }
+
+    #[test]
+    fn roles_are_not_vacated_before_the_game_is_started() {
+        let mut game = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne, InGameID::PlayerTwo]);
+        game.is_lobby = true;
+        game.round_number = 0;
+
+        game.remove_player_with_id(PlayerID(InGameID::PlayerOne as i32));
+
+        assert!(game.vacated_roles.is_empty());
+        assert_eq!(game.first_role_with_player_from(InGameID::PlayerOne), InGameID::PlayerTwo);
+        assert!(skipped_roles(&game).is_empty());
+    }
+
+    #[test]
+    fn a_vacated_role_that_is_claimed_again_gets_its_turn() {
+        let mut game = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne, InGameID::PlayerTwo, InGameID::PlayerThree]);
+        game.current_players_turn = InGameID::PlayerOne;
+        game.remove_player_with_id(PlayerID(InGameID::PlayerTwo as i32));
+        assert_eq!(game.vacated_roles, vec![InGameID::PlayerTwo]);
+
+        let new_player_id = PlayerID(10);
+        assert_eq!(game.assign_player_to_game(Player::new(new_player_id, "Newcomer".to_string())), Ok(()));
+        assert_eq!(game.assign_player_role((new_player_id, InGameID::PlayerTwo)), Ok(()));
+        game.next_player_turn();
+
+        assert!(game.vacated_roles.is_empty());
+        assert_eq!(game.current_players_turn, InGameID::PlayerTwo);
+        assert!(skipped_roles(&game).is_empty());
+    }
+
+    #[test]
+    fn timed_props_do_not_tick_while_the_session_clock_is_paused() {
+        let mut game = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne]);
+        game.session_clock = SessionClock::started(0, None);
+        game.lobby_settings.timed_props.push(TimedProp {
+            name: "Ferry".to_string(),
+            interval_seconds: 10,
+            kind: TimedPropKind::Countdown { ticks: 3, measure_template_id: None },
+        });
+        game.start_timed_props(0);
+
+        game.pause_or_resume_session_clock(&PlayerInputType::RevokeMeasure, 5_000);
+
+        assert!(game.due_timed_props(10_000).is_empty());
+
+        game.pause_or_resume_session_clock(&PlayerInputType::Pass, 25_000);
+
+        assert!(game.due_timed_props(29_999).is_empty());
+        assert_eq!(game.due_timed_props(30_000), vec!["Ferry".to_string()]);
+    }
+
+    #[test]
+    fn objective_cards_of_others_are_hidden_until_the_game_is_finished() {
+        let mut game = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne, InGameID::PlayerTwo]);
+        let card = PlayerObjectiveCard::new("Packages".to_string(), NodeID(1), NodeID(2), NodeID(3), Vec::new(), TypeEntitiesToTransport::Packages, 5);
+        game.players.iter_mut().for_each(|player| player.objective_card = Some(card.clone()));
+        let player_one = PlayerID(InGameID::PlayerOne as i32);
+
+        let mut shown_game = game.clone();
+        shown_game.hide_objective_cards_from_others(Some(player_one));
+        let players_with_cards: Vec<PlayerID> = shown_game.players.iter().filter(|player| player.objective_card.is_some()).map(|player| player.unique_id).collect();
+        assert_eq!(players_with_cards, vec![player_one]);
+
+        game.status = GameStatus::Finished { winners: Vec::new(), reason: Default::default(), winning_side: Default::default() };
+        let mut shown_game = game.clone();
+        shown_game.hide_objective_cards_from_others(None);
+        assert!(shown_game.players.iter().all(|player| player.objective_card.is_some()));
+    }
+
+    #[test]
+    fn finishing_the_game_changes_the_turn_section() {
+        let before = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne]);
+        let mut after = before.clone();
+        after.status = GameStatus::Finished { winners: vec![PlayerID(InGameID::PlayerOne as i32)], reason: Default::default(), winning_side: Default::default() };
+
+        assert_eq!(GameState::changed_sections(&before, &after), vec![StateSection::Turn]);
+
+        let view = serde_json::to_value(GameStateView::new(&after, vec![StateSection::Turn])).unwrap();
+        assert_eq!(view["status"], serde_json::to_value(&after.status).unwrap());
+    }
+
+    #[test]
+    fn checkpoints_are_restored_from_the_copies_in_the_history() {
+        let mut game = started_game_with_roles(&[InGameID::Orchestrator, InGameID::PlayerOne]);
+        let mut history = GameHistory::default();
+        history.create_checkpoint(&mut game, "Before the measures".to_string()).unwrap();
+        game.turn_number = 7;
+
+        assert!(game.clone().restore_checkpoint("Before the measures", None).is_err());
+        history.restore_checkpoint(&mut game, "Before the measures").unwrap();
+
+        assert_eq!(game.turn_number, 0);
+        assert_eq!(game.checkpoints.len(), 1);
+        assert_eq!(game.archived_branches.len(), 1);
+        assert_eq!(history.checkpoint_snapshots.len(), 1);
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{Emissions, RoundNumber, TurnNumber}, enums::{in_game_id::InGameID, win_condition::WinCondition}};
+
+use super::gamestate::GameState;
+
+/// The GroupMetrics struct describes how the whole group is doing against the group targets of a cooperative game in a round, see [`WinCondition::Cooperative`].
+///
+/// It's measured by the GroupMetricsExtension when the round starts and at the end of every turn.
+///
+/// [`WinCondition::Cooperative`]: ../../enums/win_condition/enum.WinCondition.html
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct GroupMetrics {
+    pub round_number: RoundNumber,
+    /// The sum of the emissions of the travellers.
+    pub total_emissions: Emissions,
+    /// The average amount of turns the travellers have spent on their commute, rounded up: the turns it took each traveller to drop off what their objective card asks for,
+    /// or the turns they have travelled so far if they have not done it yet. It's counted from the turn the game was started in, over all the turns like the most turns of the lobby settings.
+    pub average_commute_turns: TurnNumber,
+    /// The amount of travellers that have dropped off what their objective card asks for.
+    pub travellers_arrived: u32,
+    pub travellers: u32,
+    /// True if the group is within all the group targets of the win condition.
+    pub within_targets: bool,
+}
+
+impl GroupMetrics {
+    /// Measures how the travellers of the game are doing, and whether that is within the group targets of the cooperative win condition of the game. The metrics are always within the targets of the other win conditions.
+    #[must_use]
+    pub fn measure(game: &GameState) -> Self {
+        let travellers: Vec<_> = game.players.iter().filter(|player| player.in_game_id != InGameID::Orchestrator).collect();
+        let total_emissions: Emissions = travellers.iter().map(|player| player.emissions).sum();
+        let total_commute_turns: TurnNumber = travellers
+            .iter()
+            .map(|player| {
+                game.statistics
+                    .objective_completion_turn_of_role(player.in_game_id)
+                    .unwrap_or(game.turn_number)
+                    .saturating_sub(game.statistics.started_in_turn)
+            })
+            .sum();
+        let traveller_count = u32::try_from(travellers.len()).unwrap_or(u32::MAX);
+        let average_commute_turns = if traveller_count == 0 { 0 } else { total_commute_turns.div_ceil(traveller_count) };
+        let travellers_arrived = travellers
+            .iter()
+            .filter(|player| player.objective_card.as_ref().is_some_and(|objective_card| objective_card.dropped_package_off))
+            .count();
+        let within_targets = match game.lobby_settings.win_condition {
+            WinCondition::Cooperative { max_total_emissions, max_average_commute_turns } => {
+                max_total_emissions.is_none_or(|max_total_emissions| total_emissions <= max_total_emissions)
+                    && max_average_commute_turns.is_none_or(|max_average_commute_turns| average_commute_turns <= max_average_commute_turns)
+            }
+            _ => true,
+        };
+        Self {
+            round_number: game.round_number,
+            total_emissions,
+            average_commute_turns,
+            travellers_arrived: u32::try_from(travellers_arrived).unwrap_or(u32::MAX),
+            travellers: traveller_count,
+            within_targets,
+        }
+    }
+}
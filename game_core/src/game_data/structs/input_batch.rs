@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use super::gamestate::GameState;
+
+/// The InputBatchExecution struct describes the outcome of handling a batch of inputs from one player. The inputs are handled one by one in order, and the batch stops at the first input that fails.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct InputBatchExecution {
+    /// The game after the handled inputs.
+    pub game: GameState,
+    /// How many of the inputs were handled, counted from the start of the batch.
+    pub handled_inputs: usize,
+    /// How many of the inputs were not handled, starting with the input that failed.
+    pub unhandled_inputs: usize,
+    /// Why the first of the unhandled inputs failed, if any inputs were not handled.
+    pub error: Option<String>,
+}
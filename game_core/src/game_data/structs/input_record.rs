@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{ErrorData, Timestamp, TurnNumber}, enums::export_format::ExportFormat};
+
+use super::{player_input::PlayerInput, rule_override::RuleOverride, rule_violation::RuleViolation, session_clock::current_timestamp};
+
+/// What happened to an input a player sent to the server.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum InputOutcome {
+    /// The input was valid and was applied or added to the staged actions of the turn.
+    Accepted,
+    /// The input was accepted as a staged action, but was later undone by the player.
+    Undone,
+    /// The input broke one of the rules of the game.
+    Rejected(RuleViolation),
+    /// The input followed the rules, but could not be applied to the game.
+    Failed(ErrorData),
+    /// The input only broke lenient rules of the facilitated rule profile, and waits for the orchestrator to accept it. The first rule it broke is given.
+    AwaitingOverride(RuleViolation),
+    /// The input was applied or added to the staged actions of the turn without checking some of the rules, because the orchestrator overrode them.
+    Overridden(RuleOverride),
+}
+
+/// The InputRecord struct describes an input a player sent to the server, when it was received and what the outcome was.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct InputRecord {
+    /// The turn of the game the input was received in.
+    pub turn_number: TurnNumber,
+    /// When the input was received, in milliseconds since the unix epoch.
+    pub timestamp: Timestamp,
+    pub input: PlayerInput,
+    pub outcome: InputOutcome,
+}
+
+impl InputRecord {
+    /// Creates a new InputRecord with the current time as the timestamp.
+    #[must_use]
+    pub fn new(turn_number: TurnNumber, input: PlayerInput, outcome: InputOutcome) -> Self {
+        Self {
+            turn_number,
+            timestamp: current_timestamp(),
+            input,
+            outcome,
+        }
+    }
+
+    /// Returns the record as a row of the given export format, ending with a newline.
+    pub fn to_export_row(&self, format: ExportFormat) -> Result<String, String> {
+        match format {
+            ExportFormat::Ndjson => serde_json::to_string(self)
+                .map(|row| row + "\n")
+                .map_err(|e| format!("Failed to serialize the input record because: {e}")),
+            ExportFormat::Csv => {
+                let input = serde_json::to_string(&self.input).map_err(|e| format!("Failed to serialize the input because: {e}"))?;
+                let (outcome, rule_name, message) = match &self.outcome {
+                    InputOutcome::Accepted => ("Accepted", String::new(), String::new()),
+                    InputOutcome::Undone => ("Undone", String::new(), String::new()),
+                    InputOutcome::Rejected(violation) => ("Rejected", violation.rule_name.clone(), violation.message.clone()),
+                    InputOutcome::Failed(message) => ("Failed", String::new(), message.clone()),
+                    InputOutcome::AwaitingOverride(violation) => ("AwaitingOverride", violation.rule_name.clone(), violation.message.clone()),
+                    InputOutcome::Overridden(rule_override) => (
+                        "Overridden",
+                        rule_override.rules.join(";"),
+                        rule_override.justification.clone().unwrap_or_default(),
+                    ),
+                };
+                Ok(format!(
+                    "{},{},{},{:?},{},{},{},{}\n",
+                    self.turn_number,
+                    self.timestamp,
+                    self.input.player_id,
+                    self.input.input_type,
+                    outcome,
+                    csv_field(&rule_name),
+                    csv_field(&message),
+                    csv_field(&input),
+                ))
+            }
+        }
+    }
+}
+
+/// Quotes the field if it contains a comma, a quote or a line break, as CSV requires.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
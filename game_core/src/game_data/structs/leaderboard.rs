@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{Emissions, Money, PlayerID, Score}, enums::{achievement::Achievement, in_game_id::InGameID}};
+
+use super::{gamestate::GameState, player::Player, player_objective_card::PlayerObjectiveCard, score_weights::ScoreWeights};
+
+/// The LeaderboardEntry struct describes how a player has done in all the rounds of a session put together.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct LeaderboardEntry {
+    /// The place of the player on the leaderboard, starting at 1. Players with the same score share the place.
+    pub rank: usize,
+    pub player_id: PlayerID,
+    pub name: String,
+    /// The role of the player in the latest round.
+    pub role: InGameID,
+    /// The amount of rounds the player has played in.
+    pub rounds_played: usize,
+    /// The sum of the points the player got from the objective cards.
+    pub objective_points: Score,
+    /// The sum of the emissions the player was responsible for.
+    pub emissions: Emissions,
+    /// The sum of the money the player had left of the budget at the end of each round.
+    pub budget_left: Money,
+    /// The sum of the weighted scores of each round.
+    pub score: Score,
+    /// The achievements the player earned, once for every round they were earned in.
+    pub achievements: Vec<Achievement>,
+}
+
+/// The Leaderboard struct ranks the players of a session by their cumulative score across the rounds (the scenarios of a campaign), weighted with the score weights of the game.
+///
+/// A game that is not part of a campaign is a session with one round.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Leaderboard {
+    pub score_weights: ScoreWeights,
+    /// The amount of rounds that are counted, including the round being played if it has started.
+    pub rounds: usize,
+    /// The players, best first. The orchestrator is not on the leaderboard.
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// Creates the leaderboard of the session the game is part of, from the completed scenarios of the campaign and the game itself if it has started.
+    #[must_use]
+    pub fn new(game: &GameState) -> Self {
+        let mut rounds: Vec<&[Player]> = game
+            .campaign
+            .as_ref()
+            .map(|campaign| campaign.completed_scenarios.iter().map(|summary| summary.players.as_slice()).collect())
+            .unwrap_or_default();
+        if !game.is_lobby {
+            rounds.push(&game.players);
+        }
+
+        let mut entries: Vec<LeaderboardEntry> = Vec::new();
+        for player in rounds.iter().flat_map(|players| players.iter()).filter(|player| player.in_game_id != InGameID::Orchestrator) {
+            let objective_points = player.objective_card.as_ref().map_or(0, PlayerObjectiveCard::earned_score);
+            let score = game.score_weights.weighted_score(objective_points, player.emissions, player.budget);
+            let index = entries.iter().position(|entry| entry.player_id == player.unique_id).unwrap_or_else(|| {
+                entries.push(LeaderboardEntry {
+                    rank: 0,
+                    player_id: player.unique_id,
+                    name: player.name.clone(),
+                    role: player.in_game_id,
+                    rounds_played: 0,
+                    objective_points: 0,
+                    emissions: 0,
+                    budget_left: 0,
+                    score: 0,
+                    achievements: Vec::new(),
+                });
+                entries.len() - 1
+            });
+            let entry = &mut entries[index];
+            entry.name = player.name.clone();
+            entry.role = player.in_game_id;
+            entry.rounds_played += 1;
+            entry.objective_points = entry.objective_points.saturating_add(objective_points);
+            entry.emissions = entry.emissions.saturating_add(player.emissions);
+            entry.budget_left = entry.budget_left.saturating_add(player.budget);
+            entry.score = entry.score.saturating_add(score);
+            entry.achievements.extend_from_slice(&player.achievements);
+        }
+
+        entries.sort_by(|a, b| b.score.cmp(&a.score).then(a.player_id.cmp(&b.player_id)));
+        let scores: Vec<Score> = entries.iter().map(|entry| entry.score).collect();
+        entries
+            .iter_mut()
+            .for_each(|entry| entry.rank = scores.iter().filter(|score| **score > entry.score).count() + 1);
+
+        Self {
+            score_weights: game.score_weights,
+            rounds: rounds.len(),
+            entries,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::enums::{modifier_persistence::ModifierPersistence, game_length::GameLength, move_refill::MoveRefill, session_reminder::SessionReminder, turn_end_policy::TurnEndPolicy, rule_profile::RuleProfile, win_condition::WinCondition};
+use crate::game_data::custom_types::TurnNumber;
+
+use crate::game_data::constants::{DEFAULT_LENIENT_RULES, MAX_STAGED_ACTIONS};
+
+use super::{bot_seat::BotSeat, deal_constraints::DealConstraints, orchestrator_targets::OrchestratorTargets, timed_prop::TimedProp};
+
+/// The LobbySettings struct describes the settings (game variant) the orchestrator has chosen for the game.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+pub struct LobbySettings {
+    #[serde(default)]
+    pub modifier_persistence: ModifierPersistence,
+    /// Used to deal objective cards with a fitting distance when the game starts.
+    #[serde(default)]
+    pub game_length: GameLength,
+    /// The constraints on how the objective cards are dealt when the game starts, usually set by a scenario.
+    #[serde(default)]
+    pub deal_constraints: DealConstraints,
+    /// The most moves (movement inputs) a player can do in one turn. There is no limit if it's not set.
+    #[serde(default)]
+    pub max_moves_per_turn: Option<u32>,
+    /// The least moves (movement inputs) a player has to do before ending their turn. Players that have no moves left, are riding with someone or have delivered what their objective card asks for do not have to move.
+    #[serde(default)]
+    pub min_moves_per_turn: Option<u32>,
+    /// The most actions (like moves and measures) that can be staged in one turn. [`MAX_STAGED_ACTIONS`](../../constants/constant.MAX_STAGED_ACTIONS.html) is used if it's not set, which is also the highest allowed value.
+    #[serde(default)]
+    pub max_staged_actions: Option<u32>,
+    #[serde(default)]
+    pub move_refill: MoveRefill,
+    /// Decides if the players have to pass before they can end their turn.
+    #[serde(default)]
+    pub turn_end_policy: TurnEndPolicy,
+    /// If true the orchestrator can send district modifiers and edge restrictions during the turns of the other players. They are applied when the turn ends, so that the plan of the player that is moving stays valid.
+    #[serde(default)]
+    pub async_measures: bool,
+    /// How long the session is planned to be, in minutes. The players are only reminded of the time left if it's set.
+    #[serde(default)]
+    pub session_length_minutes: Option<u32>,
+    /// When the players are reminded of how much time is left of the session. [`SessionReminder::DEFAULT`] is used if it's not set.
+    #[serde(default)]
+    pub session_reminders: Option<Vec<SessionReminder>>,
+    /// The props that change the game as time passes, independent of the turns. The server only ticks the games that have timed props.
+    /// Left out when there are none, so that the state hash of the games without timed props is the same as before they existed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timed_props: Vec<TimedProp>,
+    /// How strictly the server enforces the rules.
+    #[serde(default, skip_serializing_if = "RuleProfile::is_strict")]
+    pub rule_profile: RuleProfile,
+    /// The names of the rules that only give a warning with the facilitated rule profile. The default lenient rules are used if it's empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lenient_rules: Vec<String>,
+    /// What the orchestrator has to achieve to win the game, which finishes the game when it's met. Left out when it's not set, so that the state hash of the games without targets is the same as before they existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orchestrator_targets: Option<OrchestratorTargets>,
+    /// What success means in the scenario, which decides who wins when the game is finished. Left out when it's the default, so that the state hash of the games is the same as before it existed.
+    #[serde(default, skip_serializing_if = "WinCondition::is_all_objectives_reached")]
+    pub win_condition: WinCondition,
+    /// The most turns the game lasts, counted over all the rounds and including the turns of the orchestrator. The game is finished when the last of them ends. There is no limit if it's not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_turns: Option<TurnNumber>,
+    /// The seats the orchestrator has filled with bot players, which join the game as players and play their turns by themselves. Left out when there are none, so that the state hash of the games without bots is the same as before they existed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bots: Vec<BotSeat>,
+}
+
+impl LobbySettings {
+    /// Returns when the players should be reminded of how much time is left of the session.
+    pub fn session_reminders(&self) -> &[SessionReminder] {
+        self.session_reminders.as_deref().unwrap_or(&SessionReminder::DEFAULT)
+    }
+
+    /// Returns `true` if breaking the rule with the given name only gives a warning, which is the case for the lenient rules with the facilitated rule profile.
+    pub fn is_rule_lenient(&self, rule_name: &str) -> bool {
+        if self.rule_profile != RuleProfile::Facilitated {
+            return false;
+        }
+        match self.lenient_rules.is_empty() {
+            true => DEFAULT_LENIENT_RULES.contains(&rule_name),
+            false => self.lenient_rules.iter().any(|name| name == rule_name),
+        }
+    }
+
+    /// Returns the most actions that can be staged in one turn.
+    pub fn max_staged_actions(&self) -> usize {
+        self.max_staged_actions
+            .map_or(MAX_STAGED_ACTIONS, |max_actions| usize::try_from(max_actions).unwrap_or(usize::MAX).min(MAX_STAGED_ACTIONS))
+    }
+}
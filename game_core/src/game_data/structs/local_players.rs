@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{ControlToken, NodeID, PlayerID};
+
+use super::{gamestate::GameState, move_plan::PlannedMove};
+
+/// The LocalPlayers struct describes the players that are played from the same client (hot-seat play), all controlled with the same control token.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct LocalPlayers {
+    pub control_token: ControlToken,
+    pub player_ids: Vec<PlayerID>,
+}
+
+impl LocalPlayers {
+    /// Creates a new LocalPlayers without any players.
+    #[must_use]
+    pub const fn new(control_token: ControlToken) -> Self {
+        Self {
+            control_token,
+            player_ids: Vec::new(),
+        }
+    }
+}
+
+/// The PlayerView struct describes what is specific to one player when looking at a game.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PlayerView {
+    pub player_id: PlayerID,
+    pub legal_nodes: Vec<NodeID>,
+    /// The moves the player plans to make in their next turn.
+    pub move_plan: Vec<PlannedMove>,
+    /// True if it's the turn of the player and the player has a plan, so that the client can offer to execute it.
+    pub can_execute_plan: bool,
+}
+
+/// The LocalPlayersView struct bundles the game and the views of all the local players of a client in the game, so that a hot-seat client only needs one request.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct LocalPlayersView {
+    pub game: GameState,
+    pub player_views: Vec<PlayerView>,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::TurnNumber, enums::annotation_target::AnnotationTarget};
+
+/// The MapAnnotation struct describes a short note the orchestrator has attached to a district or an edge, like why a measure was enacted there ("school zone"). The clients show it as a label on the map.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MapAnnotation {
+    pub target: AnnotationTarget,
+    pub text: String,
+    /// The turn the note was written or last changed in.
+    pub turn_number: TurnNumber,
+}
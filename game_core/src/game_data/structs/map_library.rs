@@ -0,0 +1,151 @@
+use std::{collections::HashMap, fs, path::Path, sync::Arc};
+
+use crate::{map_loader::{is_map_file, load_map_file}, map_validation::validate_map};
+
+use super::{map_report::MapReport, node_map::NodeMap};
+
+/// The name of the map that is built into the server. A map file with this name is ignored.
+pub const DEFAULT_MAP_NAME: &str = "default";
+
+/// The MapLibrary struct contains the maps the server can use and the validation reports of all the maps it has tried to load.
+///
+/// Only maps without issues are kept, so a map that is not valid is never served.
+#[derive(Clone, Debug)]
+pub struct MapLibrary {
+    maps: HashMap<String, Arc<NodeMap>>,
+    reports: Vec<MapReport>,
+    /// The names of the maps that came from the active content pack, so that they can be replaced when another pack is activated.
+    content_pack_map_names: Vec<String>,
+}
+
+impl Default for MapLibrary {
+    fn default() -> Self {
+        let default_map = NodeMap::shared_default();
+        let report = validate_map(DEFAULT_MAP_NAME, &default_map);
+        let mut maps = HashMap::new();
+        maps.insert(DEFAULT_MAP_NAME.to_string(), default_map);
+        Self {
+            maps,
+            reports: vec![report],
+            content_pack_map_names: Vec::new(),
+        }
+    }
+}
+
+impl MapLibrary {
+    /// Creates a MapLibrary with the default map and every JSON and GeoJSON map file in the folder, named after the file without the extension. See the [`map_loader`] module for the formats.
+    ///
+    /// Every map is validated and the ones with issues are left out. Returns an error if the folder could not be read. If the folder does not exist only the default map is loaded.
+    ///
+    /// [`map_loader`]: ../../../map_loader/index.html
+    pub fn load_from_folder(folder: &Path) -> Result<Self, String> {
+        let mut library = Self::default();
+        if !folder.is_dir() {
+            return Ok(library);
+        }
+
+        let entries = match fs::read_dir(folder) {
+            Ok(entries) => entries,
+            Err(e) => return Err(format!("Failed to read the map folder {} because: {e}", folder.display())),
+        };
+
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && is_map_file(path))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let Some(name) = path.file_stem().map(|stem| stem.to_string_lossy().to_string()) else {
+                continue;
+            };
+            let map = load_map_file(&path);
+            let report = match map {
+                _ if name == DEFAULT_MAP_NAME => MapReport {
+                    name: name.clone(),
+                    nodes: 0,
+                    edges: 0,
+                    districts: 0,
+                    issues: vec![format!("The name {DEFAULT_MAP_NAME} is used by the map built into the server!")],
+                    warnings: Vec::new(),
+                    content_hash: None,
+                },
+                Ok(map) => {
+                    let report = validate_map(&name, &map);
+                    if report.is_valid() {
+                        library.maps.insert(name.clone(), completed_map(map));
+                    }
+                    report
+                }
+                Err(e) => MapReport {
+                    name: name.clone(),
+                    nodes: 0,
+                    edges: 0,
+                    districts: 0,
+                    issues: vec![e],
+                    warnings: Vec::new(),
+                    content_hash: None,
+                },
+            };
+            library.reports.push(report);
+        }
+        Ok(library)
+    }
+
+    /// Returns the map with the given name. Returns an error if the map does not exist or was not valid.
+    pub fn get_map(&self, name: &str) -> Result<Arc<NodeMap>, String> {
+        if let Some(map) = self.maps.get(name) {
+            return Ok(map.clone());
+        }
+        let Some(report) = self.reports.iter().find(|report| report.name == name) else {
+            return Err(format!("There is no map called {name}!"));
+        };
+        Err(format!("The map {} can not be used because it has the issues: {}", name, report.issues.join(" ")))
+    }
+
+    /// Returns the names of the maps that can be used, sorted by name.
+    pub fn map_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.maps.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Returns the validation reports of every map the library has tried to load, including the ones that are not valid.
+    pub fn reports(&self) -> &[MapReport] {
+        &self.reports
+    }
+
+    /// Replaces the maps from the active content pack with the given maps, and returns their validation reports.
+    ///
+    /// Like the maps in the map folder, the maps with issues are left out. A map with the same name as a map that is not from a content pack is also left out.
+    pub fn set_content_pack_maps(&mut self, maps: Vec<(String, NodeMap)>) -> Vec<MapReport> {
+        for name in self.content_pack_map_names.drain(..) {
+            self.maps.remove(&name);
+            self.reports.retain(|report| report.name != name);
+        }
+
+        let mut reports = Vec::new();
+        for (name, map) in maps {
+            let mut report = validate_map(&name, &map);
+            if self.maps.contains_key(&name) {
+                report.issues.push(format!("There is already a map called {name}!"));
+            }
+            if report.is_valid() {
+                self.maps.insert(name.clone(), completed_map(map));
+                self.content_pack_map_names.push(name);
+                self.reports.push(report.clone());
+            }
+            reports.push(report);
+        }
+        reports
+    }
+}
+
+/// Returns the map with the relationships in the opposite direction that its edges are missing, if the map asks for them to be generated.
+fn completed_map(mut map: NodeMap) -> Arc<NodeMap> {
+    if map.generate_reverse_relationships {
+        map.add_missing_reverse_relationships();
+    }
+    Arc::new(map)
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{EdgeID, MovementCost, NodeID}, enums::{district::District, restriction_type::RestrictionType}};
+
+/// The RuntimeEdgeState struct describes how an edge has been changed while the game is played. It replaces the restriction of the edge in the base map.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct RuntimeEdgeState {
+    /// The restriction on the edge. `None` means that the edge has no restriction, even if it has one in the base map.
+    pub restriction: Option<RestrictionType>,
+    pub is_modifiable: bool,
+    /// If set, the state only applies to the edge going from this node, like for one way streets. The other direction is as it is in the base map.
+    pub from_node_id: Option<NodeID>,
+}
+
+impl RuntimeEdgeState {
+    /// Returns `true` if the state applies to the edge going from the node with the given id.
+    pub fn applies_from(&self, node_id: NodeID) -> bool {
+        self.from_node_id.is_none() || self.from_node_id == Some(node_id)
+    }
+}
+
+/// The MapOverlay struct contains every change made to the base map of a game while it's played, so the base map can be shared between the games and never changes.
+///
+/// The edges are keyed by their id. Removing the entry of an edge brings back the edge as it is in the base map.
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MapOverlay {
+    pub edges: HashMap<EdgeID, RuntimeEdgeState>,
+    /// The cost of moving within the districts, replacing the cost in the base map.
+    pub neighbourhood_cost: HashMap<District, MovementCost>,
+    /// The nodes where the connection to the railway is the opposite of the base map.
+    pub toggled_rail_nodes: Vec<NodeID>,
+    /// How much more it costs to move along the edges, like while there are construction works on them.
+    #[serde(default)]
+    pub extra_edge_costs: HashMap<EdgeID, MovementCost>,
+    /// The edges that can't be used at all, like while there are construction works on them.
+    #[serde(default)]
+    pub closed_edges: Vec<EdgeID>,
+}
+
+impl MapOverlay {
+    /// Returns the runtime state of the edge going from the node with the given id, if it has been changed.
+    pub fn get_edge_state(&self, edge_id: EdgeID, from_node_id: NodeID) -> Option<&RuntimeEdgeState> {
+        self.edges
+            .get(&edge_id)
+            .filter(|edge_state| edge_state.applies_from(from_node_id))
+    }
+
+    /// Sets the runtime state of the edge with the given id.
+    pub fn set_edge_state(&mut self, edge_id: EdgeID, edge_state: RuntimeEdgeState) {
+        self.edges.insert(edge_id, edge_state);
+    }
+
+    /// Removes the runtime state of the edge with the given id, so the edge is as it is in the base map.
+    pub fn clear_edge_state(&mut self, edge_id: EdgeID) {
+        self.edges.remove(&edge_id);
+    }
+
+    /// Returns `true` if the edge with the given id is closed.
+    pub fn is_edge_closed(&self, edge_id: EdgeID) -> bool {
+        self.closed_edges.contains(&edge_id)
+    }
+
+    /// Toggles the connection to the railway of the node with the given id.
+    pub fn toggle_rail_connection(&mut self, node_id: NodeID) {
+        match self.toggled_rail_nodes.iter().position(|id| *id == node_id) {
+            Some(position) => {
+                self.toggled_rail_nodes.remove(position);
+            }
+            None => self.toggled_rail_nodes.push(node_id),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::StateHash;
+
+/// The MapReport struct describes the outcome of validating a map. A map with any issues is not valid and is not used by the server.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MapReport {
+    /// The name of the map, which is the file name without the extension for the maps loaded from the maps folder.
+    pub name: String,
+    pub nodes: usize,
+    pub edges: usize,
+    pub districts: usize,
+    pub issues: Vec<String>,
+    /// What the map author should know about the map even though it's valid, like the relationships that were generated because the edges were missing them.
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// The content hash of the map, which the log entries about the games played on the map refer to. `None` if the map could not be read.
+    #[serde(default)]
+    pub content_hash: Option<StateHash>,
+}
+
+impl MapReport {
+    /// Returns `true` if the map has no issues.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::MeasureTemplateID;
+
+use super::{district_modifier::DistrictModifier, edge_restriction::EdgeRestriction};
+
+/// The MeasureTemplate struct describes a predefined measure the orchestrator can enact with a single input. Enacting it adds all of its district modifiers and edge restrictions to the game, or none of them if one fails.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MeasureTemplate {
+    pub id: MeasureTemplateID,
+    pub name: String,
+    pub description: String,
+    pub district_modifiers: Vec<DistrictModifier>,
+    pub edge_restrictions: Vec<EdgeRestriction>,
+}
+
+impl MeasureTemplate {
+    #[must_use]
+    pub const fn new(
+        id: MeasureTemplateID,
+        name: String,
+        description: String,
+        district_modifiers: Vec<DistrictModifier>,
+        edge_restrictions: Vec<EdgeRestriction>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            description,
+            district_modifiers,
+            edge_restrictions,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::enums::{district::District, district_modifier_type::DistrictModifierType};
+
+use super::district_modifier::DistrictModifier;
+
+/// The ModifierSlots struct describes how many more modifiers of a type can be added to a district before it reaches the cap of that type.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ModifierSlots {
+    pub district: District,
+    pub modifier: DistrictModifierType,
+    /// How many modifiers of the type the district can have at the same time.
+    pub max: usize,
+    pub remaining: usize,
+}
+
+impl ModifierSlots {
+    /// Counts the remaining slots of every type of modifier in every district, given the district modifiers of a game.
+    #[must_use]
+    pub fn count(district_modifiers: &[DistrictModifier]) -> Vec<Self> {
+        let mut slots = Vec::new();
+        let mut district = Some(District::first());
+        while let Some(current_district) = district {
+            for modifier in DistrictModifierType::ALL {
+                let used = district_modifiers
+                    .iter()
+                    .filter(|district_modifier| district_modifier.district == current_district && district_modifier.modifier == modifier)
+                    .count();
+                slots.push(Self {
+                    district: current_district,
+                    modifier,
+                    max: modifier.max_count(),
+                    remaining: modifier.max_count().saturating_sub(used),
+                });
+            }
+            district = current_district.next();
+        }
+        slots
+    }
+
+    /// Returns `true` if no more modifiers of the type can be added to the district.
+    pub const fn is_full(&self) -> bool {
+        self.remaining == 0
+    }
+}
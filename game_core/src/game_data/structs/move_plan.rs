@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{EdgeID, NodeID, PlayerID};
+
+use super::gamestate::GameState;
+
+/// One move in a [`MovePlan`].
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct PlannedMove {
+    pub to_node_id: NodeID,
+    /// The edge to move along. Only needed if there are several edges between the nodes, like a road and a railway.
+    #[serde(default)]
+    pub edge_id: Option<EdgeID>,
+}
+
+/// The MovePlan struct is the moves a player plans to make in their next turn, made while waiting for the other players. The plan is not checked against the rules until it's executed.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MovePlan {
+    pub player_id: PlayerID,
+    pub moves: Vec<PlannedMove>,
+}
+
+/// The MovePlanExecution struct describes the outcome of executing a move plan. The moves are sent as movement inputs one by one, and the execution stops at the first move that is not valid.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MovePlanExecution {
+    /// The game after the executed moves.
+    pub game: GameState,
+    pub executed_moves: usize,
+    /// The moves that were not executed, which are kept as the plan of the player.
+    pub remaining_moves: Vec<PlannedMove>,
+    /// Why the first of the remaining moves could not be executed, if any moves remain.
+    pub error: Option<String>,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{
+    custom_types::{Money, MovementValue, NodeID, PlayerID},
+    enums::restriction_type::RestrictionType,
+};
+
+/// The MovementOutcome struct describes what happened when a player moved from one node to another.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MovementOutcome {
+    pub player_id: PlayerID,
+    pub from_node_id: NodeID,
+    pub to_node_id: NodeID,
+    /// The moves the move cost the player, including the cost of entering a district for the first time in the turn and the extra cost of cargo, minus any bonus moves from the district modifiers.
+    pub moves_paid: MovementValue,
+    /// The congestion charge the player paid for entering the congestion zone.
+    pub congestion_charge: Money,
+    /// The restriction of the edge the player moved along, if it had one.
+    pub restriction_crossed: Option<RestrictionType>,
+    pub by_rail: bool,
+    /// True if the player picked up what the objective card asks for with this move.
+    pub picked_up_objective: bool,
+    /// True if the player dropped off what the objective card asks for with this move.
+    pub delivered_objective: bool,
+}
+
+impl MovementOutcome {
+    /// Creates the outcome of a move that has not paid anything yet.
+    #[must_use]
+    pub const fn new(player_id: PlayerID, from_node_id: NodeID, to_node_id: NodeID) -> Self {
+        Self {
+            player_id,
+            from_node_id,
+            to_node_id,
+            moves_paid: 0,
+            congestion_charge: 0,
+            restriction_crossed: None,
+            by_rail: false,
+            picked_up_objective: false,
+            delivered_objective: false,
+        }
+    }
+
+    /// Returns `true` if the move picked up or delivered what the objective card of the player asks for.
+    pub const fn reached_objective(&self) -> bool {
+        self.picked_up_objective || self.delivered_objective
+    }
+}
@@ -1,32 +1,47 @@
 use serde::{Deserialize, Serialize};
 
-use crate::game_data::{custom_types::{NodeID, MovementCost}, enums::{district::District, restriction_type::RestrictionType}};
+use crate::game_data::{custom_types::{NodeID, MovementCost, EdgeID}, enums::{district::District, restriction_type::RestrictionType}};
+
+use super::ferry_schedule::FerrySchedule;
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct NeighbourRelationship {
+    /// The id of the edge. Both directions of an edge have the same id.
+    pub edge_id: EdgeID,
     pub to: NodeID,
     pub neighbourhood: District,
     pub movement_cost: MovementCost,
     pub is_connected_through_rail: bool,
     pub restriction: Option<RestrictionType>,
     pub is_modifiable: bool,
+    /// The schedule of the ferry if the edge is a water crossing, which can only be used in the rounds the ferry departs in. Both directions of the edge should have the same schedule.
+    /// Left out when the edge is not a ferry, so that the content hash of the maps without ferries is the same as before ferries existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ferry: Option<FerrySchedule>,
+    /// If the edge can only be used from the node it's listed under, so that it's not expected to have a relationship in the opposite direction. Left out when it's false, like `ferry`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_one_way: bool,
 }
 
 impl NeighbourRelationship {
     /// Creates a new NeighbourRelationship. Note: is_modifiable is set to true by default.
     pub const fn new(
+        edge_id: EdgeID,
         to: NodeID,
         neighbourhood: District,
         movement_cost: MovementCost,
         is_connected_through_rail: bool,
     ) -> Self {
         Self {
+            edge_id,
             to,
             neighbourhood,
             movement_cost,
             is_connected_through_rail,
             restriction: None,
             is_modifiable: true,
+            ferry: None,
+            is_one_way: false,
         }
     }
 }
\ No newline at end of file
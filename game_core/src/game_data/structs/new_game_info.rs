@@ -1,10 +1,22 @@
 use serde::{Deserialize, Serialize};
 
-use super::player::Player;
+use super::{player::Player, score_weights::ScoreWeights};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct NewGameInfo {
     pub host: Player,
     pub name: String,
+    /// The name of the objective deck the objective cards of the game are dealt from. The objective cards of the situation card are used if it's not set.
+    #[serde(default)]
+    pub objective_deck: Option<String>,
+    /// The names of the scenarios of the active content pack the players play in a row as a campaign, starting with the first. The game is not part of a campaign if it's empty.
+    #[serde(default)]
+    pub campaign: Vec<String>,
+    /// The name of the map in the map library the game is played on, like a map of another city from the maps folder. The default map is used if it's not set.
+    #[serde(default)]
+    pub map: Option<String>,
+    /// How much the objective points, the emissions and the budget count on the leaderboard of the session. The default weights are used if it's not set.
+    #[serde(default)]
+    pub score_weights: Option<ScoreWeights>,
 }
 
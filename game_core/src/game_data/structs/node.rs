@@ -2,12 +2,22 @@ use serde::{Deserialize, Serialize};
 
 use crate::game_data::custom_types::NodeID;
 
+use super::{accessibility::Accessibility, node_coordinates::NodeCoordinates};
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Node {
     pub id: NodeID,
     pub name: String,
     pub is_connected_to_rail: bool,
     pub is_parking_spot: bool,
+    #[serde(default)]
+    pub accessibility: Accessibility,
+    /// Words that describe the node beyond its name, like `hospital` or `school`, used when searching the map.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Where the node is drawn on the map. Not set for maps that are not drawn to coordinates.
+    #[serde(default)]
+    pub coordinates: Option<NodeCoordinates>,
 }
 
 impl Node {
@@ -18,11 +28,18 @@ impl Node {
             name,
             is_parking_spot: false,
             is_connected_to_rail: false,
+            accessibility: Accessibility {
+                has_elevator: false,
+                is_step_free: true,
+                is_lit: true,
+            },
+            tags: Vec::new(),
+            coordinates: None,
         }
     }
 
     /// Toggles the is_connected_to_rail field.
-    pub fn toggle_rail_connection(&mut self) {
+    pub const fn toggle_rail_connection(&mut self) {
         self.is_connected_to_rail = !self.is_connected_to_rail;
     }
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// The NodeCoordinates struct describes where a node is drawn on the map, so that the clients can show and zoom to it.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct NodeCoordinates {
+    pub x: f64,
+    pub y: f64,
+}
@@ -1,14 +1,34 @@
-use std::{collections::HashMap, mem};
+use std::{collections::HashMap, sync::Arc};
 
-use crate::game_data::{enums::{district::District, restriction_type::RestrictionType}, custom_types::{NodeID, MovementCost}};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
 
-use super::{node::Node, neighbour_relationship::NeighbourRelationship, edge_restriction::EdgeRestriction, situation_card::SituationCard};
+use crate::game_data::{enums::district::District, custom_types::{AssetID, NodeID, MovementCost, EdgeID}};
 
-#[derive(Clone, Default, Debug)]
+use super::{accessibility::Accessibility, node::Node, neighbour_relationship::NeighbourRelationship};
+
+lazy_static! {
+    static ref DEFAULT_MAP: Arc<NodeMap> = Arc::new(NodeMap::new_default());
+}
+
+/// The NodeMap struct describes the nodes and edges of a map as it is before the game starts. The changes made to the map while a game is played are kept in the [`GameMap`] of the game.
+///
+/// [`GameMap`]: ../game_map/struct.GameMap.html
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct NodeMap {
     pub nodes: Vec<Node>,
     pub edges: HashMap<NodeID, Vec<NeighbourRelationship>>,
     pub neighbourhood_cost: HashMap<District, MovementCost>,
+    /// The accessibility of the districts. Districts that are not in the map have the default accessibility.
+    #[serde(default)]
+    pub district_accessibility: HashMap<District, Accessibility>,
+    /// The image of the map the clients should show, if the map has one. See the `/resources/assets` endpoint.
+    #[serde(default)]
+    pub artwork_id: Option<AssetID>,
+    /// If the relationships in the opposite direction that the edges are missing should be generated when the map is loaded, so that the map author only has to write each edge once. The edges marked as one-way are left as they are.
+    /// Left out when it's false, so that the content hash of the existing maps stays the same.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub generate_reverse_relationships: bool,
 }
 
 impl NodeMap {
@@ -18,53 +38,50 @@ impl NodeMap {
             nodes: Vec::new(),
             edges: HashMap::new(),
             neighbourhood_cost: HashMap::new(),
-        }
-    }
-
-    /// Updates the district movement penalty of a district based on the situation card.
-    pub fn update_neighbourhood_cost(&mut self, situation_card: &SituationCard) {
-        for i in &situation_card.costs {
-            self.neighbourhood_cost
-                .insert(i.neighbourhood, i.traffic.get_movement_cost());
+            district_accessibility: HashMap::new(),
+            artwork_id: None,
+            generate_reverse_relationships: false,
         }
     }
 
     /// Creates a new NodeMap with the default nodes and edges defined in the (7th) workshop version.
     /// 
+    /// The ids of the edges must never be changed or reused, since the clients and the saved games use them to refer to the edges.
+    /// 
     /// [`Self::new_default`]: #method.new_default
     #[must_use]
     pub fn new_default() -> Self {
         let mut map = Self::new();
 
-        let node0: Node = Node::new(0, String::from("Factory"));
-        let node1: Node = Node::new(1, String::from("Refinery"));
-        let mut node2: Node = Node::new(2, String::from("Industry Park"));
-        let node3: Node = Node::new(3, String::from("I1"));
-        let node4: Node = Node::new(4, String::from("I2"));
-        let node5: Node = Node::new(5, String::from("Port"));
-        let node6: Node = Node::new(6, String::from("I3"));
-        let node7: Node = Node::new(7, String::from("Beach"));
-        let node8: Node = Node::new(8, String::from("Northside"));
-        let mut node9: Node = Node::new(9, String::from("I4"));
-        let mut node10: Node = Node::new(10, String::from("Central Station"));
-        let node11: Node = Node::new(11, String::from("City Square"));
-        let node12: Node = Node::new(12, String::from("Concert Hall"));
-        let mut node13: Node = Node::new(13, String::from("Eastside Mart"));
-        let node14: Node = Node::new(14, String::from("East Town"));
-        let node15: Node = Node::new(15, String::from("Food Court"));
-        let node16: Node = Node::new(16, String::from("City Park"));
-        let node17: Node = Node::new(17, String::from("Quarry"));
-        let node18: Node = Node::new(18, String::from("I5"));
-        let mut node19: Node = Node::new(19, String::from("I6"));
-        let node20: Node = Node::new(20, String::from("I7"));
-        let mut node21: Node = Node::new(21, String::from("I8"));
-        let node22: Node = Node::new(22, String::from("West Town"));
-        let node23: Node = Node::new(23, String::from("Lakeside"));
-        let mut node24: Node = Node::new(24, String::from("Warehouses"));
-        let node25: Node = Node::new(25, String::from("I9"));
-        let mut node26: Node = Node::new(26, String::from("I10"));
-        let mut node27: Node = Node::new(27, String::from("Terminal 1"));
-        let node28: Node = Node::new(28, String::from("Terminal 2"));
+        let mut node0: Node = Node::new(NodeID(0), String::from("Factory"));
+        let mut node1: Node = Node::new(NodeID(1), String::from("Refinery"));
+        let mut node2: Node = Node::new(NodeID(2), String::from("Industry Park"));
+        let node3: Node = Node::new(NodeID(3), String::from("I1"));
+        let node4: Node = Node::new(NodeID(4), String::from("I2"));
+        let node5: Node = Node::new(NodeID(5), String::from("Port"));
+        let node6: Node = Node::new(NodeID(6), String::from("I3"));
+        let node7: Node = Node::new(NodeID(7), String::from("Beach"));
+        let node8: Node = Node::new(NodeID(8), String::from("Northside"));
+        let mut node9: Node = Node::new(NodeID(9), String::from("I4"));
+        let mut node10: Node = Node::new(NodeID(10), String::from("Central Station"));
+        let node11: Node = Node::new(NodeID(11), String::from("City Square"));
+        let node12: Node = Node::new(NodeID(12), String::from("Concert Hall"));
+        let mut node13: Node = Node::new(NodeID(13), String::from("Eastside Mart"));
+        let node14: Node = Node::new(NodeID(14), String::from("East Town"));
+        let node15: Node = Node::new(NodeID(15), String::from("Food Court"));
+        let node16: Node = Node::new(NodeID(16), String::from("City Park"));
+        let mut node17: Node = Node::new(NodeID(17), String::from("Quarry"));
+        let node18: Node = Node::new(NodeID(18), String::from("I5"));
+        let mut node19: Node = Node::new(NodeID(19), String::from("I6"));
+        let node20: Node = Node::new(NodeID(20), String::from("I7"));
+        let mut node21: Node = Node::new(NodeID(21), String::from("I8"));
+        let node22: Node = Node::new(NodeID(22), String::from("West Town"));
+        let node23: Node = Node::new(NodeID(23), String::from("Lakeside"));
+        let mut node24: Node = Node::new(NodeID(24), String::from("Warehouses"));
+        let node25: Node = Node::new(NodeID(25), String::from("I9"));
+        let mut node26: Node = Node::new(NodeID(26), String::from("I10"));
+        let mut node27: Node = Node::new(NodeID(27), String::from("Terminal 1"));
+        let node28: Node = Node::new(NodeID(28), String::from("Terminal 2"));
 
         node2.toggle_rail_connection();
         node10.toggle_rail_connection();
@@ -79,6 +96,13 @@ impl NodeMap {
         node26.is_parking_spot = true;
         node27.is_parking_spot = true;
 
+        node0.accessibility = Accessibility::not_step_free();
+        node1.accessibility = Accessibility::not_step_free();
+        node17.accessibility = Accessibility::not_step_free();
+        node24.accessibility = Accessibility::not_step_free();
+        node10.accessibility.has_elevator = true;
+        node27.accessibility.has_elevator = true;
+
         map.nodes.push(node0.clone());
         map.nodes.push(node1.clone());
         map.nodes.push(node2.clone());
@@ -109,46 +133,46 @@ impl NodeMap {
         map.nodes.push(node27.clone());
         map.nodes.push(node28.clone());
 
-        map.add_relationship(node0.clone(), node1.clone(), District::IndustryPark, 1, false);
-        map.add_relationship(node0, node2.clone(), District::IndustryPark, 1, false);
-        map.add_relationship(node1, node2.clone(), District::IndustryPark, 1, false);
-        map.add_relationship(node2.clone(), node3.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node3.clone(), node4.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node3, node9.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node4.clone(), node5, District::Port, 1, false);
-        map.add_relationship(node4, node6.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node6.clone(), node13.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node6, node7.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node7, node8, District::Suburbs, 1, false);
-        map.add_relationship(node9.clone(), node10.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node9, node18.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node10.clone(), node11.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node10.clone(), node15.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node11.clone(), node12.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node11, node16.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node12, node13.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node13.clone(), node14.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node13, node20.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node14, node21.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node15, node16.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node16, node19.clone(), District::CityCentre, 1, false);
-        map.add_relationship(node17, node18.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node18.clone(), node19.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node18, node23.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node19, node20.clone(), District::RingRoad, 1, false);
-        map.add_relationship(node20.clone(), node26.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node20, node27.clone(), District::Airport, 1, false);
-        map.add_relationship(node21, node27.clone(), District::Airport, 1, false);
-        map.add_relationship(node22, node23.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node23, node24.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node24.clone(), node25.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node25, node26.clone(), District::Suburbs, 1, false);
-        map.add_relationship(node26, node27.clone(), District::Airport, 1, false);
-        map.add_relationship(node27.clone(), node28, District::Airport, 1, false);
+        map.add_relationship(0, node0.clone(), node1.clone(), District::IndustryPark, 1, false);
+        map.add_relationship(1, node0, node2.clone(), District::IndustryPark, 1, false);
+        map.add_relationship(2, node1, node2.clone(), District::IndustryPark, 1, false);
+        map.add_relationship(3, node2.clone(), node3.clone(), District::Suburbs, 1, false);
+        map.add_relationship(4, node3.clone(), node4.clone(), District::RingRoad, 1, false);
+        map.add_relationship(5, node3, node9.clone(), District::RingRoad, 1, false);
+        map.add_relationship(6, node4.clone(), node5, District::Port, 1, false);
+        map.add_relationship(7, node4, node6.clone(), District::RingRoad, 1, false);
+        map.add_relationship(8, node6.clone(), node13.clone(), District::RingRoad, 1, false);
+        map.add_relationship(9, node6, node7.clone(), District::Suburbs, 1, false);
+        map.add_relationship(10, node7, node8, District::Suburbs, 1, false);
+        map.add_relationship(11, node9.clone(), node10.clone(), District::CityCentre, 1, false);
+        map.add_relationship(12, node9, node18.clone(), District::RingRoad, 1, false);
+        map.add_relationship(13, node10.clone(), node11.clone(), District::CityCentre, 1, false);
+        map.add_relationship(14, node10.clone(), node15.clone(), District::CityCentre, 1, false);
+        map.add_relationship(15, node11.clone(), node12.clone(), District::CityCentre, 1, false);
+        map.add_relationship(16, node11, node16.clone(), District::CityCentre, 1, false);
+        map.add_relationship(17, node12, node13.clone(), District::CityCentre, 1, false);
+        map.add_relationship(18, node13.clone(), node14.clone(), District::Suburbs, 1, false);
+        map.add_relationship(19, node13, node20.clone(), District::RingRoad, 1, false);
+        map.add_relationship(20, node14, node21.clone(), District::Suburbs, 1, false);
+        map.add_relationship(21, node15, node16.clone(), District::CityCentre, 1, false);
+        map.add_relationship(22, node16, node19.clone(), District::CityCentre, 1, false);
+        map.add_relationship(23, node17, node18.clone(), District::Suburbs, 1, false);
+        map.add_relationship(24, node18.clone(), node19.clone(), District::RingRoad, 1, false);
+        map.add_relationship(25, node18, node23.clone(), District::Suburbs, 1, false);
+        map.add_relationship(26, node19, node20.clone(), District::RingRoad, 1, false);
+        map.add_relationship(27, node20.clone(), node26.clone(), District::Suburbs, 1, false);
+        map.add_relationship(28, node20, node27.clone(), District::Airport, 1, false);
+        map.add_relationship(29, node21, node27.clone(), District::Airport, 1, false);
+        map.add_relationship(30, node22, node23.clone(), District::Suburbs, 1, false);
+        map.add_relationship(31, node23, node24.clone(), District::Suburbs, 1, false);
+        map.add_relationship(32, node24.clone(), node25.clone(), District::Suburbs, 1, false);
+        map.add_relationship(33, node25, node26.clone(), District::Suburbs, 1, false);
+        map.add_relationship(34, node26, node27.clone(), District::Airport, 1, false);
+        map.add_relationship(35, node27.clone(), node28, District::Airport, 1, false);
 
-        map.add_relationship(node2, node10.clone(), District::IndustryPark, 1, true);
-        map.add_relationship(node10, node24.clone(), District::IndustryPark, 1, true);
-        map.add_relationship(node24, node27, District::IndustryPark, 1, true);
+        map.add_relationship(36, node2, node10.clone(), District::IndustryPark, 1, true);
+        map.add_relationship(37, node10, node24.clone(), District::IndustryPark, 1, true);
+        map.add_relationship(38, node24, node27, District::IndustryPark, 1, true);
 
         let mut neighbourhood = District::first();
         map.change_neighbourhood_cost(neighbourhood, 1);
@@ -160,18 +184,10 @@ impl NodeMap {
         map
     }
 
-    /// Replaces self with the default map defined in [`Self::new_default`].
-    pub fn reset(&mut self) {
-        let _ = mem::replace(self, Self::new_default());
-    }
-
-    /// Toggles the `is_connected_to_rail` bool of the node with the given ID.
-    pub fn toggle_rail_connection_on_node_with_id(&mut self, node_id: NodeID) -> Result<(), String> {
-        let Some(node) = self.nodes.iter_mut().find(|node| node.id == node_id) else {
-            return Err(format!("There is no node with the given ID: {}", node_id));
-        };
-        node.toggle_rail_connection();
-        Ok(())
+    /// Returns the default map defined in [`Self::new_default`]. The map is only created once and is shared by everyone that calls this function.
+    #[must_use]
+    pub fn shared_default() -> Arc<Self> {
+        Arc::clone(&DEFAULT_MAP)
     }
 
     /// Gets the node with the given ID. Returns an error if there is no node with the given ID.
@@ -214,6 +230,73 @@ impl NodeMap {
         Ok(*neighbourhood_cost)
     }
 
+    /// Returns the accessibility of the given district.
+    pub fn get_district_accessibility(&self, district: District) -> Accessibility {
+        self.district_accessibility
+            .get(&district)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if both nodes of the edge and the district of the edge are accessible. Returns an error if one of the nodes does not exist.
+    pub fn is_edge_accessible(&self, from_node_id: NodeID, relationship: &NeighbourRelationship) -> Result<bool, String> {
+        let from_node = self.get_node_by_id(from_node_id)?;
+        let to_node = self.get_node_by_id(relationship.to)?;
+        Ok(from_node.accessibility.is_accessible()
+            && to_node.accessibility.is_accessible()
+            && self.get_district_accessibility(relationship.neighbourhood).is_accessible())
+    }
+
+    /// Finds the id of the edge between the two nodes. If an edge id is given, it's checked that the edge is between the two nodes.
+    /// Returns an error if there is no such edge, or if no edge id is given and there are several edges between the nodes (like a road and a railway).
+    pub fn find_edge_id(&self, node_one: NodeID, node_two: NodeID, edge_id: Option<EdgeID>) -> Result<EdgeID, String> {
+        let Some(neighbours) = self.edges.get(&node_one) else {
+            return Err(format!("There is no node with id {} that has any neighbours!", node_one));
+        };
+        let mut edges_between = neighbours.iter().filter(|relationship| relationship.to == node_two);
+        if let Some(edge_id) = edge_id {
+            return match edges_between.any(|relationship| relationship.edge_id == edge_id) {
+                true => Ok(edge_id),
+                false => Err(format!("The edge with id {} is not between node {} and node {}!", edge_id, node_one, node_two)),
+            };
+        }
+        match (edges_between.next(), edges_between.next()) {
+            (Some(relationship), None) => Ok(relationship.edge_id),
+            (Some(_), Some(_)) => Err(format!("There are several edges between node {} and node {}, so the id of the edge has to be given!", node_one, node_two)),
+            (None, _) => Err(format!("The node {} is not neighbours with node {}!", node_one, node_two)),
+        }
+    }
+
+    /// Returns the relationships in the opposite direction that the edges which are not one-way are missing, as pairs of the node the relationship should be listed under and the relationship.
+    /// The relationships are copies of the edges they are the opposite of, going the other way, and are sorted by the node and edge id.
+    pub fn missing_reverse_relationships(&self) -> Vec<(NodeID, NeighbourRelationship)> {
+        let mut missing_relationships: Vec<(NodeID, NeighbourRelationship)> = self
+            .edges
+            .iter()
+            .flat_map(|(from_node_id, relationships)| relationships.iter().map(move |relationship| (*from_node_id, relationship)))
+            .filter(|(from_node_id, relationship)| {
+                !relationship.is_one_way
+                    && self
+                        .edges
+                        .get(&relationship.to)
+                        .is_none_or(|opposites| opposites.iter().all(|opposite| opposite.edge_id != relationship.edge_id || opposite.to != *from_node_id))
+            })
+            .map(|(from_node_id, relationship)| (relationship.to, NeighbourRelationship { to: from_node_id, ..relationship.clone() }))
+            .collect();
+        missing_relationships.sort_by_key(|(node_id, relationship)| (*node_id, relationship.edge_id, relationship.to));
+        missing_relationships.dedup_by(|(node_id, relationship), (other_node_id, other_relationship)| node_id == other_node_id && relationship.edge_id == other_relationship.edge_id && relationship.to == other_relationship.to);
+        missing_relationships
+    }
+
+    /// Adds the relationships in the opposite direction that the edges are missing, see [`Self::missing_reverse_relationships`], and returns them.
+    pub fn add_missing_reverse_relationships(&mut self) -> Vec<(NodeID, NeighbourRelationship)> {
+        let missing_relationships = self.missing_reverse_relationships();
+        for (node_id, relationship) in missing_relationships.iter() {
+            self.edges.entry(*node_id).or_default().push(relationship.clone());
+        }
+        missing_relationships
+    }
+
     /// Checks if the given node IDs are neighbours. Returns an error if something went wrong.
     pub fn are_nodes_neighbours(&self, node_1: NodeID, node_2: NodeID) -> Result<bool, String> {
         let Some(neighbours) = self.edges.get(&node_1) else {
@@ -226,13 +309,14 @@ impl NodeMap {
 
     fn add_relationship(
         &mut self,
+        edge_id: EdgeID,
         node1: Node,
         node2: Node,
         neighbourhood: District,
         cost: MovementCost,
         is_connected_through_rail: bool,
     ) {
-        let mut relationship = NeighbourRelationship::new(node2.id, neighbourhood, cost, is_connected_through_rail);
+        let mut relationship = NeighbourRelationship::new(edge_id, node2.id, neighbourhood, cost, is_connected_through_rail);
         self.edges
             .entry(node1.id)
             .or_default()
@@ -240,110 +324,4 @@ impl NodeMap {
         relationship.to = node1.id;
         self.edges.entry(node2.id).or_default().push(relationship);
     }
-
-    /// Adds the given edge restriction to the map and if the edge restriction is modifiable (removable), and returns an error if something went wrong.
-    pub fn set_restriction_on_edge(
-        &mut self,
-        edge_restriction: &EdgeRestriction,
-        modifiable: bool,
-    ) -> Result<(), String> {
-        match self.set_restriction_on_relationship(edge_restriction.node_one, edge_restriction.node_two, edge_restriction.edge_restriction, modifiable) {
-            Ok(_) => (),
-            Err(e) => return Err(e),
-        }
-        if edge_restriction.edge_restriction == RestrictionType::OneWay {
-            return Ok(()); // If the restriction is one way, we don't need to set the other way
-        }
-        match self.set_restriction_on_relationship(edge_restriction.node_two, edge_restriction.node_one, edge_restriction.edge_restriction, modifiable) {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                let mut err_string = String::new();
-                match self.remove_restriction_from_relationship(edge_restriction.node_one, edge_restriction.node_two) {
-                    Ok(_) => (),
-                    Err(e) => err_string = e,
-                }
-                Err(format!("{} and secondly {}", e, err_string))
-            }
-        }
-    }
-
-    fn set_restriction_on_relationship(
-        &mut self,
-        from_node_id: NodeID,
-        to_node_id: NodeID,
-        restriction_type: RestrictionType,
-        modifiable: bool,
-    ) -> Result<(), String> {
-        match self.are_nodes_neighbours(from_node_id, to_node_id) {
-            Ok(n) => {
-                if !n {
-                    return Err(format!("The node {} is not neighbours with node {} and can therefore not put park and ride between them!", from_node_id, to_node_id));
-                }
-            }
-            Err(e) => return Err(e),
-        }
-        let Some(neighbours) = self.edges.get_mut(&from_node_id) else {
-            return Err(format!("There is no node with id {} that has any neighbours! Therefore we cannot place park and ride!", from_node_id));
-        };
-
-        for mut neighbour in neighbours {
-            if neighbour.to != to_node_id {
-                continue;
-            }
-            neighbour.restriction = Some(restriction_type);
-            neighbour.is_modifiable = modifiable;
-        }
-        Ok(())
-    }
-
-    fn remove_restriction_from_relationship(
-        &mut self,
-        from_node_id: NodeID,
-        to_node_id: NodeID,
-    ) -> Result<(), String> {
-        match self.are_nodes_neighbours(from_node_id, to_node_id) {
-            Ok(n) => {
-                if !n {
-                    return Err(format!("The node {} is not neighbours with node {} and can therefore not put park and ride between them!", from_node_id, to_node_id));
-                }
-            }
-            Err(e) => return Err(e),
-        }
-        let Some(neighbours) = self.edges.get_mut(&from_node_id) else {
-            return Err(format!("There is no node with id {} that has any neighbours! Therefore we cannot place park and ride!", from_node_id));
-        };
-
-        for mut neighbour in neighbours {
-            if neighbour.to != to_node_id {
-                continue;
-            }
-            if !neighbour.is_modifiable {
-                return Err(format!("The edge between node {} and node {} is not modifiable!", from_node_id, to_node_id));
-            }
-            neighbour.restriction = None;
-        }
-        Ok(())
-    }
-
-    /// Tries to remove the given edge restriction from the map and returns an error if something went wrong.
-    pub fn remove_restriction_from_edge(
-        &mut self,
-        edge_restriction: &EdgeRestriction,
-    ) -> Result<(), String> {
-        match self.remove_restriction_from_relationship(edge_restriction.node_one, edge_restriction.node_two) {
-            Ok(_) => (),
-            Err(e) => return Err(e),
-        }
-        match self.remove_restriction_from_relationship(edge_restriction.node_two, edge_restriction.node_one) {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                let mut err_string = String::new();
-                match self.set_restriction_on_edge(edge_restriction, true) {
-                    Ok(_) => (),
-                    Err(e) => err_string = e,
-                }
-                Err(format!("{} and secondly {}", e, err_string))
-            }
-        }
-    }
-}
\ No newline at end of file
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::NodeID, enums::district::District};
+
+use super::node_coordinates::NodeCoordinates;
+
+/// The NodeSearchResult struct describes a node that matched a search of the map, see [`search_nodes`](../../../node_search/fn.search_nodes.html).
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct NodeSearchResult {
+    pub node_id: NodeID,
+    pub name: String,
+    /// The districts of the edges the node is connected to.
+    pub districts: Vec<District>,
+    pub tags: Vec<String>,
+    pub coordinates: Option<NodeCoordinates>,
+}
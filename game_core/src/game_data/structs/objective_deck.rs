@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::NodeID, enums::district::District};
+
+use super::player_objective_card::PlayerObjectiveCard;
+
+/// The ObjectiveDeck struct is a named set of objective cards made by a facilitator. A lobby that chooses the deck deals the objective cards from it instead of from the situation card.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ObjectiveDeck {
+    /// The name of the deck. A deck that is uploaded with the same name as another deck replaces it.
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub cards: Vec<PlayerObjectiveCard>,
+}
+
+/// The ObjectiveDeckReport struct describes the outcome of validating an objective deck. A deck with any issues is not kept by the server.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ObjectiveDeckReport {
+    pub name: String,
+    pub cards: usize,
+    /// The district of the drop off node of every card, in the order of the cards, so that the facilitator can see where the destinations are.
+    pub destination_districts: Vec<(NodeID, Option<District>)>,
+    pub issues: Vec<String>,
+}
+
+impl ObjectiveDeckReport {
+    /// Returns `true` if the deck has no issues.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
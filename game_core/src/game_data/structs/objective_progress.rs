@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{MovementCost, PlayerID, TurnNumber}, enums::in_game_id::InGameID};
+
+use super::{gamestate::GameState, player::Player};
+
+/// The ObjectiveProgress struct estimates how far a player has come with the objective card, from how much of the cheapest route from the start node is left.
+///
+/// It's only shown to the orchestrator, so that the facilitator can pace the session without the objectives being revealed to the other players.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ObjectiveProgress {
+    pub player_id: PlayerID,
+    pub name: String,
+    pub role: InGameID,
+    /// The turn the progress was estimated in.
+    pub turn_number: TurnNumber,
+    /// The cheapest movement cost on the base map of the whole objective, from the start node to the pick up node and on to the drop off node. Not set if the nodes can not be reached.
+    pub full_movement_cost: Option<MovementCost>,
+    /// The cheapest movement cost on the base map of what is left of the objective. It's 0 if the objective is done, and not set if the player has no position or the nodes can not be reached.
+    pub remaining_movement_cost: Option<MovementCost>,
+    /// How much of the objective is done, from 0 to 100. A player that has moved further away than the start node is at 0. Not set if either of the movement costs is not set.
+    pub progress_percent: Option<u8>,
+}
+
+impl ObjectiveProgress {
+    /// Estimates the progress of all the players in the game that have an objective card, in the order of the players.
+    #[must_use]
+    pub fn estimate_all(game: &GameState) -> Vec<Self> {
+        game.players.iter().filter_map(|player| Self::estimate(game, player)).collect()
+    }
+
+    fn estimate(game: &GameState, player: &Player) -> Option<Self> {
+        let objective_card = player.objective_card.as_ref()?;
+        let map = game.map.base();
+        let full_movement_cost = objective_card.full_movement_cost(map);
+        let remaining_movement_cost = objective_card.remaining_movement_cost(map, player.position_node_id);
+        Some(Self {
+            player_id: player.unique_id,
+            name: player.name.clone(),
+            role: player.in_game_id,
+            turn_number: game.turn_number,
+            full_movement_cost,
+            remaining_movement_cost,
+            progress_percent: Self::progress_percent(full_movement_cost, remaining_movement_cost, objective_card.dropped_package_off),
+        })
+    }
+
+    fn progress_percent(full_movement_cost: Option<MovementCost>, remaining_movement_cost: Option<MovementCost>, is_done: bool) -> Option<u8> {
+        if is_done {
+            return Some(100);
+        }
+        let full_movement_cost = i32::from(full_movement_cost?);
+        let remaining_movement_cost = i32::from(remaining_movement_cost?);
+        if full_movement_cost <= 0 {
+            return Some(if remaining_movement_cost <= 0 { 100 } else { 0 });
+        }
+        let done = (full_movement_cost - remaining_movement_cost).clamp(0, full_movement_cost);
+        u8::try_from(done * 100 / full_movement_cost).ok()
+    }
+}
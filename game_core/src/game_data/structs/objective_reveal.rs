@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{MovementCost, NodeID, PlayerID, TurnNumber}, enums::in_game_id::InGameID};
+
+use super::{gamestate::GameState, player::Player, player_objective_card::PlayerObjectiveCard};
+
+/// The ObjectiveReveal struct shows where a player was heading and how far the player got, so that all the objective cards can be talked about in the debrief after the game.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ObjectiveReveal {
+    pub player_id: PlayerID,
+    pub name: String,
+    pub role: InGameID,
+    pub objective_card: PlayerObjectiveCard,
+    /// Where the player was when the game ended.
+    pub position_node_id: Option<NodeID>,
+    /// How many moves the player made in the game.
+    pub moves_made: u32,
+    /// The turn the player dropped off what the objective card asks for, if the player did.
+    pub delivered_in_turn: Option<TurnNumber>,
+    /// The cheapest movement cost on the base map of what was left of the objective: to the pick up node and on to the drop off node, or only to the drop off node if it was picked up.
+    /// It's 0 if the objective was done, and not set if the player had no position or the nodes can not be reached.
+    pub remaining_movement_cost: Option<MovementCost>,
+}
+
+impl ObjectiveReveal {
+    /// Reveals the objective cards of all the players in the game that have one, in the order of the players.
+    #[must_use]
+    pub fn reveal_all(game: &GameState) -> Vec<Self> {
+        game.players.iter().filter_map(|player| Self::new(game, player)).collect()
+    }
+
+    fn new(game: &GameState, player: &Player) -> Option<Self> {
+        let objective_card = player.objective_card.clone()?;
+        Some(Self {
+            player_id: player.unique_id,
+            name: player.name.clone(),
+            role: player.in_game_id,
+            position_node_id: player.position_node_id,
+            moves_made: game.statistics.moves_of_role(player.in_game_id),
+            delivered_in_turn: game.statistics.objective_completion_turn_of_role(player.in_game_id),
+            remaining_movement_cost: objective_card.remaining_movement_cost(game.map.base(), player.position_node_id),
+            objective_card,
+        })
+    }
+}
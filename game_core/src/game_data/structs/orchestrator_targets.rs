@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::Emissions;
+
+/// The OrchestratorTargets struct describes what the orchestrator has to achieve for the orchestrator to win, like the climate goal of a city.
+///
+/// The game is finished as soon as the targets are met, even if some players have not completed their objective.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct OrchestratorTargets {
+    /// How many players have to drop off what their objective card asks for.
+    pub deliveries: u32,
+    /// The most emissions the players can have together when the deliveries are made. There is no limit if it's not set.
+    #[serde(default)]
+    pub max_total_emissions: Option<Emissions>,
+}
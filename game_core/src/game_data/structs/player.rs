@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::game_data::{custom_types::{GameID, PlayerID, MovesRemaining, NodeID}, enums::in_game_id::InGameID};
+use crate::game_data::{custom_types::{GameID, PlayerID, MovesRemaining, NodeID, MovementCost, Emissions, Money, Score}, enums::{in_game_id::InGameID, cargo_state::CargoState, achievement::Achievement, bot_difficulty::BotDifficulty}, constants::{LOADED_CARGO_EXTRA_MOVEMENT_COST, START_PLAYER_BUDGET}};
 
 use super::player_objective_card::PlayerObjectiveCard;
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Player {
     pub connected_game_id: Option<GameID>,
     pub in_game_id: InGameID,
@@ -12,8 +12,36 @@ pub struct Player {
     pub name: String,
     pub position_node_id: Option<NodeID>,
     pub remaining_moves: MovesRemaining,
+    /// The objective card of the player. Only sent to the player itself until the game is finished, see [`GameState::hide_objective_cards_from_others`](../gamestate/struct.GameState.html#method.hide_objective_cards_from_others).
     pub objective_card: Option<PlayerObjectiveCard>,
     pub is_bus: bool,
+    /// What the player is carrying. Only changes for players with a freight objective card.
+    #[serde(default)]
+    pub cargo: CargoState,
+    /// The player this player has offered a ride to, if the offer has not been accepted yet.
+    #[serde(default)]
+    pub ride_offered_to: Option<PlayerID>,
+    /// The driver of the car this player is riding in. The position of the player follows the driver until the player exits the vehicle.
+    #[serde(default)]
+    pub riding_with: Option<PlayerID>,
+    /// This player's share of the emissions of the car moves it has been a part of, either as a driver or as a passenger.
+    #[serde(default)]
+    pub emissions: Emissions,
+    /// The money the player has left to pay congestion charges with.
+    #[serde(default)]
+    pub budget: Money,
+    /// The sum of the congestion charges the player has paid.
+    #[serde(default)]
+    pub congestion_charges_paid: Money,
+    /// The score of the player. It's updated by the ScoringExtension at the end of every turn.
+    #[serde(default)]
+    pub score: Score,
+    /// The achievements the player has earned in the game. They are handed out by the AchievementExtension.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub achievements: Vec<Achievement>,
+    /// How well the player plays if it's a bot player, which plays its turns by itself. Left out for the players that are people.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot: Option<BotDifficulty>,
 }
 
 impl Player {
@@ -30,17 +58,39 @@ impl Player {
             remaining_moves: 0,
             objective_card: None,
             is_bus,
+            cargo: CargoState::Empty,
+            ride_offered_to: None,
+            riding_with: None,
+            emissions: 0,
+            budget: START_PLAYER_BUDGET,
+            congestion_charges_paid: 0,
+            score: 0,
+            achievements: Vec::new(),
+            bot: None,
         }
     }
 
     /// Sets the is_bus field to true.
-    pub fn transform_to_bus(&mut self) {
+    pub const fn transform_to_bus(&mut self) {
         self.is_bus = true;
     }
 
     /// Sets the is_bus field to false.
-    pub fn transform_to_car(&mut self) {
+    pub const fn transform_to_car(&mut self) {
         self.is_bus = false;
     }
 
+    /// Returns `true` if the player is a bot player.
+    pub const fn is_bot(&self) -> bool {
+        self.bot.is_some()
+    }
+
+    /// Returns the extra movement cost the player has to pay for every move because of the cargo it's carrying.
+    #[must_use]
+    pub const fn cargo_movement_cost(&self) -> MovementCost {
+        match self.cargo {
+            CargoState::Loaded => LOADED_CARGO_EXTRA_MOVEMENT_COST,
+            CargoState::Empty | CargoState::Delivered => 0,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{Milliseconds, PlayerID, Timestamp}, enums::connection_quality::ConnectionQuality, constants::{CONNECTION_FLAKY_AFTER, CONNECTION_STALE_AFTER}};
+
+/// The PlayerConnection struct keeps track of how often and when a player last checked in, so that the facilitators can tell if they should wait for the player or skip them.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct PlayerConnection {
+    pub player_id: PlayerID,
+    /// When the player last checked in, in milliseconds since the unix epoch.
+    pub last_seen: Timestamp,
+    /// The average time between the check ins of the player, weighted towards the latest check ins. Not set until the player has checked in twice.
+    pub average_check_in_interval: Option<Milliseconds>,
+    /// The quality of the connection when it was last judged, see [`PlayerConnection::quality_at`].
+    pub quality: ConnectionQuality,
+    /// When the player will be removed for being inactive unless the player checks in before, in milliseconds since the unix epoch.
+    /// Set when the player has gone without checking in for most of the player timeout, and the client should ask the player whether they are still there until it's cleared by a check in.
+    #[serde(default)]
+    pub removal_at: Option<Timestamp>,
+}
+
+impl PlayerConnection {
+    /// Creates a new PlayerConnection for a player that was seen at the given time.
+    #[must_use]
+    pub const fn new(player_id: PlayerID, now: Timestamp) -> Self {
+        Self {
+            player_id,
+            last_seen: now,
+            average_check_in_interval: None,
+            quality: ConnectionQuality::Good,
+            removal_at: None,
+        }
+    }
+
+    /// Records that the player checked in at the given time.
+    pub fn check_in(&mut self, now: Timestamp) {
+        let interval = now.saturating_sub(self.last_seen);
+        self.average_check_in_interval = Some(self.average_check_in_interval.map_or(interval, |average| (average * 3 + interval) / 4));
+        self.last_seen = now;
+        self.removal_at = None;
+    }
+
+    /// Returns the quality of the connection at the given time. The connection is stale if the player has not checked in for [`CONNECTION_STALE_AFTER`],
+    /// and flaky if the player has not checked in for [`CONNECTION_FLAKY_AFTER`] or usually checks in more seldom than that.
+    ///
+    /// [`CONNECTION_STALE_AFTER`]: ../../constants/constant.CONNECTION_STALE_AFTER.html
+    /// [`CONNECTION_FLAKY_AFTER`]: ../../constants/constant.CONNECTION_FLAKY_AFTER.html
+    pub fn quality_at(&self, now: Timestamp) -> ConnectionQuality {
+        let since_last_seen = now.saturating_sub(self.last_seen);
+        if since_last_seen >= CONNECTION_STALE_AFTER {
+            ConnectionQuality::Stale
+        } else if since_last_seen >= CONNECTION_FLAKY_AFTER || self.average_check_in_interval.is_some_and(|average| average >= CONNECTION_FLAKY_AFTER) {
+            ConnectionQuality::Flaky
+        } else {
+            ConnectionQuality::Good
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::game_data::{custom_types::{PlayerID, GameID, NodeID, SituationCardID}, enums::{player_input_type::PlayerInputType, in_game_id::InGameID}};
+use crate::game_data::{custom_types::{PlayerID, GameID, NodeID, EdgeID, SituationCardID, StateVersion, StateHash, MeasureID, MeasureTemplateID, DeadLetterID, RuleWarningID}, enums::{player_input_type::PlayerInputType, in_game_id::InGameID, access_level::AccessLevel, annotation_target::AnnotationTarget}};
 
-use super::{district_modifier::DistrictModifier, edge_restriction::EdgeRestriction};
+use super::{congestion_zone::CongestionZone, construction_work::ConstructionWork, district_modifier::DistrictModifier, edge_restriction::EdgeRestriction, lobby_settings::LobbySettings};
 
 /// The PlayerInput struct describes the input of a player.
 /// 
@@ -14,8 +14,94 @@ pub struct PlayerInput {
     pub input_type: PlayerInputType,
     pub related_role: Option<InGameID>,
     pub related_node_id: Option<NodeID>,
+    /// The edge to move along to the related node. Only needed if there are several edges between the nodes, like a road and a railway.
+    #[serde(default)]
+    pub edge_id: Option<EdgeID>,
     pub district_modifier: Option<DistrictModifier>,
     pub situation_card_id: Option<SituationCardID>,
     pub edge_modifier: Option<EdgeRestriction>,
     pub related_bool: Option<bool>,
+    pub lobby_settings: Option<LobbySettings>,
+    pub related_string: Option<String>,
+    #[serde(default)]
+    pub measure_id: Option<MeasureID>,
+    /// The other player the input is about. Used when offering and accepting rides.
+    #[serde(default)]
+    pub related_player_id: Option<PlayerID>,
+    /// The congestion zone to set. Used with SetCongestionZone, where `None` removes the zone.
+    #[serde(default)]
+    pub congestion_zone: Option<CongestionZone>,
+    /// The predefined measure to enact. Used with EnactMeasureTemplate.
+    #[serde(default)]
+    pub measure_template_id: Option<MeasureTemplateID>,
+    /// The construction works to schedule. Used with ScheduleConstruction.
+    #[serde(default)]
+    pub construction_work: Option<ConstructionWork>,
+    /// The dead letter to retry or discard. Used with RetryDeadLetter and DiscardDeadLetter.
+    #[serde(default)]
+    pub dead_letter_id: Option<DeadLetterID>,
+    /// The rule warning to accept. Used with OverrideRule.
+    #[serde(default)]
+    pub rule_warning_id: Option<RuleWarningID>,
+    /// The input to apply without checking the rules in `overridden_rules`. Used with OverrideAndApply.
+    #[serde(default)]
+    pub override_input: Option<Box<Self>>,
+    /// The names of the rules the input in `override_input` is not checked against. Used with OverrideAndApply.
+    #[serde(default)]
+    pub overridden_rules: Vec<String>,
+    /// The district or edge to attach the note to. Used with AnnotateMap.
+    #[serde(default)]
+    pub annotation_target: Option<AnnotationTarget>,
+    /// The version of the game the client last received. Used to hint which sections of the returned game state have changed.
+    #[serde(default)]
+    pub known_version: Option<StateVersion>,
+    /// The state hash of the game the client last received or simulated, see `GameState::state_hash`. If it's not the same as on the server, the client gets the whole game state back and the divergence is logged.
+    #[serde(default)]
+    pub known_state_hash: Option<StateHash>,
+    /// The access level of the token the input was sent with. This is set by the server, and is `None` if access control is disabled.
+    #[serde(skip)]
+    pub access_level: Option<AccessLevel>,
+}
+
+impl PlayerInput {
+    /// Creates a new PlayerInput of the given type where none of the optional values are set.
+    #[must_use]
+    pub const fn new(player_id: PlayerID, game_id: GameID, input_type: PlayerInputType) -> Self {
+        Self {
+            player_id,
+            game_id,
+            input_type,
+            related_role: None,
+            related_node_id: None,
+            edge_id: None,
+            district_modifier: None,
+            situation_card_id: None,
+            edge_modifier: None,
+            related_bool: None,
+            lobby_settings: None,
+            related_string: None,
+            measure_id: None,
+            related_player_id: None,
+            congestion_zone: None,
+            measure_template_id: None,
+            construction_work: None,
+            dead_letter_id: None,
+            rule_warning_id: None,
+            override_input: None,
+            overridden_rules: Vec::new(),
+            annotation_target: None,
+            known_version: None,
+            known_state_hash: None,
+            access_level: None,
+        }
+    }
+
+    /// Returns `true` if the input gives the player powers over the session, like becoming the orchestrator, starting the next scenario of a campaign or correcting the game (see [`PlayerInputType::is_corrective`]). These inputs need a facilitator token when access control is enabled.
+    pub fn requires_elevation(&self) -> bool {
+        match self.input_type {
+            PlayerInputType::ChangeRole => self.related_role == Some(InGameID::Orchestrator),
+            PlayerInputType::NextScenario => true,
+            _ => self.input_type.is_corrective(),
+        }
+    }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::game_data::{custom_types::{NodeID, VehicleType}, enums::{restriction_type::RestrictionType, type_entities_to_transport::TypeEntitiesToTransport}, constants::HEAVY_VEHICLE_INCLUSIVE_THRESHOLD};
+use crate::{game_data::{custom_types::{AssetID, MovementCost, NodeID, Score, VehicleType}, enums::{restriction_type::RestrictionType, type_entities_to_transport::TypeEntitiesToTransport}, constants::{DELIVERED_OBJECTIVE_SCORE, HEAVY_VEHICLE_INCLUSIVE_THRESHOLD, PICKED_UP_OBJECTIVE_SCORE}}, pathfinding::shortest_path_cost};
+
+use super::node_map::NodeMap;
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct PlayerObjectiveCard {
@@ -13,6 +15,18 @@ pub struct PlayerObjectiveCard {
     pub dropped_package_off: bool,
     pub type_of_entities_to_transport: TypeEntitiesToTransport,
     pub amount_of_entities: u32,
+    /// If true the player can only move along edges where both nodes and the district are accessible.
+    #[serde(default)]
+    pub requires_accessible_route: bool,
+    /// If true the player has the freight role: the cargo is loaded at the pick up node, makes every move more expensive and can only be delivered at the drop off node after it's loaded.
+    #[serde(default)]
+    pub is_freight: bool,
+    /// The artwork of the card the clients should show, if the card has one. See the `/resources/assets` endpoint.
+    #[serde(default)]
+    pub artwork_id: Option<AssetID>,
+    /// The points the player gets for delivering what the card asks for. The default score is used if it's not set. See [`PlayerObjectiveCard::delivered_score`].
+    #[serde(default)]
+    pub points: Option<Score>,
 }
 
 impl PlayerObjectiveCard {
@@ -42,6 +56,68 @@ impl PlayerObjectiveCard {
             name,
             amount_of_entities,
             type_of_entities_to_transport,
+            requires_accessible_route: false,
+            is_freight: false,
+            artwork_id: None,
+            points: None,
+        }
+    }
+
+    /// Makes the objective card require that the player only moves along accessible edges.
+    #[must_use]
+    pub const fn with_accessible_route_required(mut self) -> Self {
+        self.requires_accessible_route = true;
+        self
+    }
+
+    /// Gives the player with this objective card the freight role.
+    #[must_use]
+    pub const fn with_freight_role(mut self) -> Self {
+        self.is_freight = true;
+        self
+    }
+
+    /// Returns the points the player gets for delivering what the card asks for.
+    pub fn delivered_score(&self) -> Score {
+        self.points.unwrap_or(DELIVERED_OBJECTIVE_SCORE)
+    }
+
+    /// Returns the points the player has earned so far with this objective card, for picking up or delivering what it asks for.
+    pub fn earned_score(&self) -> Score {
+        if self.dropped_package_off {
+            self.delivered_score()
+        } else if self.picked_package_up {
+            self.picked_up_score()
+        } else {
+            0
         }
     }
-}
\ No newline at end of file
+
+    /// Returns the cheapest movement cost on the map of the whole objective: from the start node to the pick up node and on to the drop off node.
+    /// Will return `None` if the nodes can not be reached.
+    pub fn full_movement_cost(&self, map: &NodeMap) -> Option<MovementCost> {
+        let to_pick_up = shortest_path_cost(map, self.start_node_id, self.pick_up_node_id)?;
+        let to_drop_off = shortest_path_cost(map, self.pick_up_node_id, self.drop_off_node_id)?;
+        Some(to_pick_up.saturating_add(to_drop_off))
+    }
+
+    /// Returns the cheapest movement cost on the map of what is left of the objective from the given position: to the pick up node and on to the drop off node, or only to the drop off node if it was picked up.
+    /// It's 0 if the objective is done, and `None` if there is no position or the nodes can not be reached.
+    pub fn remaining_movement_cost(&self, map: &NodeMap, position_node_id: Option<NodeID>) -> Option<MovementCost> {
+        if self.dropped_package_off {
+            return Some(0);
+        }
+        let position_node_id = position_node_id?;
+        if self.picked_package_up {
+            return shortest_path_cost(map, position_node_id, self.drop_off_node_id);
+        }
+        let to_pick_up = shortest_path_cost(map, position_node_id, self.pick_up_node_id)?;
+        let to_drop_off = shortest_path_cost(map, self.pick_up_node_id, self.drop_off_node_id)?;
+        Some(to_pick_up.saturating_add(to_drop_off))
+    }
+
+    /// Returns the points the player gets for picking up what the card asks for, which is the same part of the delivered score as for the default score.
+    pub fn picked_up_score(&self) -> Score {
+        self.points.map_or(PICKED_UP_OBJECTIVE_SCORE, |points| points.saturating_mul(PICKED_UP_OBJECTIVE_SCORE) / DELIVERED_OBJECTIVE_SCORE)
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{GameID, PlayerID, SessionToken, Timestamp}, constants::RECONNECT_WINDOW};
+
+use super::player::Player;
+
+/// The PlayerSession struct is the stable session of a player id.
+///
+/// It lets a player whose id was removed for being inactive reconnect with the same id, and get back into the game with the role, position, remaining moves and everything else the player had.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct PlayerSession {
+    pub player_id: PlayerID,
+    pub token: SessionToken,
+    /// The player as it was in its game when the id was removed for being inactive. It's `None` while the id is in use.
+    pub disconnected_player: Option<DisconnectedPlayer>,
+}
+
+/// The DisconnectedPlayer struct describes a player that was removed from a game because its id was inactive, so that the player can be put back into the game.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct DisconnectedPlayer {
+    pub game_id: GameID,
+    pub player: Player,
+    /// When the id of the player was removed, in milliseconds since the unix epoch.
+    pub disconnected_at: Timestamp,
+}
+
+impl PlayerSession {
+    /// Creates a new PlayerSession for a player id that is in use.
+    #[must_use]
+    pub const fn new(player_id: PlayerID, token: SessionToken) -> Self {
+        Self {
+            player_id,
+            token,
+            disconnected_player: None,
+        }
+    }
+
+    /// Returns `true` if the player was disconnected so long ago that the player can no longer reconnect, see [`RECONNECT_WINDOW`].
+    ///
+    /// [`RECONNECT_WINDOW`]: ../../constants/constant.RECONNECT_WINDOW.html
+    pub fn has_expired_at(&self, now: Timestamp) -> bool {
+        self.disconnected_player
+            .as_ref()
+            .is_some_and(|disconnected| now.saturating_sub(disconnected.disconnected_at) >= RECONNECT_WINDOW)
+    }
+}
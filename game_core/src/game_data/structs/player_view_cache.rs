@@ -0,0 +1,58 @@
+use std::collections::VecDeque;
+
+use crate::game_data::{
+    constants::MAX_CACHED_PLAYER_VIEWS,
+    custom_types::{GameID, NodeID, PlayerID, StateVersion},
+};
+
+/// The PlayerViewCache struct keeps the legal nodes of the players that have been computed for a version of a game.
+///
+/// This way players polling the same unchanged game do not make the server check every move against the rules again.
+/// The entries are keyed by the game id, the player id and the version of the game, so a view is never used after the game has changed. The least recently used entries are removed when the cache is full.
+#[derive(Clone, Debug)]
+pub struct PlayerViewCache {
+    capacity: usize,
+    /// The cached views, the most recently used first.
+    entries: VecDeque<((GameID, PlayerID, StateVersion), Vec<NodeID>)>,
+}
+
+impl Default for PlayerViewCache {
+    fn default() -> Self {
+        Self::new(MAX_CACHED_PLAYER_VIEWS)
+    }
+}
+
+impl PlayerViewCache {
+    /// Creates an empty cache that keeps at most `capacity` views.
+    #[must_use]
+    pub const fn new(capacity: usize) -> Self {
+        Self { capacity, entries: VecDeque::new() }
+    }
+
+    /// Returns the legal nodes of the player in the given version of the game, if they are cached, and marks them as the most recently used.
+    pub fn get(&mut self, game_id: GameID, player_id: PlayerID, version: StateVersion) -> Option<Vec<NodeID>> {
+        let index = self.entries.iter().position(|(key, _)| *key == (game_id, player_id, version))?;
+        let entry = self.entries.remove(index)?;
+        let legal_nodes = entry.1.clone();
+        self.entries.push_front(entry);
+        Some(legal_nodes)
+    }
+
+    /// Caches the legal nodes of the player in the given version of the game, removing the least recently used view if the cache is full.
+    pub fn insert(&mut self, game_id: GameID, player_id: PlayerID, version: StateVersion, legal_nodes: Vec<NodeID>) {
+        let key = (game_id, player_id, version);
+        self.entries.retain(|(cached_key, _)| *cached_key != key);
+        self.entries.push_front((key, legal_nodes));
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Removes every cached view of the game, for example because the game has changed or has been removed.
+    pub fn invalidate_game(&mut self, game_id: GameID) {
+        self.entries.retain(|((cached_game_id, _, _), _)| *cached_game_id != game_id);
+    }
+
+    /// Removes every cached view, for example because the rules have changed.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
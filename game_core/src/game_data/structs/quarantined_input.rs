@@ -0,0 +1,27 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::Timestamp;
+
+use super::{gamestate::GameState, player_input::PlayerInput};
+
+/// The QuarantinedInput struct is an input that made the server panic, saved together with the game as it was before the input so that the panic can be reproduced.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct QuarantinedInput {
+    pub input: PlayerInput,
+    pub panic_message: String,
+    pub quarantined_at: Timestamp,
+    pub game: GameState,
+}
+
+impl QuarantinedInput {
+    /// Saves the quarantined input as a JSON file in the folder, named after the id and version of the game. Returns the path of the file.
+    pub fn save_to_folder(&self, folder: &Path) -> Result<String, String> {
+        fs::create_dir_all(folder).map_err(|e| format!("Failed to create the folder {} because: {e}", folder.display()))?;
+        let path = folder.join(format!("game_{}_version_{}_{}.json", self.game.id, self.game.version, self.quarantined_at));
+        let content = serde_json::to_string(self).map_err(|e| format!("Failed to serialize the quarantined input because: {e}"))?;
+        fs::write(&path, content).map_err(|e| format!("Failed to write {} because: {e}", path.display()))?;
+        Ok(path.display().to_string())
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{
+    custom_types::{EdgeID, Emissions, GameID, NodeID, PlayerID, RoundNumber, Score, StateVersion, TurnNumber},
+    enums::{construction_status::ConstructionStatus, game_phase::GamePhase, in_game_id::InGameID},
+};
+
+use super::{congestion_zone::CongestionZone, edge_restriction::EdgeRestriction, enacted_measure::EnactedMeasure, gamestate::GameState, group_metrics::GroupMetrics, map_annotation::MapAnnotation};
+
+/// The RenderedPlayer struct is what a renderer needs to know to draw a player on the board.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RenderedPlayer {
+    pub player_id: PlayerID,
+    pub name: String,
+    pub role: InGameID,
+    pub position_node_id: Option<NodeID>,
+    pub is_bus: bool,
+    /// The driver of the car the player is riding in, if any.
+    pub riding_with: Option<PlayerID>,
+    pub score: Score,
+    pub emissions: Emissions,
+    /// True if the player has dropped off what the objective card asks for.
+    pub objective_completed: bool,
+}
+
+/// The RenderModel struct is a compact description of how the board of a game looks at its current turn, meant for a renderer that draws images of games for reports.
+///
+/// Unlike the game state sent to the players it has no staged actions, history or settings, and the nodes of the map are found in the map with the name in `map_name`.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RenderModel {
+    pub game_id: GameID,
+    pub name: String,
+    pub map_name: String,
+    pub phase: GamePhase,
+    pub turn_number: TurnNumber,
+    pub round_number: RoundNumber,
+    pub current_players_turn: InGameID,
+    /// The version of the game the model was made from, so a renderer can tell if it has drawn the game already.
+    pub version: StateVersion,
+    /// The players, without the orchestrator.
+    pub players: Vec<RenderedPlayer>,
+    /// The measures of the orchestrator that have not been removed.
+    pub active_measures: Vec<EnactedMeasure>,
+    /// Every restriction on the edges, including the ones from the situation card.
+    pub edge_restrictions: Vec<EdgeRestriction>,
+    pub congestion_zone: Option<CongestionZone>,
+    /// The edges that have construction works on them right now.
+    pub edges_under_construction: Vec<EdgeID>,
+    /// The notes of the orchestrator, to draw as labels on the districts and edges.
+    pub map_annotations: Vec<MapAnnotation>,
+    /// How the whole group is doing against the group targets in the current round, to draw next to the board. Left out if the game is not cooperative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_metrics: Option<GroupMetrics>,
+}
+
+impl RenderModel {
+    /// Creates the render model of the given game. The staged actions of the current turn are not included, since they can still be undone.
+    #[must_use]
+    pub fn new(game: &GameState) -> Self {
+        Self {
+            game_id: game.id,
+            name: game.name.clone(),
+            map_name: game.map_name.clone(),
+            phase: game.phase(),
+            turn_number: game.turn_number,
+            round_number: game.round_number,
+            current_players_turn: game.current_players_turn,
+            version: game.version,
+            players: game
+                .players
+                .iter()
+                .filter(|player| player.in_game_id != InGameID::Orchestrator)
+                .map(|player| RenderedPlayer {
+                    player_id: player.unique_id,
+                    name: player.name.clone(),
+                    role: player.in_game_id,
+                    position_node_id: player.position_node_id,
+                    is_bus: player.is_bus,
+                    riding_with: player.riding_with,
+                    score: player.score,
+                    emissions: player.emissions,
+                    objective_completed: player.objective_card.as_ref().is_some_and(|objective_card| objective_card.dropped_package_off),
+                })
+                .collect(),
+            active_measures: game.measure_ledger.iter().filter(|measure| measure.is_active()).cloned().collect(),
+            edge_restrictions: game.edge_restrictions.clone(),
+            congestion_zone: game.congestion_zone.clone(),
+            edges_under_construction: game
+                .construction_works
+                .iter()
+                .filter(|construction| construction.status == ConstructionStatus::InProgress)
+                .flat_map(|construction| construction.work.edge_ids.iter().copied())
+                .collect(),
+            map_annotations: game.map_annotations.clone(),
+            group_metrics: game.group_metrics.last().cloned(),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::PlayerID, enums::player_input_type::PlayerInputType};
+
+/// The RuleOverride struct records that the orchestrator let an input through even though it broke some of the rules, either by accepting a rule warning or with an `OverrideAndApply` input.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct RuleOverride {
+    /// The orchestrator that overrode the rules.
+    pub overridden_by: PlayerID,
+    /// The player whose input was let through.
+    pub player_id: PlayerID,
+    pub input_type: PlayerInputType,
+    /// The names of the rules that were not checked.
+    pub rules: Vec<String>,
+    /// Why the orchestrator overrode the rules. Always given with an `OverrideAndApply` input.
+    pub justification: Option<String>,
+}
@@ -0,0 +1,28 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// The RuleSet struct describes which of the built-in rules are checked and the parameters of the rules that have them.
+///
+/// It lets facilitators run house-rule variants of the game without recompiling the server. It's read from a JSON file like `{"disabled_rules": ["max_moves_per_turn"], "max_modifiers_per_district": 3}`. Everything that is left out of the file is as in the default rules.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleSet {
+    /// The names of the built-in rules that are not checked, like `max_moves_per_turn`. The rule scripts can not be disabled here, they are removed from the rule scripts folder instead.
+    pub disabled_rules: Vec<String>,
+    /// How many district modifiers (of any type) a district can have at the same time. There is no limit other than the cap of each type if it's `None`.
+    pub max_modifiers_per_district: Option<usize>,
+}
+
+impl RuleSet {
+    /// Reads the rule set from the given JSON file. Will return an error if the file can not be read or is not a rule set.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let rule_set = fs::read_to_string(path).map_err(|e| format!("Failed to read the rule set {} because: {e}", path.display()))?;
+        serde_json::from_str(&rule_set).map_err(|e| format!("The rule set {} is not valid because: {e}", path.display()))
+    }
+
+    /// Returns `true` if the rule with the given name should be checked.
+    pub fn is_enabled(&self, rule_name: &str) -> bool {
+        !self.disabled_rules.iter().any(|disabled_rule| disabled_rule == rule_name)
+    }
+}
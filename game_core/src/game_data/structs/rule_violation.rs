@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{ErrorData, PlayerID};
+
+/// The RuleViolation struct describes which rule an input broke and why.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct RuleViolation {
+    pub rule_name: String,
+    pub message: ErrorData,
+}
+
+impl RuleViolation {
+    #[must_use]
+    pub const fn new(rule_name: String, message: ErrorData) -> Self {
+        Self { rule_name, message }
+    }
+}
+
+/// The RuleViolationCount struct describes how many times a player's inputs have been rejected by a rule.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct RuleViolationCount {
+    pub rule_name: String,
+    pub player_id: PlayerID,
+    pub count: u32,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{RuleWarningID, TurnNumber};
+
+use super::{player_input::PlayerInput, rule_violation::RuleViolation};
+
+/// The RuleWarning struct keeps an input that only broke lenient rules in a game with the facilitated rule profile, until the orchestrator accepts it with an `OverrideRule` input or the turn ends.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RuleWarning {
+    pub id: RuleWarningID,
+    pub input: PlayerInput,
+    /// The rules the input broke and why.
+    pub violations: Vec<RuleViolation>,
+    /// The turn the input was sent in.
+    pub turn_number: TurnNumber,
+}
+
+impl RuleWarning {
+    /// Returns the names of the rules the input broke.
+    pub fn rule_names(&self) -> Vec<String> {
+        self.violations.iter().map(|violation| violation.rule_name.clone()).collect()
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::SituationCardID;
+
+use super::lobby_settings::LobbySettings;
+
+/// The ScenarioTemplate struct describes a ready made setup of a game from a content pack, which the clients can use to fill in the lobby.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ScenarioTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// The situation card the game should be played with, if the scenario decides it.
+    #[serde(default)]
+    pub situation_card_id: Option<SituationCardID>,
+    /// The name of the map the game should be played on, if the scenario decides it.
+    #[serde(default)]
+    pub map_name: Option<String>,
+    /// The settings of the lobby, if the scenario decides them. This includes the constraints on how the objective cards are dealt.
+    #[serde(default)]
+    pub lobby_settings: Option<LobbySettings>,
+}
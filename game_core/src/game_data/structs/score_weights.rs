@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{constants::{EMISSIONS_PER_LOST_POINT, MAX_SCORE_WEIGHT}, custom_types::{Emissions, Money, Score}};
+
+/// The ScoreWeights struct describes how much the objective points, the emissions and the budget left of a player count on the leaderboard, in percent.
+///
+/// The default weights give the same score as the score of the players in the game, where the budget left does not count.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ScoreWeights {
+    /// How much the points of the objective cards count.
+    #[serde(default = "ScoreWeights::default_objective_points")]
+    pub objective_points: u32,
+    /// How much the points lost for the emissions count.
+    #[serde(default = "ScoreWeights::default_emissions")]
+    pub emissions: u32,
+    /// How much each unit of money the player has left of the budget counts, rewarding the players that avoided the congestion charges.
+    #[serde(default)]
+    pub budget_efficiency: u32,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            objective_points: Self::default_objective_points(),
+            emissions: Self::default_emissions(),
+            budget_efficiency: 0,
+        }
+    }
+}
+
+impl ScoreWeights {
+    const fn default_objective_points() -> u32 {
+        100
+    }
+
+    const fn default_emissions() -> u32 {
+        100
+    }
+
+    /// Returns an error if any of the weights is higher than [`MAX_SCORE_WEIGHT`](../../constants/constant.MAX_SCORE_WEIGHT.html).
+    pub fn validate(&self) -> Result<(), String> {
+        if self.objective_points > MAX_SCORE_WEIGHT || self.emissions > MAX_SCORE_WEIGHT || self.budget_efficiency > MAX_SCORE_WEIGHT {
+            return Err(format!("The score weights can not be higher than {MAX_SCORE_WEIGHT} percent!"));
+        }
+        Ok(())
+    }
+
+    /// Returns the weighted score of the given objective points, emissions and budget left.
+    #[must_use]
+    pub fn weighted_score(&self, objective_points: Score, emissions: Emissions, budget_left: Money) -> Score {
+        let emissions_penalty = i64::from(emissions / EMISSIONS_PER_LOST_POINT);
+        let weighted = (i64::from(objective_points) * i64::from(self.objective_points) - emissions_penalty * i64::from(self.emissions)
+            + i64::from(budget_left) * i64::from(self.budget_efficiency))
+            / 100;
+        Score::try_from(weighted).unwrap_or(if weighted < 0 { Score::MIN } else { Score::MAX })
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{constants::{EMISSIONS_PER_LOST_POINT, SCOREBOARD_POINTS_PER_DELIVERY}, custom_types::{Emissions, Money, Score, TurnNumber}, enums::in_game_id::InGameID};
+
+use super::gamestate::GameState;
+
+/// The TurnScore struct describes how well the policy of the orchestrator worked in one turn.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct TurnScore {
+    pub turn_number: TurnNumber,
+    /// The amount of travellers that reached their destination, by dropping off what their objective card asks for, in the turn.
+    pub deliveries: u32,
+    /// The emissions of the moves made in the turn, which depend on the vehicles the players used. Moves by bus or rail have no emissions.
+    pub emissions: Emissions,
+    /// The congestion charges the players paid in the turn.
+    pub toll_revenue: Money,
+    /// The points of the turn: [`SCOREBOARD_POINTS_PER_DELIVERY`](../../constants/constant.SCOREBOARD_POINTS_PER_DELIVERY.html) for every delivery and a point for every unit of toll revenue,
+    /// minus a point for every [`EMISSIONS_PER_LOST_POINT`](../../constants/constant.EMISSIONS_PER_LOST_POINT.html) emissions.
+    pub points: Score,
+}
+
+/// The Scoreboard struct tallies how effective the policy of the orchestrator is, turn by turn, so that the clients can show the results of the policy when the game ends.
+///
+/// It's kept up to date by the ScoreboardExtension, and is started over when the game starts.
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Scoreboard {
+    /// The score of every turn that has ended, oldest first.
+    pub turns: Vec<TurnScore>,
+    /// The sum of the points of all the turns.
+    pub total_points: Score,
+}
+
+impl Scoreboard {
+    /// Adds the score of the turn that has just ended in the game, which is what has changed since the turns that are already on the scoreboard.
+    pub fn record_turn(&mut self, game: &GameState) {
+        let players = game.players.iter().filter(|player| player.in_game_id != InGameID::Orchestrator);
+        let deliveries = players
+            .clone()
+            .filter(|player| player.objective_card.as_ref().is_some_and(|objective_card| objective_card.dropped_package_off))
+            .count();
+        let emissions: Emissions = players.clone().map(|player| player.emissions).sum();
+        let toll_revenue: Money = players.map(|player| player.congestion_charges_paid).sum();
+
+        let deliveries = u32::try_from(deliveries).unwrap_or(u32::MAX).saturating_sub(self.turns.iter().map(|turn| turn.deliveries).sum());
+        let emissions = emissions.saturating_sub(self.turns.iter().map(|turn| turn.emissions).sum());
+        let toll_revenue = toll_revenue.saturating_sub(self.turns.iter().map(|turn| turn.toll_revenue).sum()).max(0);
+        let points = Score::try_from(deliveries)
+            .unwrap_or(Score::MAX)
+            .saturating_mul(SCOREBOARD_POINTS_PER_DELIVERY)
+            .saturating_add(toll_revenue)
+            .saturating_sub(Score::try_from(emissions / EMISSIONS_PER_LOST_POINT).unwrap_or(Score::MAX));
+
+        self.total_points = self.total_points.saturating_add(points);
+        self.turns.push(TurnScore {
+            turn_number: game.turn_number,
+            deliveries,
+            emissions,
+            toll_revenue,
+            points,
+        });
+    }
+}
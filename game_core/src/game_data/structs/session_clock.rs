@@ -0,0 +1,141 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{Milliseconds, Timestamp}, enums::session_reminder::SessionReminder};
+
+/// Returns the current time in milliseconds since the unix epoch.
+pub fn current_timestamp() -> Timestamp {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| Timestamp::try_from(duration.as_millis()).unwrap_or(Timestamp::MAX))
+}
+
+/// The SessionClock struct keeps track of how long a game has been played and how much is left of the planned session, so that the facilitators can keep to their schedule.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+pub struct SessionClock {
+    /// When the game was started, in milliseconds since the unix epoch. Not set while the game is a lobby.
+    pub started_at: Option<Timestamp>,
+    /// The planned length of the session, from the lobby settings when the game was started. The game has no planned length if it's not set.
+    pub session_length: Option<Milliseconds>,
+    /// The reminders that have already been sent to the players.
+    pub sent_reminders: Vec<SessionReminder>,
+    /// How long the game has been played. Only set on the game states sent to the clients.
+    #[serde(default)]
+    pub elapsed: Milliseconds,
+    /// How much is left of the session. Only set on the game states sent to the clients, and only if the game has a planned length.
+    #[serde(default)]
+    pub remaining: Option<Milliseconds>,
+    /// When the clock was paused, in milliseconds since the unix epoch. The clock is paused while the orchestrator corrects the game, and is not set while the clock is running.
+    #[serde(default)]
+    pub paused_at: Option<Timestamp>,
+    /// How long the clock has been paused in total, not counting the pause that is going on now.
+    #[serde(default)]
+    pub paused_for: Milliseconds,
+}
+
+impl SessionClock {
+    /// Creates a new SessionClock for a game that was started at the given time.
+    #[must_use]
+    pub fn started(started_at: Timestamp, session_length_minutes: Option<u32>) -> Self {
+        Self {
+            started_at: Some(started_at),
+            session_length: session_length_minutes.map(|minutes| Milliseconds::from(minutes) * 60_000),
+            ..Self::default()
+        }
+    }
+
+    /// Returns `true` if the clock is paused.
+    pub const fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Pauses the clock at the given time, so that the time until it's resumed is not counted as played. Nothing happens if the game has not been started or the clock is already paused.
+    pub const fn pause(&mut self, now: Timestamp) {
+        if self.started_at.is_some() && self.paused_at.is_none() {
+            self.paused_at = Some(now);
+        }
+    }
+
+    /// Resumes the clock at the given time if it's paused. Returns how long the clock was paused, which is zero if it was not paused.
+    pub const fn resume(&mut self, now: Timestamp) -> Milliseconds {
+        let Some(paused_at) = self.paused_at.take() else {
+            return 0;
+        };
+        let pause_length = now.saturating_sub(paused_at);
+        self.paused_for = self.paused_for.saturating_add(pause_length);
+        pause_length
+    }
+
+    /// Returns how long the game has been played at the given time, without the time the clock has been paused. Zero if the game has not been started.
+    pub fn played_time(&self, now: Timestamp) -> Milliseconds {
+        let Some(started_at) = self.started_at else {
+            return 0;
+        };
+        let counted_until = self.paused_at.map_or(now, |paused_at| paused_at.min(now));
+        counted_until.saturating_sub(started_at).saturating_sub(self.paused_for)
+    }
+
+    /// Sets the elapsed and remaining time of the session to what they are at the given time. Nothing happens if the game has not been started.
+    pub fn update(&mut self, now: Timestamp) {
+        if self.started_at.is_none() {
+            return;
+        }
+        self.elapsed = self.played_time(now);
+        self.remaining = self.session_length.map(|session_length| session_length.saturating_sub(self.elapsed));
+    }
+
+    /// Returns the reminders that are due at the given time and have not been sent yet, and marks them as sent. The time the clock has been paused is not counted.
+    /// Reminders that would be due before the session has started, like 10 minutes left of a 5 minute session, are never sent.
+    pub fn take_due_reminders(&mut self, now: Timestamp, reminders: &[SessionReminder]) -> Vec<SessionReminder> {
+        let (Some(_), Some(session_length)) = (self.started_at, self.session_length) else {
+            return Vec::new();
+        };
+        let remaining = session_length.saturating_sub(self.played_time(now));
+        let due_reminders: Vec<SessionReminder> = reminders
+            .iter()
+            .filter(|reminder| !self.sent_reminders.contains(reminder))
+            .filter(|reminder| {
+                let time_left_when_due = reminder.time_left_when_due(session_length);
+                time_left_when_due < session_length && remaining <= time_left_when_due
+            })
+            .copied()
+            .collect();
+        self.sent_reminders.extend(due_reminders.iter().copied());
+        due_reminders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_paused_time_is_not_counted_as_played() {
+        let mut clock = SessionClock::started(1_000, Some(10));
+
+        clock.pause(61_000);
+        clock.update(121_000);
+        assert!(clock.is_paused());
+        assert_eq!(clock.elapsed, 60_000);
+
+        clock.resume(181_000);
+        clock.update(241_000);
+        assert!(!clock.is_paused());
+        assert_eq!(clock.paused_for, 120_000);
+        assert_eq!(clock.elapsed, 120_000);
+        assert_eq!(clock.remaining, Some(480_000));
+    }
+
+    #[test]
+    fn a_clock_that_has_not_been_started_can_not_be_paused() {
+        let mut clock = SessionClock::default();
+
+        clock.pause(1_000);
+        clock.resume(2_000);
+
+        assert!(!clock.is_paused());
+        assert_eq!(clock.paused_for, 0);
+        assert_eq!(clock.played_time(3_000), 0);
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::game_data::custom_types::SituationCardID;
+use crate::game_data::custom_types::{AssetID, SituationCardID};
 
 use super::{cost_tuple::CostTuple, player_objective_card::PlayerObjectiveCard};
 
@@ -13,6 +13,9 @@ pub struct SituationCard {
     /// Contains the movement penalty cost for moving within a district for the first time.
     pub costs: Vec<CostTuple>,
     pub objective_cards: Vec<PlayerObjectiveCard>,
+    /// The artwork of the card the clients should show, if the card has one. See the `/resources/assets` endpoint.
+    #[serde(default)]
+    pub artwork_id: Option<AssetID>,
 }
 
 impl SituationCard {
@@ -32,6 +35,7 @@ impl SituationCard {
             goal,
             costs,
             objective_cards,
+            artwork_id: None,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::game_data::enums::text_kind::TextKind;
+
+/// The TextFilter struct cleans up and checks the texts the players send, like the names of the players and lobbies, before they reach the games.
+///
+/// Control characters are removed and the whitespace around the text is trimmed. The text is rejected if it's longer than its [`TextKind`] allows, or contains any of the blocked words.
+///
+/// [`TextKind`]: ../../enums/text_kind/enum.TextKind.html
+#[derive(Clone, Default, Debug)]
+pub struct TextFilter {
+    /// The words the texts can not contain, in lower case. Empty unless set with [`TextFilter::with_blocked_words`].
+    blocked_words: Vec<String>,
+}
+
+impl TextFilter {
+    /// Makes the filter reject texts that contain any of the given words, ignoring the case. Only whole words are matched, so a blocked word inside a longer word is allowed.
+    #[must_use]
+    pub fn with_blocked_words(mut self, blocked_words: Vec<String>) -> Self {
+        self.blocked_words = blocked_words
+            .into_iter()
+            .map(|word| word.trim().to_lowercase())
+            .filter(|word| !word.is_empty())
+            .collect();
+        self
+    }
+
+    /// Returns the cleaned up text, or why the text is not allowed.
+    pub fn sanitize(&self, text: &str, kind: TextKind) -> Result<String, String> {
+        let sanitized: String = text.chars().filter(|character| !character.is_control()).collect();
+        let sanitized = sanitized.trim().to_string();
+        if sanitized.chars().count() > kind.max_length() {
+            return Err(format!("The text can not be longer than {} characters!", kind.max_length()));
+        }
+        let lower_case = sanitized.to_lowercase();
+        if lower_case
+            .split(|character: char| !character.is_alphanumeric())
+            .any(|word| self.blocked_words.iter().any(|blocked_word| blocked_word == word))
+        {
+            return Err("The text contains a word that is not allowed!".to_string());
+        }
+        Ok(sanitized)
+    }
+}
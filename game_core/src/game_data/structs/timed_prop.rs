@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{custom_types::{Milliseconds, Timestamp}, enums::timed_prop_kind::TimedPropKind};
+
+/// The TimedProp struct describes a prop of a scenario that changes the game as time passes, independent of the turns, like a ferry that crosses every few seconds.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct TimedProp {
+    /// The name of the prop, which has to be unique in the game.
+    pub name: String,
+    /// How many seconds there are between the ticks of the prop.
+    pub interval_seconds: u32,
+    pub kind: TimedPropKind,
+}
+
+impl TimedProp {
+    /// Returns the time between the ticks of the prop.
+    #[must_use]
+    pub fn interval(&self) -> Milliseconds {
+        Milliseconds::from(self.interval_seconds.max(1)) * 1000
+    }
+}
+
+/// The TimedPropState struct keeps track of how many times a timed prop of a game has ticked and when it ticks next.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct TimedPropState {
+    pub name: String,
+    pub ticks: u32,
+    /// When the prop ticks next, in milliseconds since the unix epoch. Not set when the prop has stopped ticking, like when a countdown has ended.
+    pub next_tick_at: Option<Timestamp>,
+}
+
+impl TimedPropState {
+    /// Creates the state of the prop that first ticks one interval after the given time.
+    #[must_use]
+    pub fn started(prop: &TimedProp, now: Timestamp) -> Self {
+        Self {
+            name: prop.name.clone(),
+            ticks: 0,
+            next_tick_at: Some(now.saturating_add(prop.interval())),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::custom_types::{ErrorData, GameID, TurnNumber};
+
+use super::{player_input::PlayerInput, rule_violation::RuleViolation};
+
+/// The ActionAudit struct describes the outcome of validating and applying one of the staged actions of a game again.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ActionAudit {
+    /// The position of the action in the staged actions of the game.
+    pub index: usize,
+    pub action: PlayerInput,
+    /// The rule the action breaks in the state it is applied to, if any.
+    pub rule_violation: Option<RuleViolation>,
+    /// Why the action could not be applied to the state, if it failed.
+    pub apply_error: Option<ErrorData>,
+}
+
+impl ActionAudit {
+    /// Returns `true` if the rules and applying the action disagree, meaning that the action is either valid but fails to apply, or breaks a rule but is applied anyway.
+    pub const fn is_discrepancy(&self) -> bool {
+        self.rule_violation.is_none() == self.apply_error.is_some()
+    }
+}
+
+/// The TurnAudit struct describes the outcome of validating and applying every staged action in the current turn of a game again, in order.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TurnAudit {
+    pub game_id: GameID,
+    pub turn_number: TurnNumber,
+    pub actions: Vec<ActionAudit>,
+}
+
+impl TurnAudit {
+    /// Returns the actions where the rules and applying the action disagree, see [`ActionAudit::is_discrepancy`].
+    ///
+    /// [`ActionAudit::is_discrepancy`]: struct.ActionAudit.html#method.is_discrepancy
+    pub fn discrepancies(&self) -> Vec<&ActionAudit> {
+        self.actions.iter().filter(|action| action.is_discrepancy()).collect()
+    }
+}
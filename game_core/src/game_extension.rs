@@ -0,0 +1,39 @@
+use crate::game_data::structs::{gamestate::GameState, movement_outcome::MovementOutcome, player_input::PlayerInput};
+
+/// A trait that defines the interface for an extension of the game, like scoring or statistics, used by the [`GameController`].
+///
+/// The hooks are called for every registered extension, in the order they were registered. All the hooks do nothing by default, so an extension only has to implement the ones it needs.
+/// The staged actions of a turn are applied to copies of the game many times before the turn ends, so the hooks should only change the game based on the game itself and the input, and never keep their own state.
+///
+/// [`GameController`]: ../game_controller/struct.GameController.html
+pub trait GameExtension {
+    /// The name of the extension, used in the logs and error messages.
+    fn name(&self) -> &str;
+
+    /// Called after the game has been started.
+    fn on_game_start(&self, _game: &mut GameState) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called after an input has been applied to the game. `game_before` is the game as it was before the input was applied.
+    fn on_input_applied(&self, _game_before: &GameState, _game: &mut GameState, _input: &PlayerInput) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called after a player has moved, before [`on_input_applied`](#method.on_input_applied) is called for the move. `outcome` describes what the move cost and if it reached the objective of the player.
+    fn on_movement(&self, _game: &mut GameState, _outcome: &MovementOutcome) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called when a turn ends, after the staged actions of the turn have been applied and before it's the next player's turn.
+    fn on_turn_end(&self, _game: &mut GameState) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called after an input has made the game over, see [`GameState::is_game_over`].
+    ///
+    /// [`GameState::is_game_over`]: ../game_data/structs/gamestate/struct.GameState.html#method.is_game_over
+    fn on_game_end(&self, _game: &mut GameState) -> Result<(), String> {
+        Ok(())
+    }
+}
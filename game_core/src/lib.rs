@@ -3,11 +3,45 @@
 //! The game_core library is the core of the game. It contains all the data structures for the game and some of the game logic.
 //! The GameController struct in the game_controller module is the game manager and is what should be used to control all of the games on the server. It has all the neccessary functions to create and handle games.
 
+/// The bots module contains the trait for the bot players that fill the empty seats of a game, and the bot the game controller uses for them.
+pub mod bots;
+/// The content_hash module contains the functions for hashing content, like maps and game states, so that log entries and reports can tell exactly which content they are about.
+pub mod content_hash;
+pub mod content_pack_archive;
 /// The game_controller module contains the game controller struct and its methods related to controlling all the games of the server. And can be thought of as the server's game manager.
 pub mod game_controller;
+/// The extensions module contains the extensions of the game that the game controller uses by default, like scoring, emissions and statistics.
+pub mod extensions;
 /// The game_data module contains all the data structures for the game and some of the game logic.
 pub mod game_data;
+/// The game_extension module contains the trait for the extensions of the game, which hook into the game controller.
+pub mod game_extension;
+pub mod map_loader;
+/// The map_validation module contains the function for validating a map before it is used by the server.
+pub mod map_validation;
+/// The measure_template_list module has the predefined measures (measure templates) the orchestrator can enact with a single input.
+pub mod measure_template_list;
+/// The node_search module contains the function for searching the nodes of a map by their names, tags and districts.
+pub mod node_search;
+/// The objective_deal module contains the function for dealing objective cards to the players when a game starts, following the deal constraints of the scenario.
+pub mod objective_deal;
+/// The objective_deck_validation module contains the function for validating the objective decks facilitators upload before they are used by the server.
+pub mod objective_deck_validation;
+/// The pathfinding module contains functions for finding paths and distances in a [`NodeMap`](game_data/structs/node_map/struct.NodeMap.html).
+pub mod pathfinding;
+/// The reachability module contains functions for finding where the players can go on the map, used to make sure no player is cut off by the orchestrator's measures.
+pub mod reachability;
+/// The replay module contains the append-only log of the inputs accepted in a game, which is used to reconstruct the game as it was at any turn.
+pub mod replay;
+/// The replication module contains the trait and data used for replicating the games to a standby server.
+pub mod replication;
 /// The rule_checker module contains the trait for the rule checker.
 pub mod rule_checker;
 /// The situation_card_list module has the default situation cards for the game, including the objective/assignment cards for each situation card.
 pub mod situation_card_list;
+/// The update_publisher module contains the trait for pushing the new state of a game to the clients of its players.
+pub mod update_publisher;
+/// The win_conditions module contains the functions for checking whether a game is finished and who won it, which are checked after every input that is applied to a game.
+pub mod win_conditions;
+/// The wire_codes module contains the stable number (wire code) of every variant of the enums the clients send and get the most, which the clients can use instead of the names.
+pub mod wire_codes;
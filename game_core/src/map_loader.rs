@@ -0,0 +1,187 @@
+//! The map_loader module contains the functions for reading maps from JSON and GeoJSON files, so that the server can be used with maps of other cities than the default one.
+//!
+//! A map can be written in one of two formats:
+//! - JSON: a [`NodeMap`], in the same format as the `/resources/map` endpoint returns.
+//! - GeoJSON: a `FeatureCollection` where every `Point` feature is a node and every `LineString` feature is an edge, so that maps of other cities can be drawn in a GIS tool.
+//!
+//! The properties of a `Point` feature are the fields of a [`Node`] except `coordinates`, which are taken from the point (`x` is the longitude and `y` the latitude):
+//! `{"id": 0, "name": "Factory", "is_connected_to_rail": true, "is_parking_spot": false, "tags": ["industry"]}`.
+//!
+//! The properties of a `LineString` feature are the fields of a [`NeighbourRelationship`] except `to`, together with the nodes the edge goes between. The line itself is only used for drawing:
+//! `{"edge_id": 0, "from": 0, "to": 1, "neighbourhood": "IndustryPark", "movement_cost": 1, "is_connected_through_rail": true, "restriction": null, "is_one_way": false}`.
+//! An edge gets a relationship in both directions unless it's one-way, in which case it can only be used from `from` to `to`.
+//!
+//! The rest of the map (like `neighbourhood_cost` and `artwork_id`) is in the `properties` of the `FeatureCollection`, with the same names as in a [`NodeMap`].
+//!
+//! The loaders only check the format of the map. A map needs to be validated with [`validate_map`] before it's used, which among other things checks that every node can be reached.
+//!
+//! [`NodeMap`]: ../game_data/structs/node_map/struct.NodeMap.html
+//! [`Node`]: ../game_data/structs/node/struct.Node.html
+//! [`NeighbourRelationship`]: ../game_data/structs/neighbour_relationship/struct.NeighbourRelationship.html
+//! [`validate_map`]: ../map_validation/fn.validate_map.html
+
+use std::{collections::HashMap, fs, path::Path};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::game_data::{
+    custom_types::{AssetID, EdgeID, MovementCost, NodeID},
+    enums::{district::District, restriction_type::RestrictionType},
+    structs::{accessibility::Accessibility, ferry_schedule::FerrySchedule, neighbour_relationship::NeighbourRelationship, node::Node, node_coordinates::NodeCoordinates, node_map::NodeMap},
+};
+
+/// The file extensions of the map files that are loaded, in the formats described in the [module documentation](index.html).
+pub const MAP_FILE_EXTENSIONS: [&str; 2] = ["json", "geojson"];
+
+/// The GeoJsonMap struct is the `FeatureCollection` a GeoJSON map is made of.
+#[derive(Deserialize)]
+struct GeoJsonMap {
+    #[serde(rename = "type")]
+    kind: String,
+    features: Vec<GeoJsonFeature>,
+    #[serde(default)]
+    properties: GeoJsonMapProperties,
+}
+
+/// The GeoJsonMapProperties struct is the part of a [`NodeMap`] that is not nodes or edges.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct GeoJsonMapProperties {
+    neighbourhood_cost: HashMap<District, MovementCost>,
+    district_accessibility: HashMap<District, Accessibility>,
+    artwork_id: Option<AssetID>,
+    generate_reverse_relationships: bool,
+}
+
+#[derive(Deserialize)]
+struct GeoJsonFeature {
+    geometry: GeoJsonGeometry,
+    #[serde(default)]
+    properties: Value,
+}
+
+/// The geometries a feature of a GeoJSON map can have. A position is `[longitude, latitude]`, optionally followed by the altitude which is ignored.
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum GeoJsonGeometry {
+    Point { coordinates: Vec<f64> },
+    /// The coordinates of the line are not read, since they are only used for drawing the edge.
+    LineString {},
+}
+
+/// The properties of a `Point` feature.
+#[derive(Deserialize)]
+struct GeoJsonNode {
+    id: NodeID,
+    name: String,
+    #[serde(default)]
+    is_connected_to_rail: bool,
+    #[serde(default)]
+    is_parking_spot: bool,
+    #[serde(default)]
+    accessibility: Accessibility,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// The properties of a `LineString` feature.
+#[derive(Deserialize)]
+struct GeoJsonEdge {
+    edge_id: EdgeID,
+    from: NodeID,
+    to: NodeID,
+    neighbourhood: District,
+    movement_cost: MovementCost,
+    #[serde(default)]
+    is_connected_through_rail: bool,
+    #[serde(default)]
+    restriction: Option<RestrictionType>,
+    #[serde(default = "default_is_modifiable")]
+    is_modifiable: bool,
+    #[serde(default)]
+    ferry: Option<FerrySchedule>,
+    #[serde(default)]
+    is_one_way: bool,
+}
+
+const fn default_is_modifiable() -> bool {
+    true
+}
+
+/// Returns `true` if the file has the extension of a map file, see [`MAP_FILE_EXTENSIONS`].
+pub fn is_map_file(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| MAP_FILE_EXTENSIONS.iter().any(|map_extension| extension == *map_extension))
+}
+
+/// Reads the map in the given file. Returns an error if the file can not be read or is not a map.
+pub fn load_map_file(path: &Path) -> Result<NodeMap, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("The map file could not be read because: {e}"))?;
+    parse_map(&content)
+}
+
+/// Parses a map written as a [`NodeMap`] or as a GeoJSON `FeatureCollection`, which is told apart by its `type`. Returns an error if it's neither.
+///
+/// [`NodeMap`]: ../game_data/structs/node_map/struct.NodeMap.html
+pub fn parse_map(content: &str) -> Result<NodeMap, String> {
+    let map: Value = serde_json::from_str(content).map_err(|e| format!("The map file is not valid JSON because: {e}"))?;
+    if map.get("type").and_then(Value::as_str) == Some("FeatureCollection") {
+        return parse_geojson_map(map);
+    }
+    serde_json::from_value(map).map_err(|e| format!("The map file is not a valid map because: {e}"))
+}
+
+/// Converts a GeoJSON `FeatureCollection` into a map, see the [module documentation](index.html) for the properties of the features.
+fn parse_geojson_map(map: Value) -> Result<NodeMap, String> {
+    let map: GeoJsonMap = serde_json::from_value(map).map_err(|e| format!("The map file is not a valid GeoJSON map because: {e}"))?;
+    if map.kind != "FeatureCollection" {
+        return Err(format!("A GeoJSON map has to be a FeatureCollection, not a {}!", map.kind));
+    }
+
+    let mut node_map = NodeMap::new();
+    node_map.neighbourhood_cost = map.properties.neighbourhood_cost;
+    node_map.district_accessibility = map.properties.district_accessibility;
+    node_map.artwork_id = map.properties.artwork_id;
+    node_map.generate_reverse_relationships = map.properties.generate_reverse_relationships;
+
+    for (index, feature) in map.features.into_iter().enumerate() {
+        match feature.geometry {
+            GeoJsonGeometry::Point { coordinates } => {
+                let (Some(&x), Some(&y)) = (coordinates.first(), coordinates.get(1)) else {
+                    return Err(format!("The point of feature {index} does not have both a longitude and a latitude!"));
+                };
+                let node: GeoJsonNode = serde_json::from_value(feature.properties).map_err(|e| format!("Feature {index} is not a valid node because: {e}"))?;
+                node_map.nodes.push(Node {
+                    id: node.id,
+                    name: node.name,
+                    is_connected_to_rail: node.is_connected_to_rail,
+                    is_parking_spot: node.is_parking_spot,
+                    accessibility: node.accessibility,
+                    tags: node.tags,
+                    coordinates: Some(NodeCoordinates { x, y }),
+                });
+            }
+            GeoJsonGeometry::LineString {} => {
+                let edge: GeoJsonEdge = serde_json::from_value(feature.properties).map_err(|e| format!("Feature {index} is not a valid edge because: {e}"))?;
+                let relationship = NeighbourRelationship {
+                    edge_id: edge.edge_id,
+                    to: edge.to,
+                    neighbourhood: edge.neighbourhood,
+                    movement_cost: edge.movement_cost,
+                    is_connected_through_rail: edge.is_connected_through_rail,
+                    restriction: edge.restriction,
+                    is_modifiable: edge.is_modifiable,
+                    ferry: edge.ferry,
+                    is_one_way: edge.is_one_way,
+                };
+                if !edge.is_one_way {
+                    let mut reverse_relationship = relationship.clone();
+                    reverse_relationship.to = edge.from;
+                    node_map.edges.entry(edge.to).or_default().push(reverse_relationship);
+                }
+                node_map.edges.entry(edge.from).or_default().push(relationship);
+            }
+        }
+    }
+    Ok(node_map)
+}
@@ -0,0 +1,129 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{content_hash::content_hash, game_data::{custom_types::{EdgeID, NodeID}, structs::{map_report::MapReport, node_map::NodeMap}}, pathfinding::movement_costs_from_node};
+
+/// Validates the map and returns a report with how many nodes, edges and districts it has and every issue found in it.
+///
+/// The map has an issue if a node id is used more than once, an edge goes to a node that does not exist or to the node it comes from, an edge id is used by more than one pair of nodes,
+/// an edge that is not one-way is missing the relationship in the opposite direction, a rail edge is between nodes that are not connected to the railway, a district has no movement cost,
+/// a ferry never departs or can't take any vehicles, the two directions of a ferry edge have different schedules, a node has no edges, a node can not be reached from the other nodes or the map has no parking spots.
+///
+/// If the map generates the missing relationships in the opposite direction, the map is validated with them, and every generated relationship is listed as a warning instead of an issue.
+pub fn validate_map(name: &str, map: &NodeMap) -> MapReport {
+    let mut issues = Vec::new();
+    let mut warnings = Vec::new();
+    let completed_map;
+    let map = match map.generate_reverse_relationships {
+        true => {
+            let mut map = map.clone();
+            warnings.extend(map.add_missing_reverse_relationships().into_iter().map(|(node_id, relationship)| {
+                format!("The edge {} from node {} to node {} did not have a relationship in the opposite direction, so it was generated!", relationship.edge_id, relationship.to, node_id)
+            }));
+            completed_map = map;
+            &completed_map
+        }
+        false => map,
+    };
+
+    let mut node_ids = HashSet::new();
+    for node in map.nodes.iter() {
+        if !node_ids.insert(node.id) {
+            issues.push(format!("There are several nodes with the id {}!", node.id));
+        }
+    }
+    if map.nodes.is_empty() {
+        issues.push("The map does not have any nodes!".to_string());
+    }
+    if !map.nodes.iter().any(|node| node.is_parking_spot) {
+        issues.push("The map does not have any parking spots!".to_string());
+    }
+
+    let is_connected_to_rail = |node_id: NodeID| map.nodes.iter().any(|node| node.id == node_id && node.is_connected_to_rail);
+    let mut edge_nodes: HashMap<EdgeID, (NodeID, NodeID)> = HashMap::new();
+    let mut districts = HashSet::new();
+    // The nodes are checked in order so that the issues are always listed in the same order.
+    let mut from_node_ids: Vec<NodeID> = map.edges.keys().copied().collect();
+    from_node_ids.sort_unstable();
+    for from_node_id in from_node_ids {
+        if !node_ids.contains(&from_node_id) {
+            issues.push(format!("There are edges from the node {}, but the node is not in the map!", from_node_id));
+        }
+        for relationship in map.edges.get(&from_node_id).into_iter().flatten() {
+            districts.insert(relationship.neighbourhood);
+            if !node_ids.contains(&relationship.to) {
+                issues.push(format!("The edge {} goes from node {} to the node {}, which is not in the map!", relationship.edge_id, from_node_id, relationship.to));
+            }
+            if relationship.to == from_node_id {
+                issues.push(format!("The edge {} goes from node {} to itself!", relationship.edge_id, from_node_id));
+            }
+            let node_pair = (from_node_id.min(relationship.to), from_node_id.max(relationship.to));
+            match edge_nodes.get(&relationship.edge_id) {
+                Some(existing_node_pair) if *existing_node_pair != node_pair => issues.push(format!("The edge id {} is used by both the edge between the nodes {:?} and the edge between the nodes {:?}!", relationship.edge_id, existing_node_pair, node_pair)),
+                Some(_) => (),
+                None => {
+                    edge_nodes.insert(relationship.edge_id, node_pair);
+                }
+            }
+            let has_opposite_relationship = map
+                .edges
+                .get(&relationship.to)
+                .is_some_and(|relationships| relationships.iter().any(|opposite| opposite.edge_id == relationship.edge_id && opposite.to == from_node_id));
+            if !has_opposite_relationship && !relationship.is_one_way {
+                issues.push(format!("The edge {} from node {} to node {} does not have a relationship in the opposite direction!", relationship.edge_id, from_node_id, relationship.to));
+            }
+            if relationship.is_connected_through_rail && (!is_connected_to_rail(from_node_id) || !is_connected_to_rail(relationship.to)) {
+                issues.push(format!("The edge {} is a rail edge, but node {} or node {} is not connected to the railway!", relationship.edge_id, from_node_id, relationship.to));
+            }
+            if let Some(ferry) = &relationship.ferry {
+                ferry
+                    .issues()
+                    .into_iter()
+                    .for_each(|issue| issues.push(format!("The ferry on the edge {} from node {} has an issue: {}!", relationship.edge_id, from_node_id, issue)));
+            }
+            let has_same_ferry_schedule_in_opposite_direction = map
+                .edges
+                .get(&relationship.to)
+                .into_iter()
+                .flatten()
+                .filter(|opposite| opposite.edge_id == relationship.edge_id && opposite.to == from_node_id)
+                .all(|opposite| opposite.ferry == relationship.ferry);
+            if !has_same_ferry_schedule_in_opposite_direction {
+                issues.push(format!("The edge {} from node {} to node {} does not have the same ferry schedule in the opposite direction!", relationship.edge_id, from_node_id, relationship.to));
+            }
+        }
+    }
+
+    let mut districts_without_cost: Vec<String> = districts
+        .iter()
+        .filter(|district| !map.neighbourhood_cost.contains_key(district))
+        .map(|district| format!("{:?}", district))
+        .collect();
+    districts_without_cost.sort();
+    districts_without_cost
+        .into_iter()
+        .for_each(|district| issues.push(format!("The district {} does not have a movement cost!", district)));
+
+    for node in map.nodes.iter() {
+        if map.edges.get(&node.id).is_none_or(|relationships| relationships.is_empty()) {
+            issues.push(format!("The node {} ({}) does not have any edges!", node.id, node.name));
+        }
+    }
+
+    if let Some(first_node) = map.nodes.first() {
+        let reachable = movement_costs_from_node(map, first_node.id);
+        let unreachable: Vec<NodeID> = map.nodes.iter().map(|node| node.id).filter(|node_id| !reachable.contains_key(node_id)).collect();
+        if !unreachable.is_empty() {
+            issues.push(format!("The nodes {:?} can not be reached from node {}!", unreachable, first_node.id));
+        }
+    }
+
+    MapReport {
+        name: name.to_string(),
+        nodes: map.nodes.len(),
+        edges: edge_nodes.len(),
+        districts: districts.len(),
+        issues,
+        warnings,
+        content_hash: content_hash(map).ok(),
+    }
+}
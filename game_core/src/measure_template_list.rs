@@ -0,0 +1,46 @@
+use crate::game_data::{structs::{measure_template::MeasureTemplate, district_modifier::DistrictModifier, edge_restriction::EdgeRestriction}, enums::{district::District, district_modifier_type::DistrictModifierType, restriction_type::RestrictionType}, custom_types::{MeasureTemplateID, NodeID}};
+
+/// Returns the predefined measures the orchestrator can enact with a single input.
+pub fn measure_template_list() -> Vec<MeasureTemplate> {
+    vec![
+        MeasureTemplate::new(
+            1,
+            "Low-emission zone".to_string(),
+            "Only electric vehicles can enter the city centre.".to_string(),
+            vec![DistrictModifier::new(District::CityCentre, DistrictModifierType::Access, Some(RestrictionType::Electric))],
+            Vec::new(),
+        ),
+        MeasureTemplate::new(
+            2,
+            "Resident-only access".to_string(),
+            "Only vehicles with a destination in the suburbs can enter the suburbs.".to_string(),
+            vec![DistrictModifier::new(District::Suburbs, DistrictModifierType::Access, Some(RestrictionType::Destination))],
+            Vec::new(),
+        ),
+        MeasureTemplate::new(
+            3,
+            "Toll ring".to_string(),
+            "Every vehicle has to pay a toll to use the ring road.".to_string(),
+            vec![DistrictModifier::new(District::RingRoad, DistrictModifierType::Toll, None).with_money_value(2)],
+            Vec::new(),
+        ),
+        MeasureTemplate::new(
+            4,
+            "Bus priority corridor".to_string(),
+            "The roads from the parking spots at I4 and I6 into the city centre become bus lanes, which only buses and cars with passengers can use.".to_string(),
+            Vec::new(),
+            vec![
+                EdgeRestriction::new(NodeID(9), NodeID(10), RestrictionType::BusLane),
+                EdgeRestriction::new(NodeID(16), NodeID(19), RestrictionType::BusLane),
+            ],
+        ),
+    ]
+}
+
+/// Returns the predefined measure with the given ID. Returns an error if there is no measure template with the given ID.
+pub fn get_measure_template_by_id(id: MeasureTemplateID) -> Result<MeasureTemplate, String> {
+    measure_template_list()
+        .into_iter()
+        .find(|template| template.id == id)
+        .ok_or_else(|| format!("There is no measure template with the ID: {}", id))
+}
@@ -0,0 +1,64 @@
+use crate::game_data::{enums::district::District, structs::{node::Node, node_map::NodeMap, node_search_result::NodeSearchResult}};
+
+/// How well a node matched the query, where the best matches are first.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchQuality {
+    NameStart,
+    Name,
+    Tag,
+    District,
+}
+
+/// Finds the nodes of the map whose name, tags or districts contain the query, so that the clients can search the map without downloading and indexing all of it.
+///
+/// The search ignores case, spaces and punctuation, so `city centre` finds the nodes in the `CityCentre` district. The nodes whose names start with the query come first,
+/// then the other nodes whose names contain it, then the nodes with a matching tag and last the nodes in a matching district. Nothing is found if the query is empty.
+pub fn search_nodes(map: &NodeMap, query: &str) -> Vec<NodeSearchResult> {
+    let query = normalize(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(MatchQuality, NodeSearchResult)> = map
+        .nodes
+        .iter()
+        .filter_map(|node| {
+            let districts = districts_of_node(map, node);
+            let name = normalize(&node.name);
+            let quality = if name.starts_with(&query) {
+                MatchQuality::NameStart
+            } else if name.contains(&query) {
+                MatchQuality::Name
+            } else if node.tags.iter().any(|tag| normalize(tag).contains(&query)) {
+                MatchQuality::Tag
+            } else if districts.iter().any(|district| normalize(&format!("{district:?}")).contains(&query)) {
+                MatchQuality::District
+            } else {
+                return None;
+            };
+            Some((quality, NodeSearchResult { node_id: node.id, name: node.name.clone(), districts, tags: node.tags.clone(), coordinates: node.coordinates }))
+        })
+        .collect();
+    matches.sort_by_key(|(quality, result)| (*quality, result.node_id));
+    matches.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Returns the districts of the edges the node is connected to, in the order of [`District::next`].
+fn districts_of_node(map: &NodeMap, node: &Node) -> Vec<District> {
+    let Some(relationships) = map.edges.get(&node.id) else {
+        return Vec::new();
+    };
+    let mut districts = Vec::new();
+    let mut district = Some(District::first());
+    while let Some(current) = district {
+        if relationships.iter().any(|relationship| relationship.neighbourhood == current) {
+            districts.push(current);
+        }
+        district = current.next();
+    }
+    districts
+}
+
+fn normalize(text: &str) -> String {
+    text.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
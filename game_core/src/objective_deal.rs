@@ -0,0 +1,190 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    game_data::{
+        custom_types::{MovementCost, NodeID},
+        enums::{district::District, game_length::GameLength, objective_difficulty::ObjectiveDifficulty},
+        structs::{deal_constraints::DealConstraints, node_map::NodeMap, player_objective_card::PlayerObjectiveCard},
+    },
+    pathfinding::{main_district_of_node, shortest_path_cost},
+};
+
+/// How much worse it is to give two players objectives in the same district compared to one movement of difference between the objective distance and the target distance.
+const OBJECTIVE_DISTRICT_COLLISION_PENALTY: i32 = 3;
+
+/// The ObjectiveDeal struct is the outcome of dealing objective cards: the dealt cards and the constraints that could not be met.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectiveDeal {
+    pub cards: Vec<PlayerObjectiveCard>,
+    /// A description of every constraint the dealt cards do not meet. Empty if all the constraints were met.
+    pub unmet_constraints: Vec<String>,
+}
+
+/// What the dealing needs to know about an objective card.
+struct ObjectiveInfo {
+    district: Option<District>,
+    drop_off_node_id: NodeID,
+    distance: i32,
+    difficulty: ObjectiveDifficulty,
+}
+
+/// Deals the given amount of objective cards on the map.
+///
+/// The sets of cards that meet the most constraints are found first. Among them the set where as few objectives as possible are in the same district and the distances of the objectives are as close to the target distance of the game length as possible is chosen.
+/// If several sets are equally good, one of them is chosen at random. The cards are returned in a random order.
+pub fn deal_objective_cards(map: &NodeMap, objective_cards: Vec<PlayerObjectiveCard>, amount: usize, game_length: GameLength, constraints: &DealConstraints) -> ObjectiveDeal {
+    let target_distance = i32::from(game_length.target_objective_distance());
+    let objective_info: Vec<ObjectiveInfo> = objective_cards
+        .iter()
+        .map(|card| {
+            let distance = match (
+                shortest_path_cost(map, card.start_node_id, card.pick_up_node_id),
+                shortest_path_cost(map, card.pick_up_node_id, card.drop_off_node_id),
+            ) {
+                (Some(to_pick_up), Some(to_drop_off)) => i32::from(to_pick_up) + i32::from(to_drop_off),
+                _ => i32::from(MovementCost::MAX),
+            };
+            ObjectiveInfo {
+                district: main_district_of_node(map, card.drop_off_node_id),
+                drop_off_node_id: card.drop_off_node_id,
+                distance,
+                difficulty: ObjectiveDifficulty::from_distance(distance, target_distance),
+            }
+        })
+        .collect();
+    let mut districts_to_cover: Vec<District> = Vec::new();
+    for district in objective_info.iter().filter_map(|info| info.district) {
+        if !districts_to_cover.contains(&district) {
+            districts_to_cover.push(district);
+        }
+    }
+
+    // Only the best combination so far is kept, and every combination that is as good replaces it with a chance of one in the amount of such combinations,
+    // so that all the best combinations are equally likely to be chosen without keeping them all in memory.
+    let mut best_combination: Option<(Vec<usize>, Vec<String>)> = None;
+    let mut best_score = (usize::MAX, i32::MAX);
+    let mut equally_good_combinations = 0_u32;
+    let mut rng = rand::thread_rng();
+    for combination in IndexCombinations::new(objective_cards.len(), amount) {
+        let unmet_constraints = unmet_constraints(&combination, &objective_info, &districts_to_cover, constraints);
+        let score = (unmet_constraints.len(), balance_score(&combination, &objective_info, target_distance));
+        if score < best_score {
+            best_score = score;
+            equally_good_combinations = 0;
+        }
+        if score == best_score {
+            equally_good_combinations += 1;
+            if rng.gen_range(0..equally_good_combinations) == 0 {
+                best_combination = Some((combination, unmet_constraints));
+            }
+        }
+    }
+
+    let Some((chosen_combination, unmet_constraints)) = best_combination else {
+        return ObjectiveDeal { cards: Vec::new(), unmet_constraints: Vec::new() };
+    };
+    let mut cards: Vec<PlayerObjectiveCard> = objective_cards
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| chosen_combination.contains(index))
+        .map(|(_, card)| card)
+        .collect();
+    cards.shuffle(&mut rng);
+    ObjectiveDeal { cards, unmet_constraints }
+}
+
+/// Returns how well the objectives are balanced, where lower is better.
+fn balance_score(combination: &[usize], objective_info: &[ObjectiveInfo], target_distance: i32) -> i32 {
+    let mut district_collisions = 0;
+    for (i, first) in combination.iter().enumerate() {
+        for second in combination.iter().skip(i + 1) {
+            if objective_info[*first].district.is_some() && objective_info[*first].district == objective_info[*second].district {
+                district_collisions += 1;
+            }
+        }
+    }
+    let distance_deviation: i32 = combination
+        .iter()
+        .map(|index| (objective_info[*index].distance - target_distance).abs())
+        .sum();
+    district_collisions * OBJECTIVE_DISTRICT_COLLISION_PENALTY + distance_deviation
+}
+
+fn unmet_constraints(combination: &[usize], objective_info: &[ObjectiveInfo], districts_to_cover: &[District], constraints: &DealConstraints) -> Vec<String> {
+    let mut unmet_constraints = Vec::new();
+    if constraints.objective_in_every_district {
+        let uncovered_districts: Vec<District> = districts_to_cover
+            .iter()
+            .filter(|district| combination.iter().all(|index| objective_info[*index].district != Some(**district)))
+            .copied()
+            .collect();
+        if !uncovered_districts.is_empty() {
+            unmet_constraints.push(format!("No objective was dealt in the districts {uncovered_districts:?}."));
+        }
+    }
+    if constraints.unique_destinations {
+        let mut destinations: Vec<NodeID> = combination.iter().map(|index| objective_info[*index].drop_off_node_id).collect();
+        destinations.sort_unstable();
+        destinations.dedup();
+        if destinations.len() < combination.len() {
+            unmet_constraints.push("Some players share the same destination node.".to_string());
+        }
+    }
+    if let Some(difficulty_mix) = constraints.difficulty_mix {
+        for (difficulty, wanted_amount) in [(ObjectiveDifficulty::Easy, difficulty_mix.easy), (ObjectiveDifficulty::Medium, difficulty_mix.medium), (ObjectiveDifficulty::Hard, difficulty_mix.hard)] {
+            let dealt_amount = combination.iter().filter(|index| objective_info[**index].difficulty == difficulty).count();
+            if dealt_amount < wanted_amount as usize {
+                unmet_constraints.push(format!("Only {dealt_amount} {difficulty:?} objectives were dealt, but {wanted_amount} were wanted."));
+            }
+        }
+    }
+    unmet_constraints
+}
+
+/// Walks through every combination of the given size of the indices below the given amount, one at a time in lexicographic order, so that the combinations are never all in memory at once.
+struct IndexCombinations {
+    amount_of_indices: usize,
+    next: Option<Vec<usize>>,
+}
+
+impl IndexCombinations {
+    fn new(amount_of_indices: usize, combination_size: usize) -> Self {
+        Self {
+            amount_of_indices,
+            next: (combination_size <= amount_of_indices).then(|| (0..combination_size).collect()),
+        }
+    }
+}
+
+impl Iterator for IndexCombinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let combination = self.next.take()?;
+        let size = combination.len();
+        // The last index that can still be increased is moved one up, and the indices after it are put right after it.
+        if let Some(position) = (0..size).rev().find(|position| combination[*position] < self.amount_of_indices - size + position) {
+            let mut next = combination.clone();
+            next[position] += 1;
+            for later_position in (position + 1)..size {
+                next[later_position] = next[later_position - 1] + 1;
+            }
+            self.next = Some(next);
+        }
+        Some(combination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_combinations_are_every_combination_once() {
+        let combinations: Vec<Vec<usize>> = IndexCombinations::new(4, 2).collect();
+        assert_eq!(combinations, vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3], vec![2, 3]]);
+        assert_eq!(IndexCombinations::new(20, 6).count(), 38_760);
+        assert_eq!(IndexCombinations::new(3, 0).collect::<Vec<_>>(), vec![Vec::<usize>::new()]);
+        assert_eq!(IndexCombinations::new(2, 3).count(), 0);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    game_data::{
+        constants::{MAX_OBJECTIVE_CARD_POINTS, MAX_OBJECTIVE_DECK_SIZE},
+        enums::restriction_type::RestrictionType,
+        structs::{node_map::NodeMap, objective_deck::{ObjectiveDeck, ObjectiveDeckReport}},
+    },
+    pathfinding::{main_district_of_node, shortest_path_cost},
+};
+
+/// Validates the objective deck against the map the games are played on and returns a report with the district of every destination and every issue found in the deck.
+///
+/// The deck has an issue if it has no name, has no cards or more than `MAX_OBJECTIVE_DECK_SIZE` cards, or if a card has no name, uses a node that is not in the map,
+/// has the same pick up and drop off node, can not reach the pick up node from the start node or the drop off node from the pick up node, asks for a special vehicle type that is not a vehicle,
+/// transports nothing or has points that are negative or larger than `MAX_OBJECTIVE_CARD_POINTS`.
+pub fn validate_objective_deck(deck: &ObjectiveDeck, map: &NodeMap) -> ObjectiveDeckReport {
+    let mut issues = Vec::new();
+    if deck.name.trim().is_empty() {
+        issues.push("The deck does not have a name!".to_string());
+    }
+    if deck.cards.is_empty() {
+        issues.push("The deck does not have any cards!".to_string());
+    }
+    if deck.cards.len() > MAX_OBJECTIVE_DECK_SIZE {
+        issues.push(format!("The deck has {} cards, but can not have more than {MAX_OBJECTIVE_DECK_SIZE}!", deck.cards.len()));
+    }
+
+    for (index, card) in deck.cards.iter().enumerate() {
+        let card_number = index + 1;
+        if card.name.trim().is_empty() {
+            issues.push(format!("Card {card_number} does not have a name!"));
+        }
+        let mut has_unknown_node = false;
+        for (node_kind, node_id) in [("start", card.start_node_id), ("pick up", card.pick_up_node_id), ("drop off", card.drop_off_node_id)] {
+            if map.get_node_by_id(node_id).is_err() {
+                issues.push(format!("The {node_kind} node {node_id} of card {card_number} is not in the map!"));
+                has_unknown_node = true;
+            }
+        }
+        if card.pick_up_node_id == card.drop_off_node_id {
+            issues.push(format!("Card {card_number} has the same pick up and drop off node!"));
+        }
+        if !has_unknown_node {
+            if shortest_path_cost(map, card.start_node_id, card.pick_up_node_id).is_none() {
+                issues.push(format!("The pick up node {} of card {card_number} can not be reached from the start node {}!", card.pick_up_node_id, card.start_node_id));
+            }
+            if shortest_path_cost(map, card.pick_up_node_id, card.drop_off_node_id).is_none() {
+                issues.push(format!("The drop off node {} of card {card_number} can not be reached from the pick up node {}!", card.drop_off_node_id, card.pick_up_node_id));
+            }
+        }
+        for vehicle_type in card.special_vehicle_types.iter() {
+            if matches!(vehicle_type, RestrictionType::OneWay | RestrictionType::BusLane) {
+                issues.push(format!("Card {card_number} asks for the special vehicle type {:?}, which is not a vehicle type!", vehicle_type));
+            }
+        }
+        if card.amount_of_entities == 0 {
+            issues.push(format!("Card {card_number} does not transport anything!"));
+        }
+        if card.points.is_some_and(|points| !(0..=MAX_OBJECTIVE_CARD_POINTS).contains(&points)) {
+            issues.push(format!("The points of card {card_number} have to be between 0 and {MAX_OBJECTIVE_CARD_POINTS}!"));
+        }
+    }
+
+    ObjectiveDeckReport {
+        name: deck.name.clone(),
+        cards: deck.cards.len(),
+        destination_districts: deck.cards.iter().map(|card| (card.drop_off_node_id, main_district_of_node(map, card.drop_off_node_id))).collect(),
+        issues,
+    }
+}
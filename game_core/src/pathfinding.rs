@@ -0,0 +1,51 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap}};
+
+use crate::game_data::{custom_types::{MovementCost, NodeID}, enums::district::District, structs::node_map::NodeMap};
+
+/// Finds the cheapest movement cost from the start node to every node that can be reached from it. The cost of moving along an edge is the movement cost of the edge, restrictions on the edges are not considered.
+pub fn movement_costs_from_node(map: &NodeMap, start_node_id: NodeID) -> HashMap<NodeID, MovementCost> {
+    let mut costs: HashMap<NodeID, MovementCost> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    costs.insert(start_node_id, 0);
+    queue.push(Reverse((0, start_node_id)));
+
+    while let Some(Reverse((cost, node_id))) = queue.pop() {
+        if costs.get(&node_id).is_some_and(|&known_cost| known_cost < cost) {
+            continue;
+        }
+        let Some(neighbours) = map.edges.get(&node_id) else {
+            continue;
+        };
+        for neighbour in neighbours {
+            let new_cost = cost + neighbour.movement_cost;
+            if costs.get(&neighbour.to).is_some_and(|&known_cost| known_cost <= new_cost) {
+                continue;
+            }
+            costs.insert(neighbour.to, new_cost);
+            queue.push(Reverse((new_cost, neighbour.to)));
+        }
+    }
+    costs
+}
+
+/// Finds the cheapest movement cost between two nodes. Returns `None` if the end node can not be reached from the start node.
+pub fn shortest_path_cost(map: &NodeMap, from_node_id: NodeID, to_node_id: NodeID) -> Option<MovementCost> {
+    movement_costs_from_node(map, from_node_id).get(&to_node_id).copied()
+}
+
+/// Returns the district most of the edges connected to the node are in. Returns `None` if the node has no edges.
+pub fn main_district_of_node(map: &NodeMap, node_id: NodeID) -> Option<District> {
+    let neighbours = map.edges.get(&node_id)?;
+    let mut district_count: Vec<(District, usize)> = Vec::new();
+    for neighbour in neighbours {
+        match district_count.iter_mut().find(|(district, _)| *district == neighbour.neighbourhood) {
+            Some((_, count)) => *count += 1,
+            None => district_count.push((neighbour.neighbourhood, 1)),
+        }
+    }
+    district_count
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(district, _)| district)
+}
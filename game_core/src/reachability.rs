@@ -0,0 +1,79 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::game_data::{custom_types::{NodeID, PlayerID}, enums::player_input_type::PlayerInputType, structs::{gamestate::GameState, player_input::PlayerInput}};
+
+/// Finds every node the player with the given id can reach from its current position, including the current position.
+///
+/// The can_move function decides if the player is allowed to move to the node in the related_node_id of the movement input, given that the player is at the position in the game state.
+/// It should only check the rules about where the player can go (like restrictions on the edges), and not the rules about how far or when the player can go, since the player is moved without paying for it.
+pub fn reachable_nodes(
+    game: &GameState,
+    player_id: PlayerID,
+    can_move: impl Fn(&GameState, &PlayerInput) -> bool,
+) -> Result<HashSet<NodeID>, String> {
+    let player = game.get_player_with_unique_id(player_id)?;
+    let Some(start_node_id) = player.position_node_id else {
+        return Err(format!("The player {} is not at any node and it's therefore not known where the player can go!", player.name));
+    };
+
+    let mut game_clone = game.clone();
+    let mut reachable = HashSet::from([start_node_id]);
+    let mut queue = VecDeque::from([start_node_id]);
+
+    while let Some(node_id) = queue.pop_front() {
+        let Some(neighbours) = game.map.get_neighbour_relationships_of_node_with_id(node_id) else {
+            continue;
+        };
+        if let Some(moved_player) = game_clone.players.iter_mut().find(|p| p.unique_id == player_id) {
+            moved_player.position_node_id = Some(node_id);
+        }
+        for neighbour in neighbours {
+            if reachable.contains(&neighbour.to) {
+                continue;
+            }
+            let input = PlayerInput {
+                related_node_id: Some(neighbour.to),
+                edge_id: Some(neighbour.edge_id),
+                ..PlayerInput::new(player_id, game.id, PlayerInputType::Movement)
+            };
+            if can_move(&game_clone, &input) {
+                reachable.insert(neighbour.to);
+                queue.push_back(neighbour.to);
+            }
+        }
+    }
+    Ok(reachable)
+}
+
+/// Returns the names of the players in the game that have been cut off from the map.
+///
+/// A player is cut off if it can not reach any parking spot, or can not reach the next node of its objective (the pick up node before the package is picked up, and the drop off node after).
+/// Players without a position, like the orchestrator, are skipped. See [`reachable_nodes`] for what the can_move function should check.
+pub fn isolated_players(game: &GameState, can_move: impl Fn(&GameState, &PlayerInput) -> bool) -> Result<Vec<String>, String> {
+    let mut isolated_players = Vec::new();
+    for player in game.players.iter().filter(|player| player.position_node_id.is_some()) {
+        let reachable = reachable_nodes(game, player.unique_id, &can_move)?;
+
+        let can_reach_parking_spot = game
+            .map
+            .base()
+            .nodes
+            .iter()
+            .any(|node| node.is_parking_spot && reachable.contains(&node.id));
+
+        let can_reach_objective = player.objective_card.as_ref().is_none_or(|objective_card| {
+            if objective_card.dropped_package_off {
+                true
+            } else if objective_card.picked_package_up {
+                reachable.contains(&objective_card.drop_off_node_id)
+            } else {
+                reachable.contains(&objective_card.pick_up_node_id)
+            }
+        });
+
+        if !can_reach_parking_spot || !can_reach_objective {
+            isolated_players.push(player.name.clone());
+        }
+    }
+    Ok(isolated_players)
+}
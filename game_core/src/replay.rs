@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_data::{
+    custom_types::{StateHash, Timestamp, TurnNumber},
+    enums::player_input_type::PlayerInputType,
+    structs::{game_snapshot::GameSnapshot, gamestate::GameState, player::Player, player_input::PlayerInput, session_clock::current_timestamp},
+};
+
+/// The ReplayLog struct is the append-only log of every input that was accepted in a game since it was started, together with a copy of the game from right before it was started.
+///
+/// Replaying the inputs in order on the copy reconstructs the game as it was at any turn, which is what [`GameController::replay_to_turn`] does.
+///
+/// The inputs that only refer to other inputs (like `OverrideRule` and `RetryDeadLetter`) are not logged, since the inputs they refer to are logged when they are accepted.
+///
+/// [`GameController::replay_to_turn`]: ../game_controller/struct.GameController.html#method.replay_to_turn
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct ReplayLog {
+    /// The game as it was right before it was started. It's `None` while the game is in the lobby.
+    pub start: Option<Box<GameSnapshot>>,
+    pub entries: Vec<ReplayEntry>,
+}
+
+/// The ReplayEntry struct describes an input that was accepted in a game, in the order it was accepted.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ReplayEntry {
+    /// The position of the entry in the replay log, starting at 0.
+    pub sequence_number: usize,
+    /// The turn of the game the input was accepted in.
+    pub turn_number: TurnNumber,
+    /// When the input was accepted, in milliseconds since the unix epoch.
+    pub timestamp: Timestamp,
+    pub input: PlayerInput,
+    /// The players right after the input if it dealt them objective cards, like when a round is started. The cards are dealt at random, so the players are set back to these when the input is replayed to get the same cards and start positions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dealt_players: Option<Vec<Player>>,
+    /// The state hash of the game right after the input, used to notice if a replay does not end up with the same game. See [`GameState::state_hash`].
+    ///
+    /// [`GameState::state_hash`]: ../game_data/structs/gamestate/struct.GameState.html#method.state_hash
+    pub state_hash: Option<StateHash>,
+}
+
+/// The ReplayedGame struct is a game reconstructed from its replay log, as it was at the start of a turn.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ReplayedGame {
+    pub turn_number: TurnNumber,
+    /// How many of the entries in the replay log were replayed to reconstruct the game.
+    pub replayed_entries: usize,
+    /// The sequence number of the first replayed entry that did not end up with the same state hash as when the input was accepted, if any.
+    /// The game after it may differ from how it was, like when a player left or reconnected to the game in between the inputs.
+    pub diverged_at: Option<usize>,
+    pub game: GameState,
+}
+
+impl ReplayLog {
+    /// Creates a new replay log starting from the given copy of the game, taken right before the game was started.
+    #[must_use]
+    pub fn new(start: GameSnapshot) -> Self {
+        Self {
+            start: Some(Box::new(start)),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the game has been started, so that the accepted inputs are logged.
+    pub const fn has_started(&self) -> bool {
+        self.start.is_some()
+    }
+
+    /// Returns `true` if inputs of the given type are logged, see [`ReplayLog`].
+    pub const fn is_logged(input_type: &PlayerInputType) -> bool {
+        !matches!(
+            input_type,
+            PlayerInputType::OverrideRule | PlayerInputType::OverrideAndApply | PlayerInputType::RetryDeadLetter | PlayerInputType::DiscardDeadLetter
+        )
+    }
+
+    /// Appends an entry for the accepted input to the log. `dealt_players` are the players right after the input if it dealt them objective cards, see [`ReplayEntry::dealt_players`].
+    pub fn append(&mut self, turn_number: TurnNumber, input: PlayerInput, dealt_players: Option<Vec<Player>>, state_hash: Option<StateHash>) {
+        self.entries.push(ReplayEntry {
+            sequence_number: self.entries.len(),
+            turn_number,
+            timestamp: current_timestamp(),
+            input,
+            dealt_players,
+            state_hash,
+        });
+    }
+}
+
+impl ReplayEntry {
+    /// Sets the players back to how they were right after the input was accepted, if the input dealt them objective cards.
+    pub fn restore_dealt_players(&self, game: &mut GameState) {
+        if let Some(players) = &self.dealt_players {
+            game.players = players.clone();
+        }
+    }
+}
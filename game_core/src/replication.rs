@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{game_data::{custom_types::{GameID, PlayerID}, structs::{game_history::GameHistory, game_snapshot::GameSnapshot, local_players::LocalPlayers}}, replay::ReplayEntry};
+
+/// A change to the state of a [`GameController`] that a standby server needs to apply to keep a warm copy of all the games.
+///
+/// [`GameController`]: ../game_controller/struct.GameController.html
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum ReplicationEntry {
+    PlayerIdAdded(PlayerID),
+    PlayerIdRemoved(PlayerID),
+    GameUpdated(Box<GameSnapshot>),
+    GameRemoved(GameID),
+    /// The history of the game changed other than by an input being added to its replay log, like when a turn started or a checkpoint was created. It's sent apart from the game, since it has many copies of it.
+    GameHistoryUpdated(GameID, Box<GameHistory>),
+    /// An accepted input was added to the replay log of the game.
+    ReplayEntryAppended(GameID, Box<ReplayEntry>),
+    LocalPlayersUpdated(LocalPlayers),
+    /// All the games, player ids and control tokens were cleared by an admin.
+    ServerReset,
+}
+
+/// A trait that defines the interface for sending the changes of a [`GameController`] to a standby server.
+///
+/// [`GameController`]: ../game_controller/struct.GameController.html
+pub trait StateReplicator {
+    /// Sends the entry to the standby server. This should not block, since it's called while the game controller is in use.
+    fn replicate(&self, entry: ReplicationEntry);
+
+    /// Sends the entry as part of a periodic flush of all the games, which also covers what changes without an input. A replicator that skips some changes (like a storage backend that only saves at the turn boundaries)
+    /// should not skip these. Sends the entry like any other by default.
+    fn flush(&self, entry: ReplicationEntry) {
+        self.replicate(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_data::{
+        constants::{MAX_CHECKPOINTS, MAX_REPLICATION_ENTRY_SIZE},
+        custom_types::NodeID,
+        enums::{in_game_id::InGameID, player_input_type::PlayerInputType},
+        structs::{game_event::GameEventKind, gamestate::GameState, input_record::InputOutcome, player::Player, player_input::PlayerInput},
+    };
+
+    const ROLES: [InGameID; 7] = [InGameID::Orchestrator, InGameID::PlayerOne, InGameID::PlayerTwo, InGameID::PlayerThree, InGameID::PlayerFour, InGameID::PlayerFive, InGameID::PlayerSix];
+
+    /// Plays the rounds in the game the way the game controller records them: every player moves a few times and ends the turn, and a copy of the game is kept from the start of every turn.
+    fn play_rounds(game: &mut GameState, history: &mut GameHistory, rounds: u32) {
+        for _ in 0..rounds {
+            for role in ROLES {
+                let player_id = PlayerID(role as i32);
+                for node_id in 0..8 {
+                    let input = PlayerInput { related_node_id: Some(NodeID(node_id)), ..PlayerInput::new(player_id, game.id, PlayerInputType::Movement) };
+                    game.record_input(game.turn_number, input.clone(), InputOutcome::Accepted);
+                    history.replay_log.append(game.turn_number, input, None, Some(0));
+                }
+                let next_turn = PlayerInput::new(player_id, game.id, PlayerInputType::NextTurn);
+                game.record_input(game.turn_number, next_turn.clone(), InputOutcome::Accepted);
+                history.replay_log.append(game.turn_number, next_turn, None, Some(0));
+                game.turn_number += 1;
+                game.current_players_turn = role;
+                game.record_event(GameEventKind::TurnChanged(role));
+                history.record_turn_snapshot(game);
+            }
+            game.round_number += 1;
+        }
+    }
+
+    #[test]
+    fn replication_entries_fit_in_the_limit_after_many_rounds() {
+        let mut game = GameState::new("Test game".to_string(), GameID(1));
+        for role in ROLES {
+            let mut player = Player::new(PlayerID(role as i32), format!("{:?}", role));
+            player.in_game_id = role;
+            game.players.push(player);
+        }
+        game.is_lobby = false;
+        let mut history = GameHistory { replay_log: crate::replay::ReplayLog::new(game.saved_copy()), ..GameHistory::default() };
+        // Enough rounds to fill the input history of the game, with the checkpoints spread out over them.
+        for checkpoint in 0..MAX_CHECKPOINTS {
+            play_rounds(&mut game, &mut history, 4);
+            history.create_checkpoint(&mut game, format!("Round {checkpoint}")).unwrap();
+        }
+
+        let game_entry = serde_json::to_vec(&ReplicationEntry::GameUpdated(Box::new(GameSnapshot::new(&game)))).unwrap();
+        let history_entry = serde_json::to_vec(&ReplicationEntry::GameHistoryUpdated(game.id, Box::new(history))).unwrap();
+
+        assert!(game_entry.len() < MAX_REPLICATION_ENTRY_SIZE, "The game entry is {} bytes", game_entry.len());
+        assert!(history_entry.len() < MAX_REPLICATION_ENTRY_SIZE, "The history entry is {} bytes", history_entry.len());
+    }
+}
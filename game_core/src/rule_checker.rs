@@ -1,8 +1,34 @@
-use crate::game_data::{structs::{player_input::PlayerInput, gamestate::GameState}, custom_types::ErrorData};
+use crate::game_data::{structs::{player_input::PlayerInput, gamestate::GameState, rule_violation::RuleViolation}, custom_types::ErrorData};
 
 /// A trait that defines the interface for a rule checker used by the [`GameController`].
 /// 
 /// [`GameController`]: ../game_controller/struct.GameController.html
 pub trait RuleChecker {
-    fn is_input_valid(&self, game: &GameState, input: &PlayerInput) -> Option<ErrorData>;
+    /// Returns the first rule the input breaks and why. Returns `None` if the input is valid.
+    fn find_rule_violation(&self, game: &GameState, input: &PlayerInput) -> Option<RuleViolation>;
+
+    /// Returns every rule the input breaks and why, in the order the rules are checked. Returns an empty list if the input is valid.
+    ///
+    /// The rules checked after the first broken one may be broken only because of it, like when the player is not in the game.
+    fn find_rule_violations(&self, game: &GameState, input: &PlayerInput) -> Vec<RuleViolation> {
+        self.find_rule_violation(game, input).into_iter().collect()
+    }
+
+    /// Returns why the input is not valid. Returns `None` if the input is valid.
+    fn is_input_valid(&self, game: &GameState, input: &PlayerInput) -> Option<ErrorData> {
+        self.find_rule_violation(game, input).map(|violation| violation.message)
+    }
+
+    /// Returns why the rule scripts of a content pack can not be used, as pairs of their path in the `rule_scripts` folder of the pack and the script. Returns `None` if they can be used.
+    fn check_content_pack_rules(&self, rule_scripts: &[(String, String)]) -> Option<ErrorData> {
+        if rule_scripts.is_empty() {
+            return None;
+        }
+        Some("This rule checker does not support rule scripts!".to_string())
+    }
+
+    /// Replaces the rules from the active content pack with the given rule scripts. Returns an error, without changing the rules, if any of the scripts can not be used.
+    fn set_content_pack_rules(&mut self, rule_scripts: &[(String, String)]) -> Result<(), ErrorData> {
+        self.check_content_pack_rules(rule_scripts).map_or(Ok(()), Err)
+    }
 }
@@ -1,10 +1,35 @@
-use crate::game_data::{structs::{situation_card_list::SituationCardList, situation_card::SituationCard, cost_tuple::CostTuple, player_objective_card::PlayerObjectiveCard}, enums::{district::District, type_entities_to_transport::TypeEntitiesToTransport, traffic::Traffic}, custom_types::VehicleType};
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use crate::game_data::{structs::{situation_card_list::SituationCardList, situation_card::SituationCard, cost_tuple::CostTuple, player_objective_card::PlayerObjectiveCard}, enums::{district::District, type_entities_to_transport::TypeEntitiesToTransport, traffic::Traffic}, custom_types::{NodeID, VehicleType}};
+
+lazy_static! {
+    static ref CONTENT_PACK_SITUATION_CARDS: RwLock<Vec<SituationCard>> = RwLock::new(Vec::new());
+}
 
 pub fn situation_card_list_wrapper() -> SituationCardList {
     SituationCardList::new(situation_card_list())
 }
 
+/// Returns the situation cards built into the server followed by the ones from the active content pack.
 pub fn situation_card_list() -> Vec<SituationCard> {
+    let mut situation_cards = built_in_situation_card_list();
+    if let Ok(content_pack_cards) = CONTENT_PACK_SITUATION_CARDS.read() {
+        situation_cards.extend(content_pack_cards.iter().cloned());
+    }
+    situation_cards
+}
+
+/// Replaces the situation cards from the active content pack. The ids of the cards should not be the same as the ids of the built-in cards.
+pub fn set_content_pack_situation_cards(situation_cards: Vec<SituationCard>) {
+    if let Ok(mut content_pack_cards) = CONTENT_PACK_SITUATION_CARDS.write() {
+        *content_pack_cards = situation_cards;
+    }
+}
+
+/// Returns the situation cards that are built into the server.
+pub fn built_in_situation_card_list() -> Vec<SituationCard> {
     vec![
         SituationCard::new(
             1,
@@ -20,12 +45,13 @@ pub fn situation_card_list() -> Vec<SituationCard> {
                 CostTuple::new(District::Airport, Traffic::LevelOne),
             ],
             vec![
-                PlayerObjectiveCard::new("Packages".to_string(), 13, 7, 15, Vec::new(), TypeEntitiesToTransport::Packages, 5),
-                PlayerObjectiveCard::new("Passengers".to_string(), 8, 11, 27, vec![VehicleType::Electric], TypeEntitiesToTransport::People, 3),
-                PlayerObjectiveCard::new("Passengers".to_string(), 15, 23, 2, Vec::new(), TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Passengers".to_string(), 17, 22, 14, vec![VehicleType::Electric], TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Passengers".to_string(), 5, 12, 28, Vec::new(), TypeEntitiesToTransport::People, 3),
-                PlayerObjectiveCard::new("Passengers".to_string(), 11, 14, 24, Vec::new(), TypeEntitiesToTransport::People, 3),
+                PlayerObjectiveCard::new("Packages".to_string(), NodeID(13), NodeID(7), NodeID(15), Vec::new(), TypeEntitiesToTransport::Packages, 5),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(8), NodeID(11), NodeID(27), vec![VehicleType::Electric], TypeEntitiesToTransport::People, 3),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(15), NodeID(23), NodeID(2), Vec::new(), TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(17), NodeID(22), NodeID(14), vec![VehicleType::Electric], TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(5), NodeID(12), NodeID(28), Vec::new(), TypeEntitiesToTransport::People, 3),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(11), NodeID(14), NodeID(24), Vec::new(), TypeEntitiesToTransport::People, 3),
+                PlayerObjectiveCard::new("Wheelchair user".to_string(), NodeID(8), NodeID(11), NodeID(27), Vec::new(), TypeEntitiesToTransport::People, 1).with_accessible_route_required(),
             ],
         ),
         SituationCard::new(
@@ -42,12 +68,13 @@ pub fn situation_card_list() -> Vec<SituationCard> {
                 CostTuple::new(District::Airport, Traffic::LevelOne),
             ],
             vec![
-                PlayerObjectiveCard::new("Passengers".to_string(), 8, 14, 12, Vec::new(), TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Passengers".to_string(), 14, 28, 12, Vec::new(), TypeEntitiesToTransport::People, 5),
-                PlayerObjectiveCard::new("Passengers".to_string(), 24, 22, 12, Vec::new(), TypeEntitiesToTransport::People, 5),
-                PlayerObjectiveCard::new("Passengers".to_string(), 22, 10, 12, vec![VehicleType::Electric], TypeEntitiesToTransport::People, 3),
-                PlayerObjectiveCard::new("Passengers".to_string(), 5, 13, 28, Vec::new(), TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Packages".to_string(), 23, 10, 2, Vec::new(), TypeEntitiesToTransport::Packages, 5),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(8), NodeID(14), NodeID(12), Vec::new(), TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(14), NodeID(28), NodeID(12), Vec::new(), TypeEntitiesToTransport::People, 5),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(24), NodeID(22), NodeID(12), Vec::new(), TypeEntitiesToTransport::People, 5),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(22), NodeID(10), NodeID(12), vec![VehicleType::Electric], TypeEntitiesToTransport::People, 3),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(5), NodeID(13), NodeID(28), Vec::new(), TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Packages".to_string(), NodeID(23), NodeID(10), NodeID(2), Vec::new(), TypeEntitiesToTransport::Packages, 5),
+                PlayerObjectiveCard::new("Freight delivery".to_string(), NodeID(2), NodeID(24), NodeID(12), Vec::new(), TypeEntitiesToTransport::Packages, 5).with_freight_role(),
             ]
         ),
         SituationCard::new(
@@ -64,12 +91,12 @@ pub fn situation_card_list() -> Vec<SituationCard> {
                 CostTuple::new(District::Airport, Traffic::LevelOne),
             ],
             vec![
-                PlayerObjectiveCard::new("Evacuate".to_string(), 4, 0, 10, vec![VehicleType::Emergency], TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Dangerous goods".to_string(), 9, 0, 17, vec![VehicleType::Hazard, VehicleType::Emergency], TypeEntitiesToTransport::Packages, 4),
-                PlayerObjectiveCard::new("Ambulance".to_string(), 15, 0, 15, vec![VehicleType::Emergency], TypeEntitiesToTransport::People, 2),
-                PlayerObjectiveCard::new("Evacuate".to_string(), 5, 1, 17, vec![VehicleType::Hazard, VehicleType::Emergency], TypeEntitiesToTransport::Packages, 3),
-                PlayerObjectiveCard::new("Passengers".to_string(), 24, 22, 10, Vec::new(), TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Packages".to_string(), 5, 5, 23, Vec::new(), TypeEntitiesToTransport::Packages, 5),
+                PlayerObjectiveCard::new("Evacuate".to_string(), NodeID(4), NodeID(0), NodeID(10), vec![VehicleType::Emergency], TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Dangerous goods".to_string(), NodeID(9), NodeID(0), NodeID(17), vec![VehicleType::Hazard, VehicleType::Emergency], TypeEntitiesToTransport::Packages, 4),
+                PlayerObjectiveCard::new("Ambulance".to_string(), NodeID(15), NodeID(0), NodeID(15), vec![VehicleType::Emergency], TypeEntitiesToTransport::People, 2),
+                PlayerObjectiveCard::new("Evacuate".to_string(), NodeID(5), NodeID(1), NodeID(17), vec![VehicleType::Hazard, VehicleType::Emergency], TypeEntitiesToTransport::Packages, 3),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(24), NodeID(22), NodeID(10), Vec::new(), TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Packages".to_string(), NodeID(5), NodeID(5), NodeID(23), Vec::new(), TypeEntitiesToTransport::Packages, 5),
             ]
         ),
         SituationCard::new(
@@ -86,12 +113,12 @@ pub fn situation_card_list() -> Vec<SituationCard> {
                 CostTuple::new(District::Airport, Traffic::LevelOne),
             ],
             vec![
-                PlayerObjectiveCard::new("Ambulance".to_string(), 15, 19, 14, vec![VehicleType::Emergency], TypeEntitiesToTransport::People, 1),
-                PlayerObjectiveCard::new("Car removal".to_string(), 14, 19, 14, vec![VehicleType::Emergency], TypeEntitiesToTransport::Packages, 1),
-                PlayerObjectiveCard::new("Passengers".to_string(), 16, 16, 28, Vec::new(), TypeEntitiesToTransport::People, 5),
-                PlayerObjectiveCard::new("Passengers".to_string(), 17, 20, 28, vec![VehicleType::Electric], TypeEntitiesToTransport::People, 3),
-                PlayerObjectiveCard::new("Passengers".to_string(), 27, 27, 15, vec![VehicleType::Electric], TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Packages".to_string(), 23, 24, 7, Vec::new(), TypeEntitiesToTransport::Packages, 5),
+                PlayerObjectiveCard::new("Ambulance".to_string(), NodeID(15), NodeID(19), NodeID(14), vec![VehicleType::Emergency], TypeEntitiesToTransport::People, 1),
+                PlayerObjectiveCard::new("Car removal".to_string(), NodeID(14), NodeID(19), NodeID(14), vec![VehicleType::Emergency], TypeEntitiesToTransport::Packages, 1),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(16), NodeID(16), NodeID(28), Vec::new(), TypeEntitiesToTransport::People, 5),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(17), NodeID(20), NodeID(28), vec![VehicleType::Electric], TypeEntitiesToTransport::People, 3),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(27), NodeID(27), NodeID(15), vec![VehicleType::Electric], TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Packages".to_string(), NodeID(23), NodeID(24), NodeID(7), Vec::new(), TypeEntitiesToTransport::Packages, 5),
             ]
         ),
         SituationCard::new(
@@ -108,12 +135,12 @@ pub fn situation_card_list() -> Vec<SituationCard> {
                 CostTuple::new(District::Airport, Traffic::LevelFour),
             ],
             vec![
-                PlayerObjectiveCard::new("Passengers".to_string(), 23, 10, 27, vec![VehicleType::Electric], TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Passengers".to_string(), 0, 2, 27, Vec::new(), TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Passengers".to_string(), 5, 7, 28, Vec::new(), TypeEntitiesToTransport::People, 5),
-                PlayerObjectiveCard::new("Passengers".to_string(), 16, 10, 28, Vec::new(), TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Passengers".to_string(), 14, 10, 27, Vec::new(), TypeEntitiesToTransport::People, 4),
-                PlayerObjectiveCard::new("Packages".to_string(), 23, 24, 8, Vec::new(), TypeEntitiesToTransport::Packages, 5),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(23), NodeID(10), NodeID(27), vec![VehicleType::Electric], TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(0), NodeID(2), NodeID(27), Vec::new(), TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(5), NodeID(7), NodeID(28), Vec::new(), TypeEntitiesToTransport::People, 5),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(16), NodeID(10), NodeID(28), Vec::new(), TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Passengers".to_string(), NodeID(14), NodeID(10), NodeID(27), Vec::new(), TypeEntitiesToTransport::People, 4),
+                PlayerObjectiveCard::new("Packages".to_string(), NodeID(23), NodeID(24), NodeID(8), Vec::new(), TypeEntitiesToTransport::Packages, 5),
             ]
         ),
     ]
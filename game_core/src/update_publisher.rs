@@ -0,0 +1,7 @@
+use crate::game_data::structs::gamestate::GameState;
+
+/// A trait that defines the interface for pushing the new state of a game to the clients of its players, for example over WebSockets, so that the clients do not have to poll the server for changes.
+pub trait UpdatePublisher {
+    /// Sends the new state of the game to the clients that are connected to it. This should not block, since it's called while the game controller is in use.
+    fn publish(&self, game: &GameState);
+}
@@ -0,0 +1,159 @@
+use crate::game_data::{
+    custom_types::Emissions,
+    enums::{finish_reason::FinishReason, game_status::GameStatus, in_game_id::InGameID, win_condition::WinCondition, winning_side::WinningSide},
+    structs::{gamestate::GameState, group_metrics::GroupMetrics, orchestrator_targets::OrchestratorTargets, player::Player},
+};
+
+/// Returns the status the game should have after the inputs that have been applied to it. A finished game stays finished.
+///
+/// A started game is finished when the [`OrchestratorTargets`] of the lobby settings are met, which the orchestrator wins,
+/// or when every player (except the orchestrator) has dropped off what their objective card asks for, where the [`WinCondition`] of the lobby settings decides who won.
+/// The targets of the orchestrator are not checked in a cooperative game, since the orchestrator is on the same side as the players.
+///
+/// [`OrchestratorTargets`]: ../game_data/structs/orchestrator_targets/struct.OrchestratorTargets.html
+/// [`WinCondition`]: ../game_data/enums/win_condition/enum.WinCondition.html
+pub fn check_game_status(game: &GameState) -> GameStatus {
+    if game.status.is_finished() || game.is_lobby {
+        return game.status.clone();
+    }
+    let are_targets_met = !game.lobby_settings.win_condition.is_cooperative()
+        && game
+            .lobby_settings
+            .orchestrator_targets
+            .as_ref()
+            .is_some_and(|targets| are_orchestrator_targets_met(game, targets));
+    if are_targets_met {
+        return finished(game, FinishReason::OrchestratorTargetsMet, WinningSide::Orchestrator);
+    }
+    if !game.have_all_players_completed_their_objectives() {
+        return GameStatus::Ongoing;
+    }
+    broken_win_condition(game).map_or_else(
+        || finished(game, FinishReason::AllObjectivesReached, winning_side_of(game.lobby_settings.win_condition)),
+        |reason| finished(game, reason, WinningSide::Nobody),
+    )
+}
+
+/// Returns the status the game should have when the turn that is ending is over, see [`check_game_status`].
+///
+/// The game is also finished at the end of a turn when the [`WinCondition`] of the lobby settings is broken, which nobody wins, or when the last of the most turns of the lobby settings ends.
+/// If the turns run out, the players have lost if the win condition is to reach every objective, and the win condition has been kept otherwise.
+///
+/// [`WinCondition`]: ../game_data/enums/win_condition/enum.WinCondition.html
+pub fn check_game_status_at_turn_end(game: &GameState) -> GameStatus {
+    let status = check_game_status(game);
+    if status.is_finished() || game.is_lobby {
+        return status;
+    }
+    if let Some(reason) = broken_win_condition(game) {
+        return finished(game, reason, WinningSide::Nobody);
+    }
+    let is_last_turn = game.lobby_settings.max_turns.is_some_and(|max_turns| game.turn_number.saturating_add(1) >= max_turns);
+    if !is_last_turn {
+        return status;
+    }
+    match game.lobby_settings.win_condition {
+        WinCondition::AllObjectivesReached => finished(game, FinishReason::MaxTurnsReached, WinningSide::Nobody),
+        win_condition => finished(game, FinishReason::MaxTurnsReached, winning_side_of(win_condition)),
+    }
+}
+
+/// Sets the status of the game to what it should be after the last applied input, see [`check_game_status`].
+pub fn update_game_status(game: &mut GameState) {
+    game.status = check_game_status(game);
+}
+
+/// Returns a sentence that sums up how a finished game ended, meant as the headline of the summary of the game. Returns `None` if the game is not finished.
+pub fn headline(game: &GameState) -> Option<String> {
+    let GameStatus::Finished { reason, winning_side, .. } = &game.status else {
+        return None;
+    };
+    let how = match reason {
+        FinishReason::AllObjectivesReached => "Every traveller reached their destination".to_string(),
+        FinishReason::OrchestratorTargetsMet => "The orchestrator met the targets".to_string(),
+        FinishReason::EmissionsExceeded => match game.lobby_settings.win_condition {
+            WinCondition::EmissionsBelow { max_total_emissions } | WinCondition::Cooperative { max_total_emissions: Some(max_total_emissions), .. } => {
+                format!("The emissions went over the limit of {max_total_emissions}")
+            }
+            _ => "The emissions went over the limit".to_string(),
+        },
+        FinishReason::CommuteTooLong => match game.lobby_settings.win_condition {
+            WinCondition::Cooperative { max_average_commute_turns: Some(max_average_commute_turns), .. } => {
+                format!("The average commute took longer than {max_average_commute_turns} turns")
+            }
+            _ => "The average commute took too long".to_string(),
+        },
+        FinishReason::BudgetExceeded => travellers(game)
+            .find(|player| player.budget < 0)
+            .map_or_else(|| "A traveller went over budget".to_string(), |player| format!("{} went over budget", player.name)),
+        FinishReason::MaxTurnsReached => format!("The game ran out of turns after {} turns", game.lobby_settings.max_turns.unwrap_or(game.turn_number)),
+    };
+    let who = match winning_side {
+        WinningSide::Players => "the travellers win",
+        WinningSide::Orchestrator => "the orchestrator wins",
+        WinningSide::Everyone => "everyone wins",
+        WinningSide::Nobody => "nobody wins",
+    };
+    let total_emissions: Emissions = travellers(game).map(|player| player.emissions).sum();
+    Some(format!("{how}, and {who}! The travellers caused {total_emissions} emissions in total."))
+}
+
+/// Returns why the game is lost if the win condition of the lobby settings is broken.
+fn broken_win_condition(game: &GameState) -> Option<FinishReason> {
+    match game.lobby_settings.win_condition {
+        WinCondition::AllObjectivesReached => None,
+        WinCondition::EmissionsBelow { max_total_emissions } => {
+            let total_emissions: Emissions = travellers(game).map(|player| player.emissions).sum();
+            (total_emissions > max_total_emissions).then_some(FinishReason::EmissionsExceeded)
+        }
+        WinCondition::BudgetNotExceeded => travellers(game).any(|player| player.budget < 0).then_some(FinishReason::BudgetExceeded),
+        WinCondition::Cooperative { max_total_emissions, max_average_commute_turns } => {
+            // The emissions and the commute times of the travellers that are still travelling only grow, so a target that is missed now can not be met later.
+            let metrics = GroupMetrics::measure(game);
+            if max_total_emissions.is_some_and(|max_total_emissions| metrics.total_emissions > max_total_emissions) {
+                return Some(FinishReason::EmissionsExceeded);
+            }
+            max_average_commute_turns
+                .is_some_and(|max_average_commute_turns| metrics.average_commute_turns > max_average_commute_turns)
+                .then_some(FinishReason::CommuteTooLong)
+        }
+    }
+}
+
+/// Returns the side that wins when the win condition is kept until the game is finished.
+const fn winning_side_of(win_condition: WinCondition) -> WinningSide {
+    match win_condition {
+        WinCondition::AllObjectivesReached | WinCondition::BudgetNotExceeded => WinningSide::Players,
+        WinCondition::EmissionsBelow { .. } | WinCondition::Cooperative { .. } => WinningSide::Everyone,
+    }
+}
+
+fn finished(game: &GameState, reason: FinishReason, winning_side: WinningSide) -> GameStatus {
+    let winners = game
+        .players
+        .iter()
+        .filter(|player| match winning_side {
+            WinningSide::Players => player.in_game_id != InGameID::Orchestrator,
+            WinningSide::Orchestrator => player.in_game_id == InGameID::Orchestrator,
+            WinningSide::Everyone => true,
+            WinningSide::Nobody => false,
+        })
+        .map(|player| player.unique_id)
+        .collect();
+    GameStatus::Finished { winners, reason, winning_side }
+}
+
+fn travellers(game: &GameState) -> impl Iterator<Item = &Player> {
+    game.players.iter().filter(|player| player.in_game_id != InGameID::Orchestrator)
+}
+
+fn are_orchestrator_targets_met(game: &GameState, targets: &OrchestratorTargets) -> bool {
+    let deliveries = travellers(game).filter(|player| has_completed_objective(player)).count();
+    let total_emissions: Emissions = travellers(game).map(|player| player.emissions).sum();
+    deliveries >= usize::try_from(targets.deliveries).unwrap_or(usize::MAX)
+        && targets.max_total_emissions.is_none_or(|max_total_emissions| total_emissions <= max_total_emissions)
+}
+
+fn has_completed_objective(player: &Player) -> bool {
+    player.objective_card.as_ref().is_some_and(|objective_card| objective_card.dropped_package_off)
+}
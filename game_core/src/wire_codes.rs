@@ -0,0 +1,234 @@
+use std::{cell::Cell, fmt, marker::PhantomData};
+
+use serde::{de::{self, Visitor}, Deserializer, Serializer};
+
+thread_local! {
+    /// If the enums with wire codes are serialized as their codes instead of their names on this thread, see [`with_compact_codes`].
+    static COMPACT_CODES: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A trait for the enums that have a stable number (wire code) for every variant, on top of their names.
+///
+/// The enums are serialized as their names, except inside [`with_compact_codes`] where they are serialized as their codes, and can always be deserialized from either.
+/// The codes must never change or be reused, since the clients that use them would then misread the games. A removed variant keeps its code reserved.
+/// Use the `wire_codes!` macro to implement the trait together with `Serialize` and `Deserialize`.
+pub trait WireCode: Sized + 'static {
+    /// The name of the enum, used in the error messages.
+    const ENUM_NAME: &'static str;
+    /// The variants of the enum together with their codes and names.
+    const VARIANTS: &'static [(Self, u16, &'static str)];
+
+    /// Returns the wire code of the variant.
+    fn code(&self) -> u16;
+
+    /// Returns the name of the variant.
+    fn name(&self) -> &'static str;
+
+    /// Returns the variant with the given wire code, if there is one.
+    fn from_code(code: u16) -> Option<&'static Self> {
+        Self::VARIANTS.iter().find(|(_, variant_code, _)| *variant_code == code).map(|(variant, _, _)| variant)
+    }
+
+    /// Returns the variant with the given name, if there is one.
+    fn from_name(name: &str) -> Option<&'static Self> {
+        Self::VARIANTS.iter().find(|(_, _, variant_name)| *variant_name == name).map(|(variant, _, _)| variant)
+    }
+}
+
+/// Serializes the enums with wire codes as their codes instead of their names while `serialize` runs, like when responding to a client that asked for compact codes.
+///
+/// Only the current thread is affected, so the games are still hashed, stored and given to the rule scripts with the names.
+pub fn with_compact_codes<T>(serialize: impl FnOnce() -> T) -> T {
+    let _restore = RestoreCompactCodes(COMPACT_CODES.with(|compact| compact.replace(true)));
+    serialize()
+}
+
+/// Sets the serialization back to how it was when dropped, so that a panic while serializing doesn't leave the thread serializing the codes.
+struct RestoreCompactCodes(bool);
+
+impl Drop for RestoreCompactCodes {
+    fn drop(&mut self) {
+        COMPACT_CODES.with(|compact| compact.set(self.0));
+    }
+}
+
+/// Serializes the variant as its name, or as its code inside [`with_compact_codes`].
+pub fn serialize<T: WireCode, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    if COMPACT_CODES.with(Cell::get) {
+        serializer.serialize_u16(value.code())
+    } else {
+        serializer.serialize_str(value.name())
+    }
+}
+
+/// Deserializes the variant from either its name or its code. A code given as a string (like in a map key or a query parameter) is also accepted.
+pub fn deserialize<'de, T: WireCode + Clone, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_any(WireCodeVisitor(PhantomData))
+}
+
+struct WireCodeVisitor<T>(PhantomData<T>);
+
+impl<'de, T: WireCode + Clone> Visitor<'de> for WireCodeVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the name or wire code of a {}", T::ENUM_NAME)
+    }
+
+    fn visit_u64<E: de::Error>(self, code: u64) -> Result<T, E> {
+        u16::try_from(code)
+            .ok()
+            .and_then(T::from_code)
+            .cloned()
+            .ok_or_else(|| E::custom(format!("{code} is not the wire code of a {}", T::ENUM_NAME)))
+    }
+
+    fn visit_i64<E: de::Error>(self, code: i64) -> Result<T, E> {
+        u64::try_from(code).map_or_else(|_| Err(E::custom(format!("{code} is not the wire code of a {}", T::ENUM_NAME))), |code| self.visit_u64(code))
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<T, E> {
+        if let Some(variant) = T::from_name(name) {
+            return Ok(variant.clone());
+        }
+        name.parse::<u64>().map_or_else(|_| Err(E::custom(format!("{name} is not the name of a {}", T::ENUM_NAME))), |code| self.visit_u64(code))
+    }
+}
+
+/// Implements [`WireCode`], `Serialize` and `Deserialize` for an enum whose variants have no fields, with the given wire code for every variant.
+/// Leaving out a variant is a compile error.
+#[macro_export]
+macro_rules! wire_codes {
+    ($enum:ident { $($variant:ident = $code:literal),* $(,)? }) => {
+        impl $crate::wire_codes::WireCode for $enum {
+            const ENUM_NAME: &'static str = stringify!($enum);
+            const VARIANTS: &'static [(Self, u16, &'static str)] = &[$((Self::$variant, $code, stringify!($variant))),*];
+
+            fn code(&self) -> u16 {
+                match self {
+                    $(Self::$variant => $code),*
+                }
+            }
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant)),*
+                }
+            }
+        }
+
+        impl serde::Serialize for $enum {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::wire_codes::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $crate::wire_codes::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::game_data::enums::{district_modifier_type::DistrictModifierType, in_game_id::InGameID, player_input_type::PlayerInputType, restriction_type::RestrictionType, traffic::Traffic};
+
+    /// Checks that every variant of the enum has exactly the wire code it has in the table, both ways and when serialized.
+    fn assert_wire_codes<T: WireCode + PartialEq + Debug>(table: &[(u16, T)]) {
+        assert_eq!(table.len(), T::VARIANTS.len(), "every variant of {} must be in the table", T::ENUM_NAME);
+        for (code, variant) in table {
+            assert_eq!(variant.code(), *code, "{}::{:?}", T::ENUM_NAME, variant);
+            assert_eq!(T::from_code(*code), Some(variant), "{}::{:?}", T::ENUM_NAME, variant);
+        }
+    }
+
+    #[test]
+    fn player_input_type_codes_have_not_changed() {
+        assert_wire_codes(&[
+            (0, PlayerInputType::Movement),
+            (1, PlayerInputType::ChangeRole),
+            (2, PlayerInputType::All),
+            (3, PlayerInputType::NextTurn),
+            (4, PlayerInputType::UndoAction),
+            (5, PlayerInputType::ModifyDistrict),
+            (6, PlayerInputType::StartGame),
+            (7, PlayerInputType::AssignSituationCard),
+            (8, PlayerInputType::LeaveGame),
+            (9, PlayerInputType::ModifyEdgeRestrictions),
+            (10, PlayerInputType::SetPlayerBusBool),
+            (11, PlayerInputType::ChangeLobbySettings),
+            (12, PlayerInputType::RenamePlayer),
+            (13, PlayerInputType::RevokeMeasure),
+            (14, PlayerInputType::OfferRide),
+            (15, PlayerInputType::AcceptRide),
+            (16, PlayerInputType::ExitVehicle),
+            (17, PlayerInputType::SetCongestionZone),
+            (18, PlayerInputType::EnactMeasureTemplate),
+            (19, PlayerInputType::CreateCheckpoint),
+            (20, PlayerInputType::RestoreCheckpoint),
+            (21, PlayerInputType::ClaimSeat),
+            (22, PlayerInputType::Pass),
+            (23, PlayerInputType::NextScenario),
+            (24, PlayerInputType::TickTimedProp),
+            (25, PlayerInputType::ScheduleConstruction),
+            (26, PlayerInputType::RetryDeadLetter),
+            (27, PlayerInputType::DiscardDeadLetter),
+            (28, PlayerInputType::OverrideRule),
+            (29, PlayerInputType::OverrideAndApply),
+            (30, PlayerInputType::AnnotateMap),
+            (31, PlayerInputType::ChatMessage),
+        ]);
+    }
+
+    #[test]
+    fn in_game_id_codes_have_not_changed() {
+        assert_wire_codes(&[
+            (0, InGameID::Undecided),
+            (1, InGameID::PlayerOne),
+            (2, InGameID::PlayerTwo),
+            (3, InGameID::PlayerThree),
+            (4, InGameID::PlayerFour),
+            (5, InGameID::PlayerFive),
+            (6, InGameID::PlayerSix),
+            (7, InGameID::Orchestrator),
+        ]);
+    }
+
+    #[test]
+    fn restriction_type_codes_have_not_changed() {
+        assert_wire_codes(&[
+            (0, RestrictionType::ParkAndRide),
+            (1, RestrictionType::Electric),
+            (2, RestrictionType::Emergency),
+            (3, RestrictionType::Hazard),
+            (4, RestrictionType::Destination),
+            (5, RestrictionType::Heavy),
+            (6, RestrictionType::OneWay),
+            (7, RestrictionType::BusLane),
+        ]);
+    }
+
+    #[test]
+    fn district_modifier_type_codes_have_not_changed() {
+        assert_wire_codes(&[(0, DistrictModifierType::Access), (1, DistrictModifierType::Priority), (2, DistrictModifierType::Toll)]);
+    }
+
+    #[test]
+    fn traffic_codes_have_not_changed() {
+        assert_wire_codes(&[(0, Traffic::LevelOne), (1, Traffic::LevelTwo), (2, Traffic::LevelThree), (3, Traffic::LevelFour), (4, Traffic::LevelFive)]);
+    }
+
+    #[test]
+    fn enums_are_serialized_as_codes_only_inside_with_compact_codes() {
+        assert_eq!(serde_json::to_string(&InGameID::Orchestrator).ok(), Some("\"Orchestrator\"".to_string()));
+        assert_eq!(with_compact_codes(|| serde_json::to_string(&InGameID::Orchestrator).ok()), Some("7".to_string()));
+        assert_eq!(serde_json::from_str::<InGameID>("7").ok(), Some(InGameID::Orchestrator));
+        assert_eq!(serde_json::from_str::<InGameID>("\"7\"").ok(), Some(InGameID::Orchestrator));
+        assert!(serde_json::from_str::<InGameID>("8").is_err());
+    }
+}
@@ -1,7 +1,15 @@
-///! This library contains a logger trait and a threshold logger struct that implements the logger trait.
+//! This library contains a logger trait, the threshold logger and multi logger structs that implement the logger trait, and the log sender the server logs through.
 
 /// The logger module contains a logger trait and the enum/datastructures it uses.
 pub mod logger;
+/// The log_sender module contains the log sender that sends the log entries to a dedicated logging thread.
+pub mod log_sender;
+/// The log_file module contains the log file struct the loggers use to write to the log files.
+pub mod log_file;
+/// The multi_logger module contains a logger that writes to several sinks (console, file and JSON), which can be changed while the server runs.
+pub mod multi_logger;
+/// The remote_logger module contains a logger that ships the log entries to a remote collector over HTTP or UDP.
+pub mod remote_logger;
 /// The threshold_logger module contains a threshold logger struct that implements the logger trait.
 pub mod threshold_logger;
 
@@ -0,0 +1,73 @@
+use chrono::Local;
+use std::env;
+use std::fs::{metadata, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use crate::{LOG_FOLDER_NAME, MAX_FILE_SIZE};
+
+/// The LogFile struct appends lines to the log files of one kind in the log folder next to the executable.
+///
+/// A new file is started every day, and when a file reaches the max file size the lines are written to a new file with a higher index.
+#[derive(Debug)]
+pub struct LogFile {
+    /// The start of the file names, like `threshold_logger`.
+    name: &'static str,
+    /// The extension of the file names, like `txt`.
+    extension: &'static str,
+    file_index: u128,
+}
+
+impl LogFile {
+    #[must_use]
+    pub const fn new(name: &'static str, extension: &'static str) -> Self {
+        Self { name, extension, file_index: 0 }
+    }
+
+    /// Appends the line to the current log file. Will return an error if the file could not be opened or written to.
+    pub fn append_line(&mut self, line: &str) -> Result<(), String> {
+        let file_path = self.create_file_path().map_err(|e| format!("Failed to make get filepath because: {e}"))?;
+        let mut file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(file_path)
+            .map_err(|e| format!("Failed to open file because: {e}"))?;
+        writeln!(file, "{line}").map_err(|e| format!("Failed to write {line} to file. Error: {e}"))
+    }
+
+    fn create_file_path(&mut self) -> Result<String, String> {
+        let mut file_name: String = self.create_file_name();
+        let mut file_path: String = match Self::create_file_path_for_file_name(&file_name) {
+            Ok(path) => path,
+            Err(e) => return Err(format!("Failed to create file path because: {e}")),
+        };
+
+        while metadata(&file_path).map(|m| m.len()).unwrap_or(0) >= MAX_FILE_SIZE {
+            self.file_index += 1;
+            file_name = self.create_file_name();
+            file_path = match Self::create_file_path_for_file_name(&file_name) {
+                Ok(path) => path,
+                Err(e) => return Err(format!("Failed to create file path because: {e}")),
+            };
+        }
+
+        Ok(file_path)
+    }
+
+    fn create_file_name(&self) -> String {
+        format!(
+            "{}_{}_{}.{}",
+            self.name,
+            Local::now().format("%d-%m-%Y"),
+            self.file_index,
+            self.extension
+        )
+    }
+
+    fn create_file_path_for_file_name(file_name: &str) -> Result<String, String> {
+        let path = env::current_exe().map_err(|_| "Failed to get the path to the executable.".to_string())?;
+        let exe_folder = path.parent().ok_or_else(|| "Failed to get path of the folder the executable is in.".to_string())?;
+        let file_path = Path::new(exe_folder).join(LOG_FOLDER_NAME.to_string() + "/" + file_name);
+        Ok(file_path.to_string_lossy().to_string())
+    }
+}
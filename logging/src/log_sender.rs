@@ -0,0 +1,81 @@
+use std::{sync::{mpsc::{self, Sender}, Arc, Mutex, PoisonError}, thread};
+
+use crate::logger::{LogData, LogLevel, Logger};
+
+/// A log entry that owns its data, so that it can be sent to the logging thread.
+#[derive(Debug)]
+struct OwnedLogData {
+    severity_level: LogLevel,
+    log_data: String,
+    caller_identifier: String,
+    references: Vec<(String, u64)>,
+}
+
+#[derive(Debug)]
+enum LogMessage {
+    Entry(OwnedLogData),
+    /// Asks the logging thread to answer when every entry sent before has been written.
+    Flush(Sender<()>),
+}
+
+/// The log sender is the front-end the rest of the server logs through.
+///
+/// The entries are sent over a channel to a dedicated thread that writes them to the logger, so logging never blocks on a slow logger and is never lost because some code panicked while it was logging.
+///
+/// The log sender is cheap to clone, and all the clones send to the same logging thread. The logger can still be changed while the server runs (like changing the sinks of a [`MultiLogger`]) by locking it,
+/// and a lock that was poisoned by a panic is taken anyway.
+///
+/// [`MultiLogger`]: ../multi_logger/struct.MultiLogger.html
+#[derive(Debug, Clone)]
+pub struct LogSender {
+    sender: Sender<LogMessage>,
+}
+
+impl LogSender {
+    /// Starts the logging thread that writes the entries to the given logger, and returns the log sender to send the entries with.
+    #[must_use]
+    pub fn spawn<L: Logger + Send + 'static>(logger: Arc<Mutex<L>>) -> Self {
+        let (sender, receiver) = mpsc::channel::<LogMessage>();
+        thread::spawn(move || {
+            for message in receiver {
+                match message {
+                    LogMessage::Entry(entry) => {
+                        let references: Vec<(&str, u64)> = entry.references.iter().map(|(name, hash)| (name.as_str(), *hash)).collect();
+                        let data = LogData::new(entry.severity_level, &entry.log_data, &entry.caller_identifier).with_references(&references);
+                        logger.lock().unwrap_or_else(PoisonError::into_inner).log(data);
+                    }
+                    LogMessage::Flush(answer) => {
+                        let _ = answer.send(());
+                    }
+                }
+            }
+        });
+        Self { sender }
+    }
+
+    /// Sends the entry to the logging thread without waiting for it to be written.
+    pub fn log(&self, data: LogData) {
+        let entry = OwnedLogData {
+            severity_level: data.severity_level,
+            log_data: data.log_data.to_string(),
+            caller_identifier: data.caller_identifier.to_string(),
+            references: data.references.iter().map(|(name, hash)| ((*name).to_string(), *hash)).collect(),
+        };
+        // The receiver is only dropped if the logging thread has panicked, in which case the entry can not be written anyway.
+        let _ = self.sender.send(LogMessage::Entry(entry));
+    }
+
+    /// Waits until every entry sent before has been written, like before the server stops.
+    pub fn flush(&self) {
+        let (answer_sender, answer_receiver) = mpsc::channel();
+        if self.sender.send(LogMessage::Flush(answer_sender)).is_ok() {
+            let _ = answer_receiver.recv();
+        }
+    }
+}
+
+impl Logger for LogSender {
+    fn log(&mut self, data: LogData) {
+        Self::log(self, data);
+    }
+}
@@ -1,7 +1,8 @@
 use chrono::Local;
-use std::fmt::Display;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
 pub enum LogLevel {
     Debug = 1,
     Info = 2,
@@ -10,11 +11,29 @@ pub enum LogLevel {
     Ignore = 5,
 }
 
+impl FromStr for LogLevel {
+    type Err = String;
+
+    /// Parses the name of a log level, like `debug` or `Warning`.
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level.to_ascii_lowercase().as_str() {
+            "debug" => Ok(Self::Debug),
+            "info" => Ok(Self::Info),
+            "warning" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            "ignore" => Ok(Self::Ignore),
+            _ => Err(format!("{level} is not a log level! The log levels are debug, info, warning, error and ignore.")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct LogData<'a> {
     pub severity_level: LogLevel,
     pub log_data: &'a str,
     pub caller_identifier: &'a str,
+    /// Named content hashes (like the hash of a map or of the state of a game) that tell exactly which content the entry is about. Written in hexadecimal after the data.
+    pub references: &'a [(&'a str, u64)],
 }
 
 impl LogData<'_> {
@@ -24,10 +43,36 @@ impl LogData<'_> {
             severity_level: severity,
             log_data: data,
             caller_identifier: type_name,
+            references: &[],
         }
     }
 }
 
+impl<'a> LogData<'a> {
+    /// Adds the named content hashes the entry is about, see [`LogData::references`].
+    #[must_use]
+    pub const fn with_references(self, references: &'a [(&'a str, u64)]) -> Self {
+        LogData { references, ..self }
+    }
+
+    /// Returns the entry as a JSON object with the current time, for the log collectors. The references are written in hexadecimal.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        let references: serde_json::Map<String, serde_json::Value> = self
+            .references
+            .iter()
+            .map(|(name, hash)| ((*name).to_string(), serde_json::Value::String(format!("{hash:016x}"))))
+            .collect();
+        serde_json::json!({
+            "timestamp": Local::now().to_rfc3339(),
+            "level": self.severity_level,
+            "identifier": self.caller_identifier,
+            "message": self.log_data,
+            "references": references,
+        })
+    }
+}
+
 impl Display for LogData<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -37,7 +82,11 @@ impl Display for LogData<'_> {
             self.severity_level,
             self.caller_identifier,
             self.log_data
-        )
+        )?;
+        for (name, hash) in self.references {
+            write!(f, " [{} {:016x}]", name, hash)?;
+        }
+        Ok(())
     }
 }
 
@@ -0,0 +1,161 @@
+use std::{any::type_name, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{log_file::LogFile, logger::{LogData, LogLevel, Logger}, remote_logger::{LogCollector, RemoteLogger}};
+
+/// Where a [`LogSink`] writes the log entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogTarget {
+    /// Prints the entries to the standard output.
+    Console,
+    /// Appends the entries as text to the log files in the log folder.
+    File,
+    /// Appends the entries as JSON lines to the JSON log files in the log folder, so that they can be read by log collectors.
+    Json,
+    /// Ships the entries to the remote log collector, see [`RemoteLogger`]. Can only be used if the logger was created with a collector.
+    Remote,
+}
+
+impl FromStr for LogTarget {
+    type Err = String;
+
+    /// Parses the name of a log target, like `console` or `Json`.
+    fn from_str(target: &str) -> Result<Self, Self::Err> {
+        match target.to_ascii_lowercase().as_str() {
+            "console" => Ok(Self::Console),
+            "file" => Ok(Self::File),
+            "json" => Ok(Self::Json),
+            "remote" => Ok(Self::Remote),
+            _ => Err(format!("{target} is not a log target! The log targets are console, file, json and remote.")),
+        }
+    }
+}
+
+/// The LogSink struct is one of the places a [`MultiLogger`] writes to, with the lowest severity level that is written there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogSink {
+    pub target: LogTarget,
+    pub min_level: LogLevel,
+}
+
+impl FromStr for LogSink {
+    type Err = String;
+
+    /// Parses a sink written as `<target>:<level>`, like `console:info`.
+    fn from_str(sink: &str) -> Result<Self, Self::Err> {
+        let Some((target, level)) = sink.split_once(':') else {
+            return Err(format!("{sink} is not a log sink! It should be written as <target>:<level>, like console:info."));
+        };
+        Ok(Self { target: target.trim().parse()?, min_level: level.trim().parse()? })
+    }
+}
+
+impl Display for LogSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}:{:?}", self.target, self.min_level)
+    }
+}
+
+/// The multi logger writes every log entry to each of its sinks that the entry is severe enough for. The sinks can be changed while the server runs.
+/// It does not log anything until it's given some sinks.
+#[derive(Debug)]
+pub struct MultiLogger {
+    sinks: Vec<LogSink>,
+    text_file: LogFile,
+    json_file: LogFile,
+    /// Ships the entries of the remote sink, if the logger has a collector.
+    remote_logger: Option<RemoteLogger>,
+}
+
+impl Default for MultiLogger {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl MultiLogger {
+    /// Creates a new multi logger without any sinks. The remote sink can only be used if a collector is given.
+    #[must_use]
+    pub fn new(collector: Option<LogCollector>) -> Self {
+        Self {
+            sinks: Vec::new(),
+            text_file: LogFile::new("server_log", "txt"),
+            json_file: LogFile::new("server_log", "jsonl"),
+            remote_logger: collector.map(|collector| RemoteLogger::new(collector, LogLevel::Debug)),
+        }
+    }
+
+    /// Returns the sinks the logger writes to.
+    #[must_use]
+    pub fn sinks(&self) -> &[LogSink] {
+        &self.sinks
+    }
+
+    /// Replaces all the sinks of the logger. If there are several sinks with the same target, only the last of them is used.
+    /// Will return an error, without changing the sinks, if there is a remote sink but the logger has no collector.
+    pub fn set_sinks(&mut self, sinks: Vec<LogSink>) -> Result<(), String> {
+        for sink in &sinks {
+            self.check_target(sink.target)?;
+        }
+        self.sinks.clear();
+        for sink in sinks {
+            self.set_min_level(sink.target, sink.min_level)?;
+        }
+        Ok(())
+    }
+
+    /// Changes the lowest severity level written to the target, adding a sink for the target if there is none. The sink of the target is removed with [`LogLevel::Ignore`].
+    /// Will return an error if the target is the remote sink but the logger has no collector.
+    pub fn set_min_level(&mut self, target: LogTarget, min_level: LogLevel) -> Result<(), String> {
+        self.check_target(target)?;
+        self.sinks.retain(|sink| sink.target != target);
+        if min_level != LogLevel::Ignore {
+            self.sinks.push(LogSink { target, min_level });
+        }
+        Ok(())
+    }
+
+    fn check_target(&self, target: LogTarget) -> Result<(), String> {
+        if target == LogTarget::Remote && self.remote_logger.is_none() {
+            return Err("The remote sink can not be used because no log collector is configured!".to_string());
+        }
+        Ok(())
+    }
+
+    fn write_to_sink(&mut self, target: LogTarget, data: LogData) -> Result<(), String> {
+        match target {
+            LogTarget::Console => {
+                println!("{data}");
+                Ok(())
+            }
+            LogTarget::File => self.text_file.append_line(&data.to_string()),
+            LogTarget::Json => self.json_file.append_line(&data.to_json().to_string()),
+            LogTarget::Remote => {
+                if let Some(remote_logger) = &mut self.remote_logger {
+                    remote_logger.log(data);
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Logger for MultiLogger {
+    /// Writes the data to every sink with a minimum level at or below the severity of the data. A failure to write to a sink is printed if the console is one of the sinks.
+    fn log(&mut self, data: LogData) {
+        let targets: Vec<LogTarget> = self
+            .sinks
+            .iter()
+            .filter(|sink| sink.min_level != LogLevel::Ignore && data.severity_level >= sink.min_level)
+            .map(|sink| sink.target)
+            .collect();
+        for target in targets {
+            if let Err(e) = self.write_to_sink(target, data) {
+                if target != LogTarget::Console && self.sinks.iter().any(|sink| sink.target == LogTarget::Console) {
+                    println!("{}", LogData::new(LogLevel::Error, e.as_str(), type_name::<Self>()));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::{collections::VecDeque, net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs, UdpSocket}, str::FromStr, sync::mpsc::{self, Receiver, RecvTimeoutError, Sender}, thread, time::{Duration, Instant}};
+
+use crate::{log_file::LogFile, logger::{LogData, LogLevel, Logger}};
+
+/// The most entries that are shipped to the collector at once.
+const MAX_BATCH_SIZE: usize = 100;
+/// How long an entry can wait to be shipped before the batch is shipped even if it's not full.
+const MAX_BATCH_DELAY: Duration = Duration::from_secs(5);
+/// How long to wait before trying to ship to the collector again after it could not be reached.
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+/// The most entries that are kept in memory while the collector can not be reached. The oldest entries are moved to the fallback file when there are more.
+const MAX_BUFFERED_ENTRIES: usize = 10_000;
+/// The largest UDP datagram that is sent, so that the datagrams are not fragmented on most networks.
+const MAX_UDP_PAYLOAD: usize = 1400;
+/// How long an HTTP request to the collector can take before the collector is considered unreachable.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where the [`RemoteLogger`] ships the log entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogCollector {
+    /// The entries are posted in batches to the url as a JSON array.
+    Http(String),
+    /// The entries are sent to the address (`host:port`) as JSON lines, with as many lines in each datagram as fit.
+    Udp(String),
+}
+
+impl FromStr for LogCollector {
+    type Err = String;
+
+    /// Parses the address of a collector, like `http://logs.example.org:8080/ingest` or `udp://logs.example.org:5140`.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let address = address.trim();
+        if address.starts_with("http://") {
+            return Ok(Self::Http(address.to_string()));
+        }
+        match address.strip_prefix("udp://") {
+            Some(host) if !host.is_empty() => Ok(Self::Udp(host.to_string())),
+            _ => Err(format!("{address} is not a log collector! It should start with http:// or udp://.")),
+        }
+    }
+}
+
+/// The remote logger ships the log entries to a collector, so that the logs of the servers run on laptops at the venues end up in one place.
+///
+/// The entries are shipped in batches by a background thread, so logging is never blocked by a slow or missing collector.
+/// While the collector can not be reached the entries are kept in memory and shipped when it's back. If too many entries pile up, the oldest of them are written to a local fallback file (`remote_log_fallback` in the log folder) instead.
+#[derive(Debug)]
+pub struct RemoteLogger {
+    min_level: LogLevel,
+    sender: Sender<String>,
+}
+
+impl RemoteLogger {
+    /// Creates a new remote logger that ships the entries at or above the given level to the collector.
+    #[must_use]
+    pub fn new(collector: LogCollector, min_level: LogLevel) -> Self {
+        let (sender, receiver) = mpsc::channel::<String>();
+        thread::spawn(move || ship_entries(&collector, &receiver));
+        Self { min_level, sender }
+    }
+}
+
+impl Logger for RemoteLogger {
+    fn log(&mut self, data: LogData) {
+        if self.min_level == LogLevel::Ignore || data.severity_level < self.min_level {
+            return;
+        }
+        // The receiver is only dropped if the background thread has panicked, in which case there is nothing more to do.
+        let _ = self.sender.send(data.to_json().to_string());
+    }
+}
+
+/// Ships the entries from the receiver to the collector until the remote logger is dropped. Then it tries to ship what is left once more, and writes what could not be shipped to the fallback file.
+fn ship_entries(collector: &LogCollector, receiver: &Receiver<String>) {
+    let agent = ureq::AgentBuilder::new().timeout(HTTP_TIMEOUT).build();
+    let mut buffer: VecDeque<String> = VecDeque::new();
+    let mut fallback_file = LogFile::new("remote_log_fallback", "jsonl");
+    let mut oldest_unshipped: Option<Instant> = None;
+    let mut retry_at: Option<Instant> = None;
+    let mut is_connected = true;
+    loop {
+        let received = receiver.recv_timeout(MAX_BATCH_DELAY);
+        let is_closed = matches!(received, Err(RecvTimeoutError::Disconnected));
+        if let Ok(entry) = received {
+            buffer.push_back(entry);
+            oldest_unshipped.get_or_insert_with(Instant::now);
+        }
+        while buffer.len() > MAX_BUFFERED_ENTRIES {
+            if let Some(entry) = buffer.pop_front() {
+                let _ = fallback_file.append_line(&entry);
+            }
+        }
+
+        let now = Instant::now();
+        let is_due = buffer.len() >= MAX_BATCH_SIZE || oldest_unshipped.is_some_and(|oldest| now.duration_since(oldest) >= MAX_BATCH_DELAY) || is_closed;
+        if is_due && !buffer.is_empty() && retry_at.is_none_or(|retry_at| now >= retry_at || is_closed) {
+            while !buffer.is_empty() {
+                let batch_size = buffer.len().min(MAX_BATCH_SIZE);
+                let batch: Vec<&String> = buffer.iter().take(batch_size).collect();
+                if let Err(e) = ship_batch(collector, &agent, &batch) {
+                    if is_connected {
+                        let error = format!("Could not ship the logs to the collector, keeping them until it can be reached because: {e}");
+                        let _ = fallback_file.append_line(&LogData::new(LogLevel::Warning, &error, "RemoteLogger").to_json().to_string());
+                    }
+                    is_connected = false;
+                    retry_at = Some(now + RETRY_DELAY);
+                    break;
+                }
+                buffer.drain(..batch_size);
+                is_connected = true;
+                retry_at = None;
+            }
+            oldest_unshipped = if buffer.is_empty() { None } else { Some(now) };
+        }
+
+        if is_closed {
+            for entry in buffer {
+                let _ = fallback_file.append_line(&entry);
+            }
+            return;
+        }
+    }
+}
+
+fn ship_batch(collector: &LogCollector, agent: &ureq::Agent, batch: &[&String]) -> Result<(), String> {
+    match collector {
+        LogCollector::Http(url) => {
+            let body = format!("[{}]", batch.iter().map(|entry| entry.as_str()).collect::<Vec<&str>>().join(","));
+            agent
+                .post(url)
+                .set("Content-Type", "application/json")
+                .send_string(&body)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }
+        LogCollector::Udp(address) => {
+            let Some(collector_address) = address.to_socket_addrs().map_err(|e| e.to_string())?.next() else {
+                return Err(format!("{address} could not be resolved"));
+            };
+            let local_address = match collector_address {
+                SocketAddr::V4(_) => SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+                SocketAddr::V6(_) => SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)),
+            };
+            let socket = UdpSocket::bind(local_address).map_err(|e| e.to_string())?;
+            socket.connect(collector_address).map_err(|e| e.to_string())?;
+            let mut datagram = String::new();
+            for entry in batch {
+                if !datagram.is_empty() && datagram.len() + entry.len() + 1 > MAX_UDP_PAYLOAD {
+                    socket.send(datagram.as_bytes()).map_err(|e| e.to_string())?;
+                    datagram.clear();
+                }
+                datagram.push_str(entry);
+                datagram.push('\n');
+            }
+            socket.send(datagram.as_bytes()).map_err(|e| e.to_string())?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! The built-in rules are split into modules by what they are about. Each module registers its rules with [`register_rule!`] in its `register_rules` function,
+//! so adding a rule to a module only means writing the function that checks it and registering it next to the other rules of the module.
+//!
+//! If you are unsure what a rule checks, it can be smart to check what the errors that can be returned are.
+
+use game_core::game_data::structs::rule_set::RuleSet;
+
+use crate::rule_registry::RuleRegistry;
+
+// ================== MACROS ====================
+// Gets what a rule needs from the context of the input, like `get_or_return_invalid_response!(context.player())`, and breaks the rule with the reason if it can not be found.
+macro_rules! get_or_return_invalid_response {
+    ($result:expr) => {{
+        match $result {
+            Ok(value) => value,
+            Err(e) => return ValidationResponse::Invalid(e),
+        }
+    }};
+}
+
+mod chat;
+mod congestion;
+mod facilitation;
+mod lobby;
+mod movement;
+mod phases;
+mod restrictions;
+mod rides;
+
+/// Returns a registry with all the built-in rules, using the parameters of the rule set.
+pub fn built_in_rules(rule_set: &RuleSet) -> RuleRegistry {
+    let mut registry = RuleRegistry::default();
+    phases::register_rules(&mut registry);
+    movement::register_rules(&mut registry);
+    rides::register_rules(&mut registry);
+    congestion::register_rules(&mut registry);
+    restrictions::register_rules(&mut registry, rule_set);
+    lobby::register_rules(&mut registry);
+    facilitation::register_rules(&mut registry);
+    chat::register_rules(&mut registry);
+    registry
+}
@@ -0,0 +1,37 @@
+use game_core::game_data::{structs::{gamestate::GameState, player_input::PlayerInput}, enums::in_game_id::InGameID};
+
+use crate::{rule_context::RuleContext, rule_registry::{register_rule, RuleRegistry, ValidationResponse}};
+
+/// Registers the rules about the messages the players send to the chat of the game.
+pub(super) fn register_rules(registry: &mut RuleRegistry) {
+    register_rule!(registry,
+        name: "chat_sender_valid",
+        description: "Only the players in the game can send messages to its chat, and a player that has not chosen a role yet can only chat in the lobby.",
+        priority: 440,
+        inputs: [ChatMessage],
+        needs: [Player],
+        check: is_chat_sender_valid,
+    );
+    register_rule!(registry,
+        name: "chat_message_not_empty",
+        description: "A chat message has to have some text in it.",
+        priority: 450,
+        inputs: [ChatMessage],
+        check: is_chat_message_not_empty,
+    );
+}
+
+fn is_chat_sender_valid(context: &RuleContext) -> ValidationResponse<String> {
+    let player = get_or_return_invalid_response!(context.player());
+    if player.in_game_id == InGameID::Undecided && !context.game.is_lobby {
+        return ValidationResponse::Invalid("A player without a role can only chat while the game is in the lobby!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+fn is_chat_message_not_empty(_game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    match player_input.related_string.as_deref() {
+        Some(text) if !text.trim().is_empty() => ValidationResponse::Valid,
+        _ => ValidationResponse::Invalid("A chat message can not be empty!".to_string()),
+    }
+}
@@ -0,0 +1,53 @@
+use game_core::game_data::structs::{gamestate::GameState, player_input::PlayerInput};
+
+use crate::rule_context::RuleContext;
+use crate::rule_registry::{register_rule, RuleRegistry, ValidationResponse};
+
+/// Registers the rules about the congestion zone and its charges.
+pub(super) fn register_rules(registry: &mut RuleRegistry) {
+    register_rule!(registry,
+        name: "afford_congestion_charge",
+        description: "A player needs enough budget to pay the congestion charge when entering the congestion zone.",
+        priority: 240,
+        inputs: [Movement],
+        needs: [Player, TargetNode],
+        check: can_afford_congestion_charge,
+    );
+    register_rule!(registry,
+        name: "congestion_zone_valid",
+        description: "A congestion zone needs at least one district and charges that are not negative.",
+        priority: 300,
+        inputs: [SetCongestionZone],
+        check: is_congestion_zone_valid,
+    );
+}
+
+fn is_congestion_zone_valid(_game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(congestion_zone) = &player_input.congestion_zone else {
+        // No zone means that the current zone should be removed.
+        return ValidationResponse::Valid;
+    };
+    if congestion_zone.districts.is_empty() {
+        return ValidationResponse::Invalid("The congestion zone has to contain at least one district!".to_string());
+    }
+    if congestion_zone.peak_charge < 0 || congestion_zone.off_peak_charge < 0 {
+        return ValidationResponse::Invalid("The charges of the congestion zone can not be negative!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+fn can_afford_congestion_charge(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, player_input, .. } = context;
+    let player = get_or_return_invalid_response!(context.player());
+    let Some(to_node_id) = player_input.related_node_id else {
+        return ValidationResponse::Invalid("There is no related node to the movement input. There needs to be a node if a players should move!".to_string());
+    };
+    let charge = match game.congestion_charge_for_move(player_input.player_id, to_node_id, player_input.edge_id) {
+        Ok(charge) => charge,
+        Err(e) => return ValidationResponse::Invalid(e),
+    };
+    if charge > player.budget {
+        return ValidationResponse::Invalid(format!("The player {} can not afford the congestion charge of {} with a budget of {}!", player.name, charge, player.budget));
+    }
+    ValidationResponse::Valid
+}
@@ -0,0 +1,114 @@
+use game_core::game_data::{constants::MAX_CHECKPOINTS, structs::{gamestate::GameState, player_input::PlayerInput}, enums::{player_input_type::PlayerInputType, annotation_target::AnnotationTarget}};
+
+use crate::rule_registry::{register_rule, RuleRegistry, ValidationResponse};
+
+/// Registers the rules about the inputs the facilitator uses to run the session, like checkpoints, rule overrides and map notes.
+pub(super) fn register_rules(registry: &mut RuleRegistry) {
+    register_rule!(registry,
+        name: "checkpoint_valid",
+        description: "Checkpoints need a label that is not empty or taken, and can only be restored if they exist.",
+        priority: 310,
+        inputs: [CreateCheckpoint, RestoreCheckpoint],
+        check: is_checkpoint_input_valid,
+    );
+    register_rule!(registry,
+        name: "dead_letter_exists",
+        description: "Only dead letters that exist can be retried or discarded.",
+        priority: 400,
+        inputs: [RetryDeadLetter, DiscardDeadLetter],
+        check: does_dead_letter_exist,
+    );
+    register_rule!(registry,
+        name: "rule_warning_exists",
+        description: "Only rule warnings that exist can be overridden.",
+        priority: 410,
+        inputs: [OverrideRule],
+        check: does_rule_warning_exist,
+    );
+    register_rule!(registry,
+        name: "override_justified",
+        description: "Rules can only be overridden with the facilitated rule profile, with a justification and with an input to apply for the same game.",
+        priority: 420,
+        inputs: [OverrideAndApply],
+        check: is_override_justified,
+    );
+    register_rule!(registry,
+        name: "annotation_valid",
+        description: "Map notes have to be about a district or an edge that exists, and a note can only be removed if there is one.",
+        priority: 430,
+        inputs: [AnnotateMap],
+        check: is_annotation_valid,
+    );
+}
+
+fn is_checkpoint_input_valid(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(label) = &player_input.related_string else {
+        return ValidationResponse::Invalid("There was no label of a checkpoint in the input!".to_string());
+    };
+    let checkpoint_exists = game.checkpoints.iter().any(|checkpoint| &checkpoint.label == label);
+    match player_input.input_type {
+        PlayerInputType::CreateCheckpoint if label.trim().is_empty() => ValidationResponse::Invalid("The label of a checkpoint can not be empty!".to_string()),
+        PlayerInputType::CreateCheckpoint if checkpoint_exists => ValidationResponse::Invalid(format!("There is already a checkpoint called {label}!")),
+        PlayerInputType::CreateCheckpoint if game.checkpoints.len() >= MAX_CHECKPOINTS => ValidationResponse::Invalid(format!("A game can not have more than {MAX_CHECKPOINTS} checkpoints!")),
+        PlayerInputType::RestoreCheckpoint if !checkpoint_exists => ValidationResponse::Invalid(format!("There is no checkpoint called {label}!")),
+        _ => ValidationResponse::Valid,
+    }
+}
+
+fn does_dead_letter_exist(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(dead_letter_id) = player_input.dead_letter_id else {
+        return ValidationResponse::Invalid("There was no dead letter id in the input, and it's therefore not known which dead letter to retry or discard!".to_string());
+    };
+    match game.get_dead_letter(dead_letter_id) {
+        Ok(_) => ValidationResponse::Valid,
+        Err(e) => ValidationResponse::Invalid(e),
+    }
+}
+
+fn does_rule_warning_exist(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(rule_warning_id) = player_input.rule_warning_id else {
+        return ValidationResponse::Invalid("There was no rule warning id in the input, and it's therefore not known which input to accept!".to_string());
+    };
+    match game.get_rule_warning(rule_warning_id) {
+        Ok(_) => ValidationResponse::Valid,
+        Err(e) => ValidationResponse::Invalid(e),
+    }
+}
+
+fn is_annotation_valid(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(annotation_target) = player_input.annotation_target else {
+        return ValidationResponse::Invalid("There was no district or edge in the input, and it's therefore not known where to attach the note!".to_string());
+    };
+    if let AnnotationTarget::Edge(edge_id) = annotation_target {
+        if !game.map.has_edge(edge_id) {
+            return ValidationResponse::Invalid(format!("There is no edge with id {edge_id} on the map of the game!"));
+        }
+    }
+    let removes_note = player_input.related_string.as_deref().is_none_or(|text| text.trim().is_empty());
+    if removes_note && game.map_annotations.iter().all(|annotation| annotation.target != annotation_target) {
+        return ValidationResponse::Invalid("There is no note on the district or edge to remove!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+fn is_override_justified(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    if game.lobby_settings.rule_profile.is_strict() {
+        return ValidationResponse::Invalid("The orchestrator can only override rules when the game uses the facilitated rule profile!".to_string());
+    }
+    if player_input.related_string.as_deref().is_none_or(|justification| justification.trim().is_empty()) {
+        return ValidationResponse::Invalid("The orchestrator has to give a justification for overriding the rules!".to_string());
+    }
+    if player_input.overridden_rules.is_empty() {
+        return ValidationResponse::Invalid("There were no rules to override in the input!".to_string());
+    }
+    let Some(override_input) = player_input.override_input.as_deref() else {
+        return ValidationResponse::Invalid("There was no input to apply in the input, and it's therefore not known what to apply!".to_string());
+    };
+    if override_input.game_id != player_input.game_id {
+        return ValidationResponse::Invalid("The input to apply has to be for the same game!".to_string());
+    }
+    if override_input.input_type == PlayerInputType::OverrideAndApply {
+        return ValidationResponse::Invalid("The input to apply can not override rules itself!".to_string());
+    }
+    ValidationResponse::Valid
+}
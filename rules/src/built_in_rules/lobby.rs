@@ -0,0 +1,243 @@
+use game_core::{measure_template_list::get_measure_template_by_id, game_data::{constants::{MAX_PLAYER_COUNT, MAX_STAGED_ACTIONS, MAX_TIMED_PROPS}, structs::{gamestate::GameState, player_input::PlayerInput}, enums::{player_input_type::PlayerInputType, in_game_id::InGameID, move_refill::MoveRefill, timed_prop_kind::TimedPropKind, win_condition::WinCondition}}};
+
+use crate::rule_context::RuleContext;
+use crate::rule_registry::{register_rule, RuleRegistry, ValidationResponse};
+
+/// Registers the rules about the settings and the players of the lobby.
+pub(super) fn register_rules(registry: &mut RuleRegistry) {
+    register_rule!(registry,
+        name: "fork_seats",
+        description: "The players of a fork claim one of the open seats instead of choosing a role.",
+        priority: 320,
+        inputs: [ClaimSeat, ChangeRole],
+        needs: [],
+        check: is_seat_input_valid,
+    );
+    register_rule!(registry,
+        name: "movement_settings_valid",
+        description: "The movement settings of the lobby have to be possible to play with.",
+        priority: 330,
+        inputs: [ChangeLobbySettings],
+        check: are_movement_settings_valid,
+    );
+    register_rule!(registry,
+        name: "session_settings_valid",
+        description: "The session settings of the lobby have to be possible to play with.",
+        priority: 340,
+        inputs: [ChangeLobbySettings],
+        check: are_session_settings_valid,
+    );
+    register_rule!(registry,
+        name: "timed_props_valid",
+        description: "The timed props of the lobby have to be possible to play with.",
+        priority: 350,
+        inputs: [ChangeLobbySettings],
+        check: are_timed_props_valid,
+    );
+    register_rule!(registry,
+        name: "orchestrator_targets_valid",
+        description: "The targets of the orchestrator have to ask for at least one delivery, so that the game does not finish as soon as it starts.",
+        priority: 355,
+        inputs: [ChangeLobbySettings],
+        check: are_orchestrator_targets_valid,
+    );
+    register_rule!(registry,
+        name: "max_turns_valid",
+        description: "The most turns of a game have to be at least one, so that the game does not finish as soon as it starts.",
+        priority: 356,
+        inputs: [ChangeLobbySettings],
+        check: is_max_turns_valid,
+    );
+    register_rule!(registry,
+        name: "bot_seats_valid",
+        description: "The bots can only take the free roles of the players that travel, one bot per role and no more than the game has room for, and can only be added before the game has started.",
+        priority: 357,
+        inputs: [ChangeLobbySettings],
+        check: are_bot_seats_valid,
+    );
+    register_rule!(registry,
+        name: "group_targets_valid",
+        description: "A cooperative game needs at least one group target, and the average commute time has to be allowed to take at least one turn.",
+        priority: 358,
+        inputs: [ChangeLobbySettings],
+        check: are_group_targets_valid,
+    );
+    register_rule!(registry,
+        name: "unique_name",
+        description: "A player can only be renamed to a name that is not empty and not used by another player.",
+        priority: 380,
+        inputs: [RenamePlayer],
+        check: is_new_name_valid,
+    );
+}
+
+fn is_new_name_valid(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(new_name) = &player_input.related_string else {
+        return ValidationResponse::Invalid("There was no name to rename the player to!".to_string());
+    };
+    if new_name.trim().is_empty() {
+        return ValidationResponse::Invalid("The name of a player can not be empty!".to_string());
+    }
+    if game.is_player_name_taken(new_name, Some(player_input.player_id)) {
+        return ValidationResponse::Invalid(format!("There is already a player with the name {new_name} in the game!"));
+    }
+    ValidationResponse::Valid
+}
+
+fn is_seat_input_valid(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, player_input, .. } = context;
+    if player_input.input_type == PlayerInputType::ChangeRole {
+        return match game.is_unstarted_fork() {
+            true => ValidationResponse::Invalid("The players of a forked game have to claim one of the open seats instead of choosing a role!".to_string()),
+            false => ValidationResponse::Valid,
+        };
+    }
+    if !game.is_unstarted_fork() {
+        return ValidationResponse::Invalid("Seats can only be claimed in a forked game that has not been started!".to_string());
+    }
+    let player = get_or_return_invalid_response!(context.player());
+    if player.in_game_id != InGameID::Undecided {
+        return ValidationResponse::Invalid("The player has already claimed a seat!".to_string());
+    }
+    let Some(seat_role) = player_input.related_role else {
+        return ValidationResponse::Invalid("There was no role of the seat to claim in the input!".to_string());
+    };
+    if game.open_seats.iter().all(|seat| seat.in_game_id != seat_role) {
+        return ValidationResponse::Invalid(format!("There is no open seat with the role {:?}!", seat_role));
+    }
+    ValidationResponse::Valid
+}
+
+fn are_movement_settings_valid(_game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(lobby_settings) = &player_input.lobby_settings else {
+        return ValidationResponse::Invalid("There were no lobby settings to check!".to_string());
+    };
+    if lobby_settings.max_moves_per_turn == Some(0) {
+        return ValidationResponse::Invalid("The most moves per turn has to be at least 1!".to_string());
+    }
+    if let Some(max_staged_actions) = lobby_settings.max_staged_actions {
+        if max_staged_actions == 0 || usize::try_from(max_staged_actions).map_or(true, |max_actions| max_actions > MAX_STAGED_ACTIONS) {
+            return ValidationResponse::Invalid(format!("The most staged actions per turn has to be between 1 and {}!", MAX_STAGED_ACTIONS));
+        }
+    }
+    if let (Some(max_moves), Some(min_moves)) = (lobby_settings.max_moves_per_turn, lobby_settings.min_moves_per_turn) {
+        if min_moves > max_moves {
+            return ValidationResponse::Invalid(format!("The least moves per turn ({}) can not be more than the most moves per turn ({})!", min_moves, max_moves));
+        }
+    }
+    if let MoveRefill::Banking(max_banked_moves) = lobby_settings.move_refill {
+        if max_banked_moves < 0 {
+            return ValidationResponse::Invalid("The amount of moves that can be banked can not be negative!".to_string());
+        }
+    }
+    ValidationResponse::Valid
+}
+
+fn are_session_settings_valid(_game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(lobby_settings) = &player_input.lobby_settings else {
+        return ValidationResponse::Invalid("There were no lobby settings to check!".to_string());
+    };
+    if lobby_settings.session_length_minutes == Some(0) {
+        return ValidationResponse::Invalid("The session has to be at least 1 minute long!".to_string());
+    }
+    if lobby_settings.session_reminders.is_some() && lobby_settings.session_length_minutes.is_none() {
+        return ValidationResponse::Invalid("The session reminders can only be set if the session length is set!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+fn are_timed_props_valid(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(lobby_settings) = &player_input.lobby_settings else {
+        return ValidationResponse::Invalid("There were no lobby settings to check!".to_string());
+    };
+    if lobby_settings.timed_props.len() > MAX_TIMED_PROPS {
+        return ValidationResponse::Invalid(format!("A game can not have more than {MAX_TIMED_PROPS} timed props!"));
+    }
+    for (index, prop) in lobby_settings.timed_props.iter().enumerate() {
+        if prop.name.trim().is_empty() {
+            return ValidationResponse::Invalid("The timed props have to have a name!".to_string());
+        }
+        if lobby_settings.timed_props.iter().skip(index + 1).any(|other_prop| other_prop.name == prop.name) {
+            return ValidationResponse::Invalid(format!("There is more than one timed prop called {}!", prop.name));
+        }
+        if prop.interval_seconds == 0 {
+            return ValidationResponse::Invalid(format!("The timed prop {} has to tick at most once a second!", prop.name));
+        }
+        match &prop.kind {
+            TimedPropKind::ToggleEdgeRestriction(edge_restriction) => {
+                if let Err(e) = game.map.find_edge_id(edge_restriction) {
+                    return ValidationResponse::Invalid(format!("The timed prop {} can not toggle the edge restriction because: {}", prop.name, e));
+                }
+            }
+            TimedPropKind::Countdown { ticks, measure_template_id } => {
+                if *ticks == 0 {
+                    return ValidationResponse::Invalid(format!("The countdown of the timed prop {} has to be at least one tick long!", prop.name));
+                }
+                if let Some(Err(e)) = measure_template_id.map(get_measure_template_by_id) {
+                    return ValidationResponse::Invalid(format!("The timed prop {} can not enact the measure template because: {}", prop.name, e));
+                }
+            }
+        }
+    }
+    ValidationResponse::Valid
+}
+
+fn are_orchestrator_targets_valid(_game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(lobby_settings) = &player_input.lobby_settings else {
+        return ValidationResponse::Invalid("There were no lobby settings to check!".to_string());
+    };
+    if lobby_settings.orchestrator_targets.as_ref().is_some_and(|targets| targets.deliveries == 0) {
+        return ValidationResponse::Invalid("The targets of the orchestrator have to ask for at least 1 delivery!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+fn is_max_turns_valid(_game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(lobby_settings) = &player_input.lobby_settings else {
+        return ValidationResponse::Invalid("There were no lobby settings to check!".to_string());
+    };
+    if lobby_settings.max_turns == Some(0) {
+        return ValidationResponse::Invalid("A game has to have at least 1 turn!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+fn are_bot_seats_valid(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(lobby_settings) = &player_input.lobby_settings else {
+        return ValidationResponse::Invalid("There were no lobby settings to check!".to_string());
+    };
+    for (index, seat) in lobby_settings.bots.iter().enumerate() {
+        if matches!(seat.role, InGameID::Orchestrator | InGameID::Undecided) {
+            return ValidationResponse::Invalid(format!("A bot can not have the role {:?}!", seat.role));
+        }
+        if lobby_settings.bots[..index].iter().any(|other_seat| other_seat.role == seat.role) {
+            return ValidationResponse::Invalid(format!("There can only be one bot with the role {:?}!", seat.role));
+        }
+        match game.players.iter().find(|player| player.in_game_id == seat.role) {
+            Some(player) if !player.is_bot() => return ValidationResponse::Invalid(format!("The role {:?} is taken by the player {}!", seat.role, player.name)),
+            None if (!game.is_lobby || game.round_number > 0) && !game.is_unstarted_fork() => return ValidationResponse::Invalid("Bots can only be added before the game has started!".to_string()),
+            _ => (),
+        }
+    }
+    let player_count = game.players.iter().filter(|player| !player.is_bot()).count() + lobby_settings.bots.len();
+    if player_count > MAX_PLAYER_COUNT {
+        return ValidationResponse::Invalid(format!("The game only has room for {} players, including the bots!", MAX_PLAYER_COUNT));
+    }
+    ValidationResponse::Valid
+}
+
+fn are_group_targets_valid(_game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(lobby_settings) = &player_input.lobby_settings else {
+        return ValidationResponse::Invalid("There were no lobby settings to check!".to_string());
+    };
+    let WinCondition::Cooperative { max_total_emissions, max_average_commute_turns } = lobby_settings.win_condition else {
+        return ValidationResponse::Valid;
+    };
+    if max_total_emissions.is_none() && max_average_commute_turns.is_none() {
+        return ValidationResponse::Invalid("A cooperative game needs a target for the total emissions or the average commute time!".to_string());
+    }
+    if max_average_commute_turns == Some(0) {
+        return ValidationResponse::Invalid("The average commute time has to be allowed to take at least 1 turn!".to_string());
+    }
+    ValidationResponse::Valid
+}
@@ -0,0 +1,383 @@
+use game_core::game_data::{structs::{gamestate::GameState, player_input::PlayerInput}, enums::{district_modifier_type::DistrictModifierType, restriction_type::RestrictionType, in_game_id::InGameID, cargo_state::CargoState}};
+
+use crate::rule_context::RuleContext;
+use crate::rule_registry::{register_rule, RuleRegistry, ValidationResponse};
+
+/// Registers the rules about how the players move on the map.
+pub(super) fn register_rules(registry: &mut RuleRegistry) {
+    register_rule!(registry,
+        name: "player_has_position",
+        description: "A player needs a position on the map to move.",
+        priority: 50,
+        inputs: [Movement],
+        needs: [Player],
+        check: has_position,
+    );
+    register_rule!(registry,
+        name: "not_current_node",
+        description: "A player can not move to the node the player is already at.",
+        priority: 90,
+        inputs: [Movement],
+        needs: [Player],
+        check: is_not_current_node,
+    );
+    register_rule!(registry,
+        name: "next_to_node",
+        description: "A player can only move to a node next to the current node.",
+        priority: 100,
+        inputs: [Movement],
+        needs: [Player, Position, TargetNode],
+        check: next_node_is_neighbour,
+    );
+    register_rule!(registry,
+        name: "enough_moves",
+        description: "A player needs enough remaining moves to pay the movement cost of the edge.",
+        priority: 110,
+        inputs: [Movement],
+        needs: [Player, MoveCost],
+        check: has_enough_moves,
+    );
+    register_rule!(registry,
+        name: "max_moves_per_turn",
+        description: "A player can not move more times in a turn than the lobby settings allow.",
+        priority: 120,
+        inputs: [Movement],
+        check: is_below_max_moves_per_turn,
+    );
+    register_rule!(registry,
+        name: "min_moves_per_turn",
+        description: "A player has to move at least as many times in a turn as the lobby settings require before ending it.",
+        priority: 130,
+        inputs: [NextTurn],
+        needs: [],
+        check: has_made_min_moves_per_turn,
+    );
+    register_rule!(registry,
+        name: "move_to_node",
+        description: "A player can only move along edges and into districts the restrictions and district modifiers allow for the player's vehicle.",
+        priority: 150,
+        inputs: [Movement],
+        needs: [Player, Position, Edge],
+        check: can_move_to_node,
+    );
+    register_rule!(registry,
+        name: "accessible_route",
+        description: "A player whose objective needs an accessible route can only move along accessible edges.",
+        priority: 160,
+        inputs: [Movement],
+        needs: [Player],
+        check: is_edge_accessible_if_required,
+    );
+    register_rule!(registry,
+        name: "ferry_departs",
+        description: "A ferry edge can only be used in the rounds the ferry departs in, and while the ferry has room.",
+        priority: 170,
+        inputs: [Movement],
+        needs: [Edge],
+        check: can_take_ferry,
+    );
+    register_rule!(registry,
+        name: "edge_not_closed",
+        description: "A player can not move along an edge that is closed by construction works.",
+        priority: 180,
+        inputs: [Movement],
+        needs: [Edge],
+        check: is_edge_open,
+    );
+    register_rule!(registry,
+        name: "freight_cargo_order",
+        description: "A freight player has to load the cargo before going to the delivery node.",
+        priority: 190,
+        inputs: [Movement],
+        needs: [Player],
+        check: is_cargo_loaded_before_delivery,
+    );
+}
+
+fn has_enough_moves(context: &RuleContext) -> ValidationResponse<String> {
+    let player = get_or_return_invalid_response!(context.player());
+
+    if player.remaining_moves == 0 {
+        return ValidationResponse::Invalid("The player has no remaining moves!".to_string());
+    }
+
+    let remaining_moves = get_or_return_invalid_response!(context.remaining_moves_after_move());
+    if remaining_moves < 0 {
+        return ValidationResponse::Invalid(
+            format!("The player does not have enough remaining moves! The player would have {} remaining moves!", remaining_moves),
+        );
+    }
+
+    ValidationResponse::Valid
+}
+
+fn is_below_max_moves_per_turn(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(max_moves) = game.lobby_settings.max_moves_per_turn else {
+        return ValidationResponse::Valid;
+    };
+    let moves_made = game.moves_made_this_turn(player_input.player_id);
+    if moves_made >= usize::try_from(max_moves).unwrap_or(usize::MAX) {
+        return ValidationResponse::Invalid(format!("The player has already made {} moves this turn, which is the most moves a player can make in one turn!", moves_made));
+    }
+    ValidationResponse::Valid
+}
+
+fn has_made_min_moves_per_turn(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, player_input, .. } = context;
+    let Some(min_moves) = game.lobby_settings.min_moves_per_turn else {
+        return ValidationResponse::Valid;
+    };
+    let player = get_or_return_invalid_response!(context.player());
+    let has_delivered = player.objective_card.as_ref().is_some_and(|card| card.dropped_package_off);
+    if player.in_game_id == InGameID::Orchestrator || player.remaining_moves <= 0 || player.riding_with.is_some() || has_delivered {
+        return ValidationResponse::Valid;
+    }
+    let moves_made = game.moves_made_this_turn(player_input.player_id);
+    if moves_made < usize::try_from(min_moves).unwrap_or(usize::MAX) {
+        return ValidationResponse::Invalid(format!("The player has only made {} moves this turn, but has to make at least {} moves before ending the turn!", moves_made, min_moves));
+    }
+    ValidationResponse::Valid
+}
+
+// Checks if the player can enter the district the player wants to move to based on their objective card/vehicle type.
+fn can_enter_district(context: &RuleContext) -> ValidationResponse<String> {
+    let game = context.game;
+    let player = get_or_return_invalid_response!(context.player());
+
+    let district_modifiers = &game.district_modifiers;
+
+    let player_objective_card = match &player.objective_card {
+        Some(objective_card) => objective_card,
+        None => {
+            return ValidationResponse::Invalid(
+                "Error: Player does not have an objective card".to_string(),
+            )
+        }
+    };
+
+    let neighbour_relationship = get_or_return_invalid_response!(context.edge());
+
+    let mut district_has_modifier = false;
+    for dm in district_modifiers {
+        if dm.district != neighbour_relationship.neighbourhood
+            || dm.modifier != DistrictModifierType::Access
+        {
+            continue;
+        }
+        let Some(vehicle_type) = dm.vehicle_type else {
+            return ValidationResponse::Invalid("Error: There was no vehicle for access modifier".to_string());
+        };
+        district_has_modifier = true;
+        if player_objective_card
+            .special_vehicle_types
+            .contains(&vehicle_type)
+            || (vehicle_type == RestrictionType::Destination
+            && GameState::player_has_objective_in_district(&game.map, player, dm.district))
+        {
+            return ValidationResponse::Valid;
+        }
+    }
+
+    if !district_has_modifier {
+        return ValidationResponse::Valid;
+    }
+    ValidationResponse::Invalid(
+        format!("Invalid move: Player does not have required vehicle type to access this district. District modifiers: {:?}", district_modifiers),
+    )
+}
+
+fn has_position(context: &RuleContext) -> ValidationResponse<String> {
+    match context.position() {
+        Ok(_) => ValidationResponse::Valid,
+        Err(e) => ValidationResponse::Invalid(e),
+    }
+}
+
+// Checks that the player does not try to move to the node the player is already at. Staying at a node is not a move: a player that wants to wait ends the turn instead.
+fn is_not_current_node(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { player_input, .. } = context;
+    let player = get_or_return_invalid_response!(context.player());
+    match player_input.related_node_id {
+        Some(node_id) if player.position_node_id == Some(node_id) => ValidationResponse::Invalid(format!("The player is already at the node with id {}! End the turn to wait where you are.", node_id)),
+        _ => ValidationResponse::Valid,
+    }
+}
+
+fn next_node_is_neighbour(context: &RuleContext) -> ValidationResponse<String> {
+    let node_id = get_or_return_invalid_response!(context.position());
+    let related_node_id = get_or_return_invalid_response!(context.target_node());
+    let are_neighbours = match context.game.map.are_nodes_neighbours(node_id, related_node_id) {
+        Ok(b) => b,
+        Err(e) => return ValidationResponse::Invalid(e),
+    };
+    if !are_neighbours {
+        return ValidationResponse::Invalid(format!(
+            "The node {related_node_id} is not a neighbour of the player's position!",
+        ));
+    }
+    ValidationResponse::Valid
+}
+
+pub(super) fn can_move_to_node(context: &RuleContext) -> ValidationResponse<String> {
+    let game = context.game;
+    let player = get_or_return_invalid_response!(context.player());
+    let player_pos = get_or_return_invalid_response!(context.position());
+
+    let to_node_id = get_or_return_invalid_response!(context.target_node());
+    let neighbour_relationship = get_or_return_invalid_response!(context.edge());
+
+    if player.is_bus {
+        if matches!(neighbour_relationship.restriction, Some(RestrictionType::ParkAndRide | RestrictionType::BusLane)) {
+            return ValidationResponse::Valid;
+        }
+        return ValidationResponse::Invalid(
+            format!("The player cannot move here because the node (with id {}) is not a neighbouring node connected with a park & ride edge or a bus lane!", to_node_id),
+        );
+    }
+
+    let current_node = match game.map.get_node_by_id(player_pos) {
+        Ok(n) => n,
+        Err(e) => {
+            return ValidationResponse::Invalid(
+                e + " And can therefore not check whether the player can move here!",
+            )
+        }
+    };
+
+    let to_node = match game.map.get_node_by_id(to_node_id) {
+        Ok(n) => n,
+        Err(e) => {
+            return ValidationResponse::Invalid(
+                e + " And can therefore not check whether the player can move here!",
+            )
+        }
+    };
+
+    if neighbour_relationship.is_connected_through_rail {
+        if current_node.is_connected_to_rail && to_node.is_connected_to_rail {
+            return ValidationResponse::Valid;
+        }
+        return ValidationResponse::Invalid(
+            format!("The player cannot move here because the node (with id {}) is not a neighbouring node connected through the railway!", to_node_id),
+        );
+    }
+
+    let Some(to_node_neighbours) = game.map.get_neighbour_relationships_of_node_with_id(to_node_id) else {
+        return ValidationResponse::Invalid(format!("The node {} does not have neighbours and can therefore not have park and ride!", to_node_id));
+    };
+
+    if let Some(to_node_neighbour_to_self) = to_node_neighbours.iter().find(|neighbour| neighbour.edge_id == neighbour_relationship.edge_id) {
+        if to_node_neighbour_to_self.restriction == Some(RestrictionType::OneWay) {
+            return ValidationResponse::Invalid(format!("The player cannot move to node with id {} because it's a one way street in the opposite direction!", to_node_id));
+        }
+    };
+
+    if neighbour_relationship.restriction == Some(RestrictionType::BusLane) {
+        if game.has_passengers(player.unique_id) {
+            return ValidationResponse::Valid;
+        }
+        return ValidationResponse::Invalid(format!("The player {} cannot move to the node {} because it's a bus lane, which can only be used by buses and cars with passengers!", player.name, to_node_id));
+    }
+
+    if let Some(restriction) = neighbour_relationship.restriction {
+        let Some(objective_card) = &player.objective_card else {
+            return ValidationResponse::Invalid(format!("The player {} does not have an objective card and we can therefore not check if the player has access to the given zone!", player.name));
+        };
+
+        if (!(objective_card.special_vehicle_types.contains(&restriction)
+        || (restriction == RestrictionType::Destination
+        && GameState::player_has_objective_in_district(&game.map, player, neighbour_relationship.neighbourhood)))) && restriction != RestrictionType::OneWay
+         {
+            return ValidationResponse::Invalid(format!("The player {} does not have access to the edge {:?} and can therefore not move to the node {}!", player.name, restriction, to_node_id));
+        }
+
+        return ValidationResponse::Valid;
+    }
+
+    match can_enter_district(context) {
+        ValidationResponse::Valid => (),
+        ValidationResponse::Invalid(e) => return ValidationResponse::Invalid(e),
+    }
+
+    if neighbour_relationship.restriction == Some(RestrictionType::ParkAndRide) {
+        return ValidationResponse::Invalid(
+            "The player cannot move here because it's a park & ride edge!".to_string(),
+        );
+    }
+
+    ValidationResponse::Valid
+}
+
+// Checks that players with objective cards requiring an accessible route only move along accessible edges.
+pub(super) fn is_edge_accessible_if_required(context: &RuleContext) -> ValidationResponse<String> {
+    let game = context.game;
+    let player = get_or_return_invalid_response!(context.player());
+    if !player.objective_card.as_ref().is_some_and(|objective_card| objective_card.requires_accessible_route) {
+        return ValidationResponse::Valid;
+    }
+
+    let player_pos = get_or_return_invalid_response!(context.position());
+
+    let to_node_id = get_or_return_invalid_response!(context.target_node());
+    let neighbour_relationship = get_or_return_invalid_response!(context.edge());
+
+    match game.map.is_edge_accessible(player_pos, neighbour_relationship) {
+        Ok(true) => ValidationResponse::Valid,
+        Ok(false) => ValidationResponse::Invalid(format!("The player {} needs an accessible route and the way to node {} is not accessible!", player.name, to_node_id)),
+        Err(e) => ValidationResponse::Invalid(e),
+    }
+}
+
+// Checks that a ferry edge is only used in the rounds the ferry departs in, and only while the ferry has room for another vehicle.
+fn can_take_ferry(context: &RuleContext) -> ValidationResponse<String> {
+    let game = context.game;
+    let player_pos = get_or_return_invalid_response!(context.position());
+
+    let to_node_id = get_or_return_invalid_response!(context.target_node());
+    let neighbour_relationship = get_or_return_invalid_response!(context.edge());
+    let Some(ferry) = neighbour_relationship.ferry else {
+        return ValidationResponse::Valid;
+    };
+
+    match ferry.rounds_until_departure(game.round_number) {
+        Some(0) => (),
+        Some(rounds) => return ValidationResponse::Invalid(format!("The ferry to node {} does not depart this round! It departs every {} rounds, and the next departure is in {} rounds.", to_node_id, ferry.departs_every_rounds, rounds)),
+        None => return ValidationResponse::Invalid(format!("The ferry to node {} never departs!", to_node_id)),
+    }
+    let crossings = game.ferry_crossings_this_round(neighbour_relationship.edge_id, player_pos);
+    if crossings >= usize::try_from(ferry.capacity).unwrap_or(usize::MAX) {
+        return ValidationResponse::Invalid(format!("The ferry to node {} is full! It only takes {} vehicles on each departure.", to_node_id, ferry.capacity));
+    }
+    ValidationResponse::Valid
+}
+
+// Checks that the edge the player moves along is not closed by construction works.
+fn is_edge_open(context: &RuleContext) -> ValidationResponse<String> {
+    let game = context.game;
+
+    let to_node_id = get_or_return_invalid_response!(context.target_node());
+    let neighbour_relationship = get_or_return_invalid_response!(context.edge());
+    if game.map.overlay.is_edge_closed(neighbour_relationship.edge_id) {
+        return ValidationResponse::Invalid(format!("The way to node {} is closed because of construction works!", to_node_id));
+    }
+    ValidationResponse::Valid
+}
+
+// Checks that players with the freight role do not go to the delivery node before they have loaded the cargo at the pick up node.
+fn is_cargo_loaded_before_delivery(context: &RuleContext) -> ValidationResponse<String> {
+    let player = get_or_return_invalid_response!(context.player());
+    let Some(objective_card) = &player.objective_card else {
+        return ValidationResponse::Valid;
+    };
+    if !objective_card.is_freight || player.cargo != CargoState::Empty {
+        return ValidationResponse::Valid;
+    }
+
+    let to_node_id = get_or_return_invalid_response!(context.target_node());
+
+    if to_node_id == objective_card.drop_off_node_id && to_node_id != objective_card.pick_up_node_id {
+        return ValidationResponse::Invalid(format!("The player {} has to pick up the cargo at node {} before going to the delivery node {}!", player.name, objective_card.pick_up_node_id, to_node_id));
+    }
+
+    ValidationResponse::Valid
+}
@@ -0,0 +1,165 @@
+use game_core::game_data::{structs::{gamestate::GameState, player_input::PlayerInput}, enums::{player_input_type::PlayerInputType, in_game_id::InGameID, turn_end_policy::TurnEndPolicy, game_status::GameStatus}};
+
+use crate::rule_context::RuleContext;
+use crate::rule_registry::{register_rule, RuleRegistry, ValidationResponse};
+
+/// Registers the rules about the phases of the game and whose turn it is.
+pub(super) fn register_rules(registry: &mut RuleRegistry) {
+    register_rule!(registry,
+        name: "game_not_finished",
+        description: "The inputs that change the game are rejected when the game is finished, except the inputs for the debrief and for moving on to the next scenario.",
+        priority: 5,
+        inputs: [Movement, ChangeRole, NextTurn, UndoAction, ModifyDistrict, StartGame, AssignSituationCard, ModifyEdgeRestrictions, SetPlayerBusBool, RevokeMeasure, OfferRide, AcceptRide, ExitVehicle, SetCongestionZone, EnactMeasureTemplate, Pass, TickTimedProp, ScheduleConstruction, RetryDeadLetter, OverrideRule, OverrideAndApply],
+        check: is_game_not_finished,
+    );
+    register_rule!(registry,
+        name: "game_started",
+        description: "The inputs that are only part of a started game are rejected while the game is in the lobby.",
+        priority: 10,
+        inputs: [Movement, ModifyDistrict, NextTurn, UndoAction, RevokeMeasure, OfferRide, AcceptRide, ExitVehicle, SetCongestionZone, EnactMeasureTemplate, CreateCheckpoint, RestoreCheckpoint, Pass, ScheduleConstruction],
+        check: has_game_started,
+    );
+    register_rule!(registry,
+        name: "players_turn",
+        description: "Only the player whose turn it is can make inputs, except for the inputs that can be made at any time.",
+        priority: 20,
+        inputs: [All],
+        needs: [],
+        check: is_players_turn,
+    );
+    register_rule!(registry,
+        name: "orchestrator_check",
+        description: "Only the orchestrator can start the game, enact measures, change the lobby settings and make the other facilitation inputs.",
+        priority: 30,
+        inputs: [StartGame, ModifyEdgeRestrictions, ModifyDistrict, ChangeLobbySettings, RevokeMeasure, SetCongestionZone, EnactMeasureTemplate, CreateCheckpoint, RestoreCheckpoint, NextScenario, ScheduleConstruction, RetryDeadLetter, DiscardDeadLetter, OverrideRule, OverrideAndApply, AnnotateMap],
+        needs: [Player],
+        check: is_orchestrator,
+    );
+    register_rule!(registry,
+        name: "in_lobby",
+        description: "The lobby settings, the names of the players and the seats of a fork can only be changed while the game is in the lobby.",
+        priority: 40,
+        inputs: [ChangeLobbySettings, RenamePlayer, ClaimSeat],
+        check: is_game_in_lobby,
+    );
+    register_rule!(registry,
+        name: "can_pass",
+        description: "The orchestrator can not pass, since the orchestrator ends the turn instead.",
+        priority: 70,
+        inputs: [Pass],
+        needs: [Player],
+        check: is_not_orchestrator,
+    );
+    register_rule!(registry,
+        name: "not_passed",
+        description: "A player who has passed can not move or pass again in the same turn.",
+        priority: 80,
+        inputs: [Movement, Pass],
+        check: has_not_passed,
+    );
+    register_rule!(registry,
+        name: "turn_end_policy",
+        description: "A player can only end the turn after passing or using all their moves, if the lobby requires it.",
+        priority: 140,
+        inputs: [NextTurn],
+        needs: [],
+        check: follows_turn_end_policy,
+    );
+    register_rule!(registry,
+        name: "server_only",
+        description: "The timed props can only be ticked by the server.",
+        priority: 360,
+        inputs: [TickTimedProp],
+        check: |_, _| ValidationResponse::Invalid("Only the server can tick the timed props!".to_string()),
+    );
+    register_rule!(registry,
+        name: "next_scenario",
+        description: "The next scenario of a campaign can only be started when the current scenario is over.",
+        priority: 390,
+        inputs: [NextScenario],
+        check: can_start_next_scenario,
+    );
+}
+
+fn has_game_started(game: &GameState, _player_input: &PlayerInput) -> ValidationResponse<String> {
+    match game.is_lobby {
+        true => ValidationResponse::Invalid("The game has not started yet!".to_string()),
+        false => ValidationResponse::Valid,
+    }
+}
+
+fn is_game_not_finished(game: &GameState, _player_input: &PlayerInput) -> ValidationResponse<String> {
+    match &game.status {
+        GameStatus::Finished { winners, .. } => ValidationResponse::Invalid(format!("The game is finished! The winners are the players with the ids {:?}.", winners)),
+        GameStatus::Ongoing => ValidationResponse::Valid,
+    }
+}
+
+fn is_game_in_lobby(game: &GameState, _player_input: &PlayerInput) -> ValidationResponse<String> {
+    match game.is_lobby {
+        true => ValidationResponse::Valid,
+        false => ValidationResponse::Invalid("This can only be done while the game is in the lobby!".to_string()),
+    }
+}
+
+fn can_start_next_scenario(game: &GameState, _player_input: &PlayerInput) -> ValidationResponse<String> {
+    match game.check_can_start_next_scenario() {
+        Ok(()) => ValidationResponse::Valid,
+        Err(e) => ValidationResponse::Invalid(e),
+    }
+}
+
+fn is_players_turn(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, player_input, .. } = context;
+    if game.is_lobby || matches!(player_input.input_type, PlayerInputType::LeaveGame | PlayerInputType::NextScenario | PlayerInputType::RetryDeadLetter | PlayerInputType::DiscardDeadLetter | PlayerInputType::OverrideRule | PlayerInputType::OverrideAndApply | PlayerInputType::AnnotateMap | PlayerInputType::ChatMessage) || game.is_async_measure(player_input) {
+        return ValidationResponse::Valid;
+    }
+
+    let player = get_or_return_invalid_response!(context.player());
+
+    let forces_turn_end = player_input.input_type == PlayerInputType::NextTurn && player.in_game_id == InGameID::Orchestrator && game.lobby_settings.turn_end_policy == TurnEndPolicy::RequirePass;
+    if game.current_players_turn != player.in_game_id && !forces_turn_end {
+        return ValidationResponse::Invalid("It's not the current players turn".to_string());
+    }
+
+    ValidationResponse::Valid
+}
+
+fn is_not_orchestrator(context: &RuleContext) -> ValidationResponse<String> {
+    let player = get_or_return_invalid_response!(context.player());
+    if player.in_game_id == InGameID::Orchestrator {
+        return ValidationResponse::Invalid("The orchestrator does not move and can therefore not pass!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+fn has_not_passed(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    if game.has_passed_this_turn(player_input.player_id) {
+        return ValidationResponse::Invalid("The player has passed and can not move again this turn!".to_string());
+    }
+    ValidationResponse::Valid
+}
+
+// Checks that the player has passed or used all their moves before ending the turn, if the lobby requires it. The orchestrator can always end the turn.
+fn follows_turn_end_policy(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, .. } = context;
+    if game.lobby_settings.turn_end_policy == TurnEndPolicy::Free {
+        return ValidationResponse::Valid;
+    }
+    let player = get_or_return_invalid_response!(context.player());
+    if player.in_game_id == InGameID::Orchestrator || player.remaining_moves <= 0 || game.has_passed_this_turn(player.unique_id) {
+        return ValidationResponse::Valid;
+    }
+    ValidationResponse::Invalid("The player has to pass before ending the turn!".to_string())
+}
+
+fn is_orchestrator(context: &RuleContext) -> ValidationResponse<String> {
+    let player = get_or_return_invalid_response!(context.player());
+    if player.in_game_id != InGameID::Orchestrator {
+        return ValidationResponse::Invalid(
+            "The player is not the orchestrator of the game!".to_string(),
+        );
+    }
+
+    ValidationResponse::Valid
+}
@@ -0,0 +1,267 @@
+use game_core::{reachability::isolated_players, measure_template_list::get_measure_template_by_id, game_data::{structs::{gamestate::GameState, player_input::PlayerInput, edge_restriction::EdgeRestriction, neighbour_relationship::NeighbourRelationship, rule_set::RuleSet, district_modifier::DistrictModifier}, enums::{player_input_type::PlayerInputType, restriction_type::RestrictionType}, custom_types::EdgeID}};
+
+use crate::rule_context::RuleContext;
+use crate::rule_registry::{register_rule, RuleRegistry, ValidationResponse};
+
+use super::movement::{can_move_to_node, is_edge_accessible_if_required};
+
+/// Registers the rules about the measures the orchestrator can enact, like edge restrictions, district modifiers and construction works.
+pub(super) fn register_rules(registry: &mut RuleRegistry, rule_set: &RuleSet) {
+    register_rule!(registry,
+        name: "can_modify_edge_restriction",
+        description: "Edge restrictions can only be added to and removed from edges that can be modified, following the rules of each restriction type.",
+        priority: 250,
+        inputs: [ModifyEdgeRestrictions],
+        check: is_edge_modification_action_valid,
+    );
+    register_rule!(registry,
+        name: "measure_revocable",
+        description: "Only measures that have been enacted and not revoked can be revoked.",
+        priority: 260,
+        inputs: [RevokeMeasure],
+        check: is_measure_revocable,
+    );
+    register_rule!(registry,
+        name: "measure_template_valid",
+        description: "A measure template has to exist and be possible to enact on the chosen district or edge.",
+        priority: 270,
+        inputs: [EnactMeasureTemplate],
+        check: is_measure_template_valid,
+    );
+    let max_modifiers = rule_set.max_modifiers_per_district;
+    register_rule!(registry,
+        name: "max_modifiers_per_district",
+        description: "A district can not have more district modifiers than the rule set allows.",
+        priority: 280,
+        inputs: [ModifyDistrict, EnactMeasureTemplate],
+        check: move |game, player_input| is_below_max_modifiers_per_district(game, player_input, max_modifiers),
+    );
+    register_rule!(registry,
+        name: "construction_valid",
+        description: "Construction works have to be on edges of the map, change something and start late enough for the players to be told about them.",
+        priority: 290,
+        inputs: [ScheduleConstruction],
+        check: is_construction_valid,
+    );
+    register_rule!(registry,
+        name: "keeps_players_connected",
+        description: "A measure can not leave a player without a way to a parking spot or the next node of the player's objective.",
+        priority: 370,
+        inputs: [ModifyDistrict, ModifyEdgeRestrictions, RevokeMeasure, EnactMeasureTemplate],
+        check: does_measure_keep_players_connected,
+    );
+}
+
+fn is_measure_revocable(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(measure_id) = player_input.measure_id else {
+        return ValidationResponse::Invalid("There was no measure id in the input, and it's therefore not known which measure to revoke!".to_string());
+    };
+    match game.get_active_measure(measure_id) {
+        Ok(_) => ValidationResponse::Valid,
+        Err(e) => ValidationResponse::Invalid(e),
+    }
+}
+
+// Checks that the construction works are on edges in the map, do something to the edges and start late enough that the players can be told about them the turn before.
+fn is_construction_valid(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(work) = &player_input.construction_work else {
+        return ValidationResponse::Invalid("There was no construction work in the input, and it's therefore not known what to schedule!".to_string());
+    };
+    if work.edge_ids.is_empty() {
+        return ValidationResponse::Invalid("The construction works must be on at least one edge!".to_string());
+    }
+    if let Some(edge_id) = work.edge_ids.iter().find(|edge_id| !game.map.has_edge(**edge_id)) {
+        return ValidationResponse::Invalid(format!("There is no edge with id {} in the map!", edge_id));
+    }
+    if work.duration == 0 {
+        return ValidationResponse::Invalid("The construction works must last at least one turn!".to_string());
+    }
+    if work.extra_movement_cost < 0 {
+        return ValidationResponse::Invalid("The construction works can not make the edges cheaper to use!".to_string());
+    }
+    if work.extra_movement_cost == 0 && !work.closes_edges {
+        return ValidationResponse::Invalid("The construction works must either raise the movement cost of the edges or close them!".to_string());
+    }
+    // The works are scheduled when the turn of the orchestrator ends, and announced in the turn before they start.
+    let earliest_start_turn = game.turn_number + 2;
+    if work.start_turn < earliest_start_turn {
+        return ValidationResponse::Invalid(format!("The construction works must start in turn {} or later, so the players can be told about them the turn before!", earliest_start_turn));
+    }
+    ValidationResponse::Valid
+}
+
+fn is_measure_template_valid(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let Some(template_id) = player_input.measure_template_id else {
+        return ValidationResponse::Invalid("There was no measure template id in the input, and it's therefore not known which measure to enact!".to_string());
+    };
+    let template = match get_measure_template_by_id(template_id) {
+        Ok(template) => template,
+        Err(e) => return ValidationResponse::Invalid(e),
+    };
+    for edge_restriction in &template.edge_restrictions {
+        let Some(neighbours) = game.map.get_neighbour_relationships_of_node_with_id(edge_restriction.node_one) else {
+            return ValidationResponse::Invalid(format!("The node {} does not have neighbours and can therefore not have restrictions!", edge_restriction.node_one));
+        };
+        let edge_id = match game.map.find_edge_id(edge_restriction) {
+            Ok(edge_id) => edge_id,
+            Err(e) => return ValidationResponse::Invalid(format!("The measure template {} can not be enacted because: {}", template.name, e)),
+        };
+        if let ValidationResponse::Invalid(e) = default_can_modify_edge_restriction(edge_restriction, &neighbours, edge_id) {
+            return ValidationResponse::Invalid(format!("The measure template {} can not be enacted because: {}", template.name, e));
+        }
+    }
+    ValidationResponse::Valid
+}
+
+fn is_below_max_modifiers_per_district(game: &GameState, player_input: &PlayerInput, max_modifiers: Option<usize>) -> ValidationResponse<String> {
+    let Some(max_modifiers) = max_modifiers else {
+        return ValidationResponse::Valid;
+    };
+    let added_modifiers: Vec<DistrictModifier> = match player_input.input_type {
+        PlayerInputType::EnactMeasureTemplate => match player_input.measure_template_id.map(get_measure_template_by_id) {
+            Some(Ok(template)) => template.district_modifiers,
+            // The measure_template_valid rule tells why the template can not be enacted.
+            _ => return ValidationResponse::Valid,
+        },
+        _ => player_input.district_modifier.iter().filter(|district_modifier| !district_modifier.delete).cloned().collect(),
+    };
+    for added_modifier in added_modifiers.iter() {
+        let modifiers_in_district = game
+            .district_modifiers
+            .iter()
+            .chain(added_modifiers.iter())
+            .filter(|district_modifier| district_modifier.district == added_modifier.district)
+            .count();
+        if modifiers_in_district > max_modifiers {
+            return ValidationResponse::Invalid(format!("The district {:?} can not have more than {} modifiers at the same time in this game!", added_modifier.district, max_modifiers));
+        }
+    }
+    ValidationResponse::Valid
+}
+
+// Checks if the player is allowed to modify the edge they are trying to modify.
+#[allow(unused_variables)]
+fn is_edge_modification_action_valid(
+    game: &GameState,
+    player_input: &PlayerInput,
+) -> ValidationResponse<String> {
+    let Some(edge_mod) = player_input.edge_modifier.clone() else {
+        return ValidationResponse::Invalid("There was no modifier on the edge modifier player input, and can therefore not check the input further!".to_string());
+    };
+
+    let Some(neighbours_one) = game.map.get_neighbour_relationships_of_node_with_id(edge_mod.node_one) else {
+        return ValidationResponse::Invalid(format!("The node {} does not have neighbours and can therefore not have restrictions!", edge_mod.node_one));
+    };
+
+    let Some(neighbours_two) = game.map.get_neighbour_relationships_of_node_with_id(edge_mod.node_two) else {
+        return ValidationResponse::Invalid(format!("The node {} does not have neighbours and can therefore not have restrictions!", edge_mod.node_one));
+    };
+
+    let edge_id = match game.map.find_edge_id(&edge_mod) {
+        Ok(edge_id) => edge_id,
+        Err(e) => return ValidationResponse::Invalid(e),
+    };
+
+    default_can_modify_edge_restriction(&edge_mod, &neighbours_one, edge_id)
+
+    // match edge_mod.edge_restriction { // This can be turned on if you only want to add or delete edges next to park and ride start node or other park and ride edges, but you cannot delete edges if there are cycles.
+    //     RestrictionType::ParkAndRide => can_modify_park_and_ride(game, &edge_mod, &neighbours_one, &neighbours_two), 
+    //     _ => default_can_modify_edge_restriction(&edge_mod, &neighbours_one, edge_id),
+    // }
+
+}
+
+fn default_can_modify_edge_restriction(edge_mod: &EdgeRestriction, neighbours_one: &[NeighbourRelationship], edge_id: EdgeID) -> ValidationResponse<String> {
+    let Some(relationship) = neighbours_one.iter().find(|relationship| relationship.edge_id == edge_id) else {
+        return ValidationResponse::Invalid(format!("The node {} is not connected to the edge with id {}!", edge_mod.node_one, edge_id));
+    };
+    if edge_mod.delete {
+        if relationship.is_modifiable {
+            return ValidationResponse::Valid;
+        }
+        return ValidationResponse::Invalid(format!("A edge restriction {:?} already exists on the edge between node {} and node {} or is not modifiable! Modifiable: {}", edge_mod.edge_restriction, edge_mod.node_one, edge_mod.node_two, relationship.is_modifiable));
+    }
+    else if !relationship.is_modifiable {
+        return ValidationResponse::Invalid(format!("The edge between node {} and node {} or is not modifiable!", edge_mod.node_one, edge_mod.node_two));
+    }
+    ValidationResponse::Valid
+}
+
+#[allow(dead_code)]
+fn can_modify_park_and_ride(game: &GameState, park_and_ride_mod: &EdgeRestriction, neighbours_one: &[NeighbourRelationship], neighbours_two: &[NeighbourRelationship]) -> ValidationResponse<String> {
+    if park_and_ride_mod.delete {
+        if neighbours_one
+            .iter()
+            .filter(|neighbour| neighbour.restriction == Some(RestrictionType::ParkAndRide) && neighbour.is_modifiable)
+            .count()
+            < 2
+            || neighbours_two
+                .iter()
+                .filter(|neighbour| neighbour.restriction == Some(RestrictionType::ParkAndRide) && neighbour.is_modifiable)
+                .count()
+                < 2
+        {
+            return ValidationResponse::Valid;
+        }
+        return ValidationResponse::Invalid("It's not possible to delete a park & ride edge that is connected to more than one other park & ride edge or the park & ride egde is not modifiable!".to_string());
+    }
+
+    let node_one = match game.map.get_node_by_id(park_and_ride_mod.node_one) {
+        Ok(n) => n,
+        Err(e) => {
+            return ValidationResponse::Invalid(
+                e + " and can therefore not check wether the park & ride can be placed here!",
+            )
+        }
+    };
+
+    let node_two = match game.map.get_node_by_id(park_and_ride_mod.node_two) {
+        Ok(n) => n,
+        Err(e) => {
+            return ValidationResponse::Invalid(
+                e + " and can therefore not check wether the park & ride can be placed here!",
+            )
+        }
+    };
+
+    if node_one.is_parking_spot || node_two.is_parking_spot {
+        return ValidationResponse::Valid;
+    }
+
+    if neighbours_one
+        .iter()
+        .filter(|neighbour| neighbour.restriction == Some(RestrictionType::ParkAndRide))
+        .count()
+        > 0
+        || neighbours_two
+            .iter()
+            .filter(|neighbour| neighbour.restriction == Some(RestrictionType::ParkAndRide))
+            .count()
+            > 0
+    {
+        return ValidationResponse::Valid;
+    }
+
+    ValidationResponse::Invalid(format!("Cannot place park & ride on the edge between node with ids {} and {} because there is no adjacent parking spots or park and ride edges!", park_and_ride_mod.node_one, park_and_ride_mod.node_two))
+}
+
+// Checks that the measure does not leave any player without a way to a parking spot or to the next node of the player's objective.
+fn does_measure_keep_players_connected(game: &GameState, player_input: &PlayerInput) -> ValidationResponse<String> {
+    let mut game_clone = game.clone();
+    if let Err(e) = game_clone.apply_measure_input(player_input.clone()) {
+        return ValidationResponse::Invalid(e);
+    }
+
+    match isolated_players(&game_clone, can_go_to_node) {
+        Ok(players) if players.is_empty() => ValidationResponse::Valid,
+        Ok(players) => ValidationResponse::Invalid(format!("The measure would leave {} without a way to a parking spot or their objective!", players.join(", "))),
+        Err(e) => ValidationResponse::Invalid(e),
+    }
+}
+
+// Checks the rules that decide where a player can go, but not how far or when. Used to find the nodes a player can reach.
+fn can_go_to_node(game: &GameState, player_input: &PlayerInput) -> bool {
+    let context = RuleContext::new(game, player_input);
+    matches!(can_move_to_node(&context), ValidationResponse::Valid)
+        && matches!(is_edge_accessible_if_required(&context), ValidationResponse::Valid)
+}
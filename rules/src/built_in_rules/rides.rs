@@ -0,0 +1,163 @@
+use game_core::game_data::enums::in_game_id::InGameID;
+
+use crate::rule_context::RuleContext;
+use crate::rule_registry::{register_rule, RuleRegistry, ValidationResponse};
+
+/// Registers the rules about buses and players riding with each other.
+pub(super) fn register_rules(registry: &mut RuleRegistry) {
+    register_rule!(registry,
+        name: "toggle_bus",
+        description: "A player can only become a bus (or stop being one) on a parking spot.",
+        priority: 60,
+        inputs: [SetPlayerBusBool],
+        needs: [Player],
+        check: can_toggle_bus,
+    );
+    register_rule!(registry,
+        name: "not_a_passenger",
+        description: "A passenger can not move, become a bus or offer rides, since passengers move along with the driver.",
+        priority: 200,
+        inputs: [Movement, SetPlayerBusBool, OfferRide],
+        needs: [Player],
+        check: is_not_riding_with_anyone,
+    );
+    register_rule!(registry,
+        name: "offer_ride",
+        description: "Only a car can offer a ride, and only to a player at the same node who is not riding with someone already.",
+        priority: 210,
+        inputs: [OfferRide],
+        needs: [Player],
+        check: can_offer_ride,
+    );
+    register_rule!(registry,
+        name: "accept_ride",
+        description: "A ride can only be accepted if it was offered to the player by a driver at the same node.",
+        priority: 220,
+        inputs: [AcceptRide],
+        needs: [Player],
+        check: can_accept_ride,
+    );
+    register_rule!(registry,
+        name: "exit_vehicle",
+        description: "A passenger can only exit the vehicle at the node the vehicle is at.",
+        priority: 230,
+        inputs: [ExitVehicle],
+        needs: [Player],
+        check: can_exit_vehicle,
+    );
+}
+
+// Checks that the player is not a passenger in another player's car, since passengers can only move along with the driver.
+fn is_not_riding_with_anyone(context: &RuleContext) -> ValidationResponse<String> {
+    let player = get_or_return_invalid_response!(context.player());
+    match player.riding_with {
+        Some(_) => ValidationResponse::Invalid(format!("The player {} is a passenger and has to exit the vehicle first!", player.name)),
+        None => ValidationResponse::Valid,
+    }
+}
+
+fn can_offer_ride(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, player_input, .. } = context;
+    let driver = get_or_return_invalid_response!(context.player());
+    let Some(passenger_id) = player_input.related_player_id else {
+        return ValidationResponse::Invalid("There was no player to offer a ride to!".to_string());
+    };
+    if passenger_id == driver.unique_id {
+        return ValidationResponse::Invalid("A player can not offer a ride to itself!".to_string());
+    }
+    let passenger = match game.get_player_with_unique_id(passenger_id) {
+        Ok(p) => p,
+        Err(e) => return ValidationResponse::Invalid(e.to_string()),
+    };
+
+    if driver.is_bus {
+        return ValidationResponse::Invalid("Only cars can offer rides, and the player is a bus!".to_string());
+    }
+    if passenger.in_game_id == InGameID::Orchestrator {
+        return ValidationResponse::Invalid("The orchestrator can not ride with other players!".to_string());
+    }
+    if passenger.riding_with.is_some() {
+        return ValidationResponse::Invalid(format!("The player {} is already riding with someone!", passenger.name));
+    }
+
+    let driver_pos = get_or_return_invalid_response!(context.position());
+    if passenger.position_node_id != Some(driver_pos) {
+        return ValidationResponse::Invalid(format!("The player {} has to be at the same node as {} to be offered a ride!", passenger.name, driver.name));
+    }
+
+    ValidationResponse::Valid
+}
+
+fn can_accept_ride(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, player_input, .. } = context;
+    let passenger = get_or_return_invalid_response!(context.player());
+    let Some(driver_id) = player_input.related_player_id else {
+        return ValidationResponse::Invalid("There was no driver whose ride should be accepted!".to_string());
+    };
+    let driver = match game.get_player_with_unique_id(driver_id) {
+        Ok(p) => p,
+        Err(e) => return ValidationResponse::Invalid(e.to_string()),
+    };
+
+    if driver.ride_offered_to != Some(passenger.unique_id) {
+        return ValidationResponse::Invalid(format!("The player {} has not offered a ride to {}!", driver.name, passenger.name));
+    }
+    if passenger.riding_with.is_some() {
+        return ValidationResponse::Invalid(format!("The player {} is already riding with someone!", passenger.name));
+    }
+    if game.players.iter().any(|player| player.riding_with == Some(passenger.unique_id)) {
+        return ValidationResponse::Invalid(format!("The player {} has passengers and can therefore not ride with someone else!", passenger.name));
+    }
+
+    let passenger_pos = get_or_return_invalid_response!(context.position());
+    if driver.position_node_id != Some(passenger_pos) {
+        return ValidationResponse::Invalid(format!("The player {} has to be at the same node as {} to accept the ride!", passenger.name, driver.name));
+    }
+
+    ValidationResponse::Valid
+}
+
+// Checks that the passenger exits the vehicle at the node the vehicle is at, and not somewhere along an edge.
+fn can_exit_vehicle(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { player_input, .. } = context;
+    let passenger = get_or_return_invalid_response!(context.player());
+    if passenger.riding_with.is_none() {
+        return ValidationResponse::Invalid(format!("The player {} is not riding with anyone!", passenger.name));
+    }
+
+    let Some(exit_node_id) = player_input.related_node_id else {
+        return ValidationResponse::Invalid("There was no node to exit the vehicle at!".to_string());
+    };
+
+    let passenger_pos = get_or_return_invalid_response!(context.position());
+    if exit_node_id != passenger_pos {
+        return ValidationResponse::Invalid(format!("The vehicle is at node {} and the player can not exit it at node {} or anywhere between nodes!", passenger_pos, exit_node_id));
+    }
+
+    ValidationResponse::Valid
+}
+
+fn can_toggle_bus(context: &RuleContext) -> ValidationResponse<String> {
+    let RuleContext { game, player_input, .. } = context;
+    let Some(_) = player_input.related_bool else {
+        return ValidationResponse::Invalid("Could not check if you can toggle bus because the related bool was not set. It's needed for so that we can know if you want to stop being a bus or change to a bus!".to_string());
+    };
+
+    let player_pos = get_or_return_invalid_response!(context.position());
+    let node = match game.map.get_node_by_id(player_pos) {
+        Ok(n) => n,
+        Err(e) => {
+            return ValidationResponse::Invalid(
+                e + " and can therefore not check wether the player can toggle bus!",
+            )
+        }
+    };
+
+    if !node.is_parking_spot {
+        return ValidationResponse::Invalid(
+            "You cannot toggle bus if you are not on a parking spot!".to_string(),
+        );
+    }
+
+    ValidationResponse::Valid
+}
@@ -1,4 +1,12 @@
 //! This library contains a implementation of the RuleChecker trait.
 
+/// This module contains the built-in rules, split into modules by what they are about.
+mod built_in_rules;
 /// This module contains the implementation of the RuleChecker trait.
 pub mod game_rule_checker;
+/// This module contains the context the rules check an input with, which shares what several rules need to know about the input.
+mod rule_context;
+/// This module contains the registry the rules are registered in with the `register_rule!` macro, and the descriptions of the rules.
+pub mod rule_registry;
+/// This module contains the rules that are written as scripts, so that scenario authors can add rules without recompiling the server.
+pub mod script_rules;
@@ -0,0 +1,101 @@
+use std::cell::OnceCell;
+
+use game_core::game_data::{custom_types::{MovesRemaining, NodeID}, structs::{gamestate::GameState, neighbour_relationship::NeighbourRelationship, player::Player, player_input::PlayerInput}};
+
+/// The things about an input a rule can declare that it depends on with `needs` in [`register_rule!`].
+///
+/// The dependencies of a rule are resolved before the rule is checked, and the rule is broken with the reason if one of them can not be resolved, so the rule itself does not have to handle it.
+///
+/// [`register_rule!`]: ../rule_registry/macro.register_rule.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleDependency {
+    /// The player that made the input, see [`RuleContext::player`].
+    Player,
+    /// The node the player is at, see [`RuleContext::position`].
+    Position,
+    /// The node the player wants to move to, see [`RuleContext::target_node`].
+    TargetNode,
+    /// The edge the player wants to move along, see [`RuleContext::edge`].
+    Edge,
+    /// The remaining moves of the player after the move, see [`RuleContext::remaining_moves_after_move`].
+    MoveCost,
+}
+
+/// The RuleContext struct is what the rules check an input with: the game, the input and the things about the input that several rules need.
+///
+/// The things are looked up the first time a rule needs them and shared with the rest of the rules that are checked for the same input, so that the player, the edge and the cost of a move are only found once per input.
+pub struct RuleContext<'a> {
+    pub game: &'a GameState,
+    pub player_input: &'a PlayerInput,
+    player: OnceCell<Result<&'a Player, String>>,
+    edge: OnceCell<Result<NeighbourRelationship, String>>,
+    remaining_moves_after_move: OnceCell<Result<MovesRemaining, String>>,
+}
+
+impl<'a> RuleContext<'a> {
+    pub const fn new(game: &'a GameState, player_input: &'a PlayerInput) -> Self {
+        Self {
+            game,
+            player_input,
+            player: OnceCell::new(),
+            edge: OnceCell::new(),
+            remaining_moves_after_move: OnceCell::new(),
+        }
+    }
+
+    /// Returns an error if the dependency can not be resolved for the input.
+    pub fn resolve(&self, dependency: RuleDependency) -> Result<(), String> {
+        match dependency {
+            RuleDependency::Player => self.player().map(|_| ()),
+            RuleDependency::Position => self.position().map(|_| ()),
+            RuleDependency::TargetNode => self.target_node().map(|_| ()),
+            RuleDependency::Edge => self.edge().map(|_| ()),
+            RuleDependency::MoveCost => self.remaining_moves_after_move().map(|_| ()),
+        }
+    }
+
+    /// The player that made the input. Returns an error if the player is not in the game.
+    pub fn player(&self) -> Result<&'a Player, String> {
+        self.player
+            .get_or_init(|| {
+                self.game
+                    .players
+                    .iter()
+                    .find(|player| player.unique_id == self.player_input.player_id)
+                    .ok_or_else(|| "There is no player in the game with the given id".to_string())
+            })
+            .clone()
+    }
+
+    /// The node the player that made the input is at. Returns an error if the player does not have a position.
+    pub fn position(&self) -> Result<NodeID, String> {
+        self.player()?.position_node_id.ok_or_else(|| "The player does not have a position!".to_string())
+    }
+
+    /// The node the player wants to move to. Returns an error if the input does not have a node.
+    pub fn target_node(&self) -> Result<NodeID, String> {
+        self.player_input
+            .related_node_id
+            .ok_or_else(|| "There is no related node to the movement input. There needs to be a node if a players should move!".to_string())
+    }
+
+    /// The edge from the position of the player to the node the player wants to move to, with the restrictions it has in the game.
+    /// Returns an error if the player does not have a position, the input does not have a node or there is no such edge.
+    pub fn edge(&self) -> Result<&NeighbourRelationship, String> {
+        self.edge
+            .get_or_init(|| self.game.map.get_edge_between(self.position()?, self.target_node()?, self.player_input.edge_id))
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// How many moves the player would have left after making the move, which is negative if the player can not afford it. Returns an error if the move can not be made.
+    pub fn remaining_moves_after_move(&self) -> Result<MovesRemaining, String> {
+        self.remaining_moves_after_move
+            .get_or_init(|| {
+                let mut game_after_move = self.game.clone();
+                game_after_move.move_player_with_id(self.player_input.player_id, self.target_node()?, self.player_input.edge_id)?;
+                game_after_move.get_player_with_unique_id(self.player_input.player_id).map(|player| player.remaining_moves).map_err(|e| e.to_string())
+            })
+            .clone()
+    }
+}
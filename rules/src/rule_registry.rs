@@ -0,0 +1,124 @@
+use game_core::game_data::{enums::player_input_type::PlayerInputType, structs::rule_set::RuleSet};
+
+use crate::rule_context::{RuleContext, RuleDependency};
+
+/// The priority of the rules from rule scripts, which are checked after all the built-in rules.
+pub const SCRIPT_RULE_PRIORITY: u32 = u32::MAX;
+
+pub(crate) type RuleFn = Box<dyn Fn(&RuleContext) -> ValidationResponse<String> + Send + Sync>;
+
+pub(crate) enum ValidationResponse<T> {
+    Valid,
+    Invalid(T),
+}
+
+pub(crate) struct Rule {
+    pub name: String,
+    pub description: String,
+    /// The rules with a lower priority are checked first. Since only the first broken rule is told to the player, the rules that explain the most (like whose turn it is) should be checked before the more specific ones.
+    pub priority: u32,
+    pub related_inputs: Vec<PlayerInputType>,
+    /// What the rule needs to know about the input, which is resolved before the rule is checked. See [`RuleDependency`].
+    pub dependencies: Vec<RuleDependency>,
+    pub rule_fn: RuleFn,
+}
+
+impl Rule {
+    /// Checks the input in the context against the rule. The rule is broken if one of its dependencies can not be resolved.
+    pub fn check(&self, context: &RuleContext) -> ValidationResponse<String> {
+        if let Some(e) = self.dependencies.iter().find_map(|dependency| context.resolve(*dependency).err()) {
+            return ValidationResponse::Invalid(e);
+        }
+        (self.rule_fn)(context)
+    }
+}
+
+/// The RuleDescription struct describes a rule that is checked, so that facilitators can see which rules a rule set can disable and what they do.
+#[derive(Clone, Debug)]
+pub struct RuleDescription {
+    pub name: String,
+    pub description: String,
+    pub priority: u32,
+    pub related_inputs: Vec<PlayerInputType>,
+}
+
+impl From<&Rule> for RuleDescription {
+    fn from(rule: &Rule) -> Self {
+        Self {
+            name: rule.name.clone(),
+            description: rule.description.clone(),
+            priority: rule.priority,
+            related_inputs: rule.related_inputs.clone(),
+        }
+    }
+}
+
+/// The RuleRegistry struct collects the rules the rule modules register with [`register_rule!`], and orders them by their priority.
+#[derive(Default)]
+pub(crate) struct RuleRegistry {
+    rules: Vec<Rule>,
+}
+
+impl RuleRegistry {
+    /// Adds the rule to the registry. Use [`register_rule!`] instead, so that no part of the rule is forgotten.
+    pub fn register(&mut self, rule: Rule) {
+        debug_assert!(self.rules.iter().all(|registered_rule| registered_rule.name != rule.name), "The rule {} is registered twice!", rule.name);
+        self.rules.push(rule);
+    }
+
+    /// Returns the names of all the registered rules, including the ones the rule set disables.
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name.as_str()).collect()
+    }
+
+    /// Returns the registered rules the rule set has not disabled, ordered by their priority. Rules with the same priority are in the order they were registered.
+    pub fn into_rules(self, rule_set: &RuleSet) -> Vec<Rule> {
+        let mut rules: Vec<Rule> = self.rules.into_iter().filter(|rule| rule_set.is_enabled(&rule.name)).collect();
+        rules.sort_by_key(|rule| rule.priority);
+        rules
+    }
+}
+
+/// Registers a rule in a [`RuleRegistry`] with its name, description, priority, the input types it's checked for and the function that checks it:
+///
+/// ```ignore
+/// register_rule!(registry,
+///     name: "edge_not_closed",
+///     description: "A player can not move along an edge that is closed by construction works.",
+///     priority: 180,
+///     inputs: [Movement],
+///     needs: [Edge],
+///     check: is_edge_open,
+/// );
+/// ```
+///
+/// The name is what the rule is called in rule violations and rule sets, so it must never be changed. The inputs are the names of [`PlayerInputType`] variants, where `All` checks the rule for every input.
+///
+/// A rule that declares what it `needs` (the names of [`RuleDependency`] variants, or `[]`) is checked with a function that takes the [`RuleContext`] of the input,
+/// so that it can share the player, the edge and the cost of the move with the other rules. A rule without `needs` is checked with a function that takes the game and the input.
+///
+/// [`RuleDependency`]: ../rule_context/enum.RuleDependency.html
+/// [`RuleContext`]: ../rule_context/struct.RuleContext.html
+macro_rules! register_rule {
+    ($registry:expr, name: $name:literal, description: $description:literal, priority: $priority:expr, inputs: [$($input:ident),+ $(,)?], check: $rule_fn:expr $(,)?) => {
+        $registry.register($crate::rule_registry::Rule {
+            name: $name.to_string(),
+            description: $description.to_string(),
+            priority: $priority,
+            related_inputs: vec![$(::game_core::game_data::enums::player_input_type::PlayerInputType::$input),+],
+            dependencies: Vec::new(),
+            rule_fn: Box::new(move |context: &$crate::rule_context::RuleContext| ($rule_fn)(context.game, context.player_input)),
+        })
+    };
+    ($registry:expr, name: $name:literal, description: $description:literal, priority: $priority:expr, inputs: [$($input:ident),+ $(,)?], needs: [$($dependency:ident),* $(,)?], check: $rule_fn:expr $(,)?) => {
+        $registry.register($crate::rule_registry::Rule {
+            name: $name.to_string(),
+            description: $description.to_string(),
+            priority: $priority,
+            related_inputs: vec![$(::game_core::game_data::enums::player_input_type::PlayerInputType::$input),+],
+            dependencies: vec![$($crate::rule_context::RuleDependency::$dependency),*],
+            rule_fn: Box::new($rule_fn),
+        })
+    };
+}
+pub(crate) use register_rule;
@@ -0,0 +1,212 @@
+use std::{fs, path::Path, sync::Arc};
+
+use game_core::game_data::{
+    custom_types::{ErrorData, SituationCardID},
+    structs::{gamestate::GameState, player_input::PlayerInput},
+};
+use rhai::{module_resolvers::DummyModuleResolver, serde::to_dynamic, Dynamic, Engine, Scope, AST};
+
+/// The file extension the rule scripts needs to have to be loaded.
+pub const RULE_SCRIPT_EXTENSION: &str = "rhai";
+/// The name of the function every rule script has to define. It receives the game state and the player input.
+pub const VALIDATION_FUNCTION_NAME: &str = "validate";
+
+const MAX_SCRIPT_OPERATIONS: u64 = 100_000;
+const MAX_SCRIPT_CALL_LEVELS: usize = 32;
+const MAX_SCRIPT_EXPRESSION_DEPTH: usize = 64;
+const MAX_SCRIPT_FUNCTION_EXPRESSION_DEPTH: usize = 32;
+const MAX_SCRIPT_STRING_SIZE: usize = 10_000;
+const MAX_SCRIPT_COLLECTION_SIZE: usize = 10_000;
+
+/// A rule written in the [rhai](https://rhai.rs) scripting language.
+///
+/// The script has to define a `validate(state, input)` function. The state and input are read-only copies of the [`GameState`] and [`PlayerInput`] (as they are serialized for the client).
+/// The function decides if the input is valid by returning:
+/// - `()` or `true` if the input is valid.
+/// - `false` if the input is invalid.
+/// - A string with the reason if the input is invalid.
+///
+/// If the script fails or uses more resources than it's allowed to, the input is treated as invalid.
+///
+/// [`GameState`]: ../../game_core/game_data/structs/gamestate/struct.GameState.html
+/// [`PlayerInput`]: ../../game_core/game_data/structs/player_input/struct.PlayerInput.html
+pub struct ScriptRule {
+    pub name: String,
+    /// The situation card (scenario) the rule is used for. If it's `None` the rule is used for all scenarios.
+    pub situation_card_id: Option<SituationCardID>,
+    engine: Arc<Engine>,
+    ast: AST,
+}
+
+impl ScriptRule {
+    /// Compiles the given script into a new ScriptRule. Returns an error if the script could not be compiled or does not define the validation function.
+    pub fn new(
+        name: String,
+        situation_card_id: Option<SituationCardID>,
+        script: &str,
+        engine: Arc<Engine>,
+    ) -> Result<Self, String> {
+        let ast = match engine.compile(script) {
+            Ok(ast) => ast,
+            Err(e) => return Err(format!("Failed to compile the rule script {name} because: {e}")),
+        };
+        if !ast
+            .iter_functions()
+            .any(|f| f.name == VALIDATION_FUNCTION_NAME && f.params.len() == 2)
+        {
+            return Err(format!(
+                "The rule script {name} does not define the function {VALIDATION_FUNCTION_NAME}(state, input)!"
+            ));
+        }
+        Ok(Self {
+            name,
+            situation_card_id,
+            engine,
+            ast,
+        })
+    }
+
+    /// Returns `true` if the rule should be checked for the given game.
+    pub fn is_used_in_game(&self, game: &GameState) -> bool {
+        self.situation_card_id
+            .is_none_or(|id| game.situation_card.as_ref().is_some_and(|card| card.card_id == id))
+    }
+
+    /// Runs the script on the given game and input. Returns the reason if the input is not valid.
+    pub fn check(&self, game: &GameState, player_input: &PlayerInput) -> Option<ErrorData> {
+        let state = match to_dynamic(game) {
+            Ok(s) => s,
+            Err(e) => return Some(format!("Failed to give the game state to the rule script {} because: {e}", self.name)),
+        };
+        let input = match to_dynamic(player_input) {
+            Ok(i) => i,
+            Err(e) => return Some(format!("Failed to give the player input to the rule script {} because: {e}", self.name)),
+        };
+
+        let result = self.engine.call_fn::<Dynamic>(
+            &mut Scope::new(),
+            &self.ast,
+            VALIDATION_FUNCTION_NAME,
+            (state, input),
+        );
+
+        match result {
+            Ok(value) => self.interpret_result(value),
+            Err(e) => Some(format!("The rule script {} failed and the input can therefore not be accepted. Error: {e}", self.name)),
+        }
+    }
+
+    fn interpret_result(&self, value: Dynamic) -> Option<ErrorData> {
+        if value.is_unit() {
+            return None;
+        }
+        if let Some(is_valid) = value.clone().try_cast::<bool>() {
+            if is_valid {
+                return None;
+            }
+            return Some(format!("The input is not allowed by the rule {}!", self.name));
+        }
+        if value.is_string() {
+            return Some(value.to_string());
+        }
+        Some(format!("The rule script {} returned {} which is not a valid result!", self.name, value.type_name()))
+    }
+}
+
+/// Creates a script engine that can not access anything outside of the script, and has limits on how much resources a script can use.
+pub fn create_sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(MAX_SCRIPT_OPERATIONS)
+        .set_max_call_levels(MAX_SCRIPT_CALL_LEVELS)
+        .set_max_expr_depths(MAX_SCRIPT_EXPRESSION_DEPTH, MAX_SCRIPT_FUNCTION_EXPRESSION_DEPTH)
+        .set_max_string_size(MAX_SCRIPT_STRING_SIZE)
+        .set_max_array_size(MAX_SCRIPT_COLLECTION_SIZE)
+        .set_max_map_size(MAX_SCRIPT_COLLECTION_SIZE)
+        .set_module_resolver(DummyModuleResolver::new())
+        .on_print(|_| ())
+        .on_debug(|_, _, _| ());
+    engine.disable_symbol("eval");
+    engine
+}
+
+/// Loads all the rule scripts in the given folder.
+///
+/// Scripts placed directly in the folder are used for every scenario, while scripts placed in a sub folder named after a situation card ID are only used for that scenario.
+/// The name of the rule is the file name without the extension. If the folder does not exist, no rules are loaded.
+pub fn load_script_rules_from_folder(folder: &Path) -> Result<Vec<ScriptRule>, String> {
+    let mut script_rules = Vec::new();
+    if !folder.is_dir() {
+        return Ok(script_rules);
+    }
+
+    let engine = Arc::new(create_sandboxed_engine());
+    let entries = match fs::read_dir(folder) {
+        Ok(entries) => entries,
+        Err(e) => return Err(format!("Failed to read the rule script folder {} because: {e}", folder.display())),
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            let folder_name = entry.file_name().to_string_lossy().to_string();
+            let Ok(situation_card_id) = folder_name.parse::<SituationCardID>() else {
+                return Err(format!("The rule script folder {folder_name} is not named after a situation card ID!"));
+            };
+            let Ok(sub_entries) = fs::read_dir(&path) else {
+                return Err(format!("Failed to read the rule script folder {}!", path.display()));
+            };
+            for sub_entry in sub_entries.flatten() {
+                if let Some(rule) = load_script_rule(&sub_entry.path(), Some(situation_card_id), engine.clone())? {
+                    script_rules.push(rule);
+                }
+            }
+            continue;
+        }
+        if let Some(rule) = load_script_rule(&path, None, engine.clone())? {
+            script_rules.push(rule);
+        }
+    }
+    Ok(script_rules)
+}
+
+/// Compiles the rule scripts of a content pack, given as pairs of their path in the `rule_scripts` folder of the pack and the script.
+///
+/// Like in the rule script folder, scripts in a sub folder named after a situation card ID are only used for that scenario. Returns an error if any of the scripts could not be compiled.
+pub fn script_rules_from_sources(rule_scripts: &[(String, String)]) -> Result<Vec<ScriptRule>, String> {
+    let engine = Arc::new(create_sandboxed_engine());
+    let mut script_rules = Vec::new();
+    for (path, script) in rule_scripts {
+        let path = Path::new(path);
+        let Some(name) = path.file_stem().map(|stem| stem.to_string_lossy().to_string()) else {
+            continue;
+        };
+        let situation_card_id = match path.parent().map(|parent| parent.to_string_lossy().to_string()).filter(|parent| !parent.is_empty()) {
+            Some(folder_name) => match folder_name.parse::<SituationCardID>() {
+                Ok(id) => Some(id),
+                Err(_) => return Err(format!("The rule script folder {folder_name} is not named after a situation card ID!")),
+            },
+            None => None,
+        };
+        script_rules.push(ScriptRule::new(name, situation_card_id, script, engine.clone())?);
+    }
+    Ok(script_rules)
+}
+
+fn load_script_rule(
+    path: &Path,
+    situation_card_id: Option<SituationCardID>,
+    engine: Arc<Engine>,
+) -> Result<Option<ScriptRule>, String> {
+    if path.extension().is_none_or(|extension| extension != RULE_SCRIPT_EXTENSION) {
+        return Ok(None);
+    }
+    let Some(name) = path.file_stem().map(|stem| stem.to_string_lossy().to_string()) else {
+        return Ok(None);
+    };
+    let script = match fs::read_to_string(path) {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Failed to read the rule script {} because: {e}", path.display())),
+    };
+    ScriptRule::new(name, situation_card_id, &script, engine).map(Some)
+}
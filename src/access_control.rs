@@ -0,0 +1,71 @@
+use actix_web::{dev::ServiceRequest, http::header::AUTHORIZATION, HttpRequest, HttpResponse};
+use game_core::{game_controller::GameController, game_data::enums::access_level::AccessLevel};
+
+use crate::{http_replicator::SentSecret, response_envelope::{ApiErrorKind, RequestInfo}};
+
+/// The prefix of the value of the `Authorization` header, before the access token.
+const BEARER_PREFIX: &str = "Bearer ";
+/// The start of the paths of the admin endpoints, which always need the admin token of the server.
+const ADMIN_PATH_PREFIX: &str = "/admin/";
+
+/// Returns the access level needed to use the endpoint with the given path, or `None` if the endpoint can be used without a token.
+///
+/// The replication endpoints need the admin level. The replication secret of the server is added as an admin token, so the primary server can send its entries with it.
+pub fn required_access_level(path: &str) -> Option<AccessLevel> {
+    if path.starts_with(ADMIN_PATH_PREFIX) || path.starts_with("/replication/") {
+        Some(AccessLevel::Admin)
+    } else if path == "/games/compare" || path == "/games/overview" || path.starts_with("/objectivedecks") || path.ends_with("/summary") || path.ends_with("/export") || path.ends_with("/audit") || path.ends_with("/fork") || path.contains("/replay") {
+        Some(AccessLevel::Facilitator)
+    } else {
+        Some(AccessLevel::Participant)
+    }
+}
+
+/// Returns the access token in the `Authorization` header of the request, if it's set.
+pub fn access_token_of(request: &HttpRequest) -> Option<String> {
+    request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        .map(|token| token.trim().to_string())
+}
+
+/// Checks that the request is sent with a token that has the access level the endpoint needs. Returns the access level of the token, or `None` if access control is disabled or the endpoint doesn't need a token.
+/// If the token is missing, unknown or doesn't have the needed access level, the response the request should be rejected with is returned.
+///
+/// The admin endpoints are checked even if access control is disabled, see [`authorize_admin`].
+pub fn authorize(service_request: &ServiceRequest, game_controller: &GameController, admin_token: Option<&str>) -> Result<Option<AccessLevel>, HttpResponse> {
+    if service_request.path().starts_with(ADMIN_PATH_PREFIX) {
+        authorize_admin(service_request, game_controller, admin_token)?;
+    }
+    if !game_controller.access_tokens.is_enabled() {
+        return Ok(None);
+    }
+    let Some(required_level) = required_access_level(service_request.path()) else {
+        return Ok(None);
+    };
+    let request_info = RequestInfo::new(service_request.request());
+    let Some(token) = access_token_of(service_request.request()) else {
+        return Err(request_info.error(ApiErrorKind::Unauthorized, "The request has to be sent with an access token in the Authorization header"));
+    };
+    match game_controller.access_tokens.level_of(&token) {
+        None => Err(request_info.error(ApiErrorKind::Unauthorized, "The access token is not valid")),
+        Some(level) if level < required_level => Err(request_info.error(ApiErrorKind::Forbidden, format!("The access token has the {:?} access level, but the {:?} access level is needed", level, required_level))),
+        Some(level) => Ok(Some(level)),
+    }
+}
+
+/// Checks a request to an admin endpoint, which can change or look into the whole server and must never be open, like in demo mode where the access tokens are not used.
+/// The admin endpoints are disabled (and respond as if they do not exist) if the server has no admin token. If access control is disabled, the request has to be sent with the admin token itself,
+/// and if it's enabled the request is checked like any other request to an endpoint that needs the admin access level.
+fn authorize_admin(service_request: &ServiceRequest, game_controller: &GameController, admin_token: Option<&str>) -> Result<(), HttpResponse> {
+    let request_info = RequestInfo::new(service_request.request());
+    let Some(admin_token) = admin_token else {
+        return Err(request_info.error(ApiErrorKind::NotFound, "The admin endpoints are disabled because the server has no admin token"));
+    };
+    if !game_controller.access_tokens.is_enabled() && !SentSecret::of(service_request.request()).matches(admin_token) {
+        return Err(request_info.error(ApiErrorKind::Unauthorized, "The admin endpoints have to be called with the admin token of the server in the Authorization header"));
+    }
+    Ok(())
+}
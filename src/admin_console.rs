@@ -0,0 +1,81 @@
+use std::{io::{self, BufRead}, thread};
+
+use actix_web::web;
+use game_core::game_data::{custom_types::{GameID, PlayerID}, structs::game_overview::GameOverview};
+use logging::{logger::LogLevel, multi_logger::LogTarget};
+
+use crate::AppData;
+
+/// The commands of the console and what they do, shown by the `help` command.
+const CONSOLE_HELP: &str = "Commands:
+  games                 lists all the games
+  player <player id>    shows the player with the given id
+  advance <game id>     ends the current turn of the game without checking the rules
+  log                   shows where the log entries are written and the lowest log level written there
+  log <target> <level>  changes the lowest log level written to the target (console, file, json or remote), where ignore stops writing to it
+  help                  shows this help";
+
+/// Starts reading admin commands from the standard input of the server on a separate thread, so that operators can look at and manage the games without HTTP tooling. The answer to every command is printed.
+pub fn start_admin_console(app_data: web::Data<AppData>) {
+    thread::spawn(move || {
+        println!("The admin console is ready, type help to see the commands.");
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            match run_command(&line, &app_data) {
+                Ok(answer) => println!("{answer}"),
+                Err(e) => println!("Error: {e}"),
+            }
+        }
+    });
+}
+
+/// Runs one console command and returns what should be printed. Will return an error if the command is not known or could not be done.
+fn run_command(line: &str, app_data: &AppData) -> Result<String, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        ["help"] => Ok(CONSOLE_HELP.to_string()),
+        ["games"] => {
+            let games = app_data.lock_game_controller().get_created_games();
+            if games.is_empty() {
+                return Ok("There are no games.".to_string());
+            }
+            Ok(games
+                .iter()
+                .map(|game| {
+                    let overview = GameOverview::new(game, None);
+                    format!("{} {:?}: {:?}, turn {}, round {}, {} players, the turn of {:?}", overview.game_id, overview.name, overview.phase, overview.turn_number, overview.round_number, overview.player_count, overview.current_players_turn)
+                })
+                .collect::<Vec<String>>()
+                .join("\n"))
+        }
+        ["player", player_id] => {
+            let player_id = player_id.parse::<PlayerID>().map_err(|_| format!("{player_id} is not a player id!"))?;
+            let player = app_data.lock_game_controller().get_player(player_id)?;
+            serde_json::to_string_pretty(&player).map_err(|e| format!("Failed to serialize the player because: {e}"))
+        }
+        ["advance", game_id] => {
+            let game_id = game_id.parse::<GameID>().map_err(|_| format!("{game_id} is not a game id!"))?;
+            let game = app_data.lock_game_controller().force_next_turn(game_id)?;
+            Ok(format!("The game with id {} is now in turn {} with the turn of {:?}.", game.id, game.turn_number, game.current_players_turn))
+        }
+        ["log"] => {
+            let logger = app_data.lock_multi_logger();
+            if logger.sinks().is_empty() {
+                return Ok("Nothing is logged.".to_string());
+            }
+            Ok(format!("Logging to {}.", logger.sinks().iter().map(ToString::to_string).collect::<Vec<String>>().join(", ")))
+        }
+        ["log", target, level] => {
+            let target = target.parse::<LogTarget>()?;
+            let level = level.parse::<LogLevel>()?;
+            app_data.lock_multi_logger().set_min_level(target, level)?;
+            Ok(format!("Changed the lowest log level written to {target:?} to {level:?}."))
+        }
+        _ => Err(format!("Unknown command {line:?}, type help to see the commands.")),
+    }
+}
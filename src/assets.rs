@@ -0,0 +1,137 @@
+use std::{collections::HashMap, convert::Infallible, fs, future::{ready, Ready}, path::Path};
+
+use actix_web::{dev::Payload, http::header::IF_NONE_MATCH, web::Bytes, FromRequest, HttpRequest};
+use serde::Serialize;
+
+/// How long (in seconds) the clients may cache an asset before asking the server if it has changed.
+pub const ASSET_CACHE_MAX_AGE: u32 = 24 * 60 * 60;
+/// The offset basis and prime of the 64-bit FNV-1a hash used for the ETags of the assets.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// The AssetInfo struct describes an image the server can send to the clients. It's what the asset manifest is made of.
+#[derive(Clone, Serialize, Debug)]
+pub struct AssetInfo {
+    /// The id the maps and cards refer to the asset with, which is the name of the file without the extension.
+    pub id: String,
+    pub file_name: String,
+    pub content_type: String,
+    /// The size of the file in bytes.
+    pub size: usize,
+    /// The ETag of the asset, which changes if the file is changed.
+    pub etag: String,
+}
+
+/// The AssetLibrary struct contains the images (like map images and card artwork) the server serves to the clients.
+///
+/// The images are read into memory when the server starts, so a file that is changed later is not served until the server is restarted.
+#[derive(Clone, Default, Debug)]
+pub struct AssetLibrary {
+    assets: HashMap<String, (AssetInfo, Bytes)>,
+    issues: Vec<String>,
+}
+
+impl AssetLibrary {
+    /// Loads every image in the folder. Files that are not images, or have the same id as another image, are left out and described in the issues.
+    /// Returns an error if the folder could not be read. If the folder does not exist the library is empty.
+    pub fn load_from_folder(folder: &Path) -> Result<Self, String> {
+        let mut library = Self::default();
+        if !folder.is_dir() {
+            return Ok(library);
+        }
+
+        let entries = fs::read_dir(folder).map_err(|e| format!("Failed to read the asset folder {} because: {e}", folder.display()))?;
+        let mut paths: Vec<_> = entries.flatten().map(|entry| entry.path()).filter(|path| path.is_file()).collect();
+        paths.sort();
+
+        for path in paths {
+            let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let Some(content_type) = path.extension().and_then(|extension| content_type_of(&extension.to_string_lossy())) else {
+                library.issues.push(format!("The asset {file_name} is not a png, jpg, webp, gif or svg image"));
+                continue;
+            };
+            let Some(id) = path.file_stem().map(|stem| stem.to_string_lossy().to_string()) else {
+                continue;
+            };
+            if let Some((existing_asset, _)) = library.assets.get(&id) {
+                library.issues.push(format!("The asset {file_name} has the same id as {}", existing_asset.file_name));
+                continue;
+            }
+            let content = match fs::read(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    library.issues.push(format!("The asset {file_name} could not be read because: {e}"));
+                    continue;
+                }
+            };
+            let hash = content.iter().fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME));
+            let info = AssetInfo {
+                id: id.clone(),
+                file_name,
+                content_type: content_type.to_string(),
+                size: content.len(),
+                etag: format!("\"{hash:016x}\""),
+            };
+            library.assets.insert(id, (info, Bytes::from(content)));
+        }
+        Ok(library)
+    }
+
+    /// Returns the asset manifest, which describes every asset sorted by id.
+    pub fn manifest(&self) -> Vec<AssetInfo> {
+        let mut manifest: Vec<AssetInfo> = self.assets.values().map(|(info, _)| info.clone()).collect();
+        manifest.sort_by(|first, second| first.id.cmp(&second.id));
+        manifest
+    }
+
+    /// Returns the description and the content of the asset with the given id, if there is one.
+    pub fn get_asset(&self, id: &str) -> Option<(&AssetInfo, Bytes)> {
+        self.assets.get(id).map(|(info, content)| (info, content.clone()))
+    }
+
+    /// Returns `true` if there is an asset with the given id.
+    pub fn contains(&self, id: &str) -> bool {
+        self.assets.contains_key(id)
+    }
+
+    /// Returns the issues of the files that were left out when the assets were loaded.
+    pub fn issues(&self) -> &[String] {
+        &self.issues
+    }
+}
+
+/// The CachedEtags struct is the ETags a request was sent with in the `If-None-Match` header, which are the versions of an asset the client already has.
+pub struct CachedEtags(Vec<String>);
+
+impl CachedEtags {
+    /// Returns `true` if the client already has the version of the asset with the given ETag.
+    pub fn contains(&self, etag: &str) -> bool {
+        self.0.iter().any(|cached_etag| cached_etag == etag || cached_etag == "*")
+    }
+}
+
+impl FromRequest for CachedEtags {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let etags = request
+            .headers()
+            .get(IF_NONE_MATCH)
+            .and_then(|value| value.to_str().ok())
+            .map(|etags| etags.split(',').map(|etag| etag.trim().to_string()).collect())
+            .unwrap_or_default();
+        ready(Ok(Self(etags)))
+    }
+}
+
+fn content_type_of(extension: &str) -> Option<&'static str> {
+    match extension.to_ascii_lowercase().as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "webp" => Some("image/webp"),
+        "gif" => Some("image/gif"),
+        "svg" => Some("image/svg+xml"),
+        _ => None,
+    }
+}
@@ -0,0 +1,86 @@
+use std::{sync::mpsc::{self, Sender}, thread, future::{ready, Ready}, convert::Infallible};
+
+use actix_web::{dev::Payload, http::header::AUTHORIZATION, FromRequest, HttpRequest};
+use game_core::replication::{ReplicationEntry, StateReplicator};
+use logging::{log_sender::LogSender, logger::{LogData, LogLevel}};
+
+use crate::access_control::access_token_of;
+
+/// The path (on the standby server) that the replication entries are posted to.
+pub const REPLICATION_ENTRY_PATH: &str = "/replication/entry";
+/// The prefix of the value of the `Authorization` header, before the replication secret.
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// The HttpReplicator sends every [`ReplicationEntry`] to a standby server over HTTP.
+///
+/// The entries are sent in order by a background thread, so the game controller is never blocked by a slow or missing standby server.
+/// If an entry could not be sent it is logged and dropped; the standby server should then be considered out of date.
+/// The entries are sent with the replication secret in the `Authorization` header, which the standby server checks with [`SentSecret`].
+///
+/// [`ReplicationEntry`]: ../../game_core/replication/enum.ReplicationEntry.html
+pub struct HttpReplicator {
+    sender: Sender<ReplicationEntry>,
+}
+
+impl HttpReplicator {
+    /// Creates a new HttpReplicator that sends the entries to the standby server at the given url (for example `http://127.0.0.1:5001`), with the secret the standby server shares with this server.
+    #[must_use]
+    pub fn new(standby_url: &str, secret: &str, logger: LogSender) -> Self {
+        let (sender, receiver) = mpsc::channel::<ReplicationEntry>();
+        let endpoint = format!("{}{}", standby_url.trim_end_matches('/'), REPLICATION_ENTRY_PATH);
+        let authorization = format!("{BEARER_PREFIX}{secret}");
+        thread::spawn(move || {
+            let agent = ureq::Agent::new();
+            for entry in receiver {
+                let result = serde_json::to_string(&entry)
+                    .map_err(|e| e.to_string())
+                    .and_then(|body| {
+                        agent
+                            .post(&endpoint)
+                            .set("Content-Type", "application/json")
+                            .set(AUTHORIZATION.as_str(), &authorization)
+                            .send_string(&body)
+                            .map_err(|e| e.to_string())
+                    });
+                if let Err(e) = result {
+                    logger.log(LogData::new(LogLevel::Error, format!("Failed to send replication entry to {endpoint} because: {e}").as_str(), "HttpReplicator"));
+                }
+            }
+        });
+        Self { sender }
+    }
+}
+
+impl StateReplicator for HttpReplicator {
+    fn replicate(&self, entry: ReplicationEntry) {
+        // The receiver is only dropped if the background thread has panicked, in which case there is nothing more to do.
+        let _ = self.sender.send(entry);
+    }
+}
+
+/// The SentSecret struct is the replication secret a request was sent with in the `Authorization` header, if any.
+pub struct SentSecret(Option<String>);
+
+impl SentSecret {
+    /// Returns the secret the request was sent with in the `Authorization` header.
+    pub fn of(request: &HttpRequest) -> Self {
+        Self(access_token_of(request))
+    }
+
+    /// Returns `true` if the request was sent with the given secret.
+    /// The secrets are compared in constant time, so the time it takes to reject a request does not tell how much of the secret was right.
+    pub fn matches(&self, secret: &str) -> bool {
+        self.0.as_deref().is_some_and(|sent_secret| {
+            sent_secret.len() == secret.len() && sent_secret.bytes().zip(secret.bytes()).fold(0, |difference, (sent, expected)| difference | (sent ^ expected)) == 0
+        })
+    }
+}
+
+impl FromRequest for SentSecret {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Ok(Self::of(request)))
+    }
+}
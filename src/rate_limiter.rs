@@ -0,0 +1,35 @@
+use std::{collections::HashMap, net::IpAddr, time::{Duration, Instant}};
+
+/// How long each client's requests are counted before the count starts over.
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+/// When more clients than this are tracked, the clients whose window is over are forgotten.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// The RateLimiter struct limits how many requests each client, by IP address, can send per minute, so that a server open on the internet can not be flooded by one client.
+#[derive(Debug)]
+pub struct RateLimiter {
+    max_requests_per_minute: u32,
+    /// When the current window of each client started and how many requests the client has sent in it.
+    clients: HashMap<IpAddr, (Instant, u32)>,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests_per_minute: u32) -> Self {
+        Self { max_requests_per_minute, clients: HashMap::new() }
+    }
+
+    /// Counts a request from the given address and returns `true` if the client is still within the limit.
+    pub fn allow(&mut self, address: IpAddr) -> bool {
+        let now = Instant::now();
+        if self.clients.len() > MAX_TRACKED_CLIENTS {
+            self.clients.retain(|_, (window_start, _)| now.duration_since(*window_start) < RATE_LIMIT_WINDOW);
+        }
+        let (window_start, requests) = self.clients.entry(address).or_insert((now, 0));
+        if now.duration_since(*window_start) >= RATE_LIMIT_WINDOW {
+            *window_start = now;
+            *requests = 0;
+        }
+        *requests = requests.saturating_add(1);
+        *requests <= self.max_requests_per_minute
+    }
+}
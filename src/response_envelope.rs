@@ -0,0 +1,157 @@
+use std::{future::{ready, Ready}, convert::Infallible, time::Instant};
+
+use actix_web::{dev::Payload, error::{InternalError, JsonPayloadError}, http::StatusCode, FromRequest, HttpMessage, HttpRequest, HttpResponse};
+use game_core::{game_data::{custom_types::{StateHash, StateVersion}, enums::access_level::AccessLevel, structs::gamestate::GameState}, wire_codes};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The header a client can set on a request to get the same id back in the [`ResponseEnvelope`], so that the logs of the client and the server can be matched.
+pub const REQUEST_ID_HEADER: &str = "X-Request-ID";
+/// The header a client can set to [`COMPACT_ENUM_FORMAT`] to get the enums with wire codes (like `PlayerInputType` and `InGameID`) in the result as their codes instead of their names.
+pub const ENUM_FORMAT_HEADER: &str = "X-Enum-Format";
+/// The value of the [`ENUM_FORMAT_HEADER`] that asks for the wire codes.
+pub const COMPACT_ENUM_FORMAT: &str = "codes";
+
+/// What kind of error a request failed with. Decides the status code of the response.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ApiErrorKind {
+    /// The request could not be read, for example because the JSON body is not valid.
+    BadRequest,
+    /// The request was sent without a valid access token while access control is enabled, or without the replication secret of the server.
+    Unauthorized,
+    /// The access token does not have the access level the endpoint needs, or the confirm token of an admin operation is not correct.
+    Forbidden,
+    /// The wanted resource does not exist, or the operation is disabled on this server.
+    NotFound,
+    /// The server is not in a state where it can do the operation, for example a failover on a server that is not a standby.
+    Conflict,
+    /// The server is a standby server and does not handle players until a failover has been done.
+    Standby,
+    /// The client has sent more requests than the server allows per minute.
+    TooManyRequests,
+    /// Anything else, including inputs that break the rules of the game.
+    ServerError,
+}
+
+impl ApiErrorKind {
+    const fn status_code(self) -> StatusCode {
+        match self {
+            Self::BadRequest => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::Conflict => StatusCode::CONFLICT,
+            Self::Standby => StatusCode::SERVICE_UNAVAILABLE,
+            Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            Self::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiError {
+    pub kind: ApiErrorKind,
+    pub message: String,
+}
+
+/// The ResponseEnvelope struct is what every endpoint responds with. Exactly one of `result` and `error` is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseEnvelope {
+    pub result: Option<Value>,
+    pub error: Option<ApiError>,
+    pub server_version: String,
+    /// How long the server used on the request, in milliseconds.
+    pub processing_time_ms: u64,
+    /// The version of the game in the result, if the result is a game.
+    pub game_version: Option<StateVersion>,
+    /// The state hash of the game in the result, if the result is a game. See `GameState::state_hash`.
+    pub state_hash: Option<StateHash>,
+    /// The value of the [`REQUEST_ID_HEADER`] of the request, if it was set.
+    pub request_id: Option<String>,
+}
+
+/// The RequestInfo struct is extracted by every endpoint to build the [`ResponseEnvelope`] of the response.
+pub struct RequestInfo {
+    request_id: Option<String>,
+    received: Instant,
+    access_level: Option<AccessLevel>,
+    /// If the enums with wire codes are serialized as their codes in the result, see [`ENUM_FORMAT_HEADER`].
+    compact_enum_codes: bool,
+}
+
+impl RequestInfo {
+    pub(crate) fn new(request: &HttpRequest) -> Self {
+        Self {
+            request_id: request
+                .headers()
+                .get(REQUEST_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string),
+            received: Instant::now(),
+            access_level: request.extensions().get::<AccessLevel>().copied(),
+            compact_enum_codes: request
+                .headers()
+                .get(ENUM_FORMAT_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.trim().eq_ignore_ascii_case(COMPACT_ENUM_FORMAT)),
+        }
+    }
+
+    /// The access level of the token the request was sent with, or `None` if access control is disabled.
+    pub const fn access_level(&self) -> Option<AccessLevel> {
+        self.access_level
+    }
+
+    /// Responds with the result.
+    pub fn ok(&self, result: impl Serialize) -> HttpResponse {
+        self.respond(StatusCode::OK, self.to_value(result), None, None)
+    }
+
+    /// Responds with the result, which is the given game or a view of it. The version and state hash of the game are added to the response.
+    pub fn ok_with_game(&self, result: impl Serialize, game: &GameState) -> HttpResponse {
+        self.respond(StatusCode::OK, self.to_value(result), None, Some(game))
+    }
+
+    /// Serializes the result, with the wire codes of the enums if the client asked for them. The state hash is always made from the names.
+    fn to_value(&self, result: impl Serialize) -> Option<Value> {
+        if self.compact_enum_codes {
+            wire_codes::with_compact_codes(|| serde_json::to_value(result).ok())
+        } else {
+            serde_json::to_value(result).ok()
+        }
+    }
+
+    /// Responds with an error of the given kind.
+    pub fn error(&self, kind: ApiErrorKind, message: impl Into<String>) -> HttpResponse {
+        let error = ApiError { kind, message: message.into() };
+        self.respond(kind.status_code(), None, Some(error), None)
+    }
+
+    fn respond(&self, status_code: StatusCode, result: Option<Value>, error: Option<ApiError>, game: Option<&GameState>) -> HttpResponse {
+        let processing_time_ms = u64::try_from(self.received.elapsed().as_millis()).unwrap_or(u64::MAX);
+        HttpResponse::build(status_code).json(ResponseEnvelope {
+            result,
+            error,
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            processing_time_ms,
+            game_version: game.map(|game| game.version),
+            state_hash: game.and_then(|game| game.state_hash().ok()),
+            request_id: self.request_id.clone(),
+        })
+    }
+}
+
+impl FromRequest for RequestInfo {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Ok(Self::new(request)))
+    }
+}
+
+/// Wraps the errors from reading a JSON body in a [`ResponseEnvelope`], so that the clients get the same kind of response when the body is not valid.
+pub fn json_error_handler(error: JsonPayloadError, request: &HttpRequest) -> actix_web::Error {
+    let response = RequestInfo::new(request).error(ApiErrorKind::BadRequest, format!("The body of the request is not valid because: {error}"));
+    InternalError::from_response(error, response).into()
+}
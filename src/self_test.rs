@@ -0,0 +1,121 @@
+use game_core::{game_controller::GameController, game_data::{custom_types::{GameID, NodeID, PlayerID, SituationCardID, StateHash}, enums::{in_game_id::InGameID, player_input_type::PlayerInputType, type_entities_to_transport::TypeEntitiesToTransport}, structs::{gamestate::GameState, new_game_info::NewGameInfo, objective_deck::ObjectiveDeck, player::Player, player_input::PlayerInput, player_objective_card::PlayerObjectiveCard}}, rule_checker::RuleChecker};
+use logging::log_sender::LogSender;
+
+/// The state hash the scripted game has to end with, see [`run_self_test`]. It has to be updated when the rules or the default map are changed on purpose.
+pub const EXPECTED_SELF_TEST_STATE_HASH: StateHash = 0x472f_17b1_382b_c797;
+
+/// The name of the objective deck the scripted game is dealt from. Every card in it is the same, so that the deal does not depend on chance.
+const SELF_TEST_DECK_NAME: &str = "Self test";
+/// The situation card the scripted game is played with.
+const SELF_TEST_SITUATION_CARD: SituationCardID = 1;
+/// The players of the scripted game, where the first one is the host.
+const SELF_TEST_PLAYER_NAMES: [&str; 3] = ["Self test host", "Self test bot 1", "Self test bot 2"];
+
+/// One input of the scripted game, done by the player with the index in [`SELF_TEST_PLAYER_NAMES`].
+#[derive(Clone, Copy, Debug)]
+enum ScriptedInput {
+    ChangeRole(usize, InGameID),
+    AssignSituationCard(usize),
+    StartGame(usize),
+    Move(usize, NodeID),
+    Pass(usize),
+    NextTurn(usize),
+}
+
+/// The inputs of the scripted game, in order. The game is played for two rounds, where both bots start at the Eastside Mart. In the first round one bot picks the packages up at the Beach while the other one heads into the city centre,
+/// and in the second round (where the cards are dealt again) the first bot picks the packages up and starts bringing them back while the other one passes after one move.
+const SELF_TEST_SCRIPT: [ScriptedInput; 21] = [
+    ScriptedInput::ChangeRole(0, InGameID::Orchestrator),
+    ScriptedInput::ChangeRole(1, InGameID::PlayerOne),
+    ScriptedInput::ChangeRole(2, InGameID::PlayerTwo),
+    ScriptedInput::AssignSituationCard(0),
+    ScriptedInput::StartGame(0),
+    ScriptedInput::NextTurn(0),
+    ScriptedInput::Move(1, NodeID(6)),
+    ScriptedInput::Move(1, NodeID(7)),
+    ScriptedInput::NextTurn(1),
+    ScriptedInput::Move(2, NodeID(12)),
+    ScriptedInput::Move(2, NodeID(11)),
+    ScriptedInput::NextTurn(2),
+    ScriptedInput::StartGame(0),
+    ScriptedInput::NextTurn(0),
+    ScriptedInput::Move(1, NodeID(6)),
+    ScriptedInput::Move(1, NodeID(7)),
+    ScriptedInput::Move(1, NodeID(6)),
+    ScriptedInput::NextTurn(1),
+    ScriptedInput::Move(2, NodeID(14)),
+    ScriptedInput::Pass(2),
+    ScriptedInput::NextTurn(2),
+];
+
+impl ScriptedInput {
+    fn to_player_input(self, player_ids: &[PlayerID], game_id: GameID) -> PlayerInput {
+        let (player_index, input_type) = match self {
+            Self::ChangeRole(player_index, _) => (player_index, PlayerInputType::ChangeRole),
+            Self::AssignSituationCard(player_index) => (player_index, PlayerInputType::AssignSituationCard),
+            Self::StartGame(player_index) => (player_index, PlayerInputType::StartGame),
+            Self::Move(player_index, _) => (player_index, PlayerInputType::Movement),
+            Self::Pass(player_index) => (player_index, PlayerInputType::Pass),
+            Self::NextTurn(player_index) => (player_index, PlayerInputType::NextTurn),
+        };
+        let mut input = PlayerInput::new(player_ids[player_index], game_id, input_type);
+        match self {
+            Self::ChangeRole(_, role) => input.related_role = Some(role),
+            Self::AssignSituationCard(_) => input.situation_card_id = Some(SELF_TEST_SITUATION_CARD),
+            Self::Move(_, node_id) => input.related_node_id = Some(node_id),
+            Self::StartGame(_) | Self::Pass(_) | Self::NextTurn(_) => (),
+        }
+        input
+    }
+}
+
+/// Plays the bundled scripted game through a new game controller with the given rule checker, and returns an error if any of the inputs is rejected or the game does not end with [`EXPECTED_SELF_TEST_STATE_HASH`].
+/// This catches changes to the rules or the default map that would change how the games are played before a workshop begins.
+///
+/// The ids of the game and the players are chosen at random, so they are replaced with fixed ids before the final state is hashed.
+pub fn run_self_test(rule_checker: Box<dyn RuleChecker + Send + Sync>, logger: LogSender) -> Result<StateHash, String> {
+    let mut game_controller = GameController::new(logger, rule_checker);
+    let objective_card = PlayerObjectiveCard::new("Self test packages".to_string(), NodeID(13), NodeID(7), NodeID(15), Vec::new(), TypeEntitiesToTransport::Packages, 5);
+    let deck = ObjectiveDeck { name: SELF_TEST_DECK_NAME.to_string(), description: String::new(), cards: vec![objective_card.clone(), objective_card] };
+    let deck_report = game_controller.upload_objective_deck(deck)?;
+    if !deck_report.is_valid() {
+        return Err(format!("The objective deck of the self test is not valid because: {}", deck_report.issues.join(" ")));
+    }
+
+    let mut player_ids = Vec::new();
+    for _ in SELF_TEST_PLAYER_NAMES {
+        player_ids.push(game_controller.generate_player_id().map_err(str::to_string)?);
+    }
+    let host = Player::new(player_ids[0], SELF_TEST_PLAYER_NAMES[0].to_string());
+    let game = game_controller.create_new_game(NewGameInfo { host, name: "Self test".to_string(), objective_deck: Some(SELF_TEST_DECK_NAME.to_string()), campaign: Vec::new(), map: None, score_weights: None })?;
+    for (player_id, name) in player_ids.iter().zip(SELF_TEST_PLAYER_NAMES).skip(1) {
+        game_controller.join_game(game.id, Player::new(*player_id, name.to_string()))?;
+    }
+
+    let mut final_state = game;
+    for (step, scripted_input) in SELF_TEST_SCRIPT.iter().enumerate() {
+        final_state = game_controller
+            .handle_player_input(scripted_input.to_player_input(&player_ids, final_state.id))
+            .map_err(|e| format!("The input {:?} (step {}) of the self test was rejected: {}", scripted_input, step + 1, e))?;
+    }
+
+    let state_hash = normalized_state_hash(&final_state, &player_ids)?;
+    if state_hash != EXPECTED_SELF_TEST_STATE_HASH {
+        return Err(format!("The self test ended with the state hash {:016x}, but {:016x} was expected. The rules or the default map have changed how the game is played.", state_hash, EXPECTED_SELF_TEST_STATE_HASH));
+    }
+    Ok(state_hash)
+}
+
+/// Returns the state hash of the game after replacing the id of the game with 0 and the ids of the players with their position in the given list, starting at 1.
+fn normalized_state_hash(game: &GameState, player_ids: &[PlayerID]) -> Result<StateHash, String> {
+    let normalized_id = |player_id: PlayerID| player_ids.iter().position(|id| *id == player_id).map_or(PlayerID(0), |index| PlayerID(index as i32 + 1));
+    let mut game = game.clone();
+    game.id = GameID(0);
+    for player in game.players.iter_mut() {
+        player.unique_id = normalized_id(player.unique_id);
+        player.connected_game_id = player.connected_game_id.map(|_| GameID(0));
+        player.ride_offered_to = player.ride_offered_to.map(normalized_id);
+        player.riding_with = player.riding_with.map(normalized_id);
+    }
+    game.state_hash()
+}
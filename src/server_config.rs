@@ -0,0 +1,256 @@
+use std::{env, fs::{self, File}, io::BufReader, net::{IpAddr, Ipv4Addr, SocketAddr}, path::{Path, PathBuf}};
+
+use logging::{multi_logger::LogSink, remote_logger::LogCollector};
+use rustls_pemfile::Item;
+use game_core::game_data::structs::rule_set::RuleSet;
+use serde::Deserialize;
+use storage::persistence_policy::PersistencePolicy;
+
+/// The file (relative to where the server is started) the server config is read from if [`CONFIG_FILE_ENV`] is not set. The defaults are used if the file does not exist.
+pub const DEFAULT_CONFIG_FILE: &str = "server_config.json";
+/// The environment variable with the path of the server config file.
+pub const CONFIG_FILE_ENV: &str = "BOARDGAME_CONFIG";
+/// The environment variable with a comma separated list of the addresses to listen on, like `0.0.0.0,::`. Overrides the addresses in the config file.
+pub const BIND_ADDRESSES_ENV: &str = "BOARDGAME_BIND_ADDRESSES";
+/// The environment variable with a comma separated list of the ports to listen on. Overrides the ports in the config file.
+pub const SERVER_PORT_ENV: &str = "BOARDGAME_PORT";
+/// The environment variable with a comma separated list of words the names and other texts from the players can not contain. Overrides the words in the config file.
+pub const BLOCKED_WORDS_ENV: &str = "BOARDGAME_BLOCKED_WORDS";
+/// The environment variable that, if set to `1` or `true`, runs the server in demo mode. Overrides `demo_mode` in the config file.
+pub const DEMO_MODE_ENV: &str = "BOARDGAME_DEMO_MODE";
+/// The environment variable with the most requests a client can send per minute. Overrides `rate_limit_per_minute` in the config file.
+pub const RATE_LIMIT_ENV: &str = "BOARDGAME_RATE_LIMIT";
+/// The environment variable with a comma separated list of log sinks, like `console:info,json:warning`. Overrides `log_sinks` in the config file.
+pub const LOG_SINKS_ENV: &str = "BOARDGAME_LOG_SINKS";
+/// The environment variable with the address of the remote log collector, like `udp://logs.example.org:5140`. Overrides `log_collector` in the config file.
+pub const LOG_COLLECTOR_ENV: &str = "BOARDGAME_LOG_COLLECTOR";
+/// The environment variable with the path of the PEM file with the TLS certificate chain. Has to be set together with [`TLS_KEY_ENV`].
+pub const TLS_CERT_ENV: &str = "BOARDGAME_TLS_CERT";
+/// The environment variable with the path of the PEM file with the private key of the TLS certificate. Has to be set together with [`TLS_CERT_ENV`].
+pub const TLS_KEY_ENV: &str = "BOARDGAME_TLS_KEY";
+/// The environment variable with how many milliseconds an input can take before it's logged as slow. Overrides `slow_input_threshold_ms` in the config file.
+pub const SLOW_INPUT_THRESHOLD_ENV: &str = "BOARDGAME_SLOW_INPUT_MS";
+/// The environment variable with when the changes of the games are saved, `turn_boundary` or `every_change`. Overrides `persistence_policy` in the config file.
+pub const PERSISTENCE_POLICY_ENV: &str = "BOARDGAME_PERSISTENCE_POLICY";
+/// The environment variable with the path of the JSON file with the rule set, see [`RuleSet`]. Overrides `rule_set_path` in the config file.
+pub const RULE_SET_ENV: &str = "BOARDGAME_RULE_SET";
+/// The environment variable with how many seconds a player can go without checking in before the player is removed. Overrides `player_timeout_seconds` in the config file.
+pub const PLAYER_TIMEOUT_ENV: &str = "BOARDGAME_PLAYER_TIMEOUT";
+const DEFAULT_BIND_ADDRESS: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+const DEFAULT_SERVER_PORT: u16 = 5000;
+/// The most requests a client can send per minute in demo mode if no other limit is set.
+const DEMO_RATE_LIMIT_PER_MINUTE: u32 = 120;
+/// How many milliseconds an input can take, from when it arrives until it's handled, before it's logged as slow if no other threshold is set.
+const DEFAULT_SLOW_INPUT_THRESHOLD_MS: u64 = 500;
+
+/// The TlsConfig struct contains the paths of the PEM files the server uses to serve HTTPS.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+/// The ServerConfig struct describes where the server listens and if it uses TLS.
+///
+/// It's read from a JSON file and the environment variables override what is in the file. The server listens on every combination of the addresses and the ports.
+/// Note that on most systems listening on `::` also listens on every IPv4 address, so it can't be combined with `0.0.0.0` on the same port.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ServerConfig {
+    #[serde(default = "default_bind_addresses")]
+    pub bind_addresses: Vec<IpAddr>,
+    #[serde(default = "default_ports")]
+    pub ports: Vec<u16>,
+    /// The server serves HTTPS instead of HTTP on all the addresses and ports if it's set.
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// The words the names and other texts from the players can not contain, ignoring the case.
+    #[serde(default)]
+    pub blocked_words: Vec<String>,
+    /// If true the server runs the public demo: access tokens are not used, only a few games can exist at the same time and inactive games are removed.
+    #[serde(default)]
+    pub demo_mode: bool,
+    /// The most requests a client (by IP address) can send per minute. There is no limit if it's not set, unless the server is in demo mode.
+    #[serde(default)]
+    pub rate_limit_per_minute: Option<u32>,
+    /// Where the log entries are written when the server starts, and the lowest severity level written to each place. Nothing is logged if it's empty. The sinks can be changed while the server runs with `/admin/logging`.
+    #[serde(default)]
+    pub log_sinks: Vec<LogSink>,
+    /// The address of the collector the `Remote` log sink ships the log entries to, like `http://logs.example.org:8080/ingest` or `udp://logs.example.org:5140`.
+    #[serde(default)]
+    pub log_collector: Option<String>,
+    /// How many milliseconds an input can take, including waiting for the other inputs, before a warning is logged. See `/admin/metrics` for how the inputs are doing.
+    #[serde(default = "default_slow_input_threshold_ms")]
+    pub slow_input_threshold_ms: u64,
+    /// When the changes of the games are saved to the storage backend, if the server has one. By default a game is saved every time a turn or round starts.
+    #[serde(default)]
+    pub persistence_policy: PersistencePolicy,
+    /// The JSON file with the rule set that says which of the built-in rules are checked and their parameters, for running house-rule variants. The default rules are used if it's not set.
+    #[serde(default)]
+    pub rule_set_path: Option<PathBuf>,
+    /// How many seconds a player can go without checking in before the player is removed from the game. The player and the orchestrator are warned when 75% of it has passed. The default timeout of 90 seconds is used if it's not set.
+    #[serde(default)]
+    pub player_timeout_seconds: Option<u64>,
+}
+
+fn default_bind_addresses() -> Vec<IpAddr> {
+    vec![DEFAULT_BIND_ADDRESS]
+}
+
+fn default_ports() -> Vec<u16> {
+    vec![DEFAULT_SERVER_PORT]
+}
+
+const fn default_slow_input_threshold_ms() -> u64 {
+    DEFAULT_SLOW_INPUT_THRESHOLD_MS
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_addresses: default_bind_addresses(),
+            ports: default_ports(),
+            tls: None,
+            blocked_words: Vec::new(),
+            demo_mode: false,
+            rate_limit_per_minute: None,
+            log_sinks: Vec::new(),
+            log_collector: None,
+            slow_input_threshold_ms: default_slow_input_threshold_ms(),
+            persistence_policy: PersistencePolicy::default(),
+            rule_set_path: None,
+            player_timeout_seconds: None,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Reads the config file given by [`CONFIG_FILE_ENV`] (or [`DEFAULT_CONFIG_FILE`] if it exists) and applies the environment variables on top of it.
+    pub fn load() -> Result<Self, String> {
+        let mut config = match env::var(CONFIG_FILE_ENV) {
+            Ok(path) => Self::from_file(Path::new(&path))?,
+            Err(_) if Path::new(DEFAULT_CONFIG_FILE).exists() => Self::from_file(Path::new(DEFAULT_CONFIG_FILE))?,
+            Err(_) => Self::default(),
+        };
+        if let Ok(addresses) = env::var(BIND_ADDRESSES_ENV) {
+            config.bind_addresses = parse_list(&addresses, BIND_ADDRESSES_ENV)?;
+        }
+        if let Ok(ports) = env::var(SERVER_PORT_ENV) {
+            config.ports = parse_list(&ports, SERVER_PORT_ENV)?;
+        }
+        if let Ok(blocked_words) = env::var(BLOCKED_WORDS_ENV) {
+            config.blocked_words = parse_list(&blocked_words, BLOCKED_WORDS_ENV)?;
+        }
+        if let Ok(demo_mode) = env::var(DEMO_MODE_ENV) {
+            config.demo_mode = demo_mode == "1" || demo_mode.eq_ignore_ascii_case("true");
+        }
+        if let Ok(rate_limit) = env::var(RATE_LIMIT_ENV) {
+            config.rate_limit_per_minute = Some(rate_limit.trim().parse().map_err(|_| format!("{rate_limit} in {RATE_LIMIT_ENV} is not valid"))?);
+        }
+        if let Ok(log_sinks) = env::var(LOG_SINKS_ENV) {
+            config.log_sinks = parse_list(&log_sinks, LOG_SINKS_ENV)?;
+        }
+        if let Ok(log_collector) = env::var(LOG_COLLECTOR_ENV) {
+            config.log_collector = Some(log_collector);
+        }
+        if let Ok(slow_input_threshold) = env::var(SLOW_INPUT_THRESHOLD_ENV) {
+            config.slow_input_threshold_ms = slow_input_threshold.trim().parse().map_err(|_| format!("{slow_input_threshold} in {SLOW_INPUT_THRESHOLD_ENV} is not valid"))?;
+        }
+        if let Ok(persistence_policy) = env::var(PERSISTENCE_POLICY_ENV) {
+            config.persistence_policy = persistence_policy.trim().parse()?;
+        }
+        if let Ok(rule_set_path) = env::var(RULE_SET_ENV) {
+            config.rule_set_path = Some(PathBuf::from(rule_set_path));
+        }
+        if let Ok(player_timeout) = env::var(PLAYER_TIMEOUT_ENV) {
+            config.player_timeout_seconds = Some(player_timeout.trim().parse().map_err(|_| format!("{player_timeout} in {PLAYER_TIMEOUT_ENV} is not valid"))?);
+        }
+        match (env::var(TLS_CERT_ENV), env::var(TLS_KEY_ENV)) {
+            (Ok(cert_path), Ok(key_path)) => config.tls = Some(TlsConfig { cert_path: PathBuf::from(cert_path), key_path: PathBuf::from(key_path) }),
+            (Err(_), Err(_)) => (),
+            _ => return Err(format!("{TLS_CERT_ENV} and {TLS_KEY_ENV} have to be set together")),
+        }
+        if config.bind_addresses.is_empty() || config.ports.is_empty() {
+            return Err("The server has to listen on at least one address and port".to_string());
+        }
+        if config.player_timeout_seconds == Some(0) {
+            return Err("The player timeout has to be at least 1 second".to_string());
+        }
+        Ok(config)
+    }
+
+    fn from_file(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("Could not read the config file {} because: {e}", path.display()))?;
+        serde_json::from_str(&content).map_err(|e| format!("The config file {} is not valid because: {e}", path.display()))
+    }
+
+    /// Returns the most requests a client can send per minute, or `None` if there is no limit.
+    pub const fn rate_limit(&self) -> Option<u32> {
+        match self.rate_limit_per_minute {
+            Some(rate_limit) => Some(rate_limit),
+            None if self.demo_mode => Some(DEMO_RATE_LIMIT_PER_MINUTE),
+            None => None,
+        }
+    }
+
+    /// Returns the remote log collector, if one is configured. Will return an error if the address of the collector is not valid.
+    pub fn log_collector(&self) -> Result<Option<LogCollector>, String> {
+        self.log_collector
+            .as_deref()
+            .map(|collector| collector.parse().map_err(|e| format!("The log collector is not valid because: {e}")))
+            .transpose()
+    }
+
+    /// Returns every combination of the addresses and ports the server should listen on.
+    pub fn socket_addresses(&self) -> Vec<SocketAddr> {
+        self.bind_addresses
+            .iter()
+            .flat_map(|address| self.ports.iter().map(|port| SocketAddr::new(*address, *port)))
+            .collect()
+    }
+
+    /// Loads the rule set, or returns the default rule set if none is configured. Will return an error if the rule set file can not be read or is not valid.
+    pub fn load_rule_set(&self) -> Result<RuleSet, String> {
+        self.rule_set_path.as_ref().map_or_else(|| Ok(RuleSet::default()), |path| RuleSet::from_file(path))
+    }
+
+    /// Loads the TLS certificate chain and private key, if TLS is configured.
+    pub fn load_tls(&self) -> Result<Option<rustls::ServerConfig>, String> {
+        let Some(tls) = &self.tls else {
+            return Ok(None);
+        };
+        let mut certificates = Vec::new();
+        for item in read_pem_file(&tls.cert_path)? {
+            if let Item::X509Certificate(certificate) = item {
+                certificates.push(rustls::Certificate(certificate));
+            }
+        }
+        if certificates.is_empty() {
+            return Err(format!("There are no certificates in {}", tls.cert_path.display()));
+        }
+        let Some(private_key) = read_pem_file(&tls.key_path)?.into_iter().find_map(|item| match item {
+            Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(rustls::PrivateKey(key)),
+            _ => None,
+        }) else {
+            return Err(format!("There is no private key in {}", tls.key_path.display()));
+        };
+        rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certificates, private_key)
+            .map(Some)
+            .map_err(|e| format!("Could not use the TLS certificate because: {e}"))
+    }
+}
+
+fn read_pem_file(path: &Path) -> Result<Vec<Item>, String> {
+    let file = File::open(path).map_err(|e| format!("Could not open {} because: {e}", path.display()))?;
+    rustls_pemfile::read_all(&mut BufReader::new(file)).map_err(|e| format!("Could not read {} because: {e}", path.display()))
+}
+
+fn parse_list<T: std::str::FromStr>(list: &str, variable: &str) -> Result<Vec<T>, String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<T>().map_err(|_| format!("{value} in {variable} is not valid")))
+        .collect()
+}
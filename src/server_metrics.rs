@@ -0,0 +1,197 @@
+use std::{cmp::Reverse, collections::HashMap, sync::{Mutex, MutexGuard, PoisonError}, time::{Duration, Instant}};
+
+use game_core::game_data::custom_types::{GameID, PlayerID};
+use logging::{log_sender::LogSender, logger::{LogData, LogLevel}};
+use serde::Serialize;
+
+/// The ServerMetrics struct collects the performance signals of the server: how many inputs are queued for each game, how long the requests wait for the game controller,
+/// how late the background tasks are and how many inputs were slow. The inputs that take longer than the slow input threshold are logged as warnings.
+///
+/// It doesn't use the game controller, so the metrics can be read even while the game controller is busy.
+pub struct ServerMetrics {
+    slow_input_threshold: Duration,
+    logger: LogSender,
+    lock_waits: Mutex<LockWaitMetrics>,
+    queued_inputs: Mutex<QueuedInputs>,
+    inputs: Mutex<InputMetrics>,
+    background_tasks: Mutex<HashMap<&'static str, BackgroundTaskMetrics>>,
+}
+
+/// How long the requests have waited to lock the game controller since the server started.
+#[derive(Serialize, Clone, Default)]
+pub struct LockWaitMetrics {
+    pub acquisitions: u64,
+    pub total_wait_us: u64,
+    pub max_wait_us: u64,
+}
+
+/// The inputs that have arrived for each game and are not done yet, either because they wait for the game controller or because they're being handled.
+#[derive(Default)]
+struct QueuedInputs {
+    per_game: HashMap<GameID, usize>,
+    max_depth: usize,
+}
+
+/// How long the inputs have taken since the server started, from when they arrived until they were handled.
+#[derive(Serialize, Clone, Default)]
+pub struct InputMetrics {
+    pub handled: u64,
+    pub slow: u64,
+    pub max_duration_ms: u64,
+}
+
+/// How late a background task has been. The lag of a run is the time from when the task was due until it was done, which includes waiting for the game controller.
+#[derive(Serialize, Clone, Default)]
+pub struct BackgroundTaskMetrics {
+    pub runs: u64,
+    pub last_lag_ms: u64,
+    pub max_lag_ms: u64,
+}
+
+/// The GameQueueDepth struct describes how many inputs are queued for a game.
+#[derive(Serialize, Clone)]
+pub struct GameQueueDepth {
+    pub game_id: GameID,
+    pub queued_inputs: usize,
+}
+
+/// The BackgroundTaskReport struct describes how late a background task has been.
+#[derive(Serialize, Clone)]
+pub struct BackgroundTaskReport {
+    pub name: &'static str,
+    #[serde(flatten)]
+    pub metrics: BackgroundTaskMetrics,
+}
+
+/// The MetricsReport struct is what `/admin/metrics` returns.
+#[derive(Serialize, Clone)]
+pub struct MetricsReport {
+    /// The games that have inputs queued right now. Games without queued inputs are left out.
+    pub queued_inputs: Vec<GameQueueDepth>,
+    /// The most inputs that have been queued for one game at the same time since the server started.
+    pub max_queued_inputs: usize,
+    pub lock_waits: LockWaitMetrics,
+    pub inputs: InputMetrics,
+    pub slow_input_threshold_ms: u64,
+    pub background_tasks: Vec<BackgroundTaskReport>,
+}
+
+/// Keeps an input counted as queued for its game until it's dropped, and then records how long the input took.
+pub struct QueuedInput<'a> {
+    metrics: &'a ServerMetrics,
+    game_id: GameID,
+    player_id: PlayerID,
+    arrived: Instant,
+}
+
+impl Drop for QueuedInput<'_> {
+    fn drop(&mut self) {
+        self.metrics.finish_input(self.game_id, self.player_id, self.arrived.elapsed());
+    }
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl ServerMetrics {
+    pub fn new(slow_input_threshold: Duration, logger: LogSender) -> Self {
+        Self {
+            slow_input_threshold,
+            logger,
+            lock_waits: Mutex::new(LockWaitMetrics::default()),
+            queued_inputs: Mutex::new(QueuedInputs::default()),
+            inputs: Mutex::new(InputMetrics::default()),
+            background_tasks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records how long a request waited to lock the game controller.
+    pub fn record_lock_wait(&self, wait: Duration) {
+        let wait_us = u64::try_from(wait.as_micros()).unwrap_or(u64::MAX);
+        let mut lock_waits = lock(&self.lock_waits);
+        lock_waits.acquisitions += 1;
+        lock_waits.total_wait_us = lock_waits.total_wait_us.saturating_add(wait_us);
+        lock_waits.max_wait_us = lock_waits.max_wait_us.max(wait_us);
+    }
+
+    /// Counts an input that just arrived as queued for its game until the returned value is dropped.
+    pub fn queue_input(&self, game_id: GameID, player_id: PlayerID) -> QueuedInput<'_> {
+        let mut queued_inputs = lock(&self.queued_inputs);
+        let depth = queued_inputs.per_game.entry(game_id).or_default();
+        *depth += 1;
+        let depth = *depth;
+        queued_inputs.max_depth = queued_inputs.max_depth.max(depth);
+        QueuedInput { metrics: self, game_id, player_id, arrived: Instant::now() }
+    }
+
+    fn finish_input(&self, game_id: GameID, player_id: PlayerID, duration: Duration) {
+        let still_queued = {
+            let mut queued_inputs = lock(&self.queued_inputs);
+            let depth = queued_inputs.per_game.get(&game_id).copied().unwrap_or(1).saturating_sub(1);
+            if depth == 0 {
+                queued_inputs.per_game.remove(&game_id);
+            } else {
+                queued_inputs.per_game.insert(game_id, depth);
+            }
+            depth
+        };
+        let is_slow = duration > self.slow_input_threshold;
+        {
+            let mut inputs = lock(&self.inputs);
+            inputs.handled += 1;
+            inputs.max_duration_ms = inputs.max_duration_ms.max(as_millis(duration));
+            if is_slow {
+                inputs.slow += 1;
+            }
+        }
+        if is_slow {
+            self.logger.log(LogData::new(
+                LogLevel::Warning,
+                format!("The input of player {player_id} to game {game_id} took {} ms, which is more than the slow input threshold of {} ms. There are still {still_queued} inputs queued for the game", as_millis(duration), as_millis(self.slow_input_threshold)).as_str(),
+                "ServerMetrics",
+            ));
+        }
+    }
+
+    /// Records that a run of the background task with the given name was done, `lag` after it was due.
+    pub fn record_background_task(&self, name: &'static str, lag: Duration) {
+        let lag_ms = as_millis(lag);
+        let mut background_tasks = lock(&self.background_tasks);
+        let task = background_tasks.entry(name).or_default();
+        task.runs += 1;
+        task.last_lag_ms = lag_ms;
+        task.max_lag_ms = task.max_lag_ms.max(lag_ms);
+    }
+
+    /// Returns the metrics as they are now.
+    pub fn report(&self) -> MetricsReport {
+        let (mut queued_inputs, max_queued_inputs) = {
+            let queued_inputs = lock(&self.queued_inputs);
+            let per_game: Vec<GameQueueDepth> = queued_inputs
+                .per_game
+                .iter()
+                .map(|(game_id, depth)| GameQueueDepth { game_id: *game_id, queued_inputs: *depth })
+                .collect();
+            (per_game, queued_inputs.max_depth)
+        };
+        queued_inputs.sort_by_key(|game| Reverse(game.queued_inputs));
+        let mut background_tasks: Vec<BackgroundTaskReport> = lock(&self.background_tasks)
+            .iter()
+            .map(|(name, metrics)| BackgroundTaskReport { name, metrics: metrics.clone() })
+            .collect();
+        background_tasks.sort_by_key(|task| task.name);
+        MetricsReport {
+            queued_inputs,
+            max_queued_inputs,
+            lock_waits: lock(&self.lock_waits).clone(),
+            inputs: lock(&self.inputs).clone(),
+            slow_input_threshold_ms: as_millis(self.slow_input_threshold),
+            background_tasks,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::{sync::atomic::Ordering, thread, time::{Duration, Instant}};
+
+use actix_web::web;
+
+use crate::AppData;
+
+/// How often all the games are handed to the storage backend.
+const STORE_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Starts handing all the games to the storage backend on a separate thread every [`STORE_FLUSH_INTERVAL`], on top of the changes that are handed to it as they happen.
+/// The storage backend saves all of them whatever its persistence policy says, so that the changes the policy skips (like the lobby settings and the session clocks) are at most a flush old.
+/// A standby server doesn't save the games, since the primary server does. How long each flush takes, including waiting for the game controller, is recorded in the metrics as the lag of the flusher.
+pub fn start_store_flusher(app_data: web::Data<AppData>) {
+    thread::spawn(move || loop {
+        thread::sleep(STORE_FLUSH_INTERVAL);
+        if app_data.is_standby.load(Ordering::SeqCst) {
+            continue;
+        }
+        let due = Instant::now();
+        app_data.lock_game_controller().save_all();
+        app_data.metrics.record_background_task("store_flusher", due.elapsed());
+    });
+}
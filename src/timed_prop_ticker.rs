@@ -0,0 +1,22 @@
+use std::{sync::atomic::Ordering, thread, time::{Duration, Instant}};
+
+use actix_web::web;
+use game_core::game_data::constants::TIMED_PROP_CHECK_INTERVAL;
+
+use crate::AppData;
+
+/// Starts ticking the timed props of the games on a separate thread, independent of the turns and the requests of the players. The games are checked for due props every
+/// [`TIMED_PROP_CHECK_INTERVAL`] milliseconds, so a prop can tick up to that much late. A standby server doesn't tick the props, since it gets the ticks of the primary server through the replication.
+///
+/// How long each check takes, including waiting for the game controller, is recorded in the metrics as the lag of the ticker.
+pub fn start_timed_prop_ticker(app_data: web::Data<AppData>) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_millis(TIMED_PROP_CHECK_INTERVAL));
+        if app_data.is_standby.load(Ordering::SeqCst) {
+            continue;
+        }
+        let due = Instant::now();
+        app_data.lock_game_controller().tick_due_timed_props();
+        app_data.metrics.record_background_task("timed_prop_ticker", due.elapsed());
+    });
+}
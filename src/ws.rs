@@ -0,0 +1,171 @@
+use std::{collections::HashMap, sync::{atomic::{AtomicUsize, Ordering}, Arc, Mutex, MutexGuard, PoisonError}, time::{Duration, Instant}};
+
+use actix::{Actor, ActorContext, AsyncContext, Handler, Message, Recipient, StreamHandler};
+use actix_web_actors::ws;
+use game_core::{game_data::{custom_types::{ChatMessageID, GameID, PlayerID}, structs::gamestate::GameState}, update_publisher::UpdatePublisher};
+
+/// How often the server pings the clients to find out if the sockets are still open.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a client can go without answering a ping (or pinging the server) before its socket is closed.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// The new state of a game, as JSON, that is sent to the socket of a player.
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct GameUpdate(pub String);
+
+/// What is sent on a socket.
+#[derive(Clone, Copy, Debug)]
+pub enum SocketFeed {
+    /// The state of the game, every time an input to the game succeeds.
+    Game,
+    /// The new messages in the chat of the game as a list, every time a player sends one. Holds the id of the latest message the socket has been sent, if any.
+    Chat(Option<ChatMessageID>),
+}
+
+/// A socket that is open to a game.
+struct Subscription {
+    socket_id: usize,
+    /// The player that opened the socket.
+    player_id: PlayerID,
+    feed: SocketFeed,
+    recipient: Recipient<GameUpdate>,
+}
+
+/// The sockets that are open to each game.
+type GameSockets = HashMap<GameID, Vec<Subscription>>;
+
+/// The GameSocketHub keeps track of the WebSockets the players have opened to their games, and pushes the new state of a game (or the new chat messages) to them every time an input to the game succeeds.
+/// Each player gets the game without the objective cards of the other players until the game is finished.
+///
+/// It's cloned into every socket, so all the clones share the same sockets.
+#[derive(Clone, Default)]
+pub struct GameSocketHub {
+    sockets: Arc<Mutex<GameSockets>>,
+    next_socket_id: Arc<AtomicUsize>,
+}
+
+impl GameSocketHub {
+    /// Locks the open sockets. The lock is taken even if it was poisoned, since the sockets are always left in a usable state.
+    fn lock_sockets(&self) -> MutexGuard<'_, GameSockets> {
+        self.sockets.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Starts sending the updates of the game to the recipient, and returns the id of its socket.
+    fn subscribe(&self, game_id: GameID, player_id: PlayerID, feed: SocketFeed, recipient: Recipient<GameUpdate>) -> usize {
+        let socket_id = self.next_socket_id.fetch_add(1, Ordering::SeqCst);
+        self.lock_sockets().entry(game_id).or_default().push(Subscription { socket_id, player_id, feed, recipient });
+        socket_id
+    }
+
+    /// Stops sending the updates of the game to the socket with the given id.
+    fn unsubscribe(&self, game_id: GameID, socket_id: usize) {
+        let mut sockets = self.lock_sockets();
+        if let Some(game_sockets) = sockets.get_mut(&game_id) {
+            game_sockets.retain(|subscription| subscription.socket_id != socket_id);
+            if game_sockets.is_empty() {
+                sockets.remove(&game_id);
+            }
+        }
+    }
+}
+
+impl UpdatePublisher for GameSocketHub {
+    fn publish(&self, game: &GameState) {
+        let mut sockets = self.lock_sockets();
+        let Some(game_sockets) = sockets.get_mut(&game.id) else {
+            return;
+        };
+        // The players that have left the game should not get its updates anymore.
+        game_sockets.retain(|subscription| game.contains_player_with_unique_id(subscription.player_id));
+        for subscription in game_sockets.iter_mut() {
+            match &mut subscription.feed {
+                SocketFeed::Game => {
+                    // Every player gets its own copy of the game, without the objective cards of the other players.
+                    let mut shown_game = game.clone();
+                    shown_game.hide_objective_cards_from_others(Some(subscription.player_id));
+                    if let Ok(update) = serde_json::to_string(&shown_game) {
+                        subscription.recipient.do_send(GameUpdate(update));
+                    }
+                }
+                SocketFeed::Chat(last_message_id) => {
+                    let messages = game.chat_messages_since(*last_message_id);
+                    let (Some(last_message), Ok(update)) = (messages.last(), serde_json::to_string(messages)) else {
+                        continue;
+                    };
+                    *last_message_id = Some(last_message.id);
+                    subscription.recipient.do_send(GameUpdate(update));
+                }
+            }
+        }
+    }
+}
+
+/// The GameSocket is a WebSocket a player has opened to a game. It sends the state of the game (or its chat) when it's opened and every time it changes, and closes if the client stops answering the pings.
+/// The server ignores the messages the clients send on it, since the inputs are sent to `/games/input` like before.
+pub struct GameSocket {
+    hub: GameSocketHub,
+    game_id: GameID,
+    player_id: PlayerID,
+    feed: SocketFeed,
+    /// The state of the game (or its chat) when the socket was opened, sent as soon as it's open.
+    initial_update: Option<String>,
+    socket_id: Option<usize>,
+    last_heartbeat: Instant,
+}
+
+impl GameSocket {
+    pub fn new(hub: GameSocketHub, game_id: GameID, player_id: PlayerID, feed: SocketFeed, initial_update: String) -> Self {
+        Self { hub, game_id, player_id, feed, initial_update: Some(initial_update), socket_id: None, last_heartbeat: Instant::now() }
+    }
+}
+
+impl Actor for GameSocket {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, context: &mut Self::Context) {
+        self.socket_id = Some(self.hub.subscribe(self.game_id, self.player_id, self.feed, context.address().recipient()));
+        if let Some(initial_update) = self.initial_update.take() {
+            context.text(initial_update);
+        }
+        context.run_interval(HEARTBEAT_INTERVAL, |socket, context| {
+            if socket.last_heartbeat.elapsed() > CLIENT_TIMEOUT {
+                context.stop();
+                return;
+            }
+            context.ping(b"");
+        });
+    }
+
+    fn stopped(&mut self, _: &mut Self::Context) {
+        if let Some(socket_id) = self.socket_id {
+            self.hub.unsubscribe(self.game_id, socket_id);
+        }
+    }
+}
+
+impl Handler<GameUpdate> for GameSocket {
+    type Result = ();
+
+    fn handle(&mut self, update: GameUpdate, context: &mut Self::Context) {
+        context.text(update.0);
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for GameSocket {
+    fn handle(&mut self, message: Result<ws::Message, ws::ProtocolError>, context: &mut Self::Context) {
+        match message {
+            Ok(ws::Message::Ping(bytes)) => {
+                self.last_heartbeat = Instant::now();
+                context.pong(&bytes);
+            }
+            Ok(ws::Message::Pong(_)) => self.last_heartbeat = Instant::now(),
+            Ok(ws::Message::Close(reason)) => {
+                context.close(reason);
+                context.stop();
+            }
+            Ok(_) => (),
+            Err(_) => context.stop(),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use game_core::game_data::{
+    custom_types::{Emissions, GameID, SituationCardID, Timestamp, TurnNumber},
+    structs::{enacted_measure::Measure, gamestate::GameState, session_clock::current_timestamp},
+};
+use serde::{Deserialize, Serialize};
+
+/// The length of a day in milliseconds. The emission trends are grouped by day.
+pub const MILLISECONDS_PER_DAY: Timestamp = 24 * 60 * 60 * 1000;
+
+/// The GameRecord struct is what the analytics know about a game. It's updated every time a started game changes, and is kept after the game is removed from the server or the server is reset, so that a whole workshop series can be analysed.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct GameRecord {
+    pub game_id: GameID,
+    pub map_name: String,
+    /// The id of the situation card the game is played with, which decides the scenario of the game.
+    pub situation_card_id: Option<SituationCardID>,
+    pub turn_number: TurnNumber,
+    pub total_emissions: Emissions,
+    /// When the record was made, in milliseconds since the unix epoch.
+    pub updated_at: Timestamp,
+    /// A label for every measure the orchestrator has enacted in the game, like `Toll in Airport`.
+    pub measures: Vec<String>,
+}
+
+impl GameRecord {
+    /// Creates a record of the given game as it is now.
+    #[must_use]
+    pub fn new(game: &GameState) -> Self {
+        Self {
+            game_id: game.id,
+            map_name: game.map_name.clone(),
+            situation_card_id: game.situation_card.as_ref().map(|card| card.card_id),
+            turn_number: game.turn_number,
+            total_emissions: game.players.iter().map(|player| player.emissions).sum(),
+            updated_at: current_timestamp(),
+            measures: game.measure_ledger.iter().map(|enacted_measure| measure_label(&enacted_measure.measure)).collect(),
+        }
+    }
+}
+
+/// Returns a label that is the same for measures that do the same to the game, so that the measures can be counted across games.
+fn measure_label(measure: &Measure) -> String {
+    match measure {
+        Measure::DistrictModifier(modifier) => modifier.vehicle_type.map_or_else(
+            || format!("{:?} in {:?}", modifier.modifier, modifier.district),
+            |vehicle_type| format!("{:?} for {:?} in {:?}", modifier.modifier, vehicle_type, modifier.district),
+        ),
+        Measure::EdgeRestriction(restriction) => format!("{:?} between node {} and node {}", restriction.edge_restriction, restriction.node_one, restriction.node_two),
+    }
+}
+
+/// How many games have been played on a map and how many turns they lasted on average.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MapGameLength {
+    pub map_name: String,
+    pub game_count: i64,
+    pub average_turns: f64,
+}
+
+/// How many times a measure has been enacted, and in how many games.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MeasureUsage {
+    pub measure: String,
+    pub times_enacted: i64,
+    pub game_count: i64,
+}
+
+/// The average emissions of the games played with a situation card on one day.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct EmissionTrendPoint {
+    pub situation_card_id: Option<SituationCardID>,
+    /// The start of the day, in milliseconds since the unix epoch.
+    pub day: Timestamp,
+    pub game_count: i64,
+    pub average_emissions: f64,
+}
+
+/// A trait that defines the queries researchers can run across all the games a storage backend has recorded.
+#[async_trait]
+pub trait GameAnalytics {
+    /// Saves the record, replacing any earlier record of the game with the same id.
+    async fn record_game(&self, record: &GameRecord) -> Result<(), String>;
+
+    /// Returns the number of games and their average length for each map, sorted by the name of the map.
+    async fn average_game_length_per_map(&self) -> Result<Vec<MapGameLength>, String>;
+
+    /// Returns the measures that have been enacted the most times, at most `limit` of them.
+    async fn most_used_measures(&self, limit: u32) -> Result<Vec<MeasureUsage>, String>;
+
+    /// Returns the average emissions per day for each situation card, sorted by the situation card and then by the day.
+    async fn emission_trends_per_scenario(&self) -> Result<Vec<EmissionTrendPoint>, String>;
+}
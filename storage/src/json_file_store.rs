@@ -0,0 +1,158 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use game_core::game_data::{custom_types::GameID, structs::{game_history::GameHistory, game_snapshot::GameSnapshot}};
+
+use crate::game_store::{GameStore, QuarantinedGame};
+
+const FILE_EXTENSION: &str = "json";
+const REASON_FILE_EXTENSION: &str = "reason";
+/// The extension of the files the histories of the games are saved in, like `<game id>.history`.
+const HISTORY_FILE_EXTENSION: &str = "history";
+/// The name of the sub folder the quarantined saves are moved to.
+const QUARANTINE_FOLDER_NAME: &str = "quarantine";
+
+/// Saves each game as a JSON file named after the game id. This is meant for running the server on a laptop where no database is available.
+pub struct JsonFileStore {
+    folder: PathBuf,
+}
+
+impl JsonFileStore {
+    /// Creates a new JsonFileStore that saves the games in the given folder. The folder is created if it does not exist.
+    pub async fn new(folder: &str) -> Result<Self, String> {
+        let folder = PathBuf::from(folder);
+        tokio::fs::create_dir_all(&folder)
+            .await
+            .map_err(|e| format!("Failed to create the folder {} because: {e}", folder.display()))?;
+        Ok(Self { folder })
+    }
+
+    fn path_of(&self, game_id: GameID) -> PathBuf {
+        self.folder.join(format!("{game_id}.{FILE_EXTENSION}"))
+    }
+
+    fn history_path_of(&self, game_id: GameID) -> PathBuf {
+        self.folder.join(format!("{game_id}.{HISTORY_FILE_EXTENSION}"))
+    }
+
+    /// Writes the data to the file at the path through a temporary file, so that a crash while saving never leaves a half written save behind.
+    async fn write_atomically(path: &Path, data: String) -> Result<(), String> {
+        let temporary_path = path.with_extension(format!("{}.tmp", path.extension().and_then(|extension| extension.to_str()).unwrap_or_default()));
+        tokio::fs::write(&temporary_path, data)
+            .await
+            .map_err(|e| format!("Failed to write {} because: {e}", temporary_path.display()))?;
+        tokio::fs::rename(&temporary_path, path)
+            .await
+            .map_err(|e| format!("Failed to move {} to {} because: {e}", temporary_path.display(), path.display()))
+    }
+
+    async fn remove_file_if_it_exists(path: &Path) -> Result<(), String> {
+        match tokio::fs::remove_file(path).await {
+            Ok(_) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("Failed to delete {} because: {e}", path.display())),
+        }
+    }
+
+    fn quarantine_folder(&self) -> PathBuf {
+        self.folder.join(QUARANTINE_FOLDER_NAME)
+    }
+
+    async fn game_ids_in_folder(folder: &Path) -> Result<Vec<GameID>, String> {
+        let mut entries = tokio::fs::read_dir(folder)
+            .await
+            .map_err(|e| format!("Failed to read the folder {} because: {e}", folder.display()))?;
+        let mut game_ids = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| format!("Failed to read the folder {} because: {e}", folder.display()))?
+        {
+            let path = entry.path();
+            if path.extension().and_then(|extension| extension.to_str()) != Some(FILE_EXTENSION) {
+                continue;
+            }
+            if let Some(game_id) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<GameID>().ok())
+            {
+                game_ids.push(game_id);
+            }
+        }
+        Ok(game_ids)
+    }
+}
+
+#[async_trait]
+impl GameStore for JsonFileStore {
+    async fn save(&self, snapshot: &GameSnapshot) -> Result<(), String> {
+        let data = serde_json::to_string(snapshot).map_err(|e| format!("Failed to serialize the game because: {e}"))?;
+        Self::write_atomically(&self.path_of(snapshot.game.id), data).await
+    }
+
+    async fn load(&self, game_id: GameID) -> Result<GameSnapshot, String> {
+        let path = self.path_of(game_id);
+        let data = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|e| format!("Failed to read {} because: {e}", path.display()))?;
+        serde_json::from_str(&data).map_err(|e| format!("Failed to deserialize {} because: {e}", path.display()))
+    }
+
+    async fn list(&self) -> Result<Vec<GameID>, String> {
+        Self::game_ids_in_folder(&self.folder).await
+    }
+
+    async fn delete(&self, game_id: GameID) -> Result<(), String> {
+        Self::remove_file_if_it_exists(&self.path_of(game_id)).await?;
+        Self::remove_file_if_it_exists(&self.history_path_of(game_id)).await
+    }
+
+    async fn save_history(&self, game_id: GameID, history: &GameHistory) -> Result<(), String> {
+        let data = serde_json::to_string(history).map_err(|e| format!("Failed to serialize the history of the game because: {e}"))?;
+        Self::write_atomically(&self.history_path_of(game_id), data).await
+    }
+
+    async fn load_history(&self, game_id: GameID) -> Result<Option<GameHistory>, String> {
+        let path = self.history_path_of(game_id);
+        let data = match tokio::fs::read_to_string(&path).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("Failed to read {} because: {e}", path.display())),
+        };
+        serde_json::from_str(&data).map(Some).map_err(|e| format!("Failed to deserialize {} because: {e}", path.display()))
+    }
+
+    async fn quarantine(&self, game_id: GameID, reason: &str) -> Result<(), String> {
+        let quarantine_folder = self.quarantine_folder();
+        tokio::fs::create_dir_all(&quarantine_folder)
+            .await
+            .map_err(|e| format!("Failed to create the folder {} because: {e}", quarantine_folder.display()))?;
+        let path = self.path_of(game_id);
+        let quarantined_path = quarantine_folder.join(format!("{game_id}.{FILE_EXTENSION}"));
+        tokio::fs::rename(&path, &quarantined_path)
+            .await
+            .map_err(|e| format!("Failed to move {} to {} because: {e}", path.display(), quarantined_path.display()))?;
+        let reason_path = quarantine_folder.join(format!("{game_id}.{REASON_FILE_EXTENSION}"));
+        tokio::fs::write(&reason_path, reason)
+            .await
+            .map_err(|e| format!("Failed to write {} because: {e}", reason_path.display()))?;
+        Self::remove_file_if_it_exists(&self.history_path_of(game_id)).await
+    }
+
+    async fn list_quarantined(&self) -> Result<Vec<QuarantinedGame>, String> {
+        let quarantine_folder = self.quarantine_folder();
+        if !quarantine_folder.exists() {
+            return Ok(Vec::new());
+        }
+        let mut quarantined_games = Vec::new();
+        for game_id in Self::game_ids_in_folder(&quarantine_folder).await? {
+            let reason_path = quarantine_folder.join(format!("{game_id}.{REASON_FILE_EXTENSION}"));
+            let reason = tokio::fs::read_to_string(&reason_path)
+                .await
+                .unwrap_or_else(|e| format!("The reason could not be read from {} because: {e}", reason_path.display()));
+            quarantined_games.push(QuarantinedGame { game_id, reason });
+        }
+        Ok(quarantined_games)
+    }
+}
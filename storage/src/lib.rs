@@ -0,0 +1,16 @@
+//! This library contains the GameStore trait and the storage backends that implement it.
+
+/// This module contains the GameAnalytics trait and the records and results of the queries researchers can run across many games.
+pub mod analytics;
+/// This module contains the GameStore trait and the config used to choose which storage backend to use.
+pub mod game_store;
+/// This module contains a storage backend that saves each game as a JSON file.
+pub mod json_file_store;
+/// This module contains the policy for when the changes of the games are saved, so that slow disks are not written to for every input.
+pub mod persistence_policy;
+/// This module contains a storage backend that saves the games in a Postgres database.
+pub mod postgres_store;
+/// This module contains a storage backend that saves the games in a SQLite database.
+pub mod sqlite_store;
+/// This module contains a replicator that saves every change of the games to a storage backend.
+pub mod store_replicator;
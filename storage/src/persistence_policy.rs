@@ -0,0 +1,92 @@
+use std::{collections::HashMap, str::FromStr};
+
+use game_core::game_data::{custom_types::{GameID, PlayerID, RoundNumber, TurnNumber}, enums::{game_status::GameStatus, in_game_id::InGameID}, structs::game_snapshot::GameSnapshot};
+use serde::{Deserialize, Serialize};
+
+/// When the changes of a game are saved to the storage backend by the [`StoreReplicator`].
+///
+/// [`StoreReplicator`]: ../store_replicator/struct.StoreReplicator.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PersistencePolicy {
+    /// Saves the game after every change, so that nothing is lost if the server goes down, at the cost of a write for every input.
+    EveryChange,
+    /// Saves the game when it's first seen, every time a turn or round starts, when it's finished and when a player joins, leaves or changes role. The other changes within a turn are only kept in memory
+    /// and in the events and log of the game until the next of these or the next periodic flush of all the games, so at most the current turn of each game is lost if the server goes down, while a slow disk gets a write per turn instead of one per input.
+    #[default]
+    TurnBoundary,
+}
+
+impl FromStr for PersistencePolicy {
+    type Err = String;
+
+    /// Parses the name of a persistence policy, like `turn_boundary` or `EveryChange`.
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        match policy.to_ascii_lowercase().replace(['_', '-'], "").as_str() {
+            "everychange" => Ok(Self::EveryChange),
+            "turnboundary" => Ok(Self::TurnBoundary),
+            _ => Err(format!("{policy} is not a persistence policy! The persistence policies are every_change and turn_boundary.")),
+        }
+    }
+}
+
+/// Where a game is in its turns, whether it's finished and who plays it in which role. A game is saved under [`PersistencePolicy::TurnBoundary`] when this changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TurnBoundary {
+    turn_number: TurnNumber,
+    round_number: RoundNumber,
+    is_lobby: bool,
+    status: GameStatus,
+    players: Vec<(PlayerID, InGameID)>,
+}
+
+impl TurnBoundary {
+    fn of(snapshot: &GameSnapshot) -> Self {
+        Self {
+            turn_number: snapshot.game.turn_number,
+            round_number: snapshot.game.round_number,
+            is_lobby: snapshot.game.is_lobby,
+            status: snapshot.game.status.clone(),
+            players: snapshot.game.players.iter().map(|player| (player.unique_id, player.in_game_id)).collect(),
+        }
+    }
+}
+
+/// The PersistenceFilter struct decides which changes of the games are saved under a [`PersistencePolicy`], by remembering where each game was in its turns when it was last saved.
+#[derive(Debug)]
+pub struct PersistenceFilter {
+    policy: PersistencePolicy,
+    saved_boundaries: HashMap<GameID, TurnBoundary>,
+}
+
+impl PersistenceFilter {
+    #[must_use]
+    pub fn new(policy: PersistencePolicy) -> Self {
+        Self {
+            policy,
+            saved_boundaries: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` if the change of the game in the snapshot should be saved now. The changes that are not saved are still in the game controller, and are saved with the next turn or round,
+    /// or with the next periodic flush of all the games, which is always saved.
+    pub fn should_save(&self, snapshot: &GameSnapshot, is_flush: bool) -> bool {
+        is_flush || self.policy == PersistencePolicy::EveryChange || self.saved_boundaries.get(&snapshot.game.id) != Some(&TurnBoundary::of(snapshot))
+    }
+
+    /// Remembers that the game in the snapshot was saved, so that the rest of the changes in the same turn are not.
+    pub fn mark_saved(&mut self, snapshot: &GameSnapshot) {
+        if self.policy == PersistencePolicy::TurnBoundary {
+            self.saved_boundaries.insert(snapshot.game.id, TurnBoundary::of(snapshot));
+        }
+    }
+
+    /// Forgets the game with the given id, like when it has been removed.
+    pub fn forget(&mut self, game_id: GameID) {
+        self.saved_boundaries.remove(&game_id);
+    }
+
+    /// Forgets all the games, like when the server has been reset.
+    pub fn clear(&mut self) {
+        self.saved_boundaries.clear();
+    }
+}
@@ -0,0 +1,255 @@
+use async_trait::async_trait;
+use game_core::game_data::{custom_types::{GameID, SituationCardID, Timestamp}, structs::{game_history::GameHistory, game_snapshot::GameSnapshot}};
+use sqlx::{sqlite::{SqliteConnectOptions, SqlitePool}, Row};
+use std::str::FromStr;
+
+use crate::{analytics::{EmissionTrendPoint, GameAnalytics, GameRecord, MapGameLength, MeasureUsage, MILLISECONDS_PER_DAY}, game_store::{GameStore, QuarantinedGame}};
+
+/// Saves the games in a SQLite database, with one row per game that has the game serialized as JSON.
+pub struct SqliteStore {
+    pool: SqlitePool,
+}
+
+impl SqliteStore {
+    /// Connects to the SQLite database with the given url and creates the games table if it does not exist. The database file is created if it does not exist.
+    pub async fn connect(url: &str) -> Result<Self, String> {
+        let options = SqliteConnectOptions::from_str(url)
+            .map_err(|e| format!("Invalid SQLite url {url} because: {e}"))?
+            .create_if_missing(true);
+        let pool = SqlitePool::connect_with(options)
+            .await
+            .map_err(|e| format!("Failed to connect to the SQLite database because: {e}"))?;
+        sqlx::query("CREATE TABLE IF NOT EXISTS games (id INTEGER PRIMARY KEY, data TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .map_err(|e| format!("Failed to create the games table because: {e}"))?;
+        sqlx::query("CREATE TABLE IF NOT EXISTS quarantined_games (id INTEGER PRIMARY KEY, data TEXT NOT NULL, reason TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .map_err(|e| format!("Failed to create the quarantined games table because: {e}"))?;
+        sqlx::query("CREATE TABLE IF NOT EXISTS game_histories (id INTEGER PRIMARY KEY, data TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .map_err(|e| format!("Failed to create the game histories table because: {e}"))?;
+        sqlx::query("CREATE TABLE IF NOT EXISTS game_records (id INTEGER PRIMARY KEY, map_name TEXT NOT NULL, situation_card_id INTEGER, turn_number INTEGER NOT NULL, total_emissions INTEGER NOT NULL, updated_at INTEGER NOT NULL)")
+            .execute(&pool)
+            .await
+            .map_err(|e| format!("Failed to create the game records table because: {e}"))?;
+        sqlx::query("CREATE TABLE IF NOT EXISTS game_record_measures (game_id INTEGER NOT NULL, measure TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .map_err(|e| format!("Failed to create the game record measures table because: {e}"))?;
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl GameStore for SqliteStore {
+    async fn save(&self, snapshot: &GameSnapshot) -> Result<(), String> {
+        let data = serde_json::to_string(snapshot).map_err(|e| format!("Failed to serialize the game because: {e}"))?;
+        sqlx::query("INSERT INTO games (id, data) VALUES (?1, ?2) ON CONFLICT (id) DO UPDATE SET data = excluded.data")
+            .bind(snapshot.game.id.0)
+            .bind(data)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to save the game with id {} because: {e}", snapshot.game.id))?;
+        Ok(())
+    }
+
+    async fn load(&self, game_id: GameID) -> Result<GameSnapshot, String> {
+        let row = sqlx::query("SELECT data FROM games WHERE id = ?1")
+            .bind(game_id.0)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load the game with id {game_id} because: {e}"))?
+            .ok_or_else(|| format!("There is no saved game with id {game_id}"))?;
+        let data: String = row.try_get("data").map_err(|e| format!("Failed to read the game with id {game_id} because: {e}"))?;
+        serde_json::from_str(&data).map_err(|e| format!("Failed to deserialize the game with id {game_id} because: {e}"))
+    }
+
+    async fn list(&self) -> Result<Vec<GameID>, String> {
+        let rows = sqlx::query("SELECT id FROM games")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to list the saved games because: {e}"))?;
+        rows.iter()
+            .map(|row| row.try_get::<i32, _>("id").map(GameID).map_err(|e| format!("Failed to read a game id because: {e}")))
+            .collect()
+    }
+
+    async fn delete(&self, game_id: GameID) -> Result<(), String> {
+        for query in ["DELETE FROM games WHERE id = ?1", "DELETE FROM game_histories WHERE id = ?1"] {
+            sqlx::query(query)
+                .bind(game_id.0)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to delete the game with id {game_id} because: {e}"))?;
+        }
+        Ok(())
+    }
+
+    async fn save_history(&self, game_id: GameID, history: &GameHistory) -> Result<(), String> {
+        let data = serde_json::to_string(history).map_err(|e| format!("Failed to serialize the history of the game because: {e}"))?;
+        sqlx::query("INSERT INTO game_histories (id, data) VALUES (?1, ?2) ON CONFLICT (id) DO UPDATE SET data = excluded.data")
+            .bind(game_id.0)
+            .bind(data)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to save the history of the game with id {game_id} because: {e}"))?;
+        Ok(())
+    }
+
+    async fn load_history(&self, game_id: GameID) -> Result<Option<GameHistory>, String> {
+        let Some(row) = sqlx::query("SELECT data FROM game_histories WHERE id = ?1")
+            .bind(game_id.0)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load the history of the game with id {game_id} because: {e}"))?
+        else {
+            return Ok(None);
+        };
+        let data: String = row.try_get("data").map_err(|e| format!("Failed to read the history of the game with id {game_id} because: {e}"))?;
+        serde_json::from_str(&data).map(Some).map_err(|e| format!("Failed to deserialize the history of the game with id {game_id} because: {e}"))
+    }
+
+    async fn quarantine(&self, game_id: GameID, reason: &str) -> Result<(), String> {
+        let map_error = |e: sqlx::Error| format!("Failed to quarantine the game with id {game_id} because: {e}");
+        let mut transaction = self.pool.begin().await.map_err(map_error)?;
+        sqlx::query("DELETE FROM quarantined_games WHERE id = ?1")
+            .bind(game_id.0)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_error)?;
+        sqlx::query("INSERT INTO quarantined_games (id, data, reason) SELECT id, data, ?2 FROM games WHERE id = ?1")
+            .bind(game_id.0)
+            .bind(reason)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_error)?;
+        sqlx::query("DELETE FROM games WHERE id = ?1")
+            .bind(game_id.0)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_error)?;
+        sqlx::query("DELETE FROM game_histories WHERE id = ?1")
+            .bind(game_id.0)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_error)?;
+        transaction.commit().await.map_err(map_error)
+    }
+
+    fn analytics(&self) -> Option<&(dyn GameAnalytics + Send + Sync)> {
+        Some(self)
+    }
+
+    async fn list_quarantined(&self) -> Result<Vec<QuarantinedGame>, String> {
+        let rows = sqlx::query("SELECT id, reason FROM quarantined_games")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to list the quarantined games because: {e}"))?;
+        rows.iter()
+            .map(|row| {
+                Ok(QuarantinedGame {
+                    game_id: row.try_get::<i32, _>("id").map(GameID).map_err(|e| format!("Failed to read a game id because: {e}"))?,
+                    reason: row.try_get("reason").map_err(|e| format!("Failed to read a reason because: {e}"))?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl GameAnalytics for SqliteStore {
+    async fn record_game(&self, record: &GameRecord) -> Result<(), String> {
+        let map_error = |e: sqlx::Error| format!("Failed to record the game with id {} for the analytics because: {e}", record.game_id);
+        let mut transaction = self.pool.begin().await.map_err(map_error)?;
+        sqlx::query(
+            "INSERT INTO game_records (id, map_name, situation_card_id, turn_number, total_emissions, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6) \
+             ON CONFLICT (id) DO UPDATE SET map_name = excluded.map_name, situation_card_id = excluded.situation_card_id, turn_number = excluded.turn_number, total_emissions = excluded.total_emissions, updated_at = excluded.updated_at",
+        )
+        .bind(record.game_id.0)
+        .bind(&record.map_name)
+        .bind(record.situation_card_id.map(i32::from))
+        .bind(i64::from(record.turn_number))
+        .bind(i64::from(record.total_emissions))
+        .bind(i64::try_from(record.updated_at).unwrap_or(i64::MAX))
+        .execute(&mut transaction)
+        .await
+        .map_err(map_error)?;
+        sqlx::query("DELETE FROM game_record_measures WHERE game_id = ?1")
+            .bind(record.game_id.0)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_error)?;
+        for measure in &record.measures {
+            sqlx::query("INSERT INTO game_record_measures (game_id, measure) VALUES (?1, ?2)")
+                .bind(record.game_id.0)
+                .bind(measure)
+                .execute(&mut transaction)
+                .await
+                .map_err(map_error)?;
+        }
+        transaction.commit().await.map_err(map_error)
+    }
+
+    async fn average_game_length_per_map(&self) -> Result<Vec<MapGameLength>, String> {
+        let map_error = |e: sqlx::Error| format!("Failed to get the average game length per map because: {e}");
+        let rows = sqlx::query("SELECT map_name, COUNT(*) AS game_count, AVG(turn_number) AS average_turns FROM game_records GROUP BY map_name ORDER BY map_name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_error)?;
+        rows.iter()
+            .map(|row| {
+                Ok(MapGameLength {
+                    map_name: row.try_get("map_name").map_err(map_error)?,
+                    game_count: row.try_get("game_count").map_err(map_error)?,
+                    average_turns: row.try_get("average_turns").map_err(map_error)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn most_used_measures(&self, limit: u32) -> Result<Vec<MeasureUsage>, String> {
+        let map_error = |e: sqlx::Error| format!("Failed to get the most used measures because: {e}");
+        let rows = sqlx::query(
+            "SELECT measure, COUNT(*) AS times_enacted, COUNT(DISTINCT game_id) AS game_count FROM game_record_measures \
+             GROUP BY measure ORDER BY times_enacted DESC, measure LIMIT ?1",
+        )
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_error)?;
+        rows.iter()
+            .map(|row| {
+                Ok(MeasureUsage {
+                    measure: row.try_get("measure").map_err(map_error)?,
+                    times_enacted: row.try_get("times_enacted").map_err(map_error)?,
+                    game_count: row.try_get("game_count").map_err(map_error)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn emission_trends_per_scenario(&self) -> Result<Vec<EmissionTrendPoint>, String> {
+        let map_error = |e: sqlx::Error| format!("Failed to get the emission trends per scenario because: {e}");
+        let rows = sqlx::query(
+            "SELECT situation_card_id, (updated_at / ?1) * ?1 AS day, COUNT(*) AS game_count, AVG(total_emissions) AS average_emissions FROM game_records \
+             GROUP BY situation_card_id, day ORDER BY situation_card_id, day",
+        )
+        .bind(i64::try_from(MILLISECONDS_PER_DAY).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_error)?;
+        rows.iter()
+            .map(|row| {
+                Ok(EmissionTrendPoint {
+                    situation_card_id: row.try_get::<Option<i32>, _>("situation_card_id").map_err(map_error)?.and_then(|id| SituationCardID::try_from(id).ok()),
+                    day: Timestamp::try_from(row.try_get::<i64, _>("day").map_err(map_error)?).unwrap_or_default(),
+                    game_count: row.try_get("game_count").map_err(map_error)?,
+                    average_emissions: row.try_get("average_emissions").map_err(map_error)?,
+                })
+            })
+            .collect()
+    }
+}
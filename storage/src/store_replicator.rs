@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use game_core::{game_data::structs::gamestate::GameState, replication::{ReplicationEntry, StateReplicator}};
+use logging::{log_sender::LogSender, logger::{LogData, LogLevel}};
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+use crate::{analytics::GameRecord, game_store::{delete_all_games, GameStore}, persistence_policy::{PersistenceFilter, PersistencePolicy}};
+
+/// The StoreReplicator saves the changes of the games to a [`GameStore`], either every change or only when a turn or round starts depending on the [`PersistencePolicy`].
+///
+/// The games handed to it by a periodic flush (see [`StateReplicator::flush`]) are always saved, to catch what changes without a new turn.
+/// The saving is done in order by a background task, so the game controller is never blocked by a slow storage backend.
+/// Changes to the player ids and local players are ignored since the ids of the players in a game are saved together with the game.
+/// The history of a game is saved every time it's sent, like at the start of every turn, while the inputs added to its replay log in between are only saved with the next history.
+/// If the store supports analytics, every started game is also recorded for the analytics, and the records are kept when the game is removed.
+///
+/// [`GameStore`]: ../game_store/trait.GameStore.html
+/// [`PersistencePolicy`]: ../persistence_policy/enum.PersistencePolicy.html
+pub struct StoreReplicator {
+    /// The entries to save, and whether each was handed over by a periodic flush.
+    sender: UnboundedSender<(ReplicationEntry, bool)>,
+}
+
+impl StoreReplicator {
+    /// Creates a new StoreReplicator that saves the games to the given store when the policy says so. Must be called from within a tokio runtime.
+    #[must_use]
+    pub fn new(store: Arc<dyn GameStore + Send + Sync>, policy: PersistencePolicy, logger: LogSender) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<(ReplicationEntry, bool)>();
+        tokio::spawn(async move {
+            let mut persistence_filter = PersistenceFilter::new(policy);
+            while let Some((entry, is_flush)) = receiver.recv().await {
+                let result = match entry {
+                    ReplicationEntry::GameUpdated(snapshot) if !persistence_filter.should_save(&snapshot, is_flush) => Ok(()),
+                    ReplicationEntry::GameUpdated(snapshot) => match store.save(&snapshot).await {
+                        Ok(()) => {
+                            persistence_filter.mark_saved(&snapshot);
+                            record_for_analytics(store.as_ref(), &snapshot.game).await
+                        }
+                        Err(e) => Err(e),
+                    },
+                    ReplicationEntry::GameHistoryUpdated(game_id, history) => store.save_history(game_id, &history).await,
+                    ReplicationEntry::GameRemoved(game_id) => {
+                        persistence_filter.forget(game_id);
+                        store.delete(game_id).await
+                    }
+                    ReplicationEntry::ServerReset => {
+                        persistence_filter.clear();
+                        delete_all_games(store.as_ref()).await
+                    }
+                    ReplicationEntry::PlayerIdAdded(_)
+                    | ReplicationEntry::PlayerIdRemoved(_)
+                    | ReplicationEntry::LocalPlayersUpdated(_)
+                    | ReplicationEntry::ReplayEntryAppended(..) => Ok(()),
+                };
+                if let Err(e) = result {
+                    logger.log(LogData::new(LogLevel::Error, format!("Failed to store a change of the games because: {e}").as_str(), "StoreReplicator"));
+                }
+            }
+        });
+        Self { sender }
+    }
+}
+
+/// Records the game for the analytics if the store supports analytics. Games that have not been started are not recorded, since nothing has been played in them yet.
+/// A game that is back in the lobby after being started (like a game forked from an earlier turn) has played rounds, so it's still recorded.
+async fn record_for_analytics(store: &(dyn GameStore + Send + Sync), game: &GameState) -> Result<(), String> {
+    let is_started = game.round_number > 0 || !game.is_lobby;
+    match store.analytics() {
+        Some(analytics) if is_started => analytics.record_game(&GameRecord::new(game)).await,
+        _ => Ok(()),
+    }
+}
+
+impl StateReplicator for StoreReplicator {
+    fn replicate(&self, entry: ReplicationEntry) {
+        // The receiver is only dropped if the background task has stopped, in which case there is nothing more to do.
+        let _ = self.sender.send((entry, false));
+    }
+
+    fn flush(&self, entry: ReplicationEntry) {
+        let _ = self.sender.send((entry, true));
+    }
+}